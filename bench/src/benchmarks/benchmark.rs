@@ -75,6 +75,7 @@ pub trait Benchmarkable {
                     .create_stream(&CreateStream {
                         stream_id: Some(stream_id),
                         name,
+                        quotas: None,
                     })
                     .await?;
 
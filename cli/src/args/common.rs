@@ -2,6 +2,7 @@ use clap::ValueEnum;
 use iggy::cli::client::get_clients::GetClientsOutput;
 use iggy::cli::consumer_group::get_consumer_groups::GetConsumerGroupsOutput;
 use iggy::cli::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokensOutput;
+use iggy::cli::roles::get_roles::GetRolesOutput;
 use iggy::cli::streams::get_streams::GetStreamsOutput;
 use iggy::cli::topics::get_topics::GetTopicsOutput;
 use iggy::cli::users::get_users::GetUsersOutput;
@@ -48,6 +49,15 @@ impl From<ListMode> for GetUsersOutput {
     }
 }
 
+impl From<ListMode> for GetRolesOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetRolesOutput::Table,
+            ListMode::List => GetRolesOutput::List,
+        }
+    }
+}
+
 impl From<ListMode> for GetClientsOutput {
     fn from(mode: ListMode) -> Self {
         match mode {
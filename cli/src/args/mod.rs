@@ -5,6 +5,7 @@ pub(crate) mod message;
 pub(crate) mod partition;
 pub(crate) mod permissions;
 pub(crate) mod personal_access_token;
+pub(crate) mod role;
 pub(crate) mod stream;
 pub(crate) mod system;
 pub(crate) mod topic;
@@ -13,8 +14,9 @@ pub(crate) mod user;
 use self::user::UserAction;
 use crate::args::{
     client::ClientAction, consumer_group::ConsumerGroupAction, message::MessageAction,
-    partition::PartitionAction, personal_access_token::PersonalAccessTokenAction,
-    stream::StreamAction, system::PingArgs, topic::TopicAction,
+    partition::PartitionAction, personal_access_token::PersonalAccessTokenAction, role::RoleAction,
+    stream::StreamAction, system::ConfigAction, system::LoggingAction, system::PingArgs,
+    topic::TopicAction,
 };
 use clap::{Args, Command as ClapCommand};
 use clap::{Parser, Subcommand};
@@ -113,12 +115,21 @@ pub(crate) enum Command {
     /// Collect basic Iggy server statistics like number of streams, topics, partitions, etc.
     /// Server OS name, version, etc. are also collected.
     Stats,
+    /// server configuration operations
+    #[command(subcommand)]
+    Config(ConfigAction),
+    /// server logging operations
+    #[command(subcommand)]
+    Logging(LoggingAction),
     /// personal access token operations
     #[command(subcommand)]
     Pat(PersonalAccessTokenAction),
     /// user operations
     #[command(subcommand, visible_alias = "u")]
     User(UserAction),
+    /// role operations
+    #[command(subcommand, visible_alias = "r")]
+    Role(RoleAction),
     /// client operations
     #[command(subcommand, visible_alias = "c")]
     Client(ClientAction),
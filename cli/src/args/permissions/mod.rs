@@ -40,15 +40,18 @@ impl From<PermissionsArgs> for Option<Permissions> {
             (Some(global), true) => Some(Permissions {
                 global: global.into(),
                 streams: None,
+                stream_patterns: None,
             }),
             (Some(global), false) => Some(Permissions {
                 global: global.into(),
                 streams: Some(stream_permissions),
+                stream_patterns: None,
             }),
             (None, true) => None,
             (None, false) => Some(Permissions {
                 global: Default::default(),
                 streams: Some(stream_permissions),
+                stream_patterns: None,
             }),
         }
     }
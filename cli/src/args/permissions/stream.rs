@@ -289,6 +289,7 @@ mod tests {
                     poll_messages: true,
                     send_messages: true,
                     topics: None,
+                    topic_patterns: None,
                 }
             }
         );
@@ -304,6 +305,7 @@ mod tests {
                     poll_messages: false,
                     send_messages: false,
                     topics: None,
+                    topic_patterns: None,
                 }
             }
         );
@@ -319,6 +321,7 @@ mod tests {
                     poll_messages: false,
                     send_messages: true,
                     topics: None,
+                    topic_patterns: None,
                 }
             }
         );
@@ -334,6 +337,7 @@ mod tests {
                     poll_messages: false,
                     send_messages: false,
                     topics: None,
+                    topic_patterns: None,
                 }
             }
         );
@@ -349,6 +353,7 @@ mod tests {
                     poll_messages: false,
                     send_messages: true,
                     topics: None,
+                    topic_patterns: None,
                 }
             }
         );
@@ -383,6 +388,7 @@ mod tests {
                             }
                         )
                     ])),
+                    topic_patterns: None,
                 }
             }
         );
@@ -418,6 +424,7 @@ mod tests {
                             }
                         )
                     ])),
+                    topic_patterns: None,
                 }
             }
         );
@@ -437,6 +444,7 @@ mod tests {
                     poll_messages: true,
                     send_messages: true,
                     topics: None,
+                    topic_patterns: None,
                 }
             }
         );
@@ -452,6 +460,7 @@ mod tests {
                     poll_messages: false,
                     send_messages: false,
                     topics: None,
+                    topic_patterns: None,
                 }
             }
         );
@@ -467,6 +476,7 @@ mod tests {
                     poll_messages: false,
                     send_messages: true,
                     topics: None,
+                    topic_patterns: None,
                 }
             }
         );
@@ -482,6 +492,7 @@ mod tests {
                     poll_messages: false,
                     send_messages: true,
                     topics: None,
+                    topic_patterns: None,
                 }
             }
         );
@@ -516,6 +527,7 @@ mod tests {
                             }
                         )
                     ])),
+                    topic_patterns: None,
                 }
             }
         );
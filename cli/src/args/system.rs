@@ -1,4 +1,5 @@
-use clap::Args;
+use clap::{Args, Subcommand};
+use iggy::utils::duration::IggyDuration;
 
 #[derive(Debug, Clone, Args)]
 pub(crate) struct PingArgs {
@@ -6,3 +7,44 @@ pub(crate) struct PingArgs {
     #[arg(short, long, default_value_t = 1)]
     pub(crate) count: u32,
 }
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum ConfigAction {
+    /// Reload iggy server configuration
+    ///
+    /// Server reads its configuration again and applies the settings which
+    /// can be changed at runtime (log level, cache size, intervals of the background
+    /// tasks and TLS certificates). Changes of any other settings are reported
+    /// as requiring the server restart.
+    ///
+    /// Examples:
+    ///  iggy config reload
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Reload,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum LoggingAction {
+    /// Set iggy server log filter
+    ///
+    /// Log filter overrides the configured log level until it's reverted
+    /// (after the optional time) or the next filter is set.
+    ///
+    /// Examples:
+    ///  iggy logging set debug
+    ///  iggy logging set info,server::streaming=debug --revert-after 10m
+    #[clap(verbatim_doc_comment, visible_alias = "s")]
+    Set(LoggingSetArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct LoggingSetArgs {
+    /// Log filter directives, e.g. "info,server::streaming=debug"
+    pub(crate) filter: String,
+    /// Time after which the configured log level is restored
+    ///
+    /// Time must be expressed in human readable format like 15min 30s
+    /// (skipping the option keeps the filter until the next one is set)
+    #[arg(short, long, value_parser = clap::value_parser!(IggyDuration))]
+    pub(crate) revert_after: Option<IggyDuration>,
+}
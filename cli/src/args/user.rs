@@ -83,6 +83,30 @@ pub(crate) enum UserAction {
     ///  iggy user permissions client
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Permissions(UserPermissionsArgs),
+    /// Set roles for user with given ID
+    ///
+    /// The user ID can be specified as either a username or an ID. The provided
+    /// roles replace the ones assigned so far, if no roles are provided, all the
+    /// roles are unassigned from the specified user. The effective permissions
+    /// of the user are the union of its own permissions and the permissions of its roles.
+    ///
+    /// Examples:
+    ///  iggy user roles 2 1 3
+    ///  iggy user roles client 1
+    ///  iggy user roles client
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Roles(UserRolesArgs),
+    /// Unlock user with given ID
+    ///
+    /// The user ID can be specified as either a username or an ID. Unlocking
+    /// clears the lockout caused by too many failed login attempts, and resets
+    /// the counter of failed login attempts for the specified user.
+    ///
+    /// Examples:
+    ///  iggy user unlock 2
+    ///  iggy user unlock testuser
+    #[clap(verbatim_doc_comment, visible_alias = "u")]
+    Unlock(UserUnlockArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -286,3 +310,23 @@ pub(crate) struct UserPermissionsArgs {
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UserRolesArgs {
+    /// User ID to update
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+    /// Role IDs to assign
+    ///
+    /// Unique numeric IDs of the roles to assign to the user
+    pub(crate) role_ids: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UserUnlockArgs {
+    /// User ID to unlock
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+}
@@ -5,8 +5,8 @@ mod logging;
 
 use crate::args::{
     client::ClientAction, consumer_group::ConsumerGroupAction, permissions::PermissionsArgs,
-    personal_access_token::PersonalAccessTokenAction, stream::StreamAction, topic::TopicAction,
-    Command, IggyConsoleArgs,
+    personal_access_token::PersonalAccessTokenAction, role::RoleAction, stream::StreamAction,
+    system::ConfigAction, system::LoggingAction, topic::TopicAction, Command, IggyConsoleArgs,
 };
 use crate::credentials::IggyCredentials;
 use crate::error::IggyCmdError;
@@ -29,11 +29,18 @@ use iggy::cli::{
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
         get_personal_access_tokens::GetPersonalAccessTokensCmd,
     },
+    roles::{
+        create_role::CreateRoleCmd,
+        delete_role::DeleteRoleCmd,
+        get_role::GetRoleCmd,
+        get_roles::GetRolesCmd,
+        update_role::{UpdateRoleCmd, UpdateRoleType},
+    },
     streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
         get_streams::GetStreamsCmd, purge_stream::PurgeStreamCmd, update_stream::UpdateStreamCmd,
     },
-    system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
+    system::{me::GetMeCmd, ping::PingCmd, reload_config::ReloadConfigCmd, stats::GetStatsCmd},
     topics::{
         create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
         get_topics::GetTopicsCmd, purge_topic::PurgeTopicCmd, update_topic::UpdateTopicCmd,
@@ -44,14 +51,17 @@ use iggy::cli::{
         delete_user::DeleteUserCmd,
         get_user::GetUserCmd,
         get_users::GetUsersCmd,
+        unlock_user::UnlockUserCmd,
         update_permissions::UpdatePermissionsCmd,
         update_user::{UpdateUserCmd, UpdateUserType},
+        update_user_roles::UpdateUserRolesCmd,
     },
     utils::personal_access_token_expiry::PersonalAccessTokenExpiry,
 };
 use iggy::cli_command::{CliCommand, PRINT_TARGET};
 use iggy::client_provider::{self, ClientProviderConfig};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::models::permissions::Permissions;
 use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
 use std::sync::Arc;
 use tracing::{event, Level};
@@ -122,6 +132,15 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
         Command::Ping(args) => Box::new(PingCmd::new(args.count)),
         Command::Me => Box::new(GetMeCmd::new()),
         Command::Stats => Box::new(GetStatsCmd::new()),
+        Command::Config(command) => match command {
+            ConfigAction::Reload => Box::new(ReloadConfigCmd::new()),
+        },
+        Command::Logging(command) => match command {
+            LoggingAction::Set(set_args) => Box::new(SetLogFilterCmd::new(
+                set_args.filter.clone(),
+                set_args.revert_after,
+            )),
+        },
         Command::Pat(command) => match command {
             PersonalAccessTokenAction::Create(pat_create_args) => {
                 Box::new(CreatePersonalAccessTokenCmd::new(
@@ -179,6 +198,43 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 )
                 .into(),
             )),
+            UserAction::Roles(roles_args) => Box::new(UpdateUserRolesCmd::new(
+                roles_args.user_id.clone(),
+                roles_args.role_ids.clone(),
+            )),
+            UserAction::Unlock(unlock_args) => {
+                Box::new(UnlockUserCmd::new(unlock_args.user_id.clone()))
+            }
+        },
+        Command::Role(command) => match command {
+            RoleAction::Create(create_args) => Box::new(CreateRoleCmd::new(
+                create_args.name.clone(),
+                PermissionsArgs::new(
+                    create_args.global_permissions.clone(),
+                    create_args.stream_permissions.clone(),
+                )
+                .into(),
+            )),
+            RoleAction::Delete(delete_args) => {
+                Box::new(DeleteRoleCmd::new(delete_args.role_id.clone()))
+            }
+            RoleAction::Get(get_args) => Box::new(GetRoleCmd::new(get_args.role_id.clone())),
+            RoleAction::List(list_args) => Box::new(GetRolesCmd::new(list_args.list_mode.into())),
+            RoleAction::Name(name_args) => Box::new(UpdateRoleCmd::new(
+                name_args.role_id.clone(),
+                UpdateRoleType::Name(name_args.name.clone()),
+            )),
+            RoleAction::Permissions(permissions_args) => {
+                let permissions: Option<Permissions> = PermissionsArgs::new(
+                    permissions_args.global_permissions.clone(),
+                    permissions_args.stream_permissions.clone(),
+                )
+                .into();
+                Box::new(UpdateRoleCmd::new(
+                    permissions_args.role_id.clone(),
+                    UpdateRoleType::Permissions(permissions.unwrap_or_default()),
+                ))
+            }
         },
         Command::Client(command) => match command {
             ClientAction::Get(get_args) => Box::new(GetClientCmd::new(get_args.client_id)),
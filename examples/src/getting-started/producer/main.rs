@@ -1,7 +1,7 @@
 use iggy::client::{Client, StreamClient, TopicClient, UserClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
 use iggy::streams::create_stream::CreateStream;
 use iggy::tcp::client::TcpClient;
 use iggy::tcp::config::TcpClientConfig;
@@ -52,6 +52,7 @@ async fn init_system(client: &IggyClient) {
         .create_stream(&CreateStream {
             stream_id: Some(STREAM_ID),
             name: "sample-stream".to_string(),
+            quotas: None,
         })
         .await
     {
@@ -107,6 +108,8 @@ async fn produce_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 stream_id: Identifier::numeric(STREAM_ID)?,
                 topic_id: Identifier::numeric(TOPIC_ID)?,
                 partitioning: Partitioning::partition_id(PARTITION_ID),
+                producer: None,
+                ack: AckLevel::Memory,
                 messages,
             })
             .await?;
@@ -63,6 +63,15 @@ pub struct Args {
     #[arg(long, default_value = "localhost")]
     pub tcp_tls_domain: String,
 
+    #[arg(long)]
+    pub tcp_tls_client_certificate: Option<String>,
+
+    #[arg(long)]
+    pub tcp_tls_client_key: Option<String>,
+
+    #[arg(long, default_value = "false")]
+    pub tcp_pipelining: bool,
+
     #[arg(long, default_value = "127.0.0.1:0")]
     pub quic_client_address: String,
 
@@ -104,6 +113,12 @@ pub struct Args {
 
     #[arg(long, default_value = "false")]
     pub quic_validate_certificate: bool,
+
+    #[arg(long)]
+    pub quic_client_certificate: Option<String>,
+
+    #[arg(long)]
+    pub quic_client_key: Option<String>,
 }
 
 impl Args {
@@ -118,6 +133,9 @@ impl Args {
             tcp_reconnection_interval: self.tcp_reconnection_interval,
             tcp_tls_enabled: self.tcp_tls_enabled,
             tcp_tls_domain: self.tcp_tls_domain.clone(),
+            tcp_tls_client_certificate: self.tcp_tls_client_certificate.clone(),
+            tcp_tls_client_key: self.tcp_tls_client_key.clone(),
+            tcp_pipelining: self.tcp_pipelining,
             quic_client_address: self.quic_client_address.clone(),
             quic_server_address: self.quic_server_address.clone(),
             quic_server_name: self.quic_server_name.clone(),
@@ -132,6 +150,8 @@ impl Args {
             quic_keep_alive_interval: self.quic_keep_alive_interval,
             quic_max_idle_timeout: self.quic_max_idle_timeout,
             quic_validate_certificate: self.quic_validate_certificate,
+            quic_client_certificate: self.quic_client_certificate.clone(),
+            quic_client_key: self.quic_client_key.clone(),
         }
     }
 }
@@ -81,6 +81,7 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Ig
         .create_stream(&CreateStream {
             stream_id: Some(args.stream_id),
             name: "sample".to_string(),
+            quotas: None,
         })
         .await?;
     client
@@ -125,6 +126,7 @@ pub async fn consume_messages(
                 strategy: PollingStrategy::next(),
                 count: args.messages_per_batch,
                 auto_commit: true,
+                read_committed: false,
             })
             .await?;
         if polled_messages.messages.is_empty() {
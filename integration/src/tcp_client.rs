@@ -8,6 +8,7 @@ use std::sync::Arc;
 #[derive(Debug, Clone)]
 pub struct TcpClientFactory {
     pub server_addr: String,
+    pub pipelining: bool,
 }
 
 #[async_trait]
@@ -15,6 +16,7 @@ impl ClientFactory for TcpClientFactory {
     async fn create_client(&self) -> Box<dyn Client> {
         let config = TcpClientConfig {
             server_address: self.server_addr.clone(),
+            pipelining: self.pipelining,
             ..TcpClientConfig::default()
         };
         let client = TcpClient::create(Arc::new(config)).unwrap();
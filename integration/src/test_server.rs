@@ -452,6 +452,7 @@ pub async fn create_user(client: &IggyClient, username: &str) {
                     send_messages: true,
                 },
                 streams: None,
+                stream_patterns: None,
             }),
         })
         .await
@@ -73,6 +73,7 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                quotas: None,
             })
             .await;
         assert!(stream.is_ok());
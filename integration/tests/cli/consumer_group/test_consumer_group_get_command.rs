@@ -79,6 +79,7 @@ impl IggyCmdTestCase for TestConsumerGroupGetCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                quotas: None,
             })
             .await;
         assert!(stream.is_ok());
@@ -76,6 +76,7 @@ impl IggyCmdTestCase for TestConsumerGroupListCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                quotas: None,
             })
             .await;
         assert!(stream.is_ok());
@@ -21,8 +21,11 @@ Commands:
   ping            ping iggy server
   me              get current client info
   stats           get iggy server statistics
+  config          server configuration operations
+  logging         server logging operations
   pat             personal access token operations
   user            user operations [aliases: u]
+  role            role operations [aliases: r]
   client          client operations [aliases: c]
   consumer-group  consumer group operations [aliases: g]
   message         message operations [aliases: m]
@@ -130,6 +130,7 @@ impl IggyCmdTestCase for TestMessageSendCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                quotas: None,
             })
             .await;
         assert!(stream.is_ok());
@@ -200,6 +201,7 @@ impl IggyCmdTestCase for TestMessageSendCmd {
                 strategy: PollingStrategy::offset(0),
                 count: self.messages.len() as u32,
                 auto_commit: false,
+                read_committed: false,
             })
             .await;
 
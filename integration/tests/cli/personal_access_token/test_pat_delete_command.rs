@@ -30,6 +30,7 @@ impl IggyCmdTestCase for TestPatDeleteCmd {
             .create_personal_access_token(&CreatePersonalAccessToken {
                 name: self.name.clone(),
                 expiry: None,
+                scope: None,
             })
             .await;
         assert!(pat.is_ok());
@@ -38,6 +38,7 @@ impl IggyCmdTestCase for TestPatListCmd {
             .create_personal_access_token(&CreatePersonalAccessToken {
                 name: self.name.clone(),
                 expiry: None,
+                scope: None,
             })
             .await;
         assert!(pat.is_ok());
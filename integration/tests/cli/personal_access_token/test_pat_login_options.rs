@@ -71,6 +71,7 @@ impl IggyCmdTestCase for TestLoginOptions {
             .create_personal_access_token(&CreatePersonalAccessToken {
                 name: self.token_name.clone(),
                 expiry: None,
+                scope: None,
             })
             .await;
         assert!(token.is_ok());
@@ -40,6 +40,7 @@ impl IggyCmdTestCase for TestStreamDeleteCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.name.clone(),
+                quotas: None,
             })
             .await;
         assert!(stream.is_ok());
@@ -39,6 +39,7 @@ impl IggyCmdTestCase for TestStreamGetCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.name.clone(),
+                quotas: None,
             })
             .await;
         assert!(stream.is_ok());
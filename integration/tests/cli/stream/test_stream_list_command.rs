@@ -36,6 +36,7 @@ impl IggyCmdTestCase for TestStreamListCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.name.clone(),
+                quotas: None,
             })
             .await;
         assert!(stream.is_ok());
@@ -1,3 +1,5 @@
+mod test_config_reload_command;
+mod test_logging_set_command;
 mod test_me_command;
 mod test_ping_command;
 mod test_stats_command;
@@ -0,0 +1,88 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::starts_with;
+use serial_test::parallel;
+
+struct TestConfigReloadCmd {}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConfigReloadCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("config")
+            .arg("reload")
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(starts_with(
+            "Executing reload config command\nServer configuration reloaded",
+        ));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test.execute_test(TestConfigReloadCmd {}).await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["config", "reload", "--help"],
+            format!(
+                r#"Reload iggy server configuration
+
+Server reads its configuration again and applies the settings which
+can be changed at runtime (log level, cache size, intervals of the background
+tasks and TLS certificates). Changes of any other settings are reported
+as requiring the server restart.
+
+Examples:
+ iggy config reload
+
+{USAGE_PREFIX} config reload
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["config", "reload", "-h"],
+            format!(
+                r#"Reload iggy server configuration
+
+{USAGE_PREFIX} config reload
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
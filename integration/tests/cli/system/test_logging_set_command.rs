@@ -0,0 +1,118 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestLoggingSetCmd {
+    filter: String,
+}
+
+impl TestLoggingSetCmd {
+    fn new(filter: String) -> Self {
+        Self { filter }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestLoggingSetCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("logging")
+            .arg("set")
+            .arg(self.filter.clone())
+            .arg("--revert-after")
+            .arg("1s")
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(diff(format!(
+            "Executing set log filter: {} reverted after: 1 s\nLog filter: {} set\n",
+            self.filter, self.filter
+        )));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestLoggingSetCmd::new(String::from(
+            "info,server::streaming=debug",
+        )))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["logging", "set", "--help"],
+            format!(
+                r#"Set iggy server log filter
+
+Log filter overrides the configured log level until it's reverted
+(after the optional time) or the next filter is set.
+
+Examples:
+ iggy logging set debug
+ iggy logging set info,server::streaming=debug --revert-after 10m
+
+{USAGE_PREFIX} logging set [OPTIONS] <FILTER>
+
+Arguments:
+  <FILTER>
+          Log filter directives, e.g. "info,server::streaming=debug"
+
+Options:
+  -r, --revert-after <REVERT_AFTER>
+          Time after which the configured log level is restored
+{CLAP_INDENT}
+          Time must be expressed in human readable format like 15min 30s (skipping the option keeps the filter until the next one is set)
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["logging", "set", "-h"],
+            format!(
+                r#"Set iggy server log filter
+
+{USAGE_PREFIX} logging set [OPTIONS] <FILTER>
+
+Arguments:
+  <FILTER>  Log filter directives, e.g. "info,server::streaming=debug"
+
+Options:
+  -r, --revert-after <REVERT_AFTER>  Time after which the configured log level is restored
+  -h, --help                         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
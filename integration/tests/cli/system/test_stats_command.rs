@@ -17,6 +17,7 @@ impl IggyCmdTestCase for TestStatsCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(1),
                 name: stream_id.as_string(),
+                quotas: None,
             })
             .await;
         assert!(stream.is_ok());
@@ -164,6 +164,7 @@ pub async fn should_be_successful() {
                         send_messages: false,
                     },
                     streams: None,
+                    stream_patterns: None,
                 }),
             ),
         ))
@@ -179,6 +180,7 @@ pub async fn should_be_successful() {
                 Some(Permissions {
                     global: GlobalPermissions::default(),
                     streams: Some(HashMap::from([(3u32, StreamPermissions::default())])),
+                    stream_patterns: None,
                 }),
             ),
         ))
@@ -208,6 +210,7 @@ pub async fn should_be_successful() {
                             ..Default::default()
                         },
                     )])),
+                    stream_patterns: None,
                 }),
             ),
         ))
@@ -248,6 +251,7 @@ pub async fn should_be_successful() {
                             ..Default::default()
                         },
                     )])),
+                    stream_patterns: None,
                 }),
             ),
         ))
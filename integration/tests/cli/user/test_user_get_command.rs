@@ -132,7 +132,8 @@ impl IggyCmdTestCase for TestUserGetCmd {
                 user_id
             )))
             .stdout(is_match(format!("| Username[ ]+| {}", self.username)).unwrap())
-            .stdout(is_match(format!("| Status[ ]+| {}", self.status)).unwrap());
+            .stdout(is_match(format!("| Status[ ]+| {}", self.status)).unwrap())
+            .stdout(is_match("| Failed logins[ ]+| 0").unwrap());
 
         // Check global permissions
         let assert = if self.check_global_perms {
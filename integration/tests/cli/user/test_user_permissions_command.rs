@@ -134,6 +134,7 @@ pub async fn should_be_successful() {
                         send_messages: true,
                     },
                     streams: None,
+                    stream_patterns: None,
                 }),
             ),
             TestUserId::Named,
@@ -148,6 +149,7 @@ pub async fn should_be_successful() {
                 Some(Permissions {
                     global: GlobalPermissions::default(),
                     streams: Some(HashMap::from([(3u32, StreamPermissions::default())])),
+                    stream_patterns: None,
                 }),
             ),
             TestUserId::Numeric,
@@ -176,6 +178,7 @@ pub async fn should_be_successful() {
                             ..Default::default()
                         },
                     )])),
+                    stream_patterns: None,
                 }),
             ),
             TestUserId::Named,
@@ -215,6 +218,7 @@ pub async fn should_be_successful() {
                             ..Default::default()
                         },
                     )])),
+                    stream_patterns: None,
                 }),
             ),
             TestUserId::Named,
@@ -0,0 +1,177 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestUserId, CLAP_INDENT,
+    USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::identifier::Identifier;
+use iggy::models::user_status::UserStatus;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::get_user::GetUser;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestUserUnlockCmd {
+    username: String,
+    password: String,
+    user_id: u32,
+    using_identifier: TestUserId,
+}
+
+impl TestUserUnlockCmd {
+    fn new(username: String, password: String, user_id: u32, using_identifier: TestUserId) -> Self {
+        Self {
+            username,
+            password,
+            user_id,
+            using_identifier,
+        }
+    }
+
+    fn to_arg(&self) -> String {
+        match self.using_identifier {
+            TestUserId::Named => self.username.clone(),
+            TestUserId::Numeric => format!("{}", self.user_id),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserUnlockCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                password: self.password.clone(),
+                status: UserStatus::Active,
+                permissions: None,
+            })
+            .await;
+        assert!(user.is_ok());
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::named(&self.username).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        self.user_id = user.unwrap().id;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("unlock")
+            .arg(self.to_arg())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let user_id = self.to_arg();
+        let message =
+            format!("Executing unlock user with ID: {user_id}\nUser with ID: {user_id} unlocked\n");
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user_id = Identifier::named(&self.username).unwrap();
+        let user = client
+            .get_user(&GetUser {
+                user_id: user_id.clone(),
+            })
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap();
+        assert_eq!(user.failed_login_attempts, 0);
+        assert!(user.locked_until.is_none());
+
+        let deleted = client.delete_user(&DeleteUser { user_id }).await;
+        assert!(deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserUnlockCmd::new(
+            String::from("username"),
+            String::from("password"),
+            0,
+            TestUserId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserUnlockCmd::new(
+            String::from("testuser"),
+            String::from("testpass"),
+            0,
+            TestUserId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "unlock", "--help"],
+            format!(
+                r"Unlock user with given ID
+
+The user ID can be specified as either a username or an ID. Unlocking
+clears the lockout caused by too many failed login attempts, and resets
+the counter of failed login attempts for the specified user.
+
+Examples:
+ iggy user unlock 2
+ iggy user unlock testuser
+
+{USAGE_PREFIX} user unlock <USER_ID>
+
+Arguments:
+  <USER_ID>
+          User ID to unlock
+{CLAP_INDENT}
+          The user ID can be specified as either a username or an ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+",
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "unlock", "-h"],
+            format!(
+                r#"Unlock user with given ID
+
+{USAGE_PREFIX} user unlock <USER_ID>
+
+Arguments:
+  <USER_ID>  User ID to unlock
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
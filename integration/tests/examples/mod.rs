@@ -124,6 +124,7 @@ impl<'a> IggyExampleTest<'a> {
                 .create_stream(&CreateStream {
                     stream_id: Some(1),
                     name: "sample-stream".to_string(),
+                    quotas: None,
                 })
                 .await
                 .unwrap();
@@ -36,6 +36,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let create_stream = CreateStream {
         stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
+        quotas: None,
     };
     system_client.create_stream(&create_stream).await.unwrap();
 
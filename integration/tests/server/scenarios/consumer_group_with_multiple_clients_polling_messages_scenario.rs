@@ -6,7 +6,7 @@ use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
 use iggy::models::consumer_group::ConsumerGroupDetails;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
@@ -60,6 +60,7 @@ async fn init_system(
     let create_stream = CreateStream {
         stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
+        quotas: None,
     };
     system_client.create_stream(&create_stream).await.unwrap();
 
@@ -139,6 +140,8 @@ async fn execute_using_messages_key_key(
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
+            producer: None,
+            ack: AckLevel::Memory,
             messages,
         };
         system_client
@@ -165,6 +168,7 @@ async fn poll_messages(client: &IggyClient) -> u32 {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        read_committed: false,
     };
 
     let mut total_read_messages_count = 0;
@@ -200,6 +204,8 @@ async fn execute_using_none_key(
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
+            producer: None,
+            ack: AckLevel::Memory,
             messages,
         };
         system_client
@@ -248,6 +254,7 @@ async fn validate_message_polling(client: &IggyClient, consumer_group: &Consumer
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        read_committed: false,
     };
 
     for i in 1..=MESSAGES_COUNT {
@@ -6,7 +6,7 @@ use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::system::get_me::GetMe;
@@ -47,6 +47,7 @@ async fn init_system(client: &IggyClient) {
     let create_stream = CreateStream {
         stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
+        quotas: None,
     };
     client.create_stream(&create_stream).await.unwrap();
 
@@ -110,6 +111,8 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
+            producer: None,
+            ack: AckLevel::Memory,
             messages,
         };
         client.send_messages(&mut send_messages).await.unwrap();
@@ -124,6 +127,7 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        read_committed: false,
     };
 
     let mut total_read_messages_count = 0;
@@ -154,6 +158,8 @@ async fn execute_using_none_key(client: &IggyClient) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
+            producer: None,
+            ack: AckLevel::Memory,
             messages,
         };
         client.send_messages(&mut send_messages).await.unwrap();
@@ -168,6 +174,7 @@ async fn execute_using_none_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        read_committed: false,
     };
 
     let mut partition_id = 1;
@@ -2,5 +2,6 @@ pub mod consumer_group_join_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
 pub mod message_headers_scenario;
+pub mod pipelined_requests_scenario;
 pub mod system_scenario;
 pub mod user_scenario;
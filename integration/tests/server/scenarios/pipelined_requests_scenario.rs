@@ -0,0 +1,127 @@
+use bytes::{BufMut, Bytes};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::client::{MessageClient, StreamClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::command::Command;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::tcp::pipelining::{REQUEST_ID_FLAG, REQUEST_ID_LENGTH};
+use iggy::topics::create_topic::CreateTopic;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::users::login_user::LoginUser;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const MESSAGES_COUNT: u32 = 100;
+const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+
+pub async fn run(client_factory: &dyn ClientFactory, server_addr: &str) {
+    // 1. Send all the requests at once, without waiting for any of the responses.
+    // Each of them depends on the previous ones, thus they succeed only if handled in their arrival order.
+    let mut requests = vec![
+        Command::LoginUser(LoginUser {
+            username: DEFAULT_ROOT_USERNAME.to_string(),
+            password: DEFAULT_ROOT_PASSWORD.to_string(),
+        }),
+        Command::CreateStream(CreateStream {
+            stream_id: Some(STREAM_ID),
+            name: STREAM_NAME.to_string(),
+            quotas: None,
+        }),
+        Command::CreateTopic(CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Some(TOPIC_ID),
+            partitions_count: 1,
+            name: TOPIC_NAME.to_string(),
+            message_expiry: None,
+            max_topic_size: None,
+            replication_factor: 1,
+        }),
+    ];
+    for id in 1..=MESSAGES_COUNT {
+        let payload = Bytes::from(format!("message {id}"));
+        requests.push(Command::SendMessages(SendMessages {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitioning: Partitioning::partition_id(PARTITION_ID),
+            messages: vec![Message {
+                id: id as u128,
+                length: payload.len() as u32,
+                payload,
+                headers: None,
+            }],
+            ..SendMessages::default()
+        }));
+    }
+
+    let mut buffer = Vec::new();
+    for (request_id, request) in requests.iter().enumerate() {
+        let request = request.as_bytes();
+        buffer.put_u32_le(request.len() as u32 | REQUEST_ID_FLAG);
+        buffer.put_u64_le(request_id as u64);
+        buffer.extend(request);
+    }
+
+    let mut stream = TcpStream::connect(server_addr).await.unwrap();
+    stream.write_all(&buffer).await.unwrap();
+
+    // 2. Each request should be completed successfully, with the response matched by its ID
+    let mut statuses = HashMap::new();
+    for _ in 0..requests.len() {
+        let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH + REQUEST_ID_LENGTH];
+        stream.read_exact(&mut response_buffer).await.unwrap();
+        let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
+        let length = u32::from_le_bytes(response_buffer[4..8].try_into().unwrap());
+        let request_id = u64::from_le_bytes(response_buffer[8..].try_into().unwrap());
+        let mut payload = vec![0u8; length as usize];
+        stream.read_exact(&mut payload).await.unwrap();
+        statuses.insert(request_id, status);
+    }
+
+    assert_eq!(statuses.len(), requests.len());
+    for (request_id, status) in statuses {
+        assert_eq!(status, 0, "request with ID: {request_id} has failed");
+    }
+
+    // 3. The messages should be appended in the order of the requests, also polled with the pipelined client
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    login_root(&client).await;
+    let polled_messages = client
+        .poll_messages(&PollMessages {
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+            strategy: PollingStrategy::offset(0),
+            count: MESSAGES_COUNT,
+            auto_commit: false,
+            ..PollMessages::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        assert_eq!(message.offset, index as u64);
+        assert_eq!(message.id, index as u128 + 1);
+    }
+
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
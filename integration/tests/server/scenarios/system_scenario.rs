@@ -16,7 +16,7 @@ use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
 use iggy::streams::create_stream::CreateStream;
@@ -26,6 +26,7 @@ use iggy::streams::get_streams::GetStreams;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
 use iggy::system::get_clients::GetClients;
+use iggy::system::get_health::GetHealth;
 use iggy::system::get_me::GetMe;
 use iggy::system::get_stats::GetStats;
 use iggy::system::ping::Ping;
@@ -60,6 +61,21 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let ping = Ping {};
     client.ping(&ping).await.unwrap();
 
+    // 0. Get the server health without authentication
+    let health = client.get_health(&GetHealth {}).await.unwrap();
+    let check_names = health
+        .checks
+        .iter()
+        .map(|check| check.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        check_names,
+        vec!["storage", "database", "background_tasks", "disk_space"]
+    );
+    assert!(health.checks[0].healthy);
+    assert!(health.checks[1].healthy);
+    assert!(health.checks[2].healthy);
+
     // 1. Login as root user
     client
         .login_user(&LoginUser {
@@ -77,6 +93,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let mut create_stream = CreateStream {
         stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
+        quotas: None,
     };
     client.create_stream(&create_stream).await.unwrap();
 
@@ -226,6 +243,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        ack: AckLevel::Memory,
         messages,
     };
     client.send_messages(&mut send_messages).await.unwrap();
@@ -242,6 +261,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        read_committed: false,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -268,6 +288,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             strategy: PollingStrategy::offset(start_offset),
             count: batch_size,
             auto_commit: false,
+            read_committed: false,
         };
 
         let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -312,6 +333,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        read_committed: false,
     };
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
     assert!(polled_messages.messages.is_empty());
@@ -379,6 +401,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::next(),
         count: messages_count,
         auto_commit: true,
+        read_committed: false,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -634,6 +657,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .update_stream(&UpdateStream {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             name: updated_stream_name.clone(),
+            quotas: None,
         })
         .await
         .unwrap();
@@ -653,6 +677,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        ack: AckLevel::Memory,
         messages,
     };
     client.send_messages(&mut send_messages).await.unwrap();
@@ -690,6 +716,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let create_stream = CreateStream {
         stream_id: None,
         name: stream_name.clone(),
+        quotas: None,
     };
     client.create_stream(&create_stream).await.unwrap();
 
@@ -2,6 +2,7 @@ use iggy::client::{PersonalAccessTokenClient, SystemClient, UserClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::identifier::Identifier;
 use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::models::user_quotas::UserQuotas;
 use iggy::models::user_status::UserStatus;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
@@ -17,6 +18,7 @@ use iggy::users::get_users::GetUsers;
 use iggy::users::login_user::LoginUser;
 use iggy::users::logout_user::LogoutUser;
 use iggy::users::update_permissions::UpdatePermissions;
+use iggy::users::update_quotas::UpdateQuotas;
 use iggy::users::update_user::UpdateUser;
 use integration::test_server::{assert_clean_system, ClientFactory};
 
@@ -88,6 +90,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
                     send_messages: true,
                 },
                 streams: None,
+                stream_patterns: None,
             }),
         })
         .await
@@ -167,6 +170,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .create_personal_access_token(&CreatePersonalAccessToken {
             name: pat_name1.to_string(),
             expiry: Some(1000),
+            scope: None,
         })
         .await
         .unwrap();
@@ -177,6 +181,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .create_personal_access_token(&CreatePersonalAccessToken {
             name: pat_name2.to_string(),
             expiry: None,
+            scope: None,
         })
         .await
         .unwrap();
@@ -285,12 +290,35 @@ pub async fn run(client_factory: &dyn ClientFactory) {
                     send_messages: true,
                 },
                 streams: None,
+                stream_patterns: None,
             }),
         })
         .await
         .unwrap();
 
-    // 23. Deleting another user should be allowed
+    // 23. Update user quotas and ensure they are returned with the user details
+    let quotas = UserQuotas {
+        produce_bytes_per_second: 1_000_000,
+        consume_bytes_per_second: 2_000_000,
+        requests_per_second: 100,
+    };
+    client
+        .update_quotas(&UpdateQuotas {
+            user_id: Identifier::named(updated_test_user).unwrap(),
+            quotas: Some(quotas),
+        })
+        .await
+        .unwrap();
+
+    let user = client
+        .get_user(&GetUser {
+            user_id: Identifier::named(updated_test_user).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(user.quotas, Some(quotas));
+
+    // 24. Deleting another user should be allowed
     client
         .delete_user(&DeleteUser {
             user_id: Identifier::named(updated_test_user).unwrap(),
@@ -298,7 +326,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
 
-    // 24. Trying to delete the root user should fail
+    // 25. Trying to delete the root user should fail
     let delete_root_user = client
         .delete_user(&DeleteUser {
             user_id: Identifier::named(DEFAULT_ROOT_USERNAME).unwrap(),
@@ -309,10 +337,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_clean_system(&client).await;
 
-    // 25. Logout
+    // 26. Logout
     client.logout_user(&LogoutUser {}).await.unwrap();
 
-    // 26. Trying to perform any secured operation after logout should fail
+    // 27. Trying to perform any secured operation after logout should fail
     let get_users = client.get_users(&GetUsers {}).await;
     assert!(get_users.is_err());
 }
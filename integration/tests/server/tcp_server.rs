@@ -1,7 +1,7 @@
 use crate::server::scenarios::{
     consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
     consumer_group_with_single_client_polling_messages_scenario, message_headers_scenario,
-    system_scenario, user_scenario,
+    pipelined_requests_scenario, system_scenario, user_scenario,
 };
 use integration::{tcp_client::TcpClientFactory, test_server::TestServer};
 use serial_test::parallel;
@@ -12,7 +12,10 @@ async fn system_scenario_should_be_valid() {
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_raw_tcp_addr().unwrap();
-    let client_factory = TcpClientFactory { server_addr };
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: false,
+    };
     system_scenario::run(&client_factory).await;
 }
 
@@ -22,7 +25,10 @@ async fn user_scenario_should_be_valid() {
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_raw_tcp_addr().unwrap();
-    let client_factory = TcpClientFactory { server_addr };
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: false,
+    };
     user_scenario::run(&client_factory).await;
 }
 
@@ -32,7 +38,10 @@ async fn message_headers_scenario_should_be_valid() {
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_raw_tcp_addr().unwrap();
-    let client_factory = TcpClientFactory { server_addr };
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: false,
+    };
     message_headers_scenario::run(&client_factory).await;
 }
 
@@ -42,7 +51,10 @@ async fn consumer_group_join_scenario_should_be_valid() {
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_raw_tcp_addr().unwrap();
-    let client_factory = TcpClientFactory { server_addr };
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: false,
+    };
     consumer_group_join_scenario::run(&client_factory).await;
 }
 
@@ -52,7 +64,10 @@ async fn consumer_group_with_single_client_polling_messages_scenario_should_be_v
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_raw_tcp_addr().unwrap();
-    let client_factory = TcpClientFactory { server_addr };
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: false,
+    };
     consumer_group_with_single_client_polling_messages_scenario::run(&client_factory).await;
 }
 
@@ -62,6 +77,35 @@ async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_b
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_raw_tcp_addr().unwrap();
-    let client_factory = TcpClientFactory { server_addr };
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: false,
+    };
     consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn message_headers_scenario_with_pipelined_requests_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: true,
+    };
+    message_headers_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn pipelined_requests_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr: server_addr.clone(),
+        pipelining: true,
+    };
+    pipelined_requests_scenario::run(&client_factory, &server_addr).await;
+}
@@ -0,0 +1,124 @@
+use crate::streaming::common::test_setup::TestSetup;
+use iggy::error::IggyError;
+use iggy::users::defaults::DEFAULT_ROOT_USERNAME;
+use server::configs::server::PersonalAccessTokenConfig;
+use server::configs::system::SystemConfig;
+use server::streaming::audit::audit_log::AuditLog;
+use server::streaming::session::Session;
+use server::streaming::systems::system::System;
+use std::net::{Ipv4Addr, SocketAddr};
+use tokio::fs;
+
+#[tokio::test]
+async fn administrative_and_security_events_should_be_recorded_and_verified() {
+    let mut config = SystemConfig::default();
+    config.audit.enabled = true;
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let root_session = Session::stateless(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    let anonymous_session =
+        Session::stateless(0, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 5678));
+    system.init().await.unwrap();
+
+    system
+        .create_stream(&root_session, Some(1), "test", None)
+        .await
+        .unwrap();
+    let login_result = system
+        .login_user(DEFAULT_ROOT_USERNAME, "invalid", Some(&anonymous_session))
+        .await;
+    assert!(login_result.is_err());
+
+    let entries = system.get_audit_log(&root_session, 0, 10).await.unwrap();
+    assert_eq!(entries.len(), 2);
+    let create_stream_entry = &entries[0];
+    assert_eq!(create_stream_entry.id, 0);
+    assert_eq!(create_stream_entry.user_id, 1);
+    assert_eq!(create_stream_entry.transport, "HTTP");
+    assert_eq!(create_stream_entry.action, "create_stream");
+    assert!(create_stream_entry.succeeded);
+    assert!(create_stream_entry.previous_hash.is_empty());
+    let login_entry = &entries[1];
+    assert_eq!(login_entry.id, 1);
+    assert_eq!(login_entry.user_id, 0);
+    assert_eq!(login_entry.address, "127.0.0.1:5678");
+    assert_eq!(login_entry.action, "login_user");
+    assert!(!login_entry.succeeded);
+    assert_eq!(login_entry.previous_hash, create_stream_entry.hash);
+
+    let audit_log = AuditLog::new(&setup.config);
+    audit_log.init().await.unwrap();
+    assert!(audit_log.verify().await.is_ok());
+
+    let content = fs::read_to_string(setup.config.get_audit_path())
+        .await
+        .unwrap();
+    let tampered_content = content.replacen("\"user_id\":1", "\"user_id\":2", 1);
+    fs::write(setup.config.get_audit_path(), tampered_content)
+        .await
+        .unwrap();
+    assert!(matches!(
+        audit_log.verify().await,
+        Err(IggyError::InvalidAuditLogEntry(0))
+    ));
+}
+
+#[tokio::test]
+async fn partially_written_entry_should_be_removed_and_removed_entries_detected() {
+    let mut config = SystemConfig::default();
+    config.audit.enabled = true;
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let root_session = Session::stateless(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    system
+        .create_stream(&root_session, Some(1), "test-1", None)
+        .await
+        .unwrap();
+    system
+        .create_stream(&root_session, Some(2), "test-2", None)
+        .await
+        .unwrap();
+    let path = setup.config.get_audit_path();
+    let content = fs::read_to_string(&path).await.unwrap();
+    fs::write(&path, format!("{content}{{\"id\":2,\"timest"))
+        .await
+        .unwrap();
+
+    let audit_log = AuditLog::new(&setup.config);
+    audit_log.init().await.unwrap();
+    assert!(audit_log.verify().await.is_ok());
+    assert_eq!(fs::read_to_string(&path).await.unwrap(), content);
+
+    let first_entry_length = content.find('\n').unwrap() + 1;
+    fs::write(&path, &content[..first_entry_length])
+        .await
+        .unwrap();
+    assert!(matches!(
+        audit_log.verify().await,
+        Err(IggyError::InvalidAuditLogEntry(1))
+    ));
+}
+
+#[tokio::test]
+async fn audit_log_should_not_be_available_when_disabled() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let root_session = Session::stateless(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+
+    let result = system.get_audit_log(&root_session, 0, 10).await;
+    assert!(matches!(result, Err(IggyError::FeatureUnavailable)));
+}
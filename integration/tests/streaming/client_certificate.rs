@@ -0,0 +1,67 @@
+use crate::streaming::common::test_setup::TestSetup;
+use iggy::error::IggyError;
+use iggy::models::user_status::UserStatus;
+use iggy::users::defaults::DEFAULT_ROOT_USERNAME;
+use server::configs::server::PersonalAccessTokenConfig;
+use server::streaming::session::Session;
+use server::streaming::systems::system::System;
+use std::net::{Ipv4Addr, SocketAddr};
+
+#[tokio::test]
+async fn client_certificate_should_authenticate_mapped_user_except_root_by_default() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+    let root_session = Session::stateless(1, address);
+    system.init().await.unwrap();
+    system
+        .create_user(
+            &root_session,
+            "orders-producer",
+            "secret",
+            UserStatus::Active,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let session = Session::stateless(0, address);
+    let user = system
+        .login_with_client_certificate("orders-producer", false, &session)
+        .await
+        .unwrap();
+
+    assert_eq!(user.username, "orders-producer");
+    assert!(session.is_authenticated());
+    assert_eq!(session.get_user_id(), user.id);
+
+    let session = Session::stateless(0, address);
+    assert!(matches!(
+        system
+            .login_with_client_certificate("invoices-producer", false, &session)
+            .await,
+        Err(IggyError::InvalidCredentials)
+    ));
+    assert!(!session.is_authenticated());
+
+    let session = Session::stateless(0, address);
+    assert!(matches!(
+        system
+            .login_with_client_certificate(DEFAULT_ROOT_USERNAME, false, &session)
+            .await,
+        Err(IggyError::InvalidCredentials)
+    ));
+    assert!(!session.is_authenticated());
+
+    let session = Session::stateless(0, address);
+    let user = system
+        .login_with_client_certificate(DEFAULT_ROOT_USERNAME, true, &session)
+        .await
+        .unwrap();
+    assert!(user.is_root());
+    assert!(session.is_authenticated());
+}
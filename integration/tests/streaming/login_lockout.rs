@@ -0,0 +1,160 @@
+use crate::streaming::common::test_setup::TestSetup;
+use futures::future::join_all;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::user_status::UserStatus;
+use server::configs::server::PersonalAccessTokenConfig;
+use server::configs::system::SystemConfig;
+use server::streaming::session::Session;
+use server::streaming::systems::system::System;
+use std::net::{Ipv4Addr, SocketAddr};
+
+#[tokio::test]
+async fn user_should_be_locked_out_after_too_many_failed_login_attempts_and_unlocked_by_admin() {
+    let mut config = SystemConfig::default();
+    config.login_lockout.enabled = true;
+    config.login_lockout.max_failed_attempts = 3;
+    config.login_lockout.ip_failed_attempts_threshold = 100;
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+    let root_session = Session::stateless(1, address);
+    system.init().await.unwrap();
+    let username = "user1";
+    let password = "secret";
+    system
+        .create_user(&root_session, username, password, UserStatus::Active, None)
+        .await
+        .unwrap();
+
+    let session = Session::stateless(0, address);
+    for _ in 0..3 {
+        let result = system.login_user(username, "invalid", Some(&session)).await;
+        assert!(matches!(result, Err(IggyError::InvalidCredentials)));
+    }
+
+    let result = system.login_user(username, password, Some(&session)).await;
+    assert!(matches!(result, Err(IggyError::UserLockedOut(_))));
+    let user_id = Identifier::named(username).unwrap();
+    let user = system.find_user(&root_session, &user_id).await.unwrap();
+    assert_eq!(user.failed_login_attempts, 3);
+    assert!(user.get_locked_until().is_some());
+
+    system.unlock_user(&root_session, &user_id).await.unwrap();
+    let user = system
+        .login_user(username, password, Some(&session))
+        .await
+        .unwrap();
+    assert_eq!(user.failed_login_attempts, 0);
+    assert!(user.get_locked_until().is_none());
+}
+
+#[tokio::test]
+async fn concurrent_failed_login_attempts_should_all_be_counted() {
+    let mut config = SystemConfig::default();
+    config.login_lockout.enabled = true;
+    config.login_lockout.max_failed_attempts = 100;
+    config.login_lockout.ip_failed_attempts_threshold = 100;
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+    let root_session = Session::stateless(1, address);
+    system.init().await.unwrap();
+    let username = "user1";
+    system
+        .create_user(&root_session, username, "secret", UserStatus::Active, None)
+        .await
+        .unwrap();
+
+    let session = Session::stateless(0, address);
+    let attempts = (0..10).map(|_| system.login_user(username, "invalid", Some(&session)));
+    for result in join_all(attempts).await {
+        assert!(matches!(result, Err(IggyError::InvalidCredentials)));
+    }
+
+    let user_id = Identifier::named(username).unwrap();
+    let user = system.find_user(&root_session, &user_id).await.unwrap();
+    assert_eq!(user.failed_login_attempts, 10);
+}
+
+#[tokio::test]
+async fn repeated_failed_login_attempts_from_the_same_address_should_be_delayed() {
+    let mut config = SystemConfig::default();
+    config.login_lockout.enabled = true;
+    config.login_lockout.max_failed_attempts = 100;
+    config.login_lockout.ip_failed_attempts_threshold = 2;
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    system.init().await.unwrap();
+
+    let session = Session::stateless(0, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    for _ in 0..2 {
+        let result = system
+            .login_user("unknown", "invalid", Some(&session))
+            .await;
+        assert!(matches!(result, Err(IggyError::InvalidCredentials)));
+    }
+
+    let result = system
+        .login_user("unknown", "invalid", Some(&session))
+        .await;
+    assert!(matches!(result, Err(IggyError::TooManyLoginAttempts(_))));
+
+    let other_session = Session::stateless(0, SocketAddr::new(Ipv4Addr::new(10, 0, 0, 1), 1234));
+    let result = system
+        .login_user("unknown", "invalid", Some(&other_session))
+        .await;
+    assert!(matches!(result, Err(IggyError::InvalidCredentials)));
+}
+
+#[tokio::test]
+async fn password_not_meeting_the_policy_should_be_rejected() {
+    let mut config = SystemConfig::default();
+    config.password_policy.min_length = 8;
+    config.password_policy.require_digit = true;
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let root_session = Session::stateless(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+
+    let result = system
+        .create_user(&root_session, "user1", "secret", UserStatus::Active, None)
+        .await;
+    assert!(matches!(result, Err(IggyError::PasswordPolicyViolation(_))));
+
+    system
+        .create_user(
+            &root_session,
+            "user1",
+            "secret123",
+            UserStatus::Active,
+            None,
+        )
+        .await
+        .unwrap();
+    let result = system
+        .change_password(
+            &root_session,
+            &Identifier::named("user1").unwrap(),
+            "secret123",
+            "password",
+        )
+        .await;
+    assert!(matches!(result, Err(IggyError::PasswordPolicyViolation(_))));
+}
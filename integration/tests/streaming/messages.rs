@@ -112,3 +112,67 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         assert_eq!(loaded_message.headers, appended_message.headers);
     }
 }
+
+#[tokio::test]
+async fn should_sync_buffered_messages_and_then_load_them_from_disk() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let messages_count = 10;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        partition: PartitionConfig {
+            messages_required_to_save: 1000,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+
+    let mut messages = Vec::with_capacity(messages_count as usize);
+    for i in 1..=messages_count {
+        let payload = Bytes::from(format!("message {}", i));
+        let checksum = checksum::calculate(&payload);
+        messages.push(Message::create(
+            (i - 1) as u64,
+            MessageState::Available,
+            IggyTimestamp::now().to_micros(),
+            i as u128,
+            payload,
+            checksum,
+            None,
+        ));
+    }
+
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+    partition.append_messages(messages).await.unwrap();
+    assert_eq!(partition.unsaved_messages_count, messages_count);
+    partition.sync_messages().await.unwrap();
+    assert_eq!(partition.unsaved_messages_count, 0);
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition.partition_id,
+        false,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+    let loaded_messages = loaded_partition
+        .get_messages_by_offset(0, messages_count)
+        .await
+        .unwrap();
+    assert_eq!(loaded_messages.len(), messages_count as usize);
+}
@@ -2,14 +2,21 @@ use bytes::Bytes;
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::checksum;
 
+mod audit_log;
+mod client_certificate;
 mod common;
 mod consumer_group;
 mod consumer_offset;
+mod login_lockout;
 mod messages;
+mod oidc;
 mod partition;
+mod permissions;
 mod personal_access_token;
+mod role;
 mod segment;
 mod stream;
+mod stream_encryption;
 mod system;
 mod topic;
 mod topic_messages;
@@ -0,0 +1,116 @@
+use crate::streaming::common::test_setup::TestSetup;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::models::user_status::UserStatus;
+use server::configs::server::PersonalAccessTokenConfig;
+use server::streaming::session::Session;
+use server::streaming::systems::system::System;
+use server::streaming::users::oidc::OidcIdentity;
+use std::net::{Ipv4Addr, SocketAddr};
+
+#[tokio::test]
+async fn oidc_identity_should_authenticate_user_and_synchronize_its_roles() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+    let root_session = Session::stateless(1, address);
+    system.init().await.unwrap();
+    let username = "user1";
+    system
+        .create_user(&root_session, username, "secret", UserStatus::Active, None)
+        .await
+        .unwrap();
+    system
+        .create_role(&root_session, "readers", read_streams_permissions())
+        .await
+        .unwrap();
+    let role = system
+        .find_role(&root_session, &Identifier::named("readers").unwrap())
+        .await
+        .unwrap();
+
+    let session = Session::stateless(0, address);
+    let identity = create_identity(username, Some(vec!["Readers", "unknown"]));
+    let user = system
+        .login_with_oidc_identity(&identity, &session)
+        .await
+        .unwrap();
+
+    assert_eq!(user.username, username);
+    assert_eq!(user.roles, vec![role.id]);
+    assert!(session.is_authenticated());
+    assert_eq!(session.get_user_id(), user.id);
+    assert!(system.find_streams(&session).is_ok());
+    let user = system
+        .get_user(&Identifier::named(username).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(user.roles, vec![role.id]);
+
+    // The token without the roles claim keeps the currently assigned roles.
+    let session = Session::stateless(0, address);
+    let identity = create_identity(username, None);
+    let user = system
+        .login_with_oidc_identity(&identity, &session)
+        .await
+        .unwrap();
+    assert_eq!(user.roles, vec![role.id]);
+    assert!(system.find_streams(&session).is_ok());
+
+    let session = Session::stateless(0, address);
+    let identity = create_identity(username, Some(vec![]));
+    let user = system
+        .login_with_oidc_identity(&identity, &session)
+        .await
+        .unwrap();
+    assert!(user.roles.is_empty());
+    assert!(matches!(
+        system.find_streams(&session),
+        Err(IggyError::Unauthorized)
+    ));
+}
+
+#[tokio::test]
+async fn oidc_identity_should_not_authenticate_unknown_user() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+    system.init().await.unwrap();
+
+    let session = Session::stateless(0, address);
+    let identity = create_identity("user2", None);
+    assert!(matches!(
+        system.login_with_oidc_identity(&identity, &session).await,
+        Err(IggyError::InvalidCredentials)
+    ));
+    assert!(!session.is_authenticated());
+}
+
+fn create_identity(username: &str, roles: Option<Vec<&str>>) -> OidcIdentity {
+    OidcIdentity {
+        username: username.to_string(),
+        roles: roles.map(|roles| roles.iter().map(|role| role.to_string()).collect()),
+        token_id: "token".to_string(),
+        expiry: 0,
+    }
+}
+
+fn read_streams_permissions() -> Permissions {
+    Permissions {
+        global: GlobalPermissions {
+            read_streams: true,
+            ..Default::default()
+        },
+        streams: None,
+        stream_patterns: None,
+    }
+}
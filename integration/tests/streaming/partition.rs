@@ -1,7 +1,14 @@
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
+use bytes::Bytes;
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::checksum;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use server::configs::system::{MessageDeduplicationConfig, SystemConfig};
 use server::streaming::partitions::partition::Partition;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
+use std::time::Duration;
 use tokio::fs;
 
 #[tokio::test]
@@ -146,6 +153,200 @@ async fn should_purge_existing_partition_on_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_load_unique_message_ids_within_expiry_of_existing_partition_from_disk() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        message_deduplication: MessageDeduplicationConfig {
+            enabled: true,
+            max_entries: 1000,
+            expiry: IggyDuration::new(Duration::ZERO),
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let partition = create_partition_with_messages(&setup, stream_id, topic_id, partition_id).await;
+    let messages_count = partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap()
+        .len();
+
+    let mut loaded_partition = load_partition(&setup, stream_id, topic_id, partition_id).await;
+    loaded_partition
+        .append_messages(create_messages())
+        .await
+        .unwrap();
+
+    let loaded_messages = loaded_partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap();
+    assert_eq!(loaded_messages.len(), messages_count);
+}
+
+#[tokio::test]
+async fn should_not_load_expired_unique_message_ids_of_existing_partition_from_disk() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        message_deduplication: MessageDeduplicationConfig {
+            enabled: true,
+            max_entries: 1000,
+            expiry: IggyDuration::new(Duration::from_secs(60)),
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let partition = create_partition_with_messages(&setup, stream_id, topic_id, partition_id).await;
+    let messages_count = partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap()
+        .len();
+
+    // The test messages are created with the timestamp far behind the expiry window.
+    let mut loaded_partition = load_partition(&setup, stream_id, topic_id, partition_id).await;
+    loaded_partition
+        .append_messages(create_messages())
+        .await
+        .unwrap();
+
+    let loaded_messages = loaded_partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap();
+    assert_eq!(loaded_messages.len(), 2 * messages_count);
+}
+
+#[tokio::test]
+async fn should_load_only_unique_message_ids_within_expiry_window_from_disk() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        message_deduplication: MessageDeduplicationConfig {
+            enabled: true,
+            max_entries: 1000,
+            expiry: IggyDuration::new(Duration::from_secs(60)),
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let now = IggyTimestamp::now().to_micros();
+    let expired_ids = [1, 2];
+    let recent_ids = [3, 4];
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    partition.persist().await.unwrap();
+    let mut messages = expired_ids
+        .iter()
+        .map(|id| create_message_with_timestamp(*id, 1))
+        .collect::<Vec<_>>();
+    messages.extend(
+        recent_ids
+            .iter()
+            .map(|id| create_message_with_timestamp(*id, now)),
+    );
+    partition.append_messages(messages).await.unwrap();
+    for segment in partition.get_segments_mut() {
+        segment.persist_messages().await.unwrap();
+    }
+
+    let mut loaded_partition = load_partition(&setup, stream_id, topic_id, partition_id).await;
+    let messages = expired_ids
+        .iter()
+        .chain(recent_ids.iter())
+        .map(|id| create_message_with_timestamp(*id, now))
+        .collect::<Vec<_>>();
+    loaded_partition.append_messages(messages).await.unwrap();
+
+    // Only the messages with the IDs older than the expiry window can be appended again.
+    let loaded_messages = loaded_partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap();
+    assert_eq!(
+        loaded_messages.len(),
+        expired_ids.len() + recent_ids.len() + expired_ids.len()
+    );
+    let appended_ids = loaded_messages
+        .iter()
+        .skip(expired_ids.len() + recent_ids.len())
+        .map(|message| message.id)
+        .collect::<Vec<_>>();
+    assert_eq!(appended_ids, expired_ids);
+}
+
+fn create_message_with_timestamp(id: u128, timestamp: u64) -> Message {
+    let payload = Bytes::from(format!("message {id}"));
+    let checksum = checksum::calculate(payload.as_ref());
+    Message::create(
+        0,
+        MessageState::Available,
+        timestamp,
+        id,
+        payload,
+        checksum,
+        None,
+    )
+}
+
+async fn create_partition_with_messages(
+    setup: &TestSetup,
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+) -> Partition {
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    partition.persist().await.unwrap();
+    partition.append_messages(create_messages()).await.unwrap();
+    for segment in partition.get_segments_mut() {
+        segment.persist_messages().await.unwrap();
+    }
+    partition
+}
+
+async fn load_partition(
+    setup: &TestSetup,
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+) -> Partition {
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    partition.load().await.unwrap();
+    partition
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     assert!(fs::metadata(&partition_path).await.is_ok());
 
@@ -0,0 +1,161 @@
+use crate::streaming::common::test_setup::TestSetup;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::permissions::{GlobalPermissions, Permissions, StreamPermissions};
+use iggy::models::user_status::UserStatus;
+use server::configs::server::PersonalAccessTokenConfig;
+use server::streaming::session::Session;
+use server::streaming::systems::system::System;
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddr};
+
+#[tokio::test]
+async fn stream_pattern_permissions_should_be_resolved_by_current_stream_name() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+    let root_session = Session::stateless(1, address);
+    system.init().await.unwrap();
+
+    let username = "user1";
+    system
+        .create_user(
+            &root_session,
+            username,
+            "secret",
+            UserStatus::Active,
+            Some(Permissions {
+                stream_patterns: Some(HashMap::from([(
+                    "orders-*".to_string(),
+                    StreamPermissions {
+                        read_stream: true,
+                        ..Default::default()
+                    },
+                )])),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap();
+    let user = system
+        .get_user(&Identifier::named(username).unwrap())
+        .await
+        .unwrap();
+    let user_session = Session::stateless(user.id, address);
+
+    system
+        .create_stream(&root_session, Some(1), "orders-eu", None)
+        .await
+        .unwrap();
+    system
+        .create_stream(&root_session, Some(2), "invoices", None)
+        .await
+        .unwrap();
+    let orders_stream_id = Identifier::named("orders-eu").unwrap();
+    assert!(system.find_stream(&user_session, &orders_stream_id).is_ok());
+    assert!(matches!(
+        system.find_stream(&user_session, &Identifier::numeric(2).unwrap()),
+        Err(IggyError::Unauthorized)
+    ));
+
+    system
+        .delete_stream(&root_session, &orders_stream_id)
+        .await
+        .unwrap();
+    system
+        .create_stream(&root_session, Some(3), "orders-eu", None)
+        .await
+        .unwrap();
+    assert!(system.find_stream(&user_session, &orders_stream_id).is_ok());
+
+    system
+        .update_stream(&root_session, &orders_stream_id, "archive", None)
+        .await
+        .unwrap();
+    assert!(matches!(
+        system.find_stream(&user_session, &Identifier::numeric(3).unwrap()),
+        Err(IggyError::Unauthorized)
+    ));
+}
+
+#[tokio::test]
+async fn scoped_personal_access_token_should_restrict_the_permissions_of_its_owner() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+    let root_session = Session::stateless(1, address);
+    system.init().await.unwrap();
+    system
+        .create_stream(&root_session, Some(1), "orders", None)
+        .await
+        .unwrap();
+    system
+        .create_stream(&root_session, Some(2), "invoices", None)
+        .await
+        .unwrap();
+
+    let token = system
+        .create_personal_access_token(
+            &root_session,
+            "orders-reader",
+            None,
+            Some(Permissions {
+                global: GlobalPermissions::default(),
+                streams: Some(HashMap::from([(
+                    1,
+                    StreamPermissions {
+                        read_stream: true,
+                        ..Default::default()
+                    },
+                )])),
+                stream_patterns: None,
+            }),
+        )
+        .await
+        .unwrap();
+    let scoped_session = Session::stateless(0, address);
+    let (user, personal_access_token) = system
+        .login_with_personal_access_token(&token, Some(&scoped_session))
+        .await
+        .unwrap();
+    assert_eq!(user.id, 1);
+    assert!(personal_access_token.scope.is_some());
+    assert!(scoped_session.is_scoped());
+
+    assert!(system
+        .find_stream(&scoped_session, &Identifier::numeric(1).unwrap())
+        .is_ok());
+    assert!(matches!(
+        system.find_stream(&scoped_session, &Identifier::numeric(2).unwrap()),
+        Err(IggyError::Unauthorized)
+    ));
+    assert!(matches!(
+        system
+            .create_stream(&scoped_session, Some(3), "payments", None)
+            .await,
+        Err(IggyError::Unauthorized)
+    ));
+    assert!(matches!(
+        system
+            .create_personal_access_token(&scoped_session, "escalated", None, None)
+            .await,
+        Err(IggyError::Unauthorized)
+    ));
+
+    system
+        .delete_personal_access_token(&root_session, "orders-reader")
+        .await
+        .unwrap();
+    assert!(system
+        .permissioner
+        .get_personal_access_token_scope(1, "orders-reader")
+        .is_none());
+}
@@ -6,9 +6,9 @@ use server::streaming::personal_access_tokens::personal_access_token::PersonalAc
 async fn many_personal_access_tokens_should_be_saved_and_loaded() {
     let setup = TestSetup::init().await;
     let now = IggyTimestamp::now().to_micros();
-    let (pat1, raw_token1) = PersonalAccessToken::new(1, "test1", now, None);
-    let (pat2, raw_token2) = PersonalAccessToken::new(2, "test2", now, Some(1000));
-    let (pat3, raw_token3) = PersonalAccessToken::new(3, "test3", now, Some(100_000));
+    let (pat1, raw_token1) = PersonalAccessToken::new(1, "test1", now, None, None);
+    let (pat2, raw_token2) = PersonalAccessToken::new(2, "test2", now, Some(1000), None);
+    let (pat3, raw_token3) = PersonalAccessToken::new(3, "test3", now, Some(100_000), None);
 
     setup
         .storage
@@ -102,7 +102,7 @@ async fn personal_access_token_should_be_deleted() {
     let setup = TestSetup::init().await;
     let user_id = 1;
     let now = IggyTimestamp::now().to_micros();
-    let (personal_access_token, _) = PersonalAccessToken::new(user_id, "test", now, None);
+    let (personal_access_token, _) = PersonalAccessToken::new(user_id, "test", now, None, None);
     setup
         .storage
         .personal_access_token
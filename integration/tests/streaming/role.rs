@@ -0,0 +1,131 @@
+use crate::streaming::common::test_setup::TestSetup;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::models::user_status::UserStatus;
+use server::configs::server::PersonalAccessTokenConfig;
+use server::streaming::session::Session;
+use server::streaming::systems::system::System;
+use std::net::{Ipv4Addr, SocketAddr};
+
+#[tokio::test]
+async fn user_should_be_granted_permissions_of_assigned_roles() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+    let root_session = Session::stateless(1, address);
+    system.init().await.unwrap();
+
+    let username = "user1";
+    let user_id = Identifier::named(username).unwrap();
+    system
+        .create_user(&root_session, username, "secret", UserStatus::Active, None)
+        .await
+        .unwrap();
+    let user = system.get_user(&user_id).await.unwrap();
+    let user_session = Session::stateless(user.id, address);
+    assert!(matches!(
+        system.find_streams(&user_session),
+        Err(IggyError::Unauthorized)
+    ));
+
+    let role_name = "readers";
+    let role_id = Identifier::named(role_name).unwrap();
+    system
+        .create_role(&root_session, role_name, read_streams_permissions())
+        .await
+        .unwrap();
+    let role = system.find_role(&root_session, &role_id).await.unwrap();
+    system
+        .update_user_roles(&root_session, &user_id, vec![role.id, role.id])
+        .await
+        .unwrap();
+    let user = system.get_user(&user_id).await.unwrap();
+    assert_eq!(user.roles, vec![role.id]);
+    assert!(system.find_streams(&user_session).is_ok());
+
+    system
+        .update_role(&root_session, &role_id, None, Some(Permissions::default()))
+        .await
+        .unwrap();
+    assert!(system.find_streams(&user_session).is_err());
+
+    system
+        .update_role(
+            &root_session,
+            &role_id,
+            None,
+            Some(read_streams_permissions()),
+        )
+        .await
+        .unwrap();
+    assert!(system.find_streams(&user_session).is_ok());
+
+    system.delete_role(&root_session, &role_id).await.unwrap();
+    assert!(system.find_streams(&user_session).is_err());
+    let user = system.get_user(&user_id).await.unwrap();
+    assert!(user.roles.is_empty());
+}
+
+#[tokio::test]
+async fn assigning_not_existing_role_should_fail() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let root_session = Session::stateless(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+
+    let result = system
+        .update_user_roles(&root_session, &Identifier::numeric(1).unwrap(), vec![999])
+        .await;
+    assert!(matches!(result, Err(IggyError::RoleNotFound(999))));
+}
+
+#[tokio::test]
+async fn renamed_role_should_be_found_only_by_its_new_name() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let root_session = Session::stateless(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+
+    let role_id = Identifier::named("readers").unwrap();
+    system
+        .create_role(&root_session, "readers", read_streams_permissions())
+        .await
+        .unwrap();
+    let role = system.find_role(&root_session, &role_id).await.unwrap();
+    system
+        .update_role(&root_session, &role_id, Some("viewers".to_string()), None)
+        .await
+        .unwrap();
+
+    let renamed_role = system
+        .find_role(&root_session, &Identifier::named("viewers").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(renamed_role.id, role.id);
+    assert_eq!(renamed_role.permissions, read_streams_permissions());
+    assert!(system.find_role(&root_session, &role_id).await.is_err());
+}
+
+fn read_streams_permissions() -> Permissions {
+    Permissions {
+        global: GlobalPermissions {
+            read_streams: true,
+            ..Default::default()
+        },
+        streams: None,
+        stream_patterns: None,
+    }
+}
@@ -1,9 +1,11 @@
 use crate::streaming::common::test_setup::TestSetup;
 use bytes::Bytes;
 use iggy::models::messages::{Message, MessageState};
+use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor, KeyRingEncryptor};
 use iggy::utils::{checksum, timestamp::IggyTimestamp};
 use server::streaming::segments::segment;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::fs;
 
@@ -126,7 +128,7 @@ async fn should_persist_and_load_segment_with_messages() {
     .await;
     let messages_count = 10;
     for i in 0..messages_count {
-        let message = create_message(i, "test", IggyTimestamp::now().to_micros());
+        let message = create_message(i, b"test", IggyTimestamp::now().to_micros());
         segment.append_messages(&[Arc::new(message)]).await.unwrap();
     }
 
@@ -183,7 +185,7 @@ async fn given_all_expired_messages_segment_should_be_expired() {
     let message_expiry = message_expiry as u64;
     let mut expired_timestamp = now - (1000 * 2 * message_expiry);
     for i in 0..messages_count {
-        let message = create_message(i, "test", expired_timestamp);
+        let message = create_message(i, b"test", expired_timestamp);
         expired_timestamp += 1;
         segment.append_messages(&[Arc::new(message)]).await.unwrap();
     }
@@ -227,8 +229,8 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
     let message_expiry = message_expiry as u64;
     let expired_timestamp = now - (1000 * 2 * message_expiry);
     let not_expired_timestamp = now - (1000 * message_expiry) + 1;
-    let expired_message = create_message(0, "test", expired_timestamp);
-    let not_expired_message = create_message(1, "test", not_expired_timestamp);
+    let expired_message = create_message(0, b"test", expired_timestamp);
+    let not_expired_message = create_message(1, b"test", not_expired_timestamp);
 
     segment
         .append_messages(&[Arc::new(expired_message)])
@@ -244,6 +246,77 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
     assert!(!is_expired);
 }
 
+#[tokio::test]
+async fn closed_segment_messages_should_be_re_encrypted_with_the_active_key() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+
+    let legacy_key = Aes256GcmEncryptor::new(&[1; 32]).unwrap();
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let payload = legacy_key.encrypt(format!("test {i}").as_bytes()).unwrap();
+        let message = create_message(i, &payload, IggyTimestamp::now().to_micros());
+        segment.append_messages(&[Arc::new(message)]).await.unwrap();
+    }
+    segment.persist_messages().await.unwrap();
+    segment.is_closed = true;
+
+    let key_ring = KeyRingEncryptor::new(
+        Some(2),
+        HashMap::from([(2, Aes256GcmEncryptor::new(&[2; 32]).unwrap())]),
+        Some(Aes256GcmEncryptor::new(&[1; 32]).unwrap()),
+    )
+    .unwrap();
+    let re_encrypted_messages = segment.re_encrypt_messages(&key_ring).await.unwrap();
+    assert_eq!(re_encrypted_messages, messages_count);
+    let re_encrypted_messages = segment.re_encrypt_messages(&key_ring).await.unwrap();
+    assert_eq!(re_encrypted_messages, 0);
+
+    let active_key_only = KeyRingEncryptor::new(
+        Some(2),
+        HashMap::from([(2, Aes256GcmEncryptor::new(&[2; 32]).unwrap())]),
+        None,
+    )
+    .unwrap();
+    let mut loaded_segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_segment.load().await.unwrap();
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count as usize);
+    for (i, message) in messages.iter().enumerate() {
+        assert_eq!(message.offset, i as u64);
+        assert_eq!(message.checksum, checksum::calculate(&message.payload));
+        let payload = active_key_only.decrypt(&message.payload).unwrap();
+        assert_eq!(payload, format!("test {i}").as_bytes());
+    }
+}
+
 async fn assert_persisted_segment(partition_path: &str, start_offset: u64) {
     let segment_path = format!("{}/{:0>20}", partition_path, start_offset);
     let log_path = format!("{}.{}", segment_path, LOG_EXTENSION);
@@ -254,8 +327,8 @@ async fn assert_persisted_segment(partition_path: &str, start_offset: u64) {
     assert!(fs::metadata(&time_index_path).await.is_ok());
 }
 
-fn create_message(offset: u64, payload: &str, timestamp: u64) -> Message {
-    let payload = Bytes::from(payload.to_string());
+fn create_message(offset: u64, payload: &[u8], timestamp: u64) -> Message {
+    let payload = Bytes::copy_from_slice(payload);
     let checksum = checksum::calculate(payload.as_ref());
     Message::create(
         offset,
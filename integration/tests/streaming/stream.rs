@@ -3,6 +3,8 @@ use crate::streaming::create_messages;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::Partitioning;
+use iggy::models::stream_quotas::StreamQuotas;
+use iggy::utils::byte_size::IggyByteSize;
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::streams::stream::Stream;
 use tokio::fs;
@@ -54,6 +56,31 @@ async fn should_load_existing_stream_from_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_load_existing_stream_with_quotas_from_disk() {
+    let setup = TestSetup::init().await;
+    setup.create_streams_directory().await;
+    let stream_id = 1;
+    let mut stream = Stream::create(
+        stream_id,
+        "test",
+        setup.config.clone(),
+        setup.storage.clone(),
+    );
+    stream.quotas = Some(StreamQuotas {
+        max_size: Some(IggyByteSize::from(1_000_000)),
+        max_topics: Some(10),
+        max_partitions: Some(100),
+        max_consumer_groups: None,
+    });
+    stream.persist().await.unwrap();
+
+    let mut loaded_stream = Stream::empty(stream_id, setup.config.clone(), setup.storage.clone());
+    loaded_stream.load().await.unwrap();
+
+    assert_eq!(loaded_stream.quotas, stream.quotas);
+}
+
 #[tokio::test]
 async fn should_delete_existing_stream_from_disk() {
     let setup = TestSetup::init().await;
@@ -61,7 +88,7 @@ async fn should_delete_existing_stream_from_disk() {
     let stream_ids = get_stream_ids();
     for stream_id in stream_ids {
         let name = format!("test-{}", stream_id);
-        let stream = Stream::create(
+        let mut stream = Stream::create(
             stream_id,
             &name,
             setup.config.clone(),
@@ -104,7 +131,7 @@ async fn should_purge_existing_stream_on_disk() {
             .get_topic(&Identifier::numeric(topic_id).unwrap())
             .unwrap();
         topic
-            .append_messages(&Partitioning::partition_id(1), messages)
+            .append_messages(&Partitioning::partition_id(1), None, None, messages)
             .await
             .unwrap();
         let loaded_messages = topic
@@ -113,6 +140,7 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                false,
             )
             .await
             .unwrap();
@@ -126,6 +154,7 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                false,
             )
             .await
             .unwrap();
@@ -0,0 +1,172 @@
+use crate::streaming::common::test_setup::TestSetup;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning};
+use iggy::utils::text;
+use server::configs::server::PersonalAccessTokenConfig;
+use server::configs::system::{EncryptionKeyConfig, SystemConfig};
+use server::streaming::polling_consumer::PollingConsumer;
+use server::streaming::session::Session;
+use server::streaming::systems::messages::PollingArgs;
+use server::streaming::systems::system::System;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn messages_should_be_encrypted_with_the_stream_data_key_and_decrypted_after_restart() {
+    let mut config = SystemConfig::default();
+    config.encryption.enabled = true;
+    config.encryption.key = text::as_base64(&[1; 32]);
+    config.encryption.per_stream_keys = true;
+    let setup = TestSetup::init_with_config(config).await;
+    let session = create_root_session();
+    let mut system = init_system(&setup, setup.config.clone()).await;
+    create_stream_and_topic(&mut system, &session).await;
+    append_message(&system, &session, "message 1").await;
+    assert_polled_message(&system, &session, "message 1").await;
+
+    let system = init_system(&setup, setup.config.clone()).await;
+    assert_polled_message(&system, &session, "message 1").await;
+}
+
+#[tokio::test]
+async fn stream_data_key_should_be_re_wrapped_with_the_active_master_key() {
+    let mut config = SystemConfig::default();
+    config.encryption.enabled = true;
+    config.encryption.per_stream_keys = true;
+    config.encryption.active_key_id = 1;
+    config.encryption.keys = vec![create_key_config(1)];
+    let setup = TestSetup::init_with_config(config).await;
+    let session = create_root_session();
+    let mut system = init_system(&setup, setup.config.clone()).await;
+    create_stream_and_topic(&mut system, &session).await;
+    append_message(&system, &session, "message 1").await;
+
+    let mut config = SystemConfig::default();
+    config.path = setup.config.path.clone();
+    config.encryption.enabled = true;
+    config.encryption.per_stream_keys = true;
+    config.encryption.active_key_id = 2;
+    config.encryption.keys = vec![create_key_config(1), create_key_config(2)];
+    let mut system = init_system(&setup, Arc::new(config)).await;
+    let re_wrapped_keys = system.re_wrap_stream_encryption_keys().await.unwrap();
+    assert_eq!(re_wrapped_keys, 1);
+    let re_wrapped_keys = system.re_wrap_stream_encryption_keys().await.unwrap();
+    assert_eq!(re_wrapped_keys, 0);
+
+    let mut config = SystemConfig::default();
+    config.path = setup.config.path.clone();
+    config.encryption.enabled = true;
+    config.encryption.per_stream_keys = true;
+    config.encryption.active_key_id = 2;
+    config.encryption.keys = vec![create_key_config(2)];
+    let system = init_system(&setup, Arc::new(config)).await;
+    assert_polled_message(&system, &session, "message 1").await;
+}
+
+#[tokio::test]
+async fn messages_should_not_be_decrypted_after_failed_deletion_of_stream_files() {
+    let mut config = SystemConfig::default();
+    config.encryption.enabled = true;
+    config.encryption.key = text::as_base64(&[1; 32]);
+    config.encryption.per_stream_keys = true;
+    let setup = TestSetup::init_with_config(config).await;
+    let session = create_root_session();
+    let mut system = init_system(&setup, setup.config.clone()).await;
+    create_stream_and_topic(&mut system, &session).await;
+    append_message(&system, &session, "message 1").await;
+    let topic_path = setup.config.get_topic_path(1, 1);
+    let moved_topic_path = format!("{topic_path}_moved");
+    std::fs::rename(&topic_path, &moved_topic_path).unwrap();
+
+    let result = system
+        .delete_stream(&session, &Identifier::numeric(1).unwrap())
+        .await;
+    assert!(matches!(result, Err(IggyError::CannotDeleteStream(1))));
+
+    std::fs::rename(&moved_topic_path, &topic_path).unwrap();
+    let polled_messages = system
+        .poll_messages(
+            &session,
+            PollingConsumer::Consumer(1, 1),
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(1).unwrap(),
+            PollingArgs::new(PollingStrategy::offset(0), 1, false, false),
+        )
+        .await;
+    assert!(matches!(polled_messages, Err(IggyError::CannotDecryptData)));
+}
+
+async fn init_system(setup: &TestSetup, config: Arc<SystemConfig>) -> System {
+    let mut system = System::new(
+        config,
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    system.init().await.unwrap();
+    system
+}
+
+async fn create_stream_and_topic(system: &mut System, session: &Session) {
+    system
+        .create_stream(session, Some(1), "test", None)
+        .await
+        .unwrap();
+    system
+        .create_topic(
+            session,
+            &Identifier::numeric(1).unwrap(),
+            Some(1),
+            "test",
+            1,
+            None,
+            None,
+            1,
+        )
+        .await
+        .unwrap();
+}
+
+async fn append_message(system: &System, session: &Session, payload: &str) {
+    let messages = vec![Message::new(None, Bytes::from(payload.to_string()), None)];
+    system
+        .append_messages(
+            session,
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(1).unwrap(),
+            &Partitioning::partition_id(1),
+            None,
+            AckLevel::Fsync,
+            &messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn assert_polled_message(system: &System, session: &Session, payload: &str) {
+    let polled_messages = system
+        .poll_messages(
+            session,
+            PollingConsumer::Consumer(1, 1),
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(1).unwrap(),
+            PollingArgs::new(PollingStrategy::offset(0), 1, false, false),
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_eq!(polled_messages.messages[0].payload, payload.as_bytes());
+}
+
+fn create_key_config(id: u32) -> EncryptionKeyConfig {
+    EncryptionKeyConfig {
+        id,
+        key: text::as_base64(&[id as u8; 32]),
+    }
+}
+
+fn create_root_session() -> Session {
+    Session::stateless(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234))
+}
@@ -44,7 +44,7 @@ async fn should_create_and_persist_stream() {
     system.init().await.unwrap();
 
     system
-        .create_stream(&session, Some(stream_id), stream_name)
+        .create_stream(&session, Some(stream_id), stream_name, None)
         .await
         .unwrap();
 
@@ -65,7 +65,7 @@ async fn should_create_and_persist_stream_with_automatically_generated_id() {
     system.init().await.unwrap();
 
     system
-        .create_stream(&session, None, stream_name)
+        .create_stream(&session, None, stream_name, None)
         .await
         .unwrap();
 
@@ -85,7 +85,7 @@ async fn should_delete_persisted_stream() {
     let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
     system.init().await.unwrap();
     system
-        .create_stream(&session, Some(stream_id), stream_name)
+        .create_stream(&session, Some(stream_id), stream_name, None)
         .await
         .unwrap();
     assert_persisted_stream(&setup.config.get_streams_path(), stream_id).await;
@@ -151,7 +151,7 @@ async fn should_purge_existing_topic_on_disk() {
         let messages = create_messages();
         let messages_count = messages.len();
         topic
-            .append_messages(&Partitioning::partition_id(1), messages)
+            .append_messages(&Partitioning::partition_id(1), None, None, messages)
             .await
             .unwrap();
         let loaded_messages = topic
@@ -160,6 +160,7 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                false,
             )
             .await
             .unwrap();
@@ -172,6 +173,7 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                false,
             )
             .await
             .unwrap();
@@ -69,7 +69,7 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
         sent_messages.push(get_message(from_utf8(&message.payload).unwrap()))
     }
     topic
-        .append_messages(&partitioning, messages)
+        .append_messages(&partitioning, None, None, messages)
         .await
         .unwrap();
 
@@ -80,6 +80,7 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
             partition_id,
             PollingStrategy::offset(0),
             messages_count,
+            false,
         )
         .await
         .unwrap();
@@ -109,7 +110,7 @@ async fn given_key_none_messages_should_be_appended_to_the_next_partition_using_
     for i in 1..=partitions_count * messages_per_partition_count {
         let payload = get_payload(i);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(&partitioning, None, None, vec![get_message(&payload)])
             .await
             .unwrap();
     }
@@ -129,7 +130,7 @@ async fn given_key_partition_id_messages_should_be_appended_to_the_chosen_partit
     for i in 1..=partitions_count * messages_per_partition_count {
         let payload = get_payload(i);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(&partitioning, None, None, vec![get_message(&payload)])
             .await
             .unwrap();
     }
@@ -153,7 +154,7 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
         let payload = get_payload(entity_id);
         let partitioning = Partitioning::messages_key_u32(entity_id);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(&partitioning, None, None, vec![get_message(&payload)])
             .await
             .unwrap();
     }
@@ -185,7 +186,13 @@ fn get_payload(id: u32) -> String {
 async fn assert_messages(topic: &Topic, partition_id: u32, expected_messages: u32) {
     let consumer = PollingConsumer::Consumer(0, partition_id);
     let polled_messages = topic
-        .get_messages(consumer, partition_id, PollingStrategy::offset(0), 1000)
+        .get_messages(
+            consumer,
+            partition_id,
+            PollingStrategy::offset(0),
+            1000,
+            false,
+        )
         .await
         .unwrap();
     assert_eq!(polled_messages.messages.len() as u32, expected_messages);
@@ -2,6 +2,7 @@ use crate::streaming::common::test_setup::TestSetup;
 use iggy::models::permissions::{
     GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
 };
+use iggy::models::user_quotas::UserQuotas;
 use iggy::models::user_status::UserStatus;
 use iggy::utils::timestamp::IggyTimestamp;
 use server::streaming::users::user::User;
@@ -68,6 +69,13 @@ fn assert_user(user: &User, loaded_user: &User) {
     assert_eq!(loaded_user.password, user.password);
     assert_eq!(loaded_user.created_at, user.created_at);
     assert_eq!(loaded_user.status, user.status);
+    assert_eq!(loaded_user.quotas, user.quotas);
+    assert_eq!(loaded_user.roles, user.roles);
+    assert_eq!(
+        loaded_user.failed_login_attempts,
+        user.failed_login_attempts
+    );
+    assert_eq!(loaded_user.locked_until, user.locked_until);
     if user.permissions.is_none() {
         assert!(loaded_user.permissions.is_none());
         return;
@@ -197,10 +205,20 @@ fn create_user(id: u32) -> User {
                             );
                             map
                         }),
+                        topic_patterns: None,
                     },
                 );
                 map
             }),
+            stream_patterns: None,
+        }),
+        quotas: Some(UserQuotas {
+            produce_bytes_per_second: 1000,
+            consume_bytes_per_second: 2000,
+            requests_per_second: 10,
         }),
+        roles: vec![1, 2],
+        failed_login_attempts: 3,
+        locked_until: IggyTimestamp::now().to_micros() + 1_000_000,
     }
 }
@@ -40,6 +40,18 @@ pub struct Args {
     #[arg(long, default_value = "localhost")]
     pub tcp_tls_domain: String,
 
+    /// The optional path to the PEM client certificate for the mutual TLS of the TCP transport.
+    #[arg(long)]
+    pub tcp_tls_client_certificate: Option<String>,
+
+    /// The optional path to the PEM private key of the client certificate for the TCP transport.
+    #[arg(long)]
+    pub tcp_tls_client_key: Option<String>,
+
+    /// Flag to enable pipelining of the requests for the TCP transport, requires the server supporting it.
+    #[arg(long, default_value = "false")]
+    pub tcp_pipelining: bool,
+
     /// The optional client address for the QUIC transport.
     #[arg(long, default_value = "127.0.0.1:0")]
     pub quic_client_address: String,
@@ -95,4 +107,12 @@ pub struct Args {
     /// Flag to enable certificate validation for QUIC.
     #[arg(long, default_value = "false")]
     pub quic_validate_certificate: bool,
+
+    /// The optional path to the PEM client certificate for the mutual TLS of the QUIC transport.
+    #[arg(long)]
+    pub quic_client_certificate: Option<String>,
+
+    /// The optional path to the PEM private key of the client certificate for the QUIC transport.
+    #[arg(long)]
+    pub quic_client_key: Option<String>,
 }
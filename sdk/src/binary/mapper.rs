@@ -1,17 +1,25 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::error::IggyError;
+use crate::models::audit_log_entry::AuditLogEntry;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
+use crate::models::config_reload::ConfigReload;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::health::{Health, HealthCheck};
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{Message, MessageState, PolledMessages};
 use crate::models::partition::Partition;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::producer_info::ProducerInfo;
+use crate::models::role_info::RoleInfo;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
+use crate::models::stream_quotas::StreamQuotas;
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction_info::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::models::user_quotas::UserQuotas;
 use crate::models::user_status::UserStatus;
 use crate::utils::byte_size::IggyByteSize;
 use bytes::Bytes;
@@ -22,7 +30,9 @@ const EMPTY_MESSAGES: Vec<Message> = vec![];
 const EMPTY_TOPICS: Vec<Topic> = vec![];
 const EMPTY_STREAMS: Vec<Stream> = vec![];
 const EMPTY_CLIENTS: Vec<ClientInfo> = vec![];
+const EMPTY_AUDIT_LOG_ENTRIES: Vec<AuditLogEntry> = vec![];
 const EMPTY_USERS: Vec<UserInfo> = vec![];
+const EMPTY_ROLES: Vec<RoleInfo> = vec![];
 const EMPTY_PERSONAL_ACCESS_TOKENS: Vec<PersonalAccessTokenInfo> = vec![];
 const EMPTY_CONSUMER_GROUPS: Vec<ConsumerGroup> = vec![];
 
@@ -105,23 +115,63 @@ pub fn map_consumer_offset(payload: &[u8]) -> Result<ConsumerOffsetInfo, IggyErr
 }
 
 pub fn map_user(payload: &[u8]) -> Result<UserInfoDetails, IggyError> {
-    let (user, position) = map_to_user_info(payload, 0)?;
+    let (user, mut position) = map_to_user_info(payload, 0)?;
     let has_permissions = payload[position];
     let permissions = if has_permissions == 1 {
         let permissions_length =
             u32::from_le_bytes(payload[position + 1..position + 5].try_into()?) as usize;
         let permissions = &payload[position + 5..position + 5 + permissions_length];
+        position += 5 + permissions_length;
         Some(Permissions::from_bytes(permissions)?)
     } else {
+        position += 1;
         None
     };
 
+    // The quotas are not available when connected to the older server.
+    let quotas = if payload.len() > position && payload[position] == 1 {
+        let quotas = UserQuotas::from_bytes(&payload[position + 1..])?;
+        position += 1 + quotas.get_size_bytes() as usize;
+        Some(quotas)
+    } else {
+        position += 1;
+        None
+    };
+
+    // The roles are not available when connected to the older server.
+    let mut roles = Vec::new();
+    if payload.len() >= position + 4 {
+        let roles_count = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        position += 4;
+        for _ in 0..roles_count {
+            roles.push(u32::from_le_bytes(
+                payload[position..position + 4].try_into()?,
+            ));
+            position += 4;
+        }
+    }
+
+    // The lockout state is not available when connected to the older server.
+    let mut failed_login_attempts = 0;
+    let mut locked_until = None;
+    if payload.len() >= position + 12 {
+        failed_login_attempts = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let timestamp = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        if timestamp > 0 {
+            locked_until = Some(timestamp);
+        }
+    }
+
     let user = UserInfoDetails {
         id: user.id,
         created_at: user.created_at,
         status: user.status,
         username: user.username,
         permissions,
+        quotas,
+        roles,
+        failed_login_attempts,
+        locked_until,
     };
     Ok(user)
 }
@@ -143,6 +193,28 @@ pub fn map_users(payload: &[u8]) -> Result<Vec<UserInfo>, IggyError> {
     Ok(users)
 }
 
+pub fn map_role(payload: &[u8]) -> Result<RoleInfo, IggyError> {
+    let (role, _) = map_to_role_info(payload, 0)?;
+    Ok(role)
+}
+
+pub fn map_roles(payload: &[u8]) -> Result<Vec<RoleInfo>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_ROLES);
+    }
+
+    let mut roles = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (role, read_bytes) = map_to_role_info(payload, position)?;
+        roles.push(role);
+        position += read_bytes;
+    }
+    roles.sort_by(|x, y| x.id.cmp(&y.id));
+    Ok(roles)
+}
+
 pub fn map_personal_access_tokens(
     payload: &[u8],
 ) -> Result<Vec<PersonalAccessTokenInfo>, IggyError> {
@@ -225,6 +297,99 @@ pub fn map_clients(payload: &[u8]) -> Result<Vec<ClientInfo>, IggyError> {
     Ok(clients)
 }
 
+pub fn map_audit_log(payload: &[u8]) -> Result<Vec<AuditLogEntry>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_AUDIT_LOG_ENTRIES);
+    }
+
+    let mut entries = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let id = u64::from_le_bytes(payload[position..position + 8].try_into()?);
+        let timestamp = u64::from_le_bytes(payload[position + 8..position + 16].try_into()?);
+        let user_id = u32::from_le_bytes(payload[position + 16..position + 20].try_into()?);
+        let client_id = u32::from_le_bytes(payload[position + 20..position + 24].try_into()?);
+        let succeeded = payload[position + 24] == 1;
+        position += 25;
+        let (address, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        let (transport, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        let (action, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        let (resource, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        let (previous_hash, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        let (hash, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        entries.push(AuditLogEntry {
+            id,
+            timestamp,
+            user_id,
+            client_id,
+            address,
+            transport,
+            action,
+            resource,
+            succeeded,
+            previous_hash,
+            hash,
+        });
+    }
+    Ok(entries)
+}
+
+pub fn map_config_reload(payload: &[u8]) -> Result<ConfigReload, IggyError> {
+    let mut position = 0;
+    let (applied_changes, read_bytes) = map_to_strings(payload, position)?;
+    position += read_bytes;
+    let (restart_required_changes, _) = map_to_strings(payload, position)?;
+    Ok(ConfigReload {
+        applied_changes,
+        restart_required_changes,
+    })
+}
+
+pub fn map_health(payload: &[u8]) -> Result<Health, IggyError> {
+    let count = u32::from_le_bytes(payload[..4].try_into()?);
+    let mut checks = Vec::with_capacity(count as usize);
+    let mut position = 4;
+    for _ in 0..count {
+        let healthy = payload[position] == 1;
+        position += 1;
+        let (name, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        let (details, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        checks.push(HealthCheck {
+            name,
+            healthy,
+            details,
+        });
+    }
+    Ok(Health { checks })
+}
+
+fn map_to_strings(payload: &[u8], position: usize) -> Result<(Vec<String>, usize), IggyError> {
+    let count = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let mut values = Vec::with_capacity(count as usize);
+    let mut read_bytes = 4;
+    for _ in 0..count {
+        let (value, value_read_bytes) = map_to_string(payload, position + read_bytes)?;
+        read_bytes += value_read_bytes;
+        values.push(value);
+    }
+    Ok((values, read_bytes))
+}
+
+fn map_to_string(payload: &[u8], position: usize) -> Result<(String, usize), IggyError> {
+    let length = u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+    let value = from_utf8(&payload[position + 4..position + 4 + length])?.to_string();
+    Ok((value, 4 + length))
+}
+
 pub fn map_polled_messages(payload: &[u8]) -> Result<PolledMessages, IggyError> {
     if payload.is_empty() {
         return Ok(PolledMessages {
@@ -288,6 +453,16 @@ pub fn map_polled_messages(payload: &[u8]) -> Result<PolledMessages, IggyError>
     })
 }
 
+pub fn map_producer_info(payload: &[u8]) -> Result<ProducerInfo, IggyError> {
+    let producer_id = u64::from_le_bytes(payload[..8].try_into()?);
+    Ok(ProducerInfo { producer_id })
+}
+
+pub fn map_transaction_info(payload: &[u8]) -> Result<TransactionInfo, IggyError> {
+    let transaction_id = u64::from_le_bytes(payload[..8].try_into()?);
+    Ok(TransactionInfo { transaction_id })
+}
+
 pub fn map_streams(payload: &[u8]) -> Result<Vec<Stream>, IggyError> {
     if payload.is_empty() {
         return Ok(EMPTY_STREAMS);
@@ -308,13 +483,19 @@ pub fn map_streams(payload: &[u8]) -> Result<Vec<Stream>, IggyError> {
 pub fn map_stream(payload: &[u8]) -> Result<StreamDetails, IggyError> {
     let (stream, mut position) = map_to_stream(payload, 0)?;
     let mut topics = Vec::new();
-    let length = payload.len();
-    while position < length {
+    for _ in 0..stream.topics_count {
         let (topic, read_bytes) = map_to_topic(payload, position)?;
         topics.push(topic);
         position += read_bytes;
     }
 
+    // The quotas are not available when connected to the older server.
+    let quotas = if payload.len() > position && payload[position] == 1 {
+        Some(StreamQuotas::from_bytes(&payload[position + 1..])?)
+    } else {
+        None
+    };
+
     topics.sort_by(|x, y| x.id.cmp(&y.id));
     let stream = StreamDetails {
         id: stream.id,
@@ -324,6 +505,7 @@ pub fn map_stream(payload: &[u8]) -> Result<StreamDetails, IggyError> {
         messages_count: stream.messages_count,
         name: stream.name,
         topics,
+        quotas,
     };
     Ok(stream)
 }
@@ -600,6 +782,31 @@ fn map_to_user_info(payload: &[u8], position: usize) -> Result<(UserInfo, usize)
     ))
 }
 
+fn map_to_role_info(payload: &[u8], position: usize) -> Result<(RoleInfo, usize), IggyError> {
+    let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let created_at = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+    let name_length = payload[position + 12] as usize;
+    let name = from_utf8(&payload[position + 13..position + 13 + name_length])?.to_string();
+    let permissions_position = position + 13 + name_length;
+    let permissions_length =
+        u32::from_le_bytes(payload[permissions_position..permissions_position + 4].try_into()?)
+            as usize;
+    let permissions = Permissions::from_bytes(
+        &payload[permissions_position + 4..permissions_position + 4 + permissions_length],
+    )?;
+    let read_bytes = 4 + 8 + 1 + name_length + 4 + permissions_length;
+
+    Ok((
+        RoleInfo {
+            id,
+            created_at,
+            name,
+            permissions,
+        },
+        read_bytes,
+    ))
+}
+
 fn map_to_pat_info(
     payload: &[u8],
     position: usize,
@@ -612,7 +819,24 @@ fn map_to_pat_info(
         0 => None,
         _ => Some(expiry),
     };
-    let read_bytes = 1 + name_length as usize + 8;
+    let position = position + 8;
+    let mut scope = None;
+    let mut read_bytes = 1 + name_length as usize + 8 + 1;
+    if payload[position] == 1 {
+        let scope_length =
+            u32::from_le_bytes(payload[position + 1..position + 5].try_into()?) as usize;
+        scope = Some(Permissions::from_bytes(
+            &payload[position + 5..position + 5 + scope_length],
+        )?);
+        read_bytes += 4 + scope_length;
+    }
 
-    Ok((PersonalAccessTokenInfo { name, expiry }, read_bytes))
+    Ok((
+        PersonalAccessTokenInfo {
+            name,
+            expiry,
+            scope,
+        },
+        read_bytes,
+    ))
 }
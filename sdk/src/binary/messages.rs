@@ -2,11 +2,13 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::client::MessageClient;
-use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::command::{INIT_PRODUCER_CODE, POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
 use crate::error::IggyError;
+use crate::messages::init_producer::InitProducer;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::models::messages::PolledMessages;
+use crate::models::producer_info::ProducerInfo;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> MessageClient for B {
@@ -24,4 +26,12 @@ impl<B: BinaryClient> MessageClient for B {
             .await?;
         Ok(())
     }
+
+    async fn init_producer(&self, command: &InitProducer) -> Result<ProducerInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(INIT_PRODUCER_CODE, &command.as_bytes())
+            .await?;
+        mapper::map_producer_info(&response)
+    }
 }
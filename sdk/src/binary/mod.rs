@@ -8,9 +8,11 @@ mod mapper;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod roles;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 
 async fn fail_if_not_authenticated(client: &dyn BinaryClient) -> Result<(), IggyError> {
@@ -19,3 +21,13 @@ async fn fail_if_not_authenticated(client: &dyn BinaryClient) -> Result<(), Iggy
     }
     Ok(())
 }
+
+/// Maps the error response with the additional payload (if any) to the specific error.
+pub(crate) fn map_error(status: u32, payload: &[u8]) -> Option<IggyError> {
+    if status == IggyError::QuotaExceeded(0).as_code() && payload.len() == 8 {
+        let retry_after = u64::from_le_bytes(payload.try_into().unwrap());
+        return Some(IggyError::QuotaExceeded(retry_after));
+    }
+
+    None
+}
@@ -0,0 +1,54 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::client::RoleClient;
+use crate::command::{
+    CREATE_ROLE_CODE, DELETE_ROLE_CODE, GET_ROLES_CODE, GET_ROLE_CODE, UPDATE_ROLE_CODE,
+};
+use crate::error::IggyError;
+use crate::models::role_info::RoleInfo;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::update_role::UpdateRole;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> RoleClient for B {
+    async fn get_role(&self, command: &GetRole) -> Result<RoleInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_ROLE_CODE, &command.as_bytes())
+            .await?;
+        mapper::map_role(&response)
+    }
+
+    async fn get_roles(&self, command: &GetRoles) -> Result<Vec<RoleInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_ROLES_CODE, &command.as_bytes())
+            .await?;
+        mapper::map_roles(&response)
+    }
+
+    async fn create_role(&self, command: &CreateRole) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(CREATE_ROLE_CODE, &command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_role(&self, command: &DeleteRole) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(DELETE_ROLE_CODE, &command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn update_role(&self, command: &UpdateRole) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UPDATE_ROLE_CODE, &command.as_bytes())
+            .await?;
+        Ok(())
+    }
+}
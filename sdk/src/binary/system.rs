@@ -2,15 +2,25 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::client::SystemClient;
-use crate::command::{GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_ME_CODE, GET_STATS_CODE, PING_CODE};
+use crate::command::{
+    GET_AUDIT_LOG_CODE, GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_HEALTH_CODE, GET_ME_CODE,
+    GET_STATS_CODE, PING_CODE, RELOAD_CONFIG_CODE, SET_LOG_FILTER_CODE,
+};
 use crate::error::IggyError;
+use crate::models::audit_log_entry::AuditLogEntry;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::config_reload::ConfigReload;
+use crate::models::health::Health;
 use crate::models::stats::Stats;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
+use crate::system::get_health::GetHealth;
 use crate::system::get_me::GetMe;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_filter::SetLogFilter;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> SystemClient for B {
@@ -46,9 +56,39 @@ impl<B: BinaryClient> SystemClient for B {
         mapper::map_clients(&response)
     }
 
+    async fn get_audit_log(&self, command: &GetAuditLog) -> Result<Vec<AuditLogEntry>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_AUDIT_LOG_CODE, &command.as_bytes())
+            .await?;
+        mapper::map_audit_log(&response)
+    }
+
+    async fn reload_config(&self, command: &ReloadConfig) -> Result<ConfigReload, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(RELOAD_CONFIG_CODE, &command.as_bytes())
+            .await?;
+        mapper::map_config_reload(&response)
+    }
+
+    async fn set_log_filter(&self, command: &SetLogFilter) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(SET_LOG_FILTER_CODE, &command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
     async fn ping(&self, command: &Ping) -> Result<(), IggyError> {
         self.send_with_response(PING_CODE, &command.as_bytes())
             .await?;
         Ok(())
     }
+
+    async fn get_health(&self, command: &GetHealth) -> Result<Health, IggyError> {
+        let response = self
+            .send_with_response(GET_HEALTH_CODE, &command.as_bytes())
+            .await?;
+        mapper::map_health(&response)
+    }
 }
@@ -0,0 +1,38 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::client::TransactionClient;
+use crate::command::{ABORT_TRANSACTION_CODE, BEGIN_TRANSACTION_CODE, COMMIT_TRANSACTION_CODE};
+use crate::error::IggyError;
+use crate::models::transaction_info::TransactionInfo;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> TransactionClient for B {
+    async fn begin_transaction(
+        &self,
+        command: &BeginTransaction,
+    ) -> Result<TransactionInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(BEGIN_TRANSACTION_CODE, &command.as_bytes())
+            .await?;
+        mapper::map_transaction_info(&response)
+    }
+
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(COMMIT_TRANSACTION_CODE, &command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(ABORT_TRANSACTION_CODE, &command.as_bytes())
+            .await?;
+        Ok(())
+    }
+}
@@ -12,9 +12,13 @@ use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
+use crate::users::login_with_token::LoginWithToken;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_roles::UpdateUserRoles;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> UserClient for B {
@@ -62,6 +66,27 @@ impl<B: BinaryClient> UserClient for B {
         Ok(())
     }
 
+    async fn update_quotas(&self, command: &UpdateQuotas) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UPDATE_QUOTAS_CODE, &command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn update_user_roles(&self, command: &UpdateUserRoles) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UPDATE_USER_ROLES_CODE, &command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UNLOCK_USER_CODE, &command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(CHANGE_PASSWORD_CODE, &command.as_bytes())
@@ -77,6 +102,14 @@ impl<B: BinaryClient> UserClient for B {
         mapper::map_identity_info(&response)
     }
 
+    async fn login_with_token(&self, command: &LoginWithToken) -> Result<IdentityInfo, IggyError> {
+        let response = self
+            .send_with_response(LOGIN_WITH_TOKEN_CODE, &command.as_bytes())
+            .await?;
+        self.set_state(ClientState::Authenticated).await;
+        mapper::map_identity_info(&response)
+    }
+
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(LOGOUT_USER_CODE, &command.as_bytes())
@@ -44,6 +44,7 @@ impl PollMessagesCmd {
                 strategy,
                 count: message_count,
                 auto_commit,
+                read_committed: false,
             },
         }
     }
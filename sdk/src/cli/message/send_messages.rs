@@ -1,7 +1,7 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
-use crate::messages::send_messages::{Message, Partitioning, SendMessages};
+use crate::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
 use anyhow::Context;
 use async_trait::async_trait;
 use std::io::{self, Read};
@@ -82,6 +82,8 @@ impl CliCommand for SendMessagesCmd {
                 stream_id: self.stream_id.clone(),
                 topic_id: self.topic_id.clone(),
                 partitioning: self.partitioning.clone(),
+                producer: None,
+                ack: AckLevel::Memory,
                 messages,
             })
             .await
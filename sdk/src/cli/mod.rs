@@ -3,6 +3,7 @@ pub mod consumer_group;
 pub mod message;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod roles;
 pub mod streams;
 pub mod system;
 pub mod topics;
@@ -30,6 +30,7 @@ impl CreatePersonalAccessTokenCmd {
                     None => None,
                     Some(value) => value.into(),
                 },
+                scope: None,
             },
             token_expiry: pat_expiry,
             quiet_mode,
@@ -0,0 +1,75 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::models::permissions::Permissions;
+use crate::roles::update_role::UpdateRole;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+#[derive(Debug, Clone)]
+pub enum UpdateRoleType {
+    Name(String),
+    Permissions(Permissions),
+}
+
+pub struct UpdateRoleCmd {
+    update_type: UpdateRoleType,
+    update_role: UpdateRole,
+}
+
+impl UpdateRoleCmd {
+    pub fn new(role_id: Identifier, update_type: UpdateRoleType) -> Self {
+        let (name, permissions) = match update_type.clone() {
+            UpdateRoleType::Name(name) => (Some(name), None),
+            UpdateRoleType::Permissions(permissions) => (None, Some(permissions)),
+        };
+
+        UpdateRoleCmd {
+            update_type,
+            update_role: UpdateRole {
+                role_id,
+                name,
+                permissions,
+            },
+        }
+    }
+
+    fn get_message(&self) -> String {
+        match &self.update_type {
+            UpdateRoleType::Name(name) => format!("name: {}", name),
+            UpdateRoleType::Permissions(_) => "permissions".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdateRoleCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update role with ID: {} with {}",
+            self.update_role.role_id,
+            self.get_message()
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_role(&self.update_role)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating role with ID: {} with {}",
+                    self.update_role.role_id,
+                    self.get_message()
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Role with ID: {} updated with {}",
+            self.update_role.role_id, self.get_message()
+        );
+
+        Ok(())
+    }
+}
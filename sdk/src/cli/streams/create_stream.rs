@@ -15,6 +15,7 @@ impl CreateStreamCmd {
             create_stream: CreateStream {
                 stream_id: Some(stream_id),
                 name,
+                quotas: None,
             },
         }
     }
@@ -13,7 +13,11 @@ pub struct UpdateStreamCmd {
 impl UpdateStreamCmd {
     pub fn new(stream_id: Identifier, name: String) -> Self {
         UpdateStreamCmd {
-            update_stream: UpdateStream { stream_id, name },
+            update_stream: UpdateStream {
+                stream_id,
+                name,
+                quotas: None,
+            },
         }
     }
 }
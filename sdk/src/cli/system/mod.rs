@@ -1,3 +1,5 @@
 pub mod me;
 pub mod ping;
+pub mod reload_config;
+pub mod set_log_filter;
 pub mod stats;
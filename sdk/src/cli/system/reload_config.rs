@@ -0,0 +1,61 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::reload_config::ReloadConfig;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct ReloadConfigCmd {
+    reload_config: ReloadConfig,
+}
+
+impl ReloadConfigCmd {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for ReloadConfigCmd {
+    fn default() -> Self {
+        Self {
+            reload_config: ReloadConfig {},
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ReloadConfigCmd {
+    fn explain(&self) -> String {
+        "reload config command".to_owned()
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let config_reload = client
+            .reload_config(&self.reload_config)
+            .await
+            .with_context(|| "Problem sending reload_config command".to_owned())?;
+
+        if config_reload.applied_changes.is_empty()
+            && config_reload.restart_required_changes.is_empty()
+        {
+            event!(target: PRINT_TARGET, Level::INFO, "Server configuration reloaded, no changes found");
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Setting", "Status"]);
+        for change in &config_reload.applied_changes {
+            table.add_row(vec![change.as_str(), "applied"]);
+        }
+        for change in &config_reload.restart_required_changes {
+            table.add_row(vec![change.as_str(), "restart required"]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "Server configuration reloaded");
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
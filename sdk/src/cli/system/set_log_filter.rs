@@ -0,0 +1,50 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::set_log_filter::SetLogFilter;
+use crate::utils::duration::IggyDuration;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct SetLogFilterCmd {
+    set_log_filter: SetLogFilter,
+}
+
+impl SetLogFilterCmd {
+    pub fn new(filter: String, revert_after: Option<IggyDuration>) -> Self {
+        Self {
+            set_log_filter: SetLogFilter {
+                filter,
+                revert_after: revert_after
+                    .filter(|duration| !duration.is_zero())
+                    .map(|duration| duration.as_secs()),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for SetLogFilterCmd {
+    fn explain(&self) -> String {
+        match self.set_log_filter.revert_after {
+            Some(revert_after) => format!(
+                "set log filter: {} reverted after: {} s",
+                self.set_log_filter.filter, revert_after
+            ),
+            None => format!("set log filter: {}", self.set_log_filter.filter),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .set_log_filter(&self.set_log_filter)
+            .await
+            .with_context(|| {
+                format!("Problem setting log filter: {}", self.set_log_filter.filter)
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "Log filter: {} set", self.set_log_filter.filter);
+
+        Ok(())
+    }
+}
@@ -114,6 +114,18 @@ impl From<&StreamPermissions> for Table {
             });
         }
 
+        if let Some(topic_patterns) = &value.topic_patterns {
+            topic_patterns
+                .iter()
+                .for_each(|(topic_pattern, topic_permissions)| {
+                    let topic_table: Table = topic_permissions.into();
+                    table.add_row(vec![
+                        format!("Topic pattern: {}", topic_pattern).as_str(),
+                        format!("{}", topic_table).as_str(),
+                    ]);
+                });
+        }
+
         table
     }
 }
@@ -154,6 +166,18 @@ impl CliCommand for GetUserCmd {
         ]);
         table.add_row(vec!["Status", format!("{}", user.status).as_str()]);
         table.add_row(vec!["Username", user.username.as_str()]);
+        table.add_row(vec![
+            "Failed logins",
+            format!("{}", user.failed_login_attempts).as_str(),
+        ]);
+        if let Some(locked_until) = user.locked_until {
+            table.add_row(vec![
+                "Locked until",
+                IggyTimestamp::from(locked_until)
+                    .to_local("%Y-%m-%d %H:%M:%S")
+                    .as_str(),
+            ]);
+        }
 
         if let Some(permissions) = user.permissions {
             let global_permissions: Table = permissions.global.into();
@@ -168,6 +192,18 @@ impl CliCommand for GetUserCmd {
                     ]);
                 });
             }
+
+            if let Some(stream_patterns) = permissions.stream_patterns {
+                stream_patterns
+                    .iter()
+                    .for_each(|(stream_pattern, stream_permissions)| {
+                        let stream_permissions: Table = stream_permissions.into();
+                        table.add_row(vec![
+                            format!("Stream pattern: {}", stream_pattern).as_str(),
+                            format!("{}", stream_permissions).as_str(),
+                        ]);
+                    });
+            }
         };
 
         event!(target: PRINT_TARGET, Level::INFO, "{table}");
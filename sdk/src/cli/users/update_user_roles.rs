@@ -0,0 +1,48 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::users::update_user_roles::UpdateUserRoles;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UpdateUserRolesCmd {
+    update_user_roles: UpdateUserRoles,
+}
+
+impl UpdateUserRolesCmd {
+    pub fn new(user_id: Identifier, role_ids: Vec<u32>) -> Self {
+        Self {
+            update_user_roles: UpdateUserRoles { user_id, role_ids },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdateUserRolesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update roles for user with ID: {}",
+            self.update_user_roles.user_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_user_roles(&self.update_user_roles)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating roles for user with ID: {}",
+                    self.update_user_roles.user_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Roles for user with ID: {} updated",
+            self.update_user_roles.user_id
+        );
+
+        Ok(())
+    }
+}
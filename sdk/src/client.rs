@@ -7,17 +7,24 @@ use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::IggyError;
+use crate::messages::init_producer::InitProducer;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
+use crate::models::audit_log_entry::AuditLogEntry;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::config_reload::ConfigReload;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::health::Health;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::producer_info::ProducerInfo;
+use crate::models::role_info::RoleInfo;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction_info::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
@@ -25,32 +32,48 @@ use crate::personal_access_tokens::create_personal_access_token::CreatePersonalA
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::update_role::UpdateRole;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
+use crate::system::get_health::GetHealth;
 use crate::system::get_me::GetMe;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_filter::SetLogFilter;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
+use crate::users::login_with_token::LoginWithToken;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_roles::UpdateUserRoles;
 use async_trait::async_trait;
 use std::fmt::Debug;
 
@@ -61,6 +84,7 @@ use std::fmt::Debug;
 pub trait Client:
     SystemClient
     + UserClient
+    + RoleClient
     + PersonalAccessTokenClient
     + StreamClient
     + TopicClient
@@ -68,6 +92,7 @@ pub trait Client:
     + MessageClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
+    + TransactionClient
     + Sync
     + Send
     + Debug
@@ -99,8 +124,25 @@ pub trait SystemClient {
     ///
     /// Authentication is required, and the permission to read the server info.
     async fn get_clients(&self, command: &GetClients) -> Result<Vec<ClientInfo>, IggyError>;
+    /// Get the entries of the audit log, containing the administrative and security events recorded by the server.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_audit_log(&self, command: &GetAuditLog) -> Result<Vec<AuditLogEntry>, IggyError>;
+    /// Reload the server configuration, and apply the settings which can be changed at runtime.
+    /// Returns the applied changes, and the ones which will take effect only after the server restart.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn reload_config(&self, command: &ReloadConfig) -> Result<ConfigReload, IggyError>;
+    /// Change the log filter of the server at runtime, optionally reverting it to the configured log level after the given time.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn set_log_filter(&self, command: &SetLogFilter) -> Result<(), IggyError>;
     /// Ping the server to check if it's alive.
     async fn ping(&self, command: &Ping) -> Result<(), IggyError>;
+    /// Get the health of the server, consisting of the checks of its storage, database, background tasks and free disk space.
+    ///
+    /// Authentication is not required.
+    async fn get_health(&self, command: &GetHealth) -> Result<Health, IggyError>;
 }
 
 /// This trait defines the methods to interact with the user module.
@@ -130,16 +172,57 @@ pub trait UserClient {
     ///
     /// Authentication is required, and the permission to manage the users.
     async fn update_permissions(&self, command: &UpdatePermissions) -> Result<(), IggyError>;
+    /// Update the throughput quotas of a user by unique ID or username.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_quotas(&self, command: &UpdateQuotas) -> Result<(), IggyError>;
+    /// Assign the roles to a user by unique ID or username, replacing the previously assigned ones.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_user_roles(&self, command: &UpdateUserRoles) -> Result<(), IggyError>;
+    /// Unlock a user by unique ID or username, locked out after too many failed login attempts.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), IggyError>;
     /// Change the password of a user by unique ID or username.
     ///
     /// Authentication is required, and the permission to manage the users, unless the provided user ID is the same as the authenticated user.
     async fn change_password(&self, command: &ChangePassword) -> Result<(), IggyError>;
     /// Login a user by username and password.
     async fn login_user(&self, command: &LoginUser) -> Result<IdentityInfo, IggyError>;
+    /// Login a user with the JWT issued by the external identity provider, which is mapped to the existing user by its claims.
+    ///
+    /// The server must have the OIDC enabled, and for the binary protocols, the login with token allowed.
+    async fn login_with_token(&self, command: &LoginWithToken) -> Result<IdentityInfo, IggyError>;
     /// Logout the currently authenticated user.
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), IggyError>;
 }
 
+/// This trait defines the methods to interact with the role module.
+#[async_trait]
+pub trait RoleClient {
+    /// Get the info about a specific role by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to read the users.
+    async fn get_role(&self, command: &GetRole) -> Result<RoleInfo, IggyError>;
+    /// Get the info about all the roles.
+    ///
+    /// Authentication is required, and the permission to read the users.
+    async fn get_roles(&self, command: &GetRoles) -> Result<Vec<RoleInfo>, IggyError>;
+    /// Create a new role.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn create_role(&self, command: &CreateRole) -> Result<(), IggyError>;
+    /// Delete a role by unique ID or name, which also unassigns it from all the users.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn delete_role(&self, command: &DeleteRole) -> Result<(), IggyError>;
+    /// Update the name and permissions of a role by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_role(&self, command: &UpdateRole) -> Result<(), IggyError>;
+}
+
 /// This trait defines the methods to interact with the personal access token module.
 #[async_trait]
 pub trait PersonalAccessTokenClient {
@@ -251,6 +334,10 @@ pub trait MessageClient {
     ///
     /// Authentication is required, and the permission to send the messages.
     async fn send_messages(&self, command: &mut SendMessages) -> Result<(), IggyError>;
+    /// Get the unique producer ID, which can be used to stamp the sent batches with the sequence numbers to make the producer idempotent.
+    ///
+    /// Authentication is required.
+    async fn init_producer(&self, command: &InitProducer) -> Result<ProducerInfo, IggyError>;
 }
 
 /// This trait defines the methods to interact with the consumer offset module.
@@ -303,3 +390,25 @@ pub trait ConsumerGroupClient {
     /// Authentication is required, and the permission to read the streams or topics.
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), IggyError>;
 }
+
+/// This trait defines the methods to interact with the transaction module.
+#[async_trait]
+pub trait TransactionClient {
+    /// Begin a new transaction for the currently connected client.
+    /// The messages sent and the consumer offsets stored by the client are part of the transaction until it's committed or aborted.
+    /// The transaction not committed within the server's timeout is aborted, and its further requests fail with `TransactionExpired`.
+    ///
+    /// Authentication is required.
+    async fn begin_transaction(
+        &self,
+        command: &BeginTransaction,
+    ) -> Result<TransactionInfo, IggyError>;
+    /// Commit the transaction by unique ID, which makes its messages visible to the consumers reading only the committed data and stores its consumer offsets.
+    ///
+    /// Authentication is required.
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), IggyError>;
+    /// Abort the transaction by unique ID, which hides its messages from the consumers reading only the committed data and discards its consumer offsets.
+    ///
+    /// Authentication is required.
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), IggyError>;
+}
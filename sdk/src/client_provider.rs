@@ -69,6 +69,8 @@ impl ClientProviderConfig {
                     keep_alive_interval: args.quic_keep_alive_interval,
                     max_idle_timeout: args.quic_max_idle_timeout,
                     validate_certificate: args.quic_validate_certificate,
+                    client_certificate: args.quic_client_certificate,
+                    client_key: args.quic_client_key,
                 }));
             }
             HTTP_TRANSPORT => {
@@ -84,6 +86,9 @@ impl ClientProviderConfig {
                     reconnection_interval: args.tcp_reconnection_interval,
                     tls_enabled: args.tcp_tls_enabled,
                     tls_domain: args.tcp_tls_domain,
+                    tls_client_certificate: args.tcp_tls_client_certificate,
+                    tls_client_key: args.tcp_tls_client_key,
+                    pipelining: args.tcp_pipelining,
                 }));
             }
             _ => return Err(ClientError::InvalidTransport(config.transport.clone())),
@@ -1,6 +1,7 @@
 use crate::client::{
     Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient, UserClient,
+    PersonalAccessTokenClient, RoleClient, StreamClient, SystemClient, TopicClient,
+    TransactionClient, UserClient,
 };
 use crate::consumer::Consumer;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
@@ -14,17 +15,24 @@ use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::message_handler::MessageHandler;
+use crate::messages::init_producer::InitProducer;
 use crate::messages::poll_messages::{PollMessages, PollingKind};
-use crate::messages::send_messages::{Partitioning, PartitioningKind, SendMessages};
+use crate::messages::send_messages::{AckLevel, Partitioning, PartitioningKind, SendMessages};
+use crate::models::audit_log_entry::AuditLogEntry;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::config_reload::ConfigReload;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::health::Health;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{Message, PolledMessages};
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::producer_info::ProducerInfo;
+use crate::models::role_info::RoleInfo;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction_info::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitioner::Partitioner;
 use crate::partitions::create_partitions::CreatePartitions;
@@ -33,17 +41,26 @@ use crate::personal_access_tokens::create_personal_access_token::CreatePersonalA
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::update_role::UpdateRole;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
+use crate::system::get_health::GetHealth;
 use crate::system::get_me::GetMe;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_filter::SetLogFilter;
 use crate::tcp::client::TcpClient;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
@@ -51,15 +68,22 @@ use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
+use crate::users::login_with_token::LoginWithToken;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_roles::UpdateUserRoles;
 use crate::utils::crypto::Encryptor;
 use async_dropper::AsyncDrop;
 use async_trait::async_trait;
@@ -338,8 +362,13 @@ impl IggyClient {
                             error!("Error when sending a message to the channel.");
                         }
                     } else if let Some(on_message) = &on_message {
+                        // The trace context of the producer (if any) is the current one while processing the message.
+                        #[cfg(feature = "telemetry")]
+                        let _context_guard = crate::telemetry::extract_context(&message).attach();
                         on_message(message);
                     } else if let Some(message_handler) = &message_handler {
+                        #[cfg(feature = "telemetry")]
+                        let _context_guard = crate::telemetry::extract_context(&message).attach();
                         message_handler.handle(message);
                     } else {
                         warn!("Received a message with ID: {} at offset: {} which won't be processed. Consider providing the custom `MessageHandler` trait implementation or `on_message` closure.", message.id, message.offset);
@@ -414,6 +443,12 @@ impl IggyClient {
                 let mut batch_messages = true;
 
                 for send_messages in &send_messages_batch.commands {
+                    // The batches of the idempotent producer are sent as they are, to keep their sequence numbers.
+                    if send_messages.producer.is_some() {
+                        batch_messages = false;
+                        break;
+                    }
+
                     if !initialized {
                         if send_messages.partitioning.kind != PartitioningKind::PartitionId {
                             batch_messages = false;
@@ -470,6 +505,8 @@ impl IggyClient {
                             length: 4,
                             value: key.value.clone(),
                         },
+                        producer: None,
+                        ack: AckLevel::Memory,
                         messages,
                     };
 
@@ -518,6 +555,18 @@ impl UserClient for IggyClient {
         self.client.read().await.update_permissions(command).await
     }
 
+    async fn update_quotas(&self, command: &UpdateQuotas) -> Result<(), IggyError> {
+        self.client.read().await.update_quotas(command).await
+    }
+
+    async fn update_user_roles(&self, command: &UpdateUserRoles) -> Result<(), IggyError> {
+        self.client.read().await.update_user_roles(command).await
+    }
+
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), IggyError> {
+        self.client.read().await.unlock_user(command).await
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), IggyError> {
         self.client.read().await.change_password(command).await
     }
@@ -526,11 +575,38 @@ impl UserClient for IggyClient {
         self.client.read().await.login_user(command).await
     }
 
+    async fn login_with_token(&self, command: &LoginWithToken) -> Result<IdentityInfo, IggyError> {
+        self.client.read().await.login_with_token(command).await
+    }
+
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), IggyError> {
         self.client.read().await.logout_user(command).await
     }
 }
 
+#[async_trait]
+impl RoleClient for IggyClient {
+    async fn get_role(&self, command: &GetRole) -> Result<RoleInfo, IggyError> {
+        self.client.read().await.get_role(command).await
+    }
+
+    async fn get_roles(&self, command: &GetRoles) -> Result<Vec<RoleInfo>, IggyError> {
+        self.client.read().await.get_roles(command).await
+    }
+
+    async fn create_role(&self, command: &CreateRole) -> Result<(), IggyError> {
+        self.client.read().await.create_role(command).await
+    }
+
+    async fn delete_role(&self, command: &DeleteRole) -> Result<(), IggyError> {
+        self.client.read().await.delete_role(command).await
+    }
+
+    async fn update_role(&self, command: &UpdateRole) -> Result<(), IggyError> {
+        self.client.read().await.update_role(command).await
+    }
+}
+
 #[async_trait]
 impl PersonalAccessTokenClient for IggyClient {
     async fn get_personal_access_tokens(
@@ -607,9 +683,25 @@ impl SystemClient for IggyClient {
         self.client.read().await.get_clients(command).await
     }
 
+    async fn get_audit_log(&self, command: &GetAuditLog) -> Result<Vec<AuditLogEntry>, IggyError> {
+        self.client.read().await.get_audit_log(command).await
+    }
+
+    async fn reload_config(&self, command: &ReloadConfig) -> Result<ConfigReload, IggyError> {
+        self.client.read().await.reload_config(command).await
+    }
+
+    async fn set_log_filter(&self, command: &SetLogFilter) -> Result<(), IggyError> {
+        self.client.read().await.set_log_filter(command).await
+    }
+
     async fn ping(&self, command: &Ping) -> Result<(), IggyError> {
         self.client.read().await.ping(command).await
     }
+
+    async fn get_health(&self, command: &GetHealth) -> Result<Health, IggyError> {
+        self.client.read().await.get_health(command).await
+    }
 }
 
 #[async_trait]
@@ -705,6 +797,11 @@ impl MessageClient for IggyClient {
             command.partitioning = Partitioning::partition_id(partition_id);
         }
 
+        #[cfg(feature = "telemetry")]
+        for message in &mut command.messages {
+            crate::telemetry::inject_current_context(message)?;
+        }
+
         if let Some(encryptor) = &self.encryptor {
             for message in &mut command.messages {
                 message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
@@ -736,6 +833,8 @@ impl MessageClient for IggyClient {
             stream_id: Identifier::from_identifier(&command.stream_id),
             topic_id: Identifier::from_identifier(&command.topic_id),
             partitioning: Partitioning::from_partitioning(&command.partitioning),
+            producer: command.producer,
+            ack: command.ack,
             messages,
         };
 
@@ -743,6 +842,10 @@ impl MessageClient for IggyClient {
         batch.commands.push_back(send_messages);
         Ok(())
     }
+
+    async fn init_producer(&self, command: &InitProducer) -> Result<ProducerInfo, IggyError> {
+        self.client.read().await.init_producer(command).await
+    }
 }
 
 #[async_trait]
@@ -804,6 +907,24 @@ impl ConsumerGroupClient for IggyClient {
     }
 }
 
+#[async_trait]
+impl TransactionClient for IggyClient {
+    async fn begin_transaction(
+        &self,
+        command: &BeginTransaction,
+    ) -> Result<TransactionInfo, IggyError> {
+        self.client.read().await.begin_transaction(command).await
+    }
+
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), IggyError> {
+        self.client.read().await.commit_transaction(command).await
+    }
+
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), IggyError> {
+        self.client.read().await.abort_transaction(command).await
+    }
+}
+
 #[async_trait]
 impl AsyncDrop for IggyClient {
     async fn async_drop(&mut self) {
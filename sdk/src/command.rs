@@ -8,6 +8,7 @@ use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::IggyError;
+use crate::messages::init_producer::InitProducer;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::partitions::create_partitions::CreatePartitions;
@@ -16,37 +17,58 @@ use crate::personal_access_tokens::create_personal_access_token::CreatePersonalA
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::update_role::UpdateRole;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
+use crate::system::get_health::GetHealth;
 use crate::system::get_me::GetMe;
+use crate::system::get_protocol_version::GetProtocolVersion;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_filter::SetLogFilter;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
+use crate::users::login_with_token::LoginWithToken;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_roles::UpdateUserRoles;
 use bytes::BufMut;
 use std::fmt::{Display, Formatter};
 
 pub const PING: &str = "ping";
 pub const PING_CODE: u32 = 1;
+pub const GET_HEALTH: &str = "health";
+pub const GET_HEALTH_CODE: u32 = 2;
+pub const GET_PROTOCOL_VERSION: &str = "protocol_version";
+pub const GET_PROTOCOL_VERSION_CODE: u32 = 3;
 pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
 pub const GET_ME: &str = "me";
@@ -55,6 +77,12 @@ pub const GET_CLIENT: &str = "client.get";
 pub const GET_CLIENT_CODE: u32 = 21;
 pub const GET_CLIENTS: &str = "client.list";
 pub const GET_CLIENTS_CODE: u32 = 22;
+pub const GET_AUDIT_LOG: &str = "audit_log.get";
+pub const GET_AUDIT_LOG_CODE: u32 = 23;
+pub const RELOAD_CONFIG: &str = "config.reload";
+pub const RELOAD_CONFIG_CODE: u32 = 24;
+pub const SET_LOG_FILTER: &str = "log_filter.set";
+pub const SET_LOG_FILTER_CODE: u32 = 25;
 pub const GET_USER: &str = "user.get";
 pub const GET_USER_CODE: u32 = 31;
 pub const GET_USERS: &str = "user.list";
@@ -73,6 +101,8 @@ pub const LOGIN_USER: &str = "user.login";
 pub const LOGIN_USER_CODE: u32 = 38;
 pub const LOGOUT_USER: &str = "user.logout";
 pub const LOGOUT_USER_CODE: u32 = 39;
+pub const UPDATE_QUOTAS: &str = "user.quotas";
+pub const UPDATE_QUOTAS_CODE: u32 = 40;
 pub const GET_PERSONAL_ACCESS_TOKENS: &str = "personal_access_token.list";
 pub const GET_PERSONAL_ACCESS_TOKENS_CODE: u32 = 41;
 pub const CREATE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.create";
@@ -81,10 +111,28 @@ pub const DELETE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.delete";
 pub const DELETE_PERSONAL_ACCESS_TOKEN_CODE: u32 = 43;
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.login";
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE: u32 = 44;
+pub const UPDATE_USER_ROLES: &str = "user.roles";
+pub const UPDATE_USER_ROLES_CODE: u32 = 45;
+pub const LOGIN_WITH_TOKEN: &str = "user.login_with_token";
+pub const LOGIN_WITH_TOKEN_CODE: u32 = 46;
+pub const UNLOCK_USER: &str = "user.unlock";
+pub const UNLOCK_USER_CODE: u32 = 47;
+pub const GET_ROLE: &str = "role.get";
+pub const GET_ROLE_CODE: u32 = 50;
+pub const GET_ROLES: &str = "role.list";
+pub const GET_ROLES_CODE: u32 = 51;
+pub const CREATE_ROLE: &str = "role.create";
+pub const CREATE_ROLE_CODE: u32 = 52;
+pub const DELETE_ROLE: &str = "role.delete";
+pub const DELETE_ROLE_CODE: u32 = 53;
+pub const UPDATE_ROLE: &str = "role.update";
+pub const UPDATE_ROLE_CODE: u32 = 54;
 pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES: &str = "message.send";
 pub const SEND_MESSAGES_CODE: u32 = 101;
+pub const INIT_PRODUCER: &str = "message.init_producer";
+pub const INIT_PRODUCER_CODE: u32 = 102;
 pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
 pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
@@ -129,14 +177,25 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const BEGIN_TRANSACTION: &str = "transaction.begin";
+pub const BEGIN_TRANSACTION_CODE: u32 = 701;
+pub const COMMIT_TRANSACTION: &str = "transaction.commit";
+pub const COMMIT_TRANSACTION_CODE: u32 = 702;
+pub const ABORT_TRANSACTION: &str = "transaction.abort";
+pub const ABORT_TRANSACTION_CODE: u32 = 703;
 
 #[derive(Debug, PartialEq)]
 pub enum Command {
     Ping(Ping),
+    GetHealth(GetHealth),
+    GetProtocolVersion(GetProtocolVersion),
     GetStats(GetStats),
     GetMe(GetMe),
     GetClient(GetClient),
     GetClients(GetClients),
+    GetAuditLog(GetAuditLog),
+    ReloadConfig(ReloadConfig),
+    SetLogFilter(SetLogFilter),
     GetUser(GetUser),
     GetUsers(GetUsers),
     CreateUser(CreateUser),
@@ -146,12 +205,22 @@ pub enum Command {
     ChangePassword(ChangePassword),
     LoginUser(LoginUser),
     LogoutUser(LogoutUser),
+    UpdateQuotas(UpdateQuotas),
     GetPersonalAccessTokens(GetPersonalAccessTokens),
     CreatePersonalAccessToken(CreatePersonalAccessToken),
     DeletePersonalAccessToken(DeletePersonalAccessToken),
     LoginWithPersonalAccessToken(LoginWithPersonalAccessToken),
+    UpdateUserRoles(UpdateUserRoles),
+    LoginWithToken(LoginWithToken),
+    UnlockUser(UnlockUser),
+    GetRole(GetRole),
+    GetRoles(GetRoles),
+    CreateRole(CreateRole),
+    DeleteRole(DeleteRole),
+    UpdateRole(UpdateRole),
     SendMessages(SendMessages),
     PollMessages(PollMessages),
+    InitProducer(InitProducer),
     GetConsumerOffset(GetConsumerOffset),
     StoreConsumerOffset(StoreConsumerOffset),
     GetStream(GetStream),
@@ -174,6 +243,9 @@ pub enum Command {
     DeleteConsumerGroup(DeleteConsumerGroup),
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
+    BeginTransaction(BeginTransaction),
+    CommitTransaction(CommitTransaction),
+    AbortTransaction(AbortTransaction),
 }
 
 /// A trait for all command payloads.
@@ -183,10 +255,17 @@ impl BytesSerializable for Command {
     fn as_bytes(&self) -> Vec<u8> {
         match self {
             Command::Ping(payload) => as_bytes(PING_CODE, &payload.as_bytes()),
+            Command::GetHealth(payload) => as_bytes(GET_HEALTH_CODE, &payload.as_bytes()),
+            Command::GetProtocolVersion(payload) => {
+                as_bytes(GET_PROTOCOL_VERSION_CODE, &payload.as_bytes())
+            }
             Command::GetStats(payload) => as_bytes(GET_STATS_CODE, &payload.as_bytes()),
             Command::GetMe(payload) => as_bytes(GET_ME_CODE, &payload.as_bytes()),
             Command::GetClient(payload) => as_bytes(GET_CLIENT_CODE, &payload.as_bytes()),
             Command::GetClients(payload) => as_bytes(GET_CLIENTS_CODE, &payload.as_bytes()),
+            Command::GetAuditLog(payload) => as_bytes(GET_AUDIT_LOG_CODE, &payload.as_bytes()),
+            Command::ReloadConfig(payload) => as_bytes(RELOAD_CONFIG_CODE, &payload.as_bytes()),
+            Command::SetLogFilter(payload) => as_bytes(SET_LOG_FILTER_CODE, &payload.as_bytes()),
             Command::GetUser(payload) => as_bytes(GET_USER_CODE, &payload.as_bytes()),
             Command::GetUsers(payload) => as_bytes(GET_USERS_CODE, &payload.as_bytes()),
             Command::CreateUser(payload) => as_bytes(CREATE_USER_CODE, &payload.as_bytes()),
@@ -198,6 +277,7 @@ impl BytesSerializable for Command {
             Command::ChangePassword(payload) => as_bytes(CHANGE_PASSWORD_CODE, &payload.as_bytes()),
             Command::LoginUser(payload) => as_bytes(LOGIN_USER_CODE, &payload.as_bytes()),
             Command::LogoutUser(payload) => as_bytes(LOGOUT_USER_CODE, &payload.as_bytes()),
+            Command::UpdateQuotas(payload) => as_bytes(UPDATE_QUOTAS_CODE, &payload.as_bytes()),
             Command::GetPersonalAccessTokens(payload) => {
                 as_bytes(GET_PERSONAL_ACCESS_TOKENS_CODE, &payload.as_bytes())
             }
@@ -210,8 +290,21 @@ impl BytesSerializable for Command {
             Command::LoginWithPersonalAccessToken(payload) => {
                 as_bytes(LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE, &payload.as_bytes())
             }
+            Command::UpdateUserRoles(payload) => {
+                as_bytes(UPDATE_USER_ROLES_CODE, &payload.as_bytes())
+            }
+            Command::LoginWithToken(payload) => {
+                as_bytes(LOGIN_WITH_TOKEN_CODE, &payload.as_bytes())
+            }
+            Command::UnlockUser(payload) => as_bytes(UNLOCK_USER_CODE, &payload.as_bytes()),
+            Command::GetRole(payload) => as_bytes(GET_ROLE_CODE, &payload.as_bytes()),
+            Command::GetRoles(payload) => as_bytes(GET_ROLES_CODE, &payload.as_bytes()),
+            Command::CreateRole(payload) => as_bytes(CREATE_ROLE_CODE, &payload.as_bytes()),
+            Command::DeleteRole(payload) => as_bytes(DELETE_ROLE_CODE, &payload.as_bytes()),
+            Command::UpdateRole(payload) => as_bytes(UPDATE_ROLE_CODE, &payload.as_bytes()),
             Command::SendMessages(payload) => as_bytes(SEND_MESSAGES_CODE, &payload.as_bytes()),
             Command::PollMessages(payload) => as_bytes(POLL_MESSAGES_CODE, &payload.as_bytes()),
+            Command::InitProducer(payload) => as_bytes(INIT_PRODUCER_CODE, &payload.as_bytes()),
             Command::StoreConsumerOffset(payload) => {
                 as_bytes(STORE_CONSUMER_OFFSET_CODE, &payload.as_bytes())
             }
@@ -254,6 +347,15 @@ impl BytesSerializable for Command {
             Command::LeaveConsumerGroup(payload) => {
                 as_bytes(LEAVE_CONSUMER_GROUP_CODE, &payload.as_bytes())
             }
+            Command::BeginTransaction(payload) => {
+                as_bytes(BEGIN_TRANSACTION_CODE, &payload.as_bytes())
+            }
+            Command::CommitTransaction(payload) => {
+                as_bytes(COMMIT_TRANSACTION_CODE, &payload.as_bytes())
+            }
+            Command::AbortTransaction(payload) => {
+                as_bytes(ABORT_TRANSACTION_CODE, &payload.as_bytes())
+            }
         }
     }
 
@@ -262,10 +364,17 @@ impl BytesSerializable for Command {
         let payload = &bytes[4..];
         match command {
             PING_CODE => Ok(Command::Ping(Ping::from_bytes(payload)?)),
+            GET_HEALTH_CODE => Ok(Command::GetHealth(GetHealth::from_bytes(payload)?)),
+            GET_PROTOCOL_VERSION_CODE => Ok(Command::GetProtocolVersion(
+                GetProtocolVersion::from_bytes(payload)?,
+            )),
             GET_STATS_CODE => Ok(Command::GetStats(GetStats::from_bytes(payload)?)),
             GET_ME_CODE => Ok(Command::GetMe(GetMe::from_bytes(payload)?)),
             GET_CLIENT_CODE => Ok(Command::GetClient(GetClient::from_bytes(payload)?)),
             GET_CLIENTS_CODE => Ok(Command::GetClients(GetClients::from_bytes(payload)?)),
+            GET_AUDIT_LOG_CODE => Ok(Command::GetAuditLog(GetAuditLog::from_bytes(payload)?)),
+            RELOAD_CONFIG_CODE => Ok(Command::ReloadConfig(ReloadConfig::from_bytes(payload)?)),
+            SET_LOG_FILTER_CODE => Ok(Command::SetLogFilter(SetLogFilter::from_bytes(payload)?)),
             GET_USER_CODE => Ok(Command::GetUser(GetUser::from_bytes(payload)?)),
             GET_USERS_CODE => Ok(Command::GetUsers(GetUsers::from_bytes(payload)?)),
             CREATE_USER_CODE => Ok(Command::CreateUser(CreateUser::from_bytes(payload)?)),
@@ -279,6 +388,7 @@ impl BytesSerializable for Command {
             )?)),
             LOGIN_USER_CODE => Ok(Command::LoginUser(LoginUser::from_bytes(payload)?)),
             LOGOUT_USER_CODE => Ok(Command::LogoutUser(LogoutUser::from_bytes(payload)?)),
+            UPDATE_QUOTAS_CODE => Ok(Command::UpdateQuotas(UpdateQuotas::from_bytes(payload)?)),
             GET_PERSONAL_ACCESS_TOKENS_CODE => Ok(Command::GetPersonalAccessTokens(
                 GetPersonalAccessTokens::from_bytes(payload)?,
             )),
@@ -291,8 +401,21 @@ impl BytesSerializable for Command {
             LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => Ok(Command::LoginWithPersonalAccessToken(
                 LoginWithPersonalAccessToken::from_bytes(payload)?,
             )),
+            UPDATE_USER_ROLES_CODE => Ok(Command::UpdateUserRoles(UpdateUserRoles::from_bytes(
+                payload,
+            )?)),
+            LOGIN_WITH_TOKEN_CODE => Ok(Command::LoginWithToken(LoginWithToken::from_bytes(
+                payload,
+            )?)),
+            UNLOCK_USER_CODE => Ok(Command::UnlockUser(UnlockUser::from_bytes(payload)?)),
+            GET_ROLE_CODE => Ok(Command::GetRole(GetRole::from_bytes(payload)?)),
+            GET_ROLES_CODE => Ok(Command::GetRoles(GetRoles::from_bytes(payload)?)),
+            CREATE_ROLE_CODE => Ok(Command::CreateRole(CreateRole::from_bytes(payload)?)),
+            DELETE_ROLE_CODE => Ok(Command::DeleteRole(DeleteRole::from_bytes(payload)?)),
+            UPDATE_ROLE_CODE => Ok(Command::UpdateRole(UpdateRole::from_bytes(payload)?)),
             SEND_MESSAGES_CODE => Ok(Command::SendMessages(SendMessages::from_bytes(payload)?)),
             POLL_MESSAGES_CODE => Ok(Command::PollMessages(PollMessages::from_bytes(payload)?)),
+            INIT_PRODUCER_CODE => Ok(Command::InitProducer(InitProducer::from_bytes(payload)?)),
             STORE_CONSUMER_OFFSET_CODE => Ok(Command::StoreConsumerOffset(
                 StoreConsumerOffset::from_bytes(payload)?,
             )),
@@ -335,6 +458,15 @@ impl BytesSerializable for Command {
             LEAVE_CONSUMER_GROUP_CODE => Ok(Command::LeaveConsumerGroup(
                 LeaveConsumerGroup::from_bytes(payload)?,
             )),
+            BEGIN_TRANSACTION_CODE => Ok(Command::BeginTransaction(BeginTransaction::from_bytes(
+                payload,
+            )?)),
+            COMMIT_TRANSACTION_CODE => Ok(Command::CommitTransaction(
+                CommitTransaction::from_bytes(payload)?,
+            )),
+            ABORT_TRANSACTION_CODE => Ok(Command::AbortTransaction(AbortTransaction::from_bytes(
+                payload,
+            )?)),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -347,14 +479,89 @@ fn as_bytes(command: u32, payload: &[u8]) -> Vec<u8> {
     bytes
 }
 
+impl Command {
+    /// Returns the name of the command (e.g. `stream.create`), without its payload.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Command::Ping(_) => PING,
+            Command::GetHealth(_) => GET_HEALTH,
+            Command::GetProtocolVersion(_) => GET_PROTOCOL_VERSION,
+            Command::GetStats(_) => GET_STATS,
+            Command::GetMe(_) => GET_ME,
+            Command::GetClient(_) => GET_CLIENT,
+            Command::GetClients(_) => GET_CLIENTS,
+            Command::GetAuditLog(_) => GET_AUDIT_LOG,
+            Command::ReloadConfig(_) => RELOAD_CONFIG,
+            Command::SetLogFilter(_) => SET_LOG_FILTER,
+            Command::GetUser(_) => GET_USER,
+            Command::GetUsers(_) => GET_USERS,
+            Command::CreateUser(_) => CREATE_USER,
+            Command::DeleteUser(_) => DELETE_USER,
+            Command::UpdateUser(_) => UPDATE_USER,
+            Command::UpdatePermissions(_) => UPDATE_PERMISSIONS,
+            Command::ChangePassword(_) => CHANGE_PASSWORD,
+            Command::LoginUser(_) => LOGIN_USER,
+            Command::LogoutUser(_) => LOGOUT_USER,
+            Command::UpdateQuotas(_) => UPDATE_QUOTAS,
+            Command::GetPersonalAccessTokens(_) => GET_PERSONAL_ACCESS_TOKENS,
+            Command::CreatePersonalAccessToken(_) => CREATE_PERSONAL_ACCESS_TOKEN,
+            Command::DeletePersonalAccessToken(_) => DELETE_PERSONAL_ACCESS_TOKEN,
+            Command::LoginWithPersonalAccessToken(_) => LOGIN_WITH_PERSONAL_ACCESS_TOKEN,
+            Command::UpdateUserRoles(_) => UPDATE_USER_ROLES,
+            Command::LoginWithToken(_) => LOGIN_WITH_TOKEN,
+            Command::UnlockUser(_) => UNLOCK_USER,
+            Command::GetRole(_) => GET_ROLE,
+            Command::GetRoles(_) => GET_ROLES,
+            Command::CreateRole(_) => CREATE_ROLE,
+            Command::DeleteRole(_) => DELETE_ROLE,
+            Command::UpdateRole(_) => UPDATE_ROLE,
+            Command::GetStream(_) => GET_STREAM,
+            Command::GetStreams(_) => GET_STREAMS,
+            Command::CreateStream(_) => CREATE_STREAM,
+            Command::DeleteStream(_) => DELETE_STREAM,
+            Command::UpdateStream(_) => UPDATE_STREAM,
+            Command::PurgeStream(_) => PURGE_STREAM,
+            Command::GetTopic(_) => GET_TOPIC,
+            Command::GetTopics(_) => GET_TOPICS,
+            Command::CreateTopic(_) => CREATE_TOPIC,
+            Command::DeleteTopic(_) => DELETE_TOPIC,
+            Command::UpdateTopic(_) => UPDATE_TOPIC,
+            Command::PurgeTopic(_) => PURGE_TOPIC,
+            Command::CreatePartitions(_) => CREATE_PARTITIONS,
+            Command::DeletePartitions(_) => DELETE_PARTITIONS,
+            Command::PollMessages(_) => POLL_MESSAGES,
+            Command::SendMessages(_) => SEND_MESSAGES,
+            Command::InitProducer(_) => INIT_PRODUCER,
+            Command::StoreConsumerOffset(_) => STORE_CONSUMER_OFFSET,
+            Command::GetConsumerOffset(_) => GET_CONSUMER_OFFSET,
+            Command::GetConsumerGroup(_) => GET_CONSUMER_GROUP,
+            Command::GetConsumerGroups(_) => GET_CONSUMER_GROUPS,
+            Command::CreateConsumerGroup(_) => CREATE_CONSUMER_GROUP,
+            Command::DeleteConsumerGroup(_) => DELETE_CONSUMER_GROUP,
+            Command::JoinConsumerGroup(_) => JOIN_CONSUMER_GROUP,
+            Command::LeaveConsumerGroup(_) => LEAVE_CONSUMER_GROUP,
+            Command::BeginTransaction(_) => BEGIN_TRANSACTION,
+            Command::CommitTransaction(_) => COMMIT_TRANSACTION,
+            Command::AbortTransaction(_) => ABORT_TRANSACTION,
+        }
+    }
+}
+
 impl Display for Command {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Command::Ping(_) => write!(formatter, "{PING}"),
+            Command::GetHealth(_) => write!(formatter, "{GET_HEALTH}"),
+            Command::GetProtocolVersion(payload) => {
+                write!(formatter, "{GET_PROTOCOL_VERSION}|{payload}")
+            }
             Command::GetStats(_) => write!(formatter, "{GET_STATS}"),
             Command::GetMe(_) => write!(formatter, "{GET_ME}"),
             Command::GetClient(payload) => write!(formatter, "{GET_CLIENT}|{payload}"),
             Command::GetClients(_) => write!(formatter, "{GET_CLIENTS}"),
+            Command::GetAuditLog(payload) => write!(formatter, "{GET_AUDIT_LOG}|{payload}"),
+            Command::ReloadConfig(_) => write!(formatter, "{RELOAD_CONFIG}"),
+            Command::SetLogFilter(payload) => write!(formatter, "{SET_LOG_FILTER}|{payload}"),
             Command::GetUser(payload) => write!(formatter, "{GET_USER}|{payload}"),
             Command::GetUsers(_) => write!(formatter, "{GET_USERS}"),
             Command::CreateUser(payload) => write!(formatter, "{CREATE_USER}|{payload}"),
@@ -368,6 +575,7 @@ impl Display for Command {
             }
             Command::LoginUser(payload) => write!(formatter, "{LOGIN_USER}|{payload}"),
             Command::LogoutUser(_) => write!(formatter, "{LOGOUT_USER}"),
+            Command::UpdateQuotas(payload) => write!(formatter, "{UPDATE_QUOTAS}|{payload}"),
             Command::GetPersonalAccessTokens(_) => {
                 write!(formatter, "{GET_PERSONAL_ACCESS_TOKENS}")
             }
@@ -380,6 +588,16 @@ impl Display for Command {
             Command::LoginWithPersonalAccessToken(payload) => {
                 write!(formatter, "{LOGIN_WITH_PERSONAL_ACCESS_TOKEN}|{payload}")
             }
+            Command::UpdateUserRoles(payload) => {
+                write!(formatter, "{UPDATE_USER_ROLES}|{payload}")
+            }
+            Command::LoginWithToken(payload) => write!(formatter, "{LOGIN_WITH_TOKEN}|{payload}"),
+            Command::UnlockUser(payload) => write!(formatter, "{UNLOCK_USER}|{payload}"),
+            Command::GetRole(payload) => write!(formatter, "{GET_ROLE}|{payload}"),
+            Command::GetRoles(_) => write!(formatter, "{GET_ROLES}"),
+            Command::CreateRole(payload) => write!(formatter, "{CREATE_ROLE}|{payload}"),
+            Command::DeleteRole(payload) => write!(formatter, "{DELETE_ROLE}|{payload}"),
+            Command::UpdateRole(payload) => write!(formatter, "{UPDATE_ROLE}|{payload}"),
             Command::GetStream(payload) => write!(formatter, "{GET_STREAM}|{payload}"),
             Command::GetStreams(_) => write!(formatter, "{GET_STREAMS}"),
             Command::CreateStream(payload) => write!(formatter, "{CREATE_STREAM}|{payload}"),
@@ -400,6 +618,7 @@ impl Display for Command {
             }
             Command::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
             Command::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
+            Command::InitProducer(_) => write!(formatter, "{INIT_PRODUCER}"),
             Command::StoreConsumerOffset(payload) => {
                 write!(formatter, "{STORE_CONSUMER_OFFSET}|{payload}")
             }
@@ -424,6 +643,13 @@ impl Display for Command {
             Command::LeaveConsumerGroup(payload) => {
                 write!(formatter, "{LEAVE_CONSUMER_GROUP}|{payload}")
             }
+            Command::BeginTransaction(_) => write!(formatter, "{BEGIN_TRANSACTION}"),
+            Command::CommitTransaction(payload) => {
+                write!(formatter, "{COMMIT_TRANSACTION}|{payload}")
+            }
+            Command::AbortTransaction(payload) => {
+                write!(formatter, "{ABORT_TRANSACTION}|{payload}")
+            }
         }
     }
 }
@@ -432,6 +658,13 @@ impl Display for Command {
 mod tests {
     use super::*;
 
+    #[test]
+    fn command_name_should_not_contain_payload() {
+        let command = Command::SetLogFilter(SetLogFilter::default());
+        assert_eq!(command.get_name(), SET_LOG_FILTER);
+        assert_eq!(command.to_string(), format!("{SET_LOG_FILTER}|info|0"));
+    }
+
     #[test]
     fn should_be_serialized_as_bytes_and_deserialized_from_bytes() {
         assert_serialized_as_bytes_and_deserialized_from_bytes(
@@ -439,6 +672,16 @@ mod tests {
             PING_CODE,
             &Ping::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetHealth(GetHealth::default()),
+            GET_HEALTH_CODE,
+            &GetHealth::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetProtocolVersion(GetProtocolVersion::default()),
+            GET_PROTOCOL_VERSION_CODE,
+            &GetProtocolVersion::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetStats(GetStats::default()),
             GET_STATS_CODE,
@@ -459,6 +702,21 @@ mod tests {
             GET_CLIENTS_CODE,
             &GetClients::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetAuditLog(GetAuditLog::default()),
+            GET_AUDIT_LOG_CODE,
+            &GetAuditLog::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::ReloadConfig(ReloadConfig::default()),
+            RELOAD_CONFIG_CODE,
+            &ReloadConfig::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::SetLogFilter(SetLogFilter::default()),
+            SET_LOG_FILTER_CODE,
+            &SetLogFilter::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetUser(GetUser::default()),
             GET_USER_CODE,
@@ -504,6 +762,11 @@ mod tests {
             LOGOUT_USER_CODE,
             &LogoutUser::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateQuotas(UpdateQuotas::default()),
+            UPDATE_QUOTAS_CODE,
+            &UpdateQuotas::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetPersonalAccessTokens(GetPersonalAccessTokens::default()),
             GET_PERSONAL_ACCESS_TOKENS_CODE,
@@ -524,6 +787,46 @@ mod tests {
             LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE,
             &LoginWithPersonalAccessToken::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateUserRoles(UpdateUserRoles::default()),
+            UPDATE_USER_ROLES_CODE,
+            &UpdateUserRoles::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::LoginWithToken(LoginWithToken::default()),
+            LOGIN_WITH_TOKEN_CODE,
+            &LoginWithToken::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UnlockUser(UnlockUser::default()),
+            UNLOCK_USER_CODE,
+            &UnlockUser::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetRole(GetRole::default()),
+            GET_ROLE_CODE,
+            &GetRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetRoles(GetRoles::default()),
+            GET_ROLES_CODE,
+            &GetRoles::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreateRole(CreateRole::default()),
+            CREATE_ROLE_CODE,
+            &CreateRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeleteRole(DeleteRole::default()),
+            DELETE_ROLE_CODE,
+            &DeleteRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateRole(UpdateRole::default()),
+            UPDATE_ROLE_CODE,
+            &UpdateRole::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::SendMessages(SendMessages::default()),
             SEND_MESSAGES_CODE,
@@ -534,6 +837,11 @@ mod tests {
             POLL_MESSAGES_CODE,
             &PollMessages::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::InitProducer(InitProducer::default()),
+            INIT_PRODUCER_CODE,
+            &InitProducer::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::StoreConsumerOffset(StoreConsumerOffset::default()),
             STORE_CONSUMER_OFFSET_CODE,
@@ -644,6 +952,21 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION_CODE,
+            &BeginTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION_CODE,
+            &CommitTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION_CODE,
+            &AbortTransaction::default(),
+        );
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
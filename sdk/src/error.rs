@@ -1,3 +1,4 @@
+use crate::utils::byte_size::IggyByteSize;
 use strum::{EnumDiscriminants, FromRepr, IntoStaticStr};
 use thiserror::Error;
 
@@ -20,6 +21,10 @@ pub enum IggyError {
     InvalidFormat = 4,
     #[error("Feature is unavailable")]
     FeatureUnavailable = 5,
+    #[error("Cannot reload configuration. Reason: {0}")]
+    CannotReloadConfiguration(String) = 6,
+    #[error("Invalid log filter: {0}")]
+    InvalidLogFilter(String) = 7,
     #[error("Cannot create base directory, Path: {0}")]
     CannotCreateBaseDirectory(String) = 10,
     #[error("Cannot create runtime directory, Path: {0}")]
@@ -68,10 +73,26 @@ pub enum IggyError {
     InvalidPersonalAccessToken = 53,
     #[error("Personal access token: {0} for user with ID: {1} has expired.")]
     PersonalAccessTokenExpired(String, u32) = 54,
+    #[error("Invalid role name")]
+    InvalidRoleName = 55,
+    #[error("Role: {0} already exists")]
+    RoleAlreadyExists(String) = 56,
+    #[error("Role with ID: {0} was not found")]
+    RoleNotFound(u32) = 57,
+    #[error("User is locked out until: {0}")]
+    UserLockedOut(u64) = 58,
+    #[error("Password does not meet the policy: {0}")]
+    PasswordPolicyViolation(String) = 59,
+    #[error("Too many failed login attempts, retry after: {0} ms.")]
+    TooManyLoginAttempts(u64) = 60,
     #[error("Not connected")]
     NotConnected = 61,
     #[error("Request error")]
     RequestError(#[from] reqwest::Error) = 62,
+    #[error("Invalid permissions pattern: {0}")]
+    InvalidPermissionsPattern(String) = 63,
+    #[error("Server is shutting down")]
+    ShuttingDown = 64,
     #[error("Invalid encryption key")]
     InvalidEncryptionKey = 70,
     #[error("Cannot encrypt data")]
@@ -92,10 +113,32 @@ pub enum IggyError {
     InvalidRefreshToken = 78,
     #[error("Refresh token expired")]
     RefreshTokenExpired = 79,
+    #[error("Invalid client certificate")]
+    InvalidClientCertificate = 80,
+    #[error("Invalid access token")]
+    InvalidAccessToken = 81,
+    #[error("Cannot load JWKS")]
+    CannotLoadJwks = 82,
     #[error("Client with ID: {0} was not found.")]
     ClientNotFound(u32) = 100,
     #[error("Invalid client ID")]
     InvalidClientId = 101,
+    #[error("Quota exceeded, retry after: {0} ms.")]
+    QuotaExceeded(u64) = 102,
+    #[error("Invalid audit log entries count: {0}, must be between 1 and 1000.")]
+    InvalidAuditLogEntriesCount(u32) = 103,
+    #[error("Audit log entry with ID: {0} is invalid, the log might have been tampered with.")]
+    InvalidAuditLogEntry(u64) = 104,
+    #[error("Request frame size: {0} bytes exceeds the maximum size: {1} bytes.")]
+    FrameTooLarge(u64, u64) = 105,
+    #[error("Malformed request frame: {0}")]
+    MalformedFrame(String) = 106,
+    #[error("Request frame was not received within the read timeout: {0}.")]
+    ReadTimeout(String) = 107,
+    #[error("Connection has been idle for longer than: {0}.")]
+    IdleTimeout(String) = 108,
+    #[error("Too many connections from IP address: {0}, the limit is: {1}.")]
+    TooManyConnections(String, u32) = 109,
     #[error("IO error")]
     IoError(#[from] std::io::Error) = 200,
     #[error("Write error")]
@@ -160,6 +203,14 @@ pub enum IggyError {
     InvalidStreamId = 1014,
     #[error("Cannot read streams")]
     CannotReadStreams = 1015,
+    #[error("Stream with ID: {0} has reached the maximum number of topics: {1}")]
+    StreamTopicsLimitExceeded(u32, u32) = 1016,
+    #[error("Stream with ID: {0} has reached the maximum number of partitions: {1}")]
+    StreamPartitionsLimitExceeded(u32, u32) = 1017,
+    #[error("Stream with ID: {0} has reached the maximum number of consumer groups: {1}")]
+    StreamConsumerGroupsLimitExceeded(u32, u32) = 1018,
+    #[error("Stream with ID: {0} has reached the maximum size: {1}")]
+    StreamSizeLimitExceeded(u32, IggyByteSize) = 1019,
     #[error("Cannot create topics directory for stream with ID: {0}, Path: {1}")]
     CannotCreateTopicsDirectory(u32, String) = 2000,
     #[error(
@@ -282,6 +333,10 @@ pub enum IggyError {
     InvalidMessageChecksum(u32, u32, u64) = 4027,
     #[error("Invalid key value length")]
     InvalidKeyValueLength = 4028,
+    #[error("Idempotent producer requires the messages to be sent to the specific partition ID")]
+    InvalidProducerPartitioning = 4029,
+    #[error("Out of order sequence: {0} for producer with ID: {1}, expected: {2}")]
+    OutOfOrderSequence(u64, u64, u64) = 4030,
     #[error("Invalid offset: {0}")]
     InvalidOffset(u64) = 4100,
     #[error("Failed to read consumers offsets for partition with ID: {0}")]
@@ -304,6 +359,14 @@ pub enum IggyError {
     CannotCreateConsumerGroupInfo(u32, u32, u32) = 5007,
     #[error("Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}.")]
     CannotDeleteConsumerGroupInfo(u32, u32, u32) = 5008,
+    #[error("Invalid transaction ID")]
+    InvalidTransactionId = 6000,
+    #[error("Transaction with ID: {0} was not found.")]
+    TransactionNotFound(u64) = 6001,
+    #[error("Transaction with ID: {0} has been already started.")]
+    TransactionAlreadyStarted(u64) = 6002,
+    #[error("Transaction with ID: {0} has expired.")]
+    TransactionExpired(u64) = 6003,
 }
 
 impl IggyError {
@@ -3,15 +3,25 @@ use crate::error::IggyError;
 use crate::http::config::HttpClientConfig;
 use crate::models::identity_info::IdentityInfo;
 use async_trait::async_trait;
-use reqwest::{Response, Url};
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
-use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Response, StatusCode, Url};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, RequestBuilder};
+use reqwest_retry::{
+    policies::ExponentialBackoff, DefaultRetryableStrategy, RetryTransientMiddleware, Retryable,
+    RetryableStrategy,
+};
 use serde::Serialize;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio::time::sleep;
+use tracing::info;
+
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
 
 const UNAUTHORIZED_PATHS: &[&str] = &[
     "/",
+    "/health/ready",
     "/metrics",
     "/ping",
     "/users/login",
@@ -26,6 +36,7 @@ pub struct HttpClient {
     /// The URL of the Iggy API.
     pub api_url: Url,
     client: ClientWithMiddleware,
+    retries: u32,
     access_token: RwLock<String>,
     refresh_token: RwLock<String>,
 }
@@ -67,12 +78,16 @@ impl HttpClient {
         let api_url = api_url.unwrap();
         let retry_policy = ExponentialBackoff::builder().build_with_max_retries(config.retries);
         let client = ClientBuilder::new(reqwest::Client::new())
-            .with(RetryTransientMiddleware::new_with_policy(retry_policy))
+            .with(RetryTransientMiddleware::new_with_policy_and_strategy(
+                retry_policy,
+                QuotaExceededRetryableStrategy,
+            ))
             .build();
 
         Ok(Self {
             api_url,
             client,
+            retries: config.retries,
             access_token: RwLock::new("".to_string()),
             refresh_token: RwLock::new("".to_string()),
         })
@@ -83,7 +98,9 @@ impl HttpClient {
         let url = self.get_url(path)?;
         self.fail_if_not_authenticated(path).await?;
         let token = self.access_token.read().await;
-        let response = self.client.get(url).bearer_auth(token).send().await?;
+        let response = self
+            .send(|| self.client.get(url.clone()).bearer_auth(&*token))
+            .await?;
         Self::handle_response(response).await
     }
 
@@ -97,11 +114,12 @@ impl HttpClient {
         self.fail_if_not_authenticated(path).await?;
         let token = self.access_token.read().await;
         let response = self
-            .client
-            .get(url)
-            .bearer_auth(token)
-            .query(query)
-            .send()
+            .send(|| {
+                self.client
+                    .get(url.clone())
+                    .bearer_auth(&*token)
+                    .query(query)
+            })
             .await?;
         Self::handle_response(response).await
     }
@@ -116,11 +134,12 @@ impl HttpClient {
         self.fail_if_not_authenticated(path).await?;
         let token = self.access_token.read().await;
         let response = self
-            .client
-            .post(url)
-            .bearer_auth(token)
-            .json(payload)
-            .send()
+            .send(|| {
+                self.client
+                    .post(url.clone())
+                    .bearer_auth(&*token)
+                    .json(payload)
+            })
             .await?;
         Self::handle_response(response).await
     }
@@ -135,11 +154,12 @@ impl HttpClient {
         self.fail_if_not_authenticated(path).await?;
         let token = self.access_token.read().await;
         let response = self
-            .client
-            .put(url)
-            .bearer_auth(token)
-            .json(payload)
-            .send()
+            .send(|| {
+                self.client
+                    .put(url.clone())
+                    .bearer_auth(&*token)
+                    .json(payload)
+            })
             .await?;
         Self::handle_response(response).await
     }
@@ -149,7 +169,9 @@ impl HttpClient {
         let url = self.get_url(path)?;
         self.fail_if_not_authenticated(path).await?;
         let token = self.access_token.read().await;
-        let response = self.client.delete(url).bearer_auth(token).send().await?;
+        let response = self
+            .send(|| self.client.delete(url.clone()).bearer_auth(&*token))
+            .await?;
         Self::handle_response(response).await
     }
 
@@ -163,11 +185,12 @@ impl HttpClient {
         self.fail_if_not_authenticated(path).await?;
         let token = self.access_token.read().await;
         let response = self
-            .client
-            .delete(url)
-            .bearer_auth(token)
-            .query(query)
-            .send()
+            .send(|| {
+                self.client
+                    .delete(url.clone())
+                    .bearer_auth(&*token)
+                    .query(query)
+            })
             .await?;
         Self::handle_response(response).await
     }
@@ -229,6 +252,37 @@ impl HttpClient {
         self.refresh_access_token(&refresh_token).await
     }
 
+    /// Sends the request built by the provided function, and sends it again after the delay returned
+    /// in the `Retry-After` header, if the request was rejected due to the exceeded quota.
+    async fn send(&self, request: impl Fn() -> RequestBuilder) -> Result<Response, IggyError> {
+        let mut retry_count = 0;
+        loop {
+            let response = request().send().await?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS || retry_count >= self.retries {
+                return Ok(response);
+            }
+
+            retry_count += 1;
+            let retry_after = Self::get_retry_after(&response);
+            info!(
+                "Quota exceeded, retrying ({retry_count}/{}) in {} ms...",
+                self.retries,
+                retry_after.as_millis()
+            );
+            sleep(retry_after).await;
+        }
+    }
+
+    fn get_retry_after(response: &Response) -> Duration {
+        response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_RETRY_AFTER)
+    }
+
     async fn handle_response(response: Response) -> Result<Response, IggyError> {
         match response.status().is_success() {
             true => Ok(response),
@@ -249,3 +303,16 @@ impl HttpClient {
         Ok(())
     }
 }
+
+/// The requests rejected due to the exceeded quota are retried by the client itself,
+/// respecting the `Retry-After` header instead of the exponential backoff.
+struct QuotaExceededRetryableStrategy;
+
+impl RetryableStrategy for QuotaExceededRetryableStrategy {
+    fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
+        match res {
+            Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => None,
+            _ => DefaultRetryableStrategy.handle(res),
+        }
+    }
+}
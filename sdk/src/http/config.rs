@@ -3,7 +3,7 @@
 pub struct HttpClientConfig {
     /// The URL of the Iggy API.
     pub api_url: String,
-    /// The number of retries to perform on transient errors.
+    /// The number of retries to perform on transient errors and the requests rejected due to the exceeded quota.
     pub retries: u32,
 }
 
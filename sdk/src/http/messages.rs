@@ -1,11 +1,15 @@
 use crate::client::MessageClient;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
+use crate::messages::init_producer::InitProducer;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::models::messages::PolledMessages;
+use crate::models::producer_info::ProducerInfo;
 use async_trait::async_trait;
 
+const PRODUCERS_PATH: &str = "/producers";
+
 #[async_trait]
 impl MessageClient for HttpClient {
     async fn poll_messages(&self, command: &PollMessages) -> Result<PolledMessages, IggyError> {
@@ -33,6 +37,12 @@ impl MessageClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn init_producer(&self, command: &InitProducer) -> Result<ProducerInfo, IggyError> {
+        let response = self.post(PRODUCERS_PATH, &command).await?;
+        let producer_info = response.json().await?;
+        Ok(producer_info)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
@@ -0,0 +1,43 @@
+use crate::client::RoleClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::models::role_info::RoleInfo;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::update_role::UpdateRole;
+use async_trait::async_trait;
+
+const PATH: &str = "/roles";
+
+#[async_trait]
+impl RoleClient for HttpClient {
+    async fn get_role(&self, command: &GetRole) -> Result<RoleInfo, IggyError> {
+        let response = self.get(&format!("{PATH}/{}", command.role_id)).await?;
+        let role = response.json().await?;
+        Ok(role)
+    }
+
+    async fn get_roles(&self, _command: &GetRoles) -> Result<Vec<RoleInfo>, IggyError> {
+        let response = self.get(PATH).await?;
+        let roles = response.json().await?;
+        Ok(roles)
+    }
+
+    async fn create_role(&self, command: &CreateRole) -> Result<(), IggyError> {
+        self.post(PATH, &command).await?;
+        Ok(())
+    }
+
+    async fn delete_role(&self, command: &DeleteRole) -> Result<(), IggyError> {
+        self.delete(&format!("{PATH}/{}", command.role_id)).await?;
+        Ok(())
+    }
+
+    async fn update_role(&self, command: &UpdateRole) -> Result<(), IggyError> {
+        self.put(&format!("{PATH}/{}", command.role_id), &command)
+            .await?;
+        Ok(())
+    }
+}
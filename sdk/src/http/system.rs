@@ -1,18 +1,29 @@
 use crate::client::SystemClient;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
+use crate::models::audit_log_entry::AuditLogEntry;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::config_reload::ConfigReload;
+use crate::models::health::Health;
 use crate::models::stats::Stats;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
+use crate::system::get_health::GetHealth;
 use crate::system::get_me::GetMe;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_filter::SetLogFilter;
 use async_trait::async_trait;
 
 const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
+const AUDIT_LOG: &str = "/audit-log";
+const CONFIG_RELOAD: &str = "/config/reload";
+const LOG_FILTER: &str = "/logging/filter";
+const HEALTH: &str = "/health/ready";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -39,8 +50,36 @@ impl SystemClient for HttpClient {
         Ok(clients)
     }
 
+    async fn get_audit_log(&self, command: &GetAuditLog) -> Result<Vec<AuditLogEntry>, IggyError> {
+        let response = self.get_with_query(AUDIT_LOG, &command).await?;
+        let entries = response.json().await?;
+        Ok(entries)
+    }
+
+    async fn reload_config(&self, command: &ReloadConfig) -> Result<ConfigReload, IggyError> {
+        let response = self.post(CONFIG_RELOAD, &command).await?;
+        let config_reload = response.json().await?;
+        Ok(config_reload)
+    }
+
+    async fn set_log_filter(&self, command: &SetLogFilter) -> Result<(), IggyError> {
+        self.put(LOG_FILTER, &command).await?;
+        Ok(())
+    }
+
     async fn ping(&self, _command: &Ping) -> Result<(), IggyError> {
         self.get(PING).await?;
         Ok(())
     }
+
+    async fn get_health(&self, _command: &GetHealth) -> Result<Health, IggyError> {
+        match self.get(HEALTH).await {
+            Ok(response) => Ok(response.json().await?),
+            // The unhealthy server responds with 503 status code, along with the failed checks.
+            Err(IggyError::HttpResponseError(503, body)) => {
+                serde_json::from_str(&body).map_err(|_| IggyError::HttpResponseError(503, body))
+            }
+            Err(error) => Err(error),
+        }
+    }
 }
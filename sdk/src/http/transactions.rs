@@ -0,0 +1,26 @@
+use crate::client::TransactionClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::models::transaction_info::TransactionInfo;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
+use async_trait::async_trait;
+
+#[async_trait]
+impl TransactionClient for HttpClient {
+    async fn begin_transaction(
+        &self,
+        _command: &BeginTransaction,
+    ) -> Result<TransactionInfo, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn commit_transaction(&self, _command: &CommitTransaction) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn abort_transaction(&self, _command: &AbortTransaction) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+}
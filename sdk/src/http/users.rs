@@ -9,9 +9,13 @@ use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
+use crate::users::login_with_token::LoginWithToken;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_roles::UpdateUserRoles;
 use async_trait::async_trait;
 use serde::Serialize;
 
@@ -53,6 +57,24 @@ impl UserClient for HttpClient {
         Ok(())
     }
 
+    async fn update_quotas(&self, command: &UpdateQuotas) -> Result<(), IggyError> {
+        self.put(&format!("{PATH}/{}/quotas", command.user_id), &command)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_user_roles(&self, command: &UpdateUserRoles) -> Result<(), IggyError> {
+        self.put(&format!("{PATH}/{}/roles", command.user_id), &command)
+            .await?;
+        Ok(())
+    }
+
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), IggyError> {
+        self.post(&format!("{PATH}/{}/unlock", command.user_id), &command)
+            .await?;
+        Ok(())
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), IggyError> {
         self.put(&format!("{PATH}/{}/password", command.user_id), &command)
             .await?;
@@ -66,6 +88,15 @@ impl UserClient for HttpClient {
         Ok(identity_info)
     }
 
+    async fn login_with_token(&self, command: &LoginWithToken) -> Result<IdentityInfo, IggyError> {
+        let response = self
+            .post(&format!("{PATH}/login-with-token"), &command)
+            .await?;
+        let identity_info: IdentityInfo = response.json().await?;
+        self.set_tokens_from_identity(&identity_info).await?;
+        Ok(identity_info)
+    }
+
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), IggyError> {
         self.post(&format!("{PATH}/logout"), &command).await?;
         self.set_access_token(None).await;
@@ -23,11 +23,15 @@ pub mod partitioner;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod quic;
+pub mod roles;
 pub mod sizeable;
 pub mod streams;
 pub mod system;
 pub mod tcp;
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
 pub mod validatable;
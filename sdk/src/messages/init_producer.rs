@@ -0,0 +1,67 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `InitProducer` command is used to get the unique producer ID assigned by the server, which is then used by the idempotent producer to stamp the sent batches.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct InitProducer {}
+
+impl CommandPayload for InitProducer {}
+
+impl Validatable<IggyError> for InitProducer {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for InitProducer {
+    fn as_bytes(&self) -> Vec<u8> {
+        Vec::with_capacity(0)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<InitProducer, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = InitProducer {};
+        command.validate()?;
+        Ok(InitProducer {})
+    }
+}
+
+impl Display for InitProducer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = InitProducer {};
+        let bytes = command.as_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let bytes: Vec<u8> = vec![];
+        let command = InitProducer::from_bytes(&bytes);
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let bytes: Vec<u8> = vec![0];
+        let command = InitProducer::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod init_producer;
 pub mod poll_messages;
 pub mod send_messages;
 
@@ -19,6 +19,7 @@ use std::str::FromStr;
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `read_committed` - whether to return only the messages of the committed transactions, the messages sent outside of the transactions are always returned.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -42,6 +43,9 @@ pub struct PollMessages {
     #[serde(default)]
     /// Whether to commit offset on the server automatically after polling the messages.
     pub auto_commit: bool,
+    #[serde(default)]
+    /// Whether to return only the messages of the committed transactions, the messages sent outside of the transactions are always returned.
+    pub read_committed: bool,
 }
 
 /// `PollingStrategy` specifies from where to start polling messages.
@@ -91,6 +95,7 @@ impl Default for PollMessages {
             strategy: default_strategy(),
             count: default_count(),
             auto_commit: false,
+            read_committed: false,
         }
     }
 }
@@ -232,7 +237,7 @@ impl BytesSerializable for PollMessages {
         let topic_id_bytes = self.topic_id.as_bytes();
         let strategy_bytes = self.strategy.as_bytes();
         let mut bytes = Vec::with_capacity(
-            9 + consumer_bytes.len()
+            10 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
                 + strategy_bytes.len(),
@@ -252,6 +257,10 @@ impl BytesSerializable for PollMessages {
         } else {
             bytes.put_u8(0);
         }
+        // The trailing flag is optional, so that the requests remain compatible with the servers not supporting it.
+        if self.read_committed {
+            bytes.put_u8(1);
+        }
 
         bytes
     }
@@ -288,6 +297,8 @@ impl BytesSerializable for PollMessages {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into()?);
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        let read_committed = bytes.get(position + 13);
+        let read_committed = matches!(read_committed, Some(1));
         let command = PollMessages {
             consumer,
             stream_id,
@@ -296,6 +307,7 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            read_committed,
         };
         command.validate()?;
         Ok(command)
@@ -306,14 +318,15 @@ impl Display for PollMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
             self.strategy,
             self.count,
-            auto_commit_to_string(self.auto_commit)
+            auto_commit_to_string(self.auto_commit),
+            read_committed_to_string(self.read_committed)
         )
     }
 }
@@ -332,6 +345,14 @@ fn auto_commit_to_string(auto_commit: bool) -> &'static str {
     }
 }
 
+fn read_committed_to_string(read_committed: bool) -> &'static str {
+    if read_committed {
+        "c"
+    } else {
+        "u"
+    }
+}
+
 impl BytesSerializable for PollingStrategy {
     fn as_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(9);
@@ -366,6 +387,7 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            read_committed: true,
         };
 
         let bytes = command.as_bytes();
@@ -392,6 +414,8 @@ mod tests {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        let read_committed = bytes[position + 13];
+        let read_committed = matches!(read_committed, 1);
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -401,6 +425,7 @@ mod tests {
         assert_eq!(strategy, command.strategy);
         assert_eq!(count, command.count);
         assert_eq!(auto_commit, command.auto_commit);
+        assert_eq!(read_committed, command.read_committed);
     }
 
     #[test]
@@ -412,13 +437,14 @@ mod tests {
         let strategy = PollingStrategy::offset(2);
         let count = 3u32;
         let auto_commit = 1u8;
+        let read_committed = 1u8;
 
         let consumer_bytes = consumer.as_bytes();
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
         let strategy_bytes = strategy.as_bytes();
         let mut bytes = Vec::with_capacity(
-            9 + consumer_bytes.len()
+            10 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
                 + strategy_bytes.len(),
@@ -430,11 +456,13 @@ mod tests {
         bytes.extend(strategy_bytes);
         bytes.put_u32_le(count);
         bytes.put_u8(auto_commit);
+        bytes.put_u8(read_committed);
 
         let command = PollMessages::from_bytes(&bytes);
         assert!(command.is_ok());
 
         let auto_commit = matches!(auto_commit, 1);
+        let read_committed = matches!(read_committed, 1);
 
         let command = command.unwrap();
         assert_eq!(command.consumer, consumer);
@@ -444,5 +472,36 @@ mod tests {
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert_eq!(command.read_committed, read_committed);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_read_committed() {
+        let command = PollMessages {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            strategy: PollingStrategy::offset(2),
+            count: 3,
+            auto_commit: true,
+            read_committed: false,
+        };
+
+        let bytes = command.as_bytes();
+        let consumer_bytes = command.consumer.as_bytes();
+        let stream_id_bytes = command.stream_id.as_bytes();
+        let topic_id_bytes = command.topic_id.as_bytes();
+        let strategy_bytes = command.strategy.as_bytes();
+        assert_eq!(
+            bytes.len(),
+            9 + consumer_bytes.len()
+                + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + strategy_bytes.len()
+        );
+
+        let deserialized_command = PollMessages::from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized_command, command);
     }
 }
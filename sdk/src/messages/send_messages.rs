@@ -15,12 +15,19 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 const EMPTY_KEY_VALUE: Vec<u8> = vec![];
+/// The most significant bit of the partitioning kind marks the extended header (producer batch and acknowledgement level)
+/// following the partitioning. The requests of the older clients don't have it, thus the default values are used.
+const EXTENDED_HEADER_FLAG: u8 = 1 << 7;
+/// Partitioning is at most 257 bytes long (kind, length and up to 255 bytes of value).
+const MAX_PARTITIONING_SIZE: usize = 257;
 
 /// `SendMessages` command is used to send messages to a topic in a stream.
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitioning` - to which partition the messages should be sent - either provided by the client or calculated by the server.
+/// - `producer` - optional idempotent producer ID and the sequence number of the batch.
+/// - `ack` - acknowledgement level of the request - none, in-memory or fsync.
 /// - `messages` - collection of messages to be sent.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct SendMessages {
@@ -32,10 +39,45 @@ pub struct SendMessages {
     pub topic_id: Identifier,
     /// To which partition the messages should be sent - either provided by the client or calculated by the server.
     pub partitioning: Partitioning,
+    /// Optional idempotent producer ID and the sequence number of the batch.
+    pub producer: Option<ProducerBatch>,
+    /// Acknowledgement level of the request - none, in-memory or fsync.
+    #[serde(default)]
+    pub ack: AckLevel,
     /// Collection of messages to be sent.
     pub messages: Vec<Message>,
 }
 
+/// `AckLevel` specifies when the server acknowledges the sent messages.
+/// It has the following kinds:
+/// - `None` - the messages are acknowledged as soon as the request is received, any error is only logged on the server.
+/// - `Memory` - the messages are acknowledged once appended to the partition, they might be buffered in memory before being saved on disk.
+/// - `Fsync` - the messages are acknowledged once the segment is persisted and synced on disk.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum AckLevel {
+    /// The messages are acknowledged as soon as the request is received, any error is only logged on the server.
+    None,
+    /// The messages are acknowledged once appended to the partition, they might be buffered in memory before being saved on disk.
+    #[default]
+    Memory,
+    /// The messages are acknowledged once the segment is persisted and synced on disk.
+    Fsync,
+}
+
+/// `ProducerBatch` is used by the idempotent producer to stamp the batch of messages sent to the particular partition.
+/// The server keeps the last sequence number for each producer and partition, ignores the duplicated batches and rejects the out of order ones.
+/// It has the following payload:
+/// - `producer_id` - unique producer ID assigned by the server with `InitProducer` command.
+/// - `sequence` - sequence number of the batch within the partition, it must be incremented by 1 for each new batch.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+pub struct ProducerBatch {
+    /// Unique producer ID assigned by the server with `InitProducer` command.
+    pub producer_id: u64,
+    /// Sequence number of the batch within the partition, it must be incremented by 1 for each new batch.
+    pub sequence: u64,
+}
+
 /// `Partitioning` is used to specify to which partition the messages should be sent.
 /// It has the following kinds:
 /// - `Balanced` - the partition ID is calculated by the server using the round-robin algorithm.
@@ -98,6 +140,8 @@ impl Default for SendMessages {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
+            producer: None,
+            ack: AckLevel::default(),
             messages: vec![Message::default()],
         }
     }
@@ -190,6 +234,29 @@ impl Partitioning {
     }
 }
 
+impl ProducerBatch {
+    /// Create the producer batch for the given producer ID and sequence number.
+    pub fn new(producer_id: u64, sequence: u64) -> Self {
+        ProducerBatch {
+            producer_id,
+            sequence,
+        }
+    }
+
+    /// Get the size of the producer batch in bytes.
+    pub fn get_size_bytes(&self) -> u32 {
+        16
+    }
+}
+
+impl SendMessages {
+    /// The producer batch and acknowledgement level are serialized only if they differ from the defaults,
+    /// so that the requests remain compatible with the servers not supporting them.
+    fn has_extended_header(&self) -> bool {
+        self.producer.is_some() || self.ack != AckLevel::default()
+    }
+}
+
 impl CommandPayload for SendMessages {}
 
 impl Validatable<IggyError> for SendMessages {
@@ -205,6 +272,16 @@ impl Validatable<IggyError> for SendMessages {
             return Err(IggyError::InvalidKeyValueLength);
         }
 
+        if let Some(producer) = &self.producer {
+            if producer.producer_id == 0 {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            if self.partitioning.kind != PartitioningKind::PartitionId {
+                return Err(IggyError::InvalidProducerPartitioning);
+            }
+        }
+
         let mut headers_size = 0;
         let mut payload_size = 0;
         for message in &self.messages {
@@ -251,6 +328,39 @@ impl PartitioningKind {
     }
 }
 
+impl AckLevel {
+    /// Get the code of the acknowledgement level.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            AckLevel::None => 1,
+            AckLevel::Memory => 2,
+            AckLevel::Fsync => 3,
+        }
+    }
+
+    /// Get the acknowledgement level from the provided code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(AckLevel::None),
+            2 => Ok(AckLevel::Memory),
+            3 => Ok(AckLevel::Fsync),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for AckLevel {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(AckLevel::None),
+            "memory" => Ok(AckLevel::Memory),
+            "fsync" => Ok(AckLevel::Fsync),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
 impl Message {
     /// Create a new message with the optional ID, payload and headers.
     pub fn new(
@@ -407,15 +517,27 @@ impl BytesSerializable for SendMessages {
             .map(Message::get_size_bytes)
             .sum::<u32>();
 
-        let key_bytes = self.partitioning.as_bytes();
+        let extended_header = self.has_extended_header();
+        let mut key_bytes = self.partitioning.as_bytes();
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes = Vec::with_capacity(
-            stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len() + messages_size as usize,
+            stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + key_bytes.len()
+                + 17
+                + messages_size as usize,
         );
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
+        if extended_header {
+            key_bytes[0] |= EXTENDED_HEADER_FLAG;
+        }
         bytes.extend(key_bytes);
+        if extended_header {
+            bytes.extend(self.producer.unwrap_or_default().as_bytes());
+            bytes.put_u8(self.ack.as_code());
+        }
         for message in &self.messages {
             bytes.extend(message.as_bytes());
         }
@@ -433,8 +555,40 @@ impl BytesSerializable for SendMessages {
         position += stream_id.get_size_bytes() as usize;
         let topic_id = Identifier::from_bytes(&bytes[position..])?;
         position += topic_id.get_size_bytes() as usize;
-        let key = Partitioning::from_bytes(&bytes[position..])?;
+        if position >= bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let extended_header = bytes[position] & EXTENDED_HEADER_FLAG != 0;
+        let key = match extended_header {
+            true => {
+                // Only the partitioning is copied to clear the flag, not the whole batch of messages.
+                let key_end = bytes.len().min(position + MAX_PARTITIONING_SIZE + 1);
+                let mut key_bytes = bytes[position..key_end].to_vec();
+                key_bytes[0] &= !EXTENDED_HEADER_FLAG;
+                Partitioning::from_bytes(&key_bytes)?
+            }
+            false => Partitioning::from_bytes(&bytes[position..])?,
+        };
         position += key.get_size_bytes() as usize;
+        let (producer, ack) = match extended_header {
+            true => {
+                let producer = ProducerBatch::from_bytes(&bytes[position..])?;
+                position += producer.get_size_bytes() as usize;
+                if position >= bytes.len() {
+                    return Err(IggyError::InvalidCommand);
+                }
+
+                let ack = AckLevel::from_code(bytes[position])?;
+                position += 1;
+                let producer = match producer.producer_id {
+                    0 => None,
+                    _ => Some(producer),
+                };
+                (producer, ack)
+            }
+            false => (None, AckLevel::default()),
+        };
         let messages_payloads = &bytes[position..];
         position = 0;
         let mut messages = Vec::new();
@@ -448,6 +602,8 @@ impl BytesSerializable for SendMessages {
             stream_id,
             topic_id,
             partitioning: key,
+            producer,
+            ack,
             messages,
         };
         command.validate()?;
@@ -455,14 +611,42 @@ impl BytesSerializable for SendMessages {
     }
 }
 
+impl BytesSerializable for ProducerBatch {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.get_size_bytes() as usize);
+        bytes.put_u64_le(self.producer_id);
+        bytes.put_u64_le(self.sequence);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        if bytes.len() < 16 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let producer_id = u64::from_le_bytes(bytes[..8].try_into()?);
+        let sequence = u64::from_le_bytes(bytes[8..16].try_into()?);
+        Ok(ProducerBatch {
+            producer_id,
+            sequence,
+        })
+    }
+}
+
 impl Display for SendMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.partitioning,
+            self.producer
+                .map_or("0|0".to_string(), |producer| producer.to_string()),
+            self.ack,
             self.messages
                 .iter()
                 .map(std::string::ToString::to_string)
@@ -472,6 +656,12 @@ impl Display for SendMessages {
     }
 }
 
+impl Display for ProducerBatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.producer_id, self.sequence)
+    }
+}
+
 impl Display for Partitioning {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.kind {
@@ -489,6 +679,16 @@ impl Display for Partitioning {
     }
 }
 
+impl Display for AckLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AckLevel::None => write!(f, "none"),
+            AckLevel::Memory => write!(f, "memory"),
+            AckLevel::Fsync => write!(f, "fsync"),
+        }
+    }
+}
+
 impl Display for PartitioningKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -513,6 +713,8 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             partitioning: Partitioning::partition_id(4),
+            producer: Some(ProducerBatch::new(5, 6)),
+            ack: AckLevel::Fsync,
             messages,
         };
 
@@ -523,8 +725,15 @@ mod tests {
         position += stream_id.get_size_bytes() as usize;
         let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
         position += topic_id.get_size_bytes() as usize;
-        let key = Partitioning::from_bytes(&bytes[position..]).unwrap();
+        assert_ne!(bytes[position] & EXTENDED_HEADER_FLAG, 0);
+        let mut key_bytes = bytes[position..].to_vec();
+        key_bytes[0] &= !EXTENDED_HEADER_FLAG;
+        let key = Partitioning::from_bytes(&key_bytes).unwrap();
         position += key.get_size_bytes() as usize;
+        let producer = ProducerBatch::from_bytes(&bytes[position..]).unwrap();
+        position += producer.get_size_bytes() as usize;
+        let ack = AckLevel::from_code(bytes[position]).unwrap();
+        position += 1;
         let messages = &bytes[position..];
         let command_messages = &command
             .messages
@@ -537,6 +746,8 @@ mod tests {
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(key, command.partitioning);
+        assert_eq!(Some(producer), command.producer);
+        assert_eq!(ack, command.ack);
         assert_eq!(messages, command_messages);
     }
 
@@ -545,6 +756,8 @@ mod tests {
         let stream_id = Identifier::numeric(1).unwrap();
         let topic_id = Identifier::numeric(2).unwrap();
         let key = Partitioning::partition_id(4);
+        let producer = ProducerBatch::new(5, 6);
+        let ack = AckLevel::Fsync;
 
         let message_1 = Message::from_str("hello 1").unwrap();
         let message_2 = Message::new(Some(2), "hello 2".into(), None);
@@ -559,11 +772,19 @@ mod tests {
         let key_bytes = key.as_bytes();
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
-        let current_position = stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len();
+        let producer_bytes = producer.as_bytes();
+        let current_position = stream_id_bytes.len()
+            + topic_id_bytes.len()
+            + key_bytes.len()
+            + producer_bytes.len()
+            + 1;
         let mut bytes = Vec::with_capacity(current_position);
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
-        bytes.extend(key_bytes);
+        bytes.put_u8(key_bytes[0] | EXTENDED_HEADER_FLAG);
+        bytes.extend(&key_bytes[1..]);
+        bytes.extend(producer_bytes);
+        bytes.put_u8(ack.as_code());
         bytes.extend(messages);
 
         let command = SendMessages::from_bytes(&bytes);
@@ -582,6 +803,8 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitioning, key);
+        assert_eq!(command.producer, Some(producer));
+        assert_eq!(command.ack, ack);
         for (index, message) in command.messages.iter().enumerate() {
             let command_message = &command.messages[index];
             assert_eq!(command_message.id, message.id);
@@ -641,4 +864,72 @@ mod tests {
         let key = Partitioning::messages_key_str(&messages_key);
         assert!(key.is_err());
     }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_extended_header() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::named("test").unwrap();
+        let key = Partitioning::balanced();
+        let message = Message::new(Some(1), "hello".into(), None);
+
+        let mut bytes = Vec::new();
+        bytes.extend(stream_id.as_bytes());
+        bytes.extend(topic_id.as_bytes());
+        bytes.extend(key.as_bytes());
+        bytes.extend(message.as_bytes());
+
+        let command = SendMessages::from_bytes(&bytes).unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partitioning, key);
+        assert_eq!(command.producer, None);
+        assert_eq!(command.ack, AckLevel::default());
+        assert_eq!(command.messages.len(), 1);
+        assert_eq!(command.messages[0].payload, message.payload);
+    }
+
+    #[test]
+    fn command_with_default_producer_and_ack_should_be_serialized_without_extended_header() {
+        let command = SendMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partitioning: Partitioning::partition_id(4),
+            ..SendMessages::default()
+        };
+
+        let bytes = command.as_bytes();
+        let position =
+            (command.stream_id.get_size_bytes() + command.topic_id.get_size_bytes()) as usize;
+        assert_eq!(bytes[position], PartitioningKind::PartitionId.as_code());
+        assert_eq!(SendMessages::from_bytes(&bytes).unwrap(), command);
+    }
+
+    #[test]
+    fn ack_level_should_be_mapped_from_code() {
+        for ack in [AckLevel::None, AckLevel::Memory, AckLevel::Fsync] {
+            assert_eq!(AckLevel::from_code(ack.as_code()).unwrap(), ack);
+            assert_eq!(AckLevel::from_str(&ack.to_string()).unwrap(), ack);
+        }
+        assert!(AckLevel::from_code(0).is_err());
+    }
+
+    #[test]
+    fn producer_batch_with_partitioning_other_than_partition_id_should_fail() {
+        let command = SendMessages {
+            partitioning: Partitioning::balanced(),
+            producer: Some(ProducerBatch::new(1, 0)),
+            ..SendMessages::default()
+        };
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn producer_batch_with_partition_id_partitioning_should_be_valid() {
+        let command = SendMessages {
+            partitioning: Partitioning::partition_id(1),
+            producer: Some(ProducerBatch::new(1, 0)),
+            ..SendMessages::default()
+        };
+        assert!(command.validate().is_ok());
+    }
 }
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// `AuditLogEntry` represents a single administrative or security event recorded by the server.
+/// The entries are chained together, as each one contains the hash of the previous one, which makes any modification detectable.
+/// It consists of the following fields:
+/// - `id`: the sequential identifier of the entry, starting from 0.
+/// - `timestamp`: the timestamp of the event in microseconds.
+/// - `user_id`: the unique identifier of the user who triggered the event, 0 if not authenticated.
+/// - `client_id`: the unique identifier of the client, 0 if the event was triggered via HTTP.
+/// - `address`: the remote address of the client.
+/// - `transport`: the transport protocol used by the client.
+/// - `action`: the name of the action, e.g. `create_stream`.
+/// - `resource`: the description of the resource affected by the action.
+/// - `succeeded`: whether the action has succeeded.
+/// - `previous_hash`: the hash of the previous entry, empty for the first one.
+/// - `hash`: the hash of this entry.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct AuditLogEntry {
+    /// The sequential identifier of the entry, starting from 0.
+    pub id: u64,
+    /// The timestamp of the event in microseconds.
+    pub timestamp: u64,
+    /// The unique identifier of the user who triggered the event, 0 if not authenticated.
+    pub user_id: u32,
+    /// The unique identifier of the client, 0 if the event was triggered via HTTP.
+    pub client_id: u32,
+    /// The remote address of the client.
+    pub address: String,
+    /// The transport protocol used by the client.
+    pub transport: String,
+    /// The name of the action, e.g. `create_stream`.
+    pub action: String,
+    /// The description of the resource affected by the action.
+    pub resource: String,
+    /// Whether the action has succeeded.
+    pub succeeded: bool,
+    /// The hash of the previous entry, empty for the first one.
+    pub previous_hash: String,
+    /// The hash of this entry.
+    pub hash: String,
+}
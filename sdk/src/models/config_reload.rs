@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// `ConfigReload` represents the result of reloading the server configuration.
+/// It consists of the following fields:
+/// - `applied_changes`: the changed settings which have been applied at runtime, e.g. `system.logging.level`.
+/// - `restart_required_changes`: the changed settings which will take effect only after the server restart.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ConfigReload {
+    /// The changed settings which have been applied at runtime.
+    pub applied_changes: Vec<String>,
+    /// The changed settings which will take effect only after the server restart.
+    pub restart_required_changes: Vec<String>,
+}
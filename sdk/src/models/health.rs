@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// `Health` represents the health of the server, which is healthy only if all of its checks pass.
+/// It consists of the following fields:
+/// - `checks`: the collection of the individual health checks.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Health {
+    /// The collection of the individual health checks.
+    pub checks: Vec<HealthCheck>,
+}
+
+/// `HealthCheck` represents the result of the single health check.
+/// It consists of the following fields:
+/// - `name`: the name of the check, e.g. `storage`.
+/// - `healthy`: whether the check passed.
+/// - `details`: the details of the check result, e.g. the reason of the failure.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+pub struct HealthCheck {
+    /// The name of the check.
+    pub name: String,
+    /// Whether the check passed.
+    pub healthy: bool,
+    /// The details of the check result.
+    pub details: String,
+}
+
+impl Health {
+    /// Returns `true` if all the checks passed.
+    pub fn is_healthy(&self) -> bool {
+        self.checks.iter().all(|check| check.healthy)
+    }
+}
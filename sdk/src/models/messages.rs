@@ -62,7 +62,8 @@ pub struct Message {
     pub payload: Bytes,
 }
 
-/// The state of the message, currently only the `Available` state is used.
+/// The state of the message, currently only the `Available` state is used for the regular messages.
+/// The `TransactionCommitted` and `TransactionAborted` states are used by the transaction markers appended to the partition when the transaction is completed.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum MessageState {
@@ -74,6 +75,10 @@ pub enum MessageState {
     Poisoned,
     /// The message is marked for deletion.
     MarkedForDeletion,
+    /// The message is the marker of the committed transaction.
+    TransactionCommitted,
+    /// The message is the marker of the aborted transaction.
+    TransactionAborted,
 }
 
 impl MessageState {
@@ -84,9 +89,20 @@ impl MessageState {
             MessageState::Unavailable => 10,
             MessageState::Poisoned => 20,
             MessageState::MarkedForDeletion => 30,
+            MessageState::TransactionCommitted => 40,
+            MessageState::TransactionAborted => 41,
         }
     }
 
+    /// Returns true if the message is the control record (e.g. the transaction marker) appended by the server,
+    /// which carries no user data, thus it's neither encrypted nor returned to the consumers.
+    pub fn is_control(&self) -> bool {
+        matches!(
+            self,
+            MessageState::TransactionCommitted | MessageState::TransactionAborted
+        )
+    }
+
     /// Returns the message state from the code.
     pub fn from_code(code: u8) -> Result<Self, IggyError> {
         match code {
@@ -94,6 +110,8 @@ impl MessageState {
             10 => Ok(MessageState::Unavailable),
             20 => Ok(MessageState::Poisoned),
             30 => Ok(MessageState::MarkedForDeletion),
+            40 => Ok(MessageState::TransactionCommitted),
+            41 => Ok(MessageState::TransactionAborted),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -106,6 +124,8 @@ impl Display for MessageState {
             MessageState::Unavailable => write!(f, "unavailable"),
             MessageState::Poisoned => write!(f, "poisoned"),
             MessageState::MarkedForDeletion => write!(f, "marked_for_deletion"),
+            MessageState::TransactionCommitted => write!(f, "transaction_committed"),
+            MessageState::TransactionAborted => write!(f, "transaction_aborted"),
         }
     }
 }
@@ -118,6 +138,8 @@ impl FromStr for MessageState {
             "unavailable" => Ok(MessageState::Unavailable),
             "poisoned" => Ok(MessageState::Poisoned),
             "marked_for_deletion" => Ok(MessageState::MarkedForDeletion),
+            "transaction_committed" => Ok(MessageState::TransactionCommitted),
+            "transaction_aborted" => Ok(MessageState::TransactionAborted),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -1,14 +1,22 @@
+pub mod audit_log_entry;
 pub mod client_info;
+pub mod config_reload;
 pub mod consumer_group;
 pub mod consumer_offset_info;
 pub mod header;
+pub mod health;
 pub mod identity_info;
 pub mod messages;
 pub mod partition;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod producer_info;
+pub mod role_info;
 pub mod stats;
 pub mod stream;
+pub mod stream_quotas;
 pub mod topic;
+pub mod transaction_info;
 pub mod user_info;
+pub mod user_quotas;
 pub mod user_status;
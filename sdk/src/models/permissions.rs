@@ -1,14 +1,21 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::error::IggyError;
+use crate::validatable::Validatable;
 use bytes::{Buf, BufMut};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::hash::Hash;
+use std::str::from_utf8;
+
+/// Maximum length of the stream or topic pattern, as it's serialized with a single byte length prefix.
+pub const MAX_PATTERN_LENGTH: usize = 255;
 
 /// `Permissions` is used to define the permissions of a user.
 /// It consists of global permissions and stream permissions.
 /// Global permissions are applied to all streams.
 /// Stream permissions are applied to a specific stream.
+/// Stream pattern permissions are applied to all the streams whose name matches the pattern.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct Permissions {
     /// Global permissions are applied to all streams.
@@ -16,6 +23,11 @@ pub struct Permissions {
 
     /// Stream permissions are applied to a specific stream.
     pub streams: Option<HashMap<u32, StreamPermissions>>,
+
+    /// Stream pattern permissions are applied to the streams whose name matches the key, which is either the exact stream name or a glob pattern (e.g. `orders-*`).
+    /// As they are resolved when the permissions are checked, they can be granted before the stream is created and are kept when it's recreated.
+    #[serde(default)]
+    pub stream_patterns: Option<HashMap<String, StreamPermissions>>,
 }
 
 /// `GlobalPermissions` are applied to all streams without a need to specify them one by one in the `streams` field.
@@ -36,12 +48,20 @@ pub struct GlobalPermissions {
     /// - update_user
     /// - delete_user
     /// - update_permissions
+    /// - update_quotas
+    /// - update_user_roles
+    /// - unlock_user
     /// - change_password
+    /// - create_role
+    /// - update_role
+    /// - delete_role
     pub manage_users: bool,
 
     /// `read_users` permission allows to invoke the following methods:
     /// - get_user
     /// - get_users
+    /// - get_role
+    /// - get_roles
     pub read_users: bool,
 
     /// `manage_streams` permission allows to manage the streams and includes all the permissions of `read_streams`.
@@ -117,6 +137,10 @@ pub struct StreamPermissions {
 
     /// The `topics` field allows to define the granular permissions for each topic of a stream.
     pub topics: Option<HashMap<u32, TopicPermissions>>,
+
+    /// The `topic_patterns` field allows to define the granular permissions for the topics whose name matches the key, which is either the exact topic name or a glob pattern (e.g. `payments-*`).
+    #[serde(default)]
+    pub topic_patterns: Option<HashMap<String, TopicPermissions>>,
 }
 
 /// `TopicPermissions` are applied to a specific topic of a stream. This is the lowest level of permissions.
@@ -151,6 +175,92 @@ impl Permissions {
                 send_messages: true,
             },
             streams: None,
+            stream_patterns: None,
+        }
+    }
+
+    /// Returns the union of both permissions, in which each permission is granted if it's granted by any of them.
+    pub fn union(&self, other: &Permissions) -> Permissions {
+        Permissions {
+            global: self.global.union(&other.global),
+            streams: union_maps(&self.streams, &other.streams, StreamPermissions::union),
+            stream_patterns: union_maps(
+                &self.stream_patterns,
+                &other.stream_patterns,
+                StreamPermissions::union,
+            ),
+        }
+    }
+}
+
+impl GlobalPermissions {
+    /// Returns the union of both global permissions.
+    pub fn union(&self, other: &GlobalPermissions) -> GlobalPermissions {
+        GlobalPermissions {
+            manage_servers: self.manage_servers || other.manage_servers,
+            read_servers: self.read_servers || other.read_servers,
+            manage_users: self.manage_users || other.manage_users,
+            read_users: self.read_users || other.read_users,
+            manage_streams: self.manage_streams || other.manage_streams,
+            read_streams: self.read_streams || other.read_streams,
+            manage_topics: self.manage_topics || other.manage_topics,
+            read_topics: self.read_topics || other.read_topics,
+            poll_messages: self.poll_messages || other.poll_messages,
+            send_messages: self.send_messages || other.send_messages,
+        }
+    }
+}
+
+impl StreamPermissions {
+    /// Returns the union of both stream permissions, including the permissions of their topics.
+    pub fn union(&self, other: &StreamPermissions) -> StreamPermissions {
+        StreamPermissions {
+            manage_stream: self.manage_stream || other.manage_stream,
+            read_stream: self.read_stream || other.read_stream,
+            manage_topics: self.manage_topics || other.manage_topics,
+            read_topics: self.read_topics || other.read_topics,
+            poll_messages: self.poll_messages || other.poll_messages,
+            send_messages: self.send_messages || other.send_messages,
+            topics: union_maps(&self.topics, &other.topics, TopicPermissions::union),
+            topic_patterns: union_maps(
+                &self.topic_patterns,
+                &other.topic_patterns,
+                TopicPermissions::union,
+            ),
+        }
+    }
+}
+
+impl TopicPermissions {
+    /// Returns the union of both topic permissions.
+    pub fn union(&self, other: &TopicPermissions) -> TopicPermissions {
+        TopicPermissions {
+            manage_topic: self.manage_topic || other.manage_topic,
+            read_topic: self.read_topic || other.read_topic,
+            poll_messages: self.poll_messages || other.poll_messages,
+            send_messages: self.send_messages || other.send_messages,
+        }
+    }
+}
+
+fn union_maps<K: Clone + Eq + Hash, V: Clone>(
+    map: &Option<HashMap<K, V>>,
+    other_map: &Option<HashMap<K, V>>,
+    union: fn(&V, &V) -> V,
+) -> Option<HashMap<K, V>> {
+    match (map, other_map) {
+        (None, None) => None,
+        (Some(map), None) | (None, Some(map)) => Some(map.clone()),
+        (Some(map), Some(other_map)) => {
+            let mut map = map.clone();
+            for (key, other_value) in other_map {
+                let value = match map.get(key) {
+                    Some(value) => union(value, other_value),
+                    None => other_value.clone(),
+                };
+                map.insert(key.clone(), value);
+            }
+            Some(map)
         }
     }
 }
@@ -171,21 +281,15 @@ impl Display for Permissions {
         if let Some(streams) = &self.streams {
             for (stream_id, stream) in streams {
                 result.push_str(&format!("stream_id: {}\n", stream_id));
-                result.push_str(&format!("manage_stream: {}\n", stream.manage_stream));
-                result.push_str(&format!("read_stream: {}\n", stream.read_stream));
-                result.push_str(&format!("manage_topics: {}\n", stream.manage_topics));
-                result.push_str(&format!("read_topics: {}\n", stream.read_topics));
-                result.push_str(&format!("poll_messages: {}\n", stream.poll_messages));
-                result.push_str(&format!("send_messages: {}\n", stream.send_messages));
-                if let Some(topics) = &stream.topics {
-                    for (topic_id, topic) in topics {
-                        result.push_str(&format!("topic_id: {}\n", topic_id));
-                        result.push_str(&format!("manage_topic: {}\n", topic.manage_topic));
-                        result.push_str(&format!("read_topic: {}\n", topic.read_topic));
-                        result.push_str(&format!("poll_messages: {}\n", topic.poll_messages));
-                        result.push_str(&format!("send_messages: {}\n", topic.send_messages));
-                    }
-                }
+                format_stream(&mut result, stream);
+                format_topics(&mut result, stream);
+            }
+        }
+        if let Some(stream_patterns) = &self.stream_patterns {
+            for (stream_pattern, stream) in stream_patterns {
+                result.push_str(&format!("stream_pattern: {}\n", stream_pattern));
+                format_stream(&mut result, stream);
+                format_topics(&mut result, stream);
             }
         }
 
@@ -193,6 +297,52 @@ impl Display for Permissions {
     }
 }
 
+fn format_stream(result: &mut String, stream: &StreamPermissions) {
+    result.push_str(&format!("manage_stream: {}\n", stream.manage_stream));
+    result.push_str(&format!("read_stream: {}\n", stream.read_stream));
+    result.push_str(&format!("manage_topics: {}\n", stream.manage_topics));
+    result.push_str(&format!("read_topics: {}\n", stream.read_topics));
+    result.push_str(&format!("poll_messages: {}\n", stream.poll_messages));
+    result.push_str(&format!("send_messages: {}\n", stream.send_messages));
+}
+
+fn format_topics(result: &mut String, stream: &StreamPermissions) {
+    if let Some(topics) = &stream.topics {
+        for (topic_id, topic) in topics {
+            result.push_str(&format!("topic_id: {}\n", topic_id));
+            format_topic(result, topic);
+        }
+    }
+    if let Some(topic_patterns) = &stream.topic_patterns {
+        for (topic_pattern, topic) in topic_patterns {
+            result.push_str(&format!("topic_pattern: {}\n", topic_pattern));
+            format_topic(result, topic);
+        }
+    }
+}
+
+fn format_topic(result: &mut String, topic: &TopicPermissions) {
+    result.push_str(&format!("manage_topic: {}\n", topic.manage_topic));
+    result.push_str(&format!("read_topic: {}\n", topic.read_topic));
+    result.push_str(&format!("poll_messages: {}\n", topic.poll_messages));
+    result.push_str(&format!("send_messages: {}\n", topic.send_messages));
+}
+
+impl Validatable<IggyError> for Permissions {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_patterns(&self.stream_patterns)?;
+        let streams = self.streams.iter().flat_map(|streams| streams.values());
+        let stream_patterns = self
+            .stream_patterns
+            .iter()
+            .flat_map(|stream_patterns| stream_patterns.values());
+        for stream in streams.chain(stream_patterns) {
+            validate_patterns(&stream.topic_patterns)?;
+        }
+        Ok(())
+    }
+}
+
 impl BytesSerializable for Permissions {
     fn as_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::new();
@@ -248,6 +398,20 @@ impl BytesSerializable for Permissions {
         } else {
             bytes.put_u8(0);
         }
+        extend_stream_patterns(&mut bytes, &self.stream_patterns);
+        if let Some(streams) = &self.streams {
+            let streams = streams
+                .iter()
+                .filter(|(_, stream)| is_not_empty(&stream.topic_patterns))
+                .collect::<Vec<_>>();
+            bytes.put_u32_le(streams.len() as u32);
+            for (stream_id, stream) in streams {
+                bytes.put_u32_le(*stream_id);
+                extend_topic_patterns(&mut bytes, &stream.topic_patterns);
+            }
+        } else {
+            bytes.put_u32_le(0);
+        }
         bytes
     }
 
@@ -311,6 +475,7 @@ impl BytesSerializable for Permissions {
                         poll_messages,
                         send_messages,
                         topics,
+                        topic_patterns: None,
                     },
                 );
                 if bytes.get_u8() == 0 {
@@ -319,6 +484,22 @@ impl BytesSerializable for Permissions {
             }
             streams = Some(streams_map);
         }
+        // The pattern permissions are optional, as they are not sent by the older clients.
+        let mut stream_patterns = None;
+        if bytes.has_remaining() {
+            stream_patterns = read_stream_patterns(&mut bytes)?;
+            let streams_count = read_u32(&mut bytes)?;
+            for _ in 0..streams_count {
+                let stream_id = read_u32(&mut bytes)?;
+                let topic_patterns = read_topic_patterns(&mut bytes)?;
+                if let Some(stream) = streams
+                    .as_mut()
+                    .and_then(|streams| streams.get_mut(&stream_id))
+                {
+                    stream.topic_patterns = topic_patterns;
+                }
+            }
+        }
         Ok(Self {
             global: GlobalPermissions {
                 manage_servers,
@@ -333,10 +514,192 @@ impl BytesSerializable for Permissions {
                 send_messages,
             },
             streams,
+            stream_patterns,
         })
     }
 }
 
+fn is_not_empty<K, V>(map: &Option<HashMap<K, V>>) -> bool {
+    map.as_ref().map_or(false, |map| !map.is_empty())
+}
+
+fn validate_patterns<T>(patterns: &Option<HashMap<String, T>>) -> Result<(), IggyError> {
+    if let Some(patterns) = patterns {
+        for pattern in patterns.keys() {
+            if pattern.is_empty() || pattern.len() > MAX_PATTERN_LENGTH {
+                return Err(IggyError::InvalidPermissionsPattern(pattern.to_string()));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn extend_pattern(bytes: &mut Vec<u8>, pattern: &str) {
+    // The patterns length is validated, so it always fits in a single byte.
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u8(pattern.len() as u8);
+    bytes.extend(pattern.as_bytes());
+}
+
+fn read_u8(bytes: &mut &[u8]) -> Result<u8, IggyError> {
+    if bytes.remaining() < 1 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(bytes.get_u8())
+}
+
+fn read_u32(bytes: &mut &[u8]) -> Result<u32, IggyError> {
+    if bytes.remaining() < 4 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(bytes.get_u32_le())
+}
+
+fn read_bool(bytes: &mut &[u8]) -> Result<bool, IggyError> {
+    Ok(read_u8(bytes)? == 1)
+}
+
+fn read_pattern(bytes: &mut &[u8]) -> Result<String, IggyError> {
+    let length = read_u8(bytes)? as usize;
+    if bytes.remaining() < length {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let pattern = from_utf8(&bytes[..length])?.to_string();
+    bytes.advance(length);
+    Ok(pattern)
+}
+
+fn extend_stream_patterns(
+    bytes: &mut Vec<u8>,
+    stream_patterns: &Option<HashMap<String, StreamPermissions>>,
+) {
+    if stream_patterns.is_none() {
+        bytes.put_u32_le(0);
+        return;
+    }
+
+    let stream_patterns = stream_patterns.as_ref().unwrap();
+
+    bytes.put_u32_le(stream_patterns.len() as u32);
+    for (pattern, stream) in stream_patterns {
+        extend_pattern(bytes, pattern);
+        bytes.put_u8(if stream.manage_stream { 1 } else { 0 });
+        bytes.put_u8(if stream.read_stream { 1 } else { 0 });
+        bytes.put_u8(if stream.manage_topics { 1 } else { 0 });
+        bytes.put_u8(if stream.read_topics { 1 } else { 0 });
+        bytes.put_u8(if stream.poll_messages { 1 } else { 0 });
+        bytes.put_u8(if stream.send_messages { 1 } else { 0 });
+        if let Some(topics) = &stream.topics {
+            bytes.put_u32_le(topics.len() as u32);
+            for (topic_id, topic) in topics {
+                bytes.put_u32_le(*topic_id);
+                extend_topic(bytes, topic);
+            }
+        } else {
+            bytes.put_u32_le(0);
+        }
+        extend_topic_patterns(bytes, &stream.topic_patterns);
+    }
+}
+
+fn read_stream_patterns(
+    bytes: &mut &[u8],
+) -> Result<Option<HashMap<String, StreamPermissions>>, IggyError> {
+    let stream_patterns_count = read_u32(bytes)?;
+    if stream_patterns_count == 0 {
+        return Ok(None);
+    }
+
+    let mut stream_patterns = HashMap::new();
+    for _ in 0..stream_patterns_count {
+        let pattern = read_pattern(bytes)?;
+        let manage_stream = read_bool(bytes)?;
+        let read_stream = read_bool(bytes)?;
+        let manage_topics = read_bool(bytes)?;
+        let read_topics = read_bool(bytes)?;
+        let poll_messages = read_bool(bytes)?;
+        let send_messages = read_bool(bytes)?;
+        let topics_count = read_u32(bytes)?;
+        let mut topics = None;
+        if topics_count > 0 {
+            let mut topics_map = HashMap::new();
+            for _ in 0..topics_count {
+                let topic_id = read_u32(bytes)?;
+                topics_map.insert(topic_id, read_topic(bytes)?);
+            }
+            topics = Some(topics_map);
+        }
+        let topic_patterns = read_topic_patterns(bytes)?;
+        stream_patterns.insert(
+            pattern,
+            StreamPermissions {
+                manage_stream,
+                read_stream,
+                manage_topics,
+                read_topics,
+                poll_messages,
+                send_messages,
+                topics,
+                topic_patterns,
+            },
+        );
+    }
+    Ok(Some(stream_patterns))
+}
+
+fn extend_topic_patterns(
+    bytes: &mut Vec<u8>,
+    topic_patterns: &Option<HashMap<String, TopicPermissions>>,
+) {
+    if topic_patterns.is_none() {
+        bytes.put_u32_le(0);
+        return;
+    }
+
+    let topic_patterns = topic_patterns.as_ref().unwrap();
+
+    bytes.put_u32_le(topic_patterns.len() as u32);
+    for (pattern, topic) in topic_patterns {
+        extend_pattern(bytes, pattern);
+        extend_topic(bytes, topic);
+    }
+}
+
+fn read_topic_patterns(
+    bytes: &mut &[u8],
+) -> Result<Option<HashMap<String, TopicPermissions>>, IggyError> {
+    let topic_patterns_count = read_u32(bytes)?;
+    if topic_patterns_count == 0 {
+        return Ok(None);
+    }
+
+    let mut topic_patterns = HashMap::new();
+    for _ in 0..topic_patterns_count {
+        let pattern = read_pattern(bytes)?;
+        topic_patterns.insert(pattern, read_topic(bytes)?);
+    }
+    Ok(Some(topic_patterns))
+}
+
+fn extend_topic(bytes: &mut Vec<u8>, topic: &TopicPermissions) {
+    bytes.put_u8(if topic.manage_topic { 1 } else { 0 });
+    bytes.put_u8(if topic.read_topic { 1 } else { 0 });
+    bytes.put_u8(if topic.poll_messages { 1 } else { 0 });
+    bytes.put_u8(if topic.send_messages { 1 } else { 0 });
+}
+
+fn read_topic(bytes: &mut &[u8]) -> Result<TopicPermissions, IggyError> {
+    Ok(TopicPermissions {
+        manage_topic: read_bool(bytes)?,
+        read_topic: read_bool(bytes)?,
+        poll_messages: read_bool(bytes)?,
+        send_messages: read_bool(bytes)?,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,6 +749,7 @@ mod tests {
                                 },
                             ),
                         ])),
+                        topic_patterns: None,
                     },
                 ),
                 (
@@ -398,6 +762,53 @@ mod tests {
                         poll_messages: true,
                         send_messages: true,
                         topics: None,
+                        topic_patterns: Some(HashMap::from([(
+                            "payments-*".to_string(),
+                            TopicPermissions {
+                                manage_topic: false,
+                                read_topic: true,
+                                poll_messages: true,
+                                send_messages: false,
+                            },
+                        )])),
+                    },
+                ),
+            ])),
+            stream_patterns: Some(HashMap::from([
+                (
+                    "orders-*".to_string(),
+                    StreamPermissions {
+                        manage_stream: false,
+                        read_stream: true,
+                        manage_topics: false,
+                        read_topics: true,
+                        poll_messages: true,
+                        send_messages: false,
+                        topics: Some(HashMap::from([(
+                            1,
+                            TopicPermissions {
+                                manage_topic: true,
+                                read_topic: true,
+                                poll_messages: false,
+                                send_messages: false,
+                            },
+                        )])),
+                        topic_patterns: Some(HashMap::from([(
+                            "*".to_string(),
+                            TopicPermissions {
+                                manage_topic: false,
+                                read_topic: false,
+                                poll_messages: false,
+                                send_messages: true,
+                            },
+                        )])),
+                    },
+                ),
+                (
+                    "invoices".to_string(),
+                    StreamPermissions {
+                        manage_stream: true,
+                        ..Default::default()
                     },
                 ),
             ])),
@@ -408,4 +819,182 @@ mod tests {
 
         assert_eq!(permissions, deserialized_permissions);
     }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_patterns() {
+        let permissions = Permissions {
+            global: GlobalPermissions {
+                read_streams: true,
+                ..Default::default()
+            },
+            streams: Some(HashMap::from([(1, StreamPermissions::default())])),
+            stream_patterns: None,
+        };
+        let bytes = permissions.as_bytes();
+        // The stream patterns count and the streams with topic patterns count.
+        let bytes = &bytes[..bytes.len() - 8];
+
+        let deserialized_permissions = Permissions::from_bytes(bytes).unwrap();
+
+        assert_eq!(permissions, deserialized_permissions);
+    }
+
+    #[test]
+    fn truncated_patterns_should_fail_to_deserialize() {
+        let permissions = Permissions {
+            stream_patterns: Some(HashMap::from([(
+                "orders-*".to_string(),
+                StreamPermissions {
+                    topic_patterns: Some(HashMap::from([(
+                        "payments-*".to_string(),
+                        TopicPermissions::default(),
+                    )])),
+                    ..Default::default()
+                },
+            )])),
+            ..Default::default()
+        };
+        let bytes = permissions.as_bytes();
+        // The global permissions and the streams flag take 11 bytes, the patterns follow them.
+        for length in 12..bytes.len() {
+            assert!(matches!(
+                Permissions::from_bytes(&bytes[..length]),
+                Err(IggyError::InvalidCommand)
+            ));
+        }
+    }
+
+    #[test]
+    fn pattern_longer_than_max_length_should_be_invalid() {
+        let valid_pattern = "a".repeat(MAX_PATTERN_LENGTH);
+        let invalid_pattern = "a".repeat(MAX_PATTERN_LENGTH + 1);
+        let create_permissions = |pattern: &str| Permissions {
+            streams: Some(HashMap::from([(
+                1,
+                StreamPermissions {
+                    topic_patterns: Some(HashMap::from([(
+                        pattern.to_string(),
+                        TopicPermissions::default(),
+                    )])),
+                    ..Default::default()
+                },
+            )])),
+            ..Default::default()
+        };
+
+        assert!(create_permissions(&valid_pattern).validate().is_ok());
+        assert!(matches!(
+            create_permissions(&invalid_pattern).validate(),
+            Err(IggyError::InvalidPermissionsPattern(_))
+        ));
+    }
+
+    #[test]
+    fn union_should_grant_permissions_granted_by_any_of_them() {
+        let permissions = Permissions {
+            global: GlobalPermissions {
+                read_streams: true,
+                ..Default::default()
+            },
+            streams: Some(HashMap::from([(
+                1,
+                StreamPermissions {
+                    poll_messages: true,
+                    topics: Some(HashMap::from([(
+                        1,
+                        TopicPermissions {
+                            read_topic: true,
+                            ..Default::default()
+                        },
+                    )])),
+                    ..Default::default()
+                },
+            )])),
+            stream_patterns: None,
+        };
+        let other = Permissions {
+            global: GlobalPermissions {
+                read_users: true,
+                ..Default::default()
+            },
+            streams: Some(HashMap::from([
+                (
+                    1,
+                    StreamPermissions {
+                        send_messages: true,
+                        topics: Some(HashMap::from([(
+                            1,
+                            TopicPermissions {
+                                manage_topic: true,
+                                ..Default::default()
+                            },
+                        )])),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    2,
+                    StreamPermissions {
+                        read_stream: true,
+                        ..Default::default()
+                    },
+                ),
+            ])),
+            stream_patterns: None,
+        };
+
+        let union = permissions.union(&other);
+
+        assert!(union.global.read_streams);
+        assert!(union.global.read_users);
+        assert!(!union.global.manage_servers);
+        let streams = union.streams.unwrap();
+        assert_eq!(streams.len(), 2);
+        let stream = streams.get(&1).unwrap();
+        assert!(stream.poll_messages);
+        assert!(stream.send_messages);
+        assert!(!stream.manage_stream);
+        let topic = stream.topics.as_ref().unwrap().get(&1).unwrap();
+        assert!(topic.read_topic);
+        assert!(topic.manage_topic);
+        assert!(streams.get(&2).unwrap().read_stream);
+    }
+
+    #[test]
+    fn union_should_merge_stream_and_topic_patterns() {
+        let permissions = Permissions {
+            stream_patterns: Some(HashMap::from([(
+                "orders-*".to_string(),
+                StreamPermissions {
+                    read_stream: true,
+                    ..Default::default()
+                },
+            )])),
+            ..Default::default()
+        };
+        let other = Permissions {
+            stream_patterns: Some(HashMap::from([(
+                "orders-*".to_string(),
+                StreamPermissions {
+                    topic_patterns: Some(HashMap::from([(
+                        "eu-*".to_string(),
+                        TopicPermissions {
+                            send_messages: true,
+                            ..Default::default()
+                        },
+                    )])),
+                    ..Default::default()
+                },
+            )])),
+            ..Default::default()
+        };
+
+        let union = permissions.union(&other);
+
+        let stream_patterns = union.stream_patterns.unwrap();
+        let stream = stream_patterns.get("orders-*").unwrap();
+        assert!(stream.read_stream);
+        let topic = stream.topic_patterns.as_ref().unwrap().get("eu-*").unwrap();
+        assert!(topic.send_messages);
+    }
 }
@@ -1,3 +1,4 @@
+use crate::models::permissions::Permissions;
 use serde::{Deserialize, Serialize};
 
 /// `RawPersonalAccessToken` represents the raw personal access token - the secured token which is returned only once during the creation.
@@ -13,10 +14,14 @@ pub struct RawPersonalAccessToken {
 /// It consists of the following fields:
 /// - `name`: the unique name of the token.
 /// - `expiry`: the optional expiry of the token.
+/// - `scope`: the optional permissions the token is limited to.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PersonalAccessTokenInfo {
     /// The unique name of the token.
     pub name: String,
     /// The optional expiry of the token.
     pub expiry: Option<u64>,
+    /// The optional permissions the token is limited to, if not provided, the token has all the permissions of the user.
+    #[serde(default)]
+    pub scope: Option<Permissions>,
 }
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// `ProducerInfo` represents the information about the idempotent producer.
+/// It consists of the following fields:
+/// - `producer_id`: the unique producer ID assigned by the server.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProducerInfo {
+    /// The unique producer ID assigned by the server.
+    pub producer_id: u64,
+}
@@ -0,0 +1,20 @@
+use crate::models::permissions::Permissions;
+use serde::{Deserialize, Serialize};
+
+/// `RoleInfo` represents the information about the role, which can be assigned to the users to grant them its permissions.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the role.
+/// - `created_at`: the timestamp when the role was created.
+/// - `name`: the unique name of the role.
+/// - `permissions`: the permissions granted to the users having the role.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct RoleInfo {
+    /// The unique identifier (numeric) of the role.
+    pub id: u32,
+    /// The timestamp when the role was created.
+    pub created_at: u64,
+    /// The unique name of the role.
+    pub name: String,
+    /// The permissions granted to the users having the role.
+    pub permissions: Permissions,
+}
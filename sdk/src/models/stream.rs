@@ -1,3 +1,4 @@
+use crate::models::stream_quotas::StreamQuotas;
 use crate::models::topic::Topic;
 use serde::{Deserialize, Serialize};
 
@@ -34,6 +35,7 @@ pub struct Stream {
 /// - `messages_count`: the total number of messages in the stream.
 /// - `topics_count`: the total number of topics in the stream.
 /// - `topics`: the list of topics in the stream.
+/// - `quotas`: the optional limits of the resources consumed by the stream.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StreamDetails {
     /// The unique identifier (numeric) of the stream.
@@ -50,4 +52,7 @@ pub struct StreamDetails {
     pub topics_count: u32,
     /// The collection of topics in the stream.
     pub topics: Vec<Topic>,
+    /// The optional limits of the resources consumed by the stream.
+    #[serde(default)]
+    pub quotas: Option<StreamQuotas>,
 }
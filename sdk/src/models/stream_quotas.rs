@@ -0,0 +1,117 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `StreamQuotas` is used to limit the resources consumed by a stream, the `None` value means no limit.
+/// It consists of the following fields:
+/// - `max_size`: the maximum total size of all the topics in the stream.
+/// - `max_topics`: the maximum number of topics in the stream.
+/// - `max_partitions`: the maximum total number of partitions of all the topics in the stream.
+/// - `max_consumer_groups`: the maximum total number of consumer groups of all the topics in the stream.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Default)]
+pub struct StreamQuotas {
+    /// The maximum total size of all the topics in the stream.
+    pub max_size: Option<IggyByteSize>,
+    /// The maximum number of topics in the stream.
+    pub max_topics: Option<u32>,
+    /// The maximum total number of partitions of all the topics in the stream.
+    pub max_partitions: Option<u32>,
+    /// The maximum total number of consumer groups of all the topics in the stream.
+    pub max_consumer_groups: Option<u32>,
+}
+
+impl StreamQuotas {
+    /// Get the size of the quotas in bytes.
+    pub fn get_size_bytes(&self) -> u32 {
+        20
+    }
+
+    /// Returns `true` if none of the limits is set.
+    pub fn is_unlimited(&self) -> bool {
+        self.max_size.is_none()
+            && self.max_topics.is_none()
+            && self.max_partitions.is_none()
+            && self.max_consumer_groups.is_none()
+    }
+}
+
+impl BytesSerializable for StreamQuotas {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.get_size_bytes() as usize);
+        match self.max_size {
+            Some(max_size) => bytes.put_u64_le(max_size.as_bytes_u64()),
+            None => bytes.put_u64_le(0),
+        }
+        bytes.put_u32_le(self.max_topics.unwrap_or(0));
+        bytes.put_u32_le(self.max_partitions.unwrap_or(0));
+        bytes.put_u32_le(self.max_consumer_groups.unwrap_or(0));
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        if bytes.len() < 20 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let max_size = match u64::from_le_bytes(bytes[..8].try_into()?) {
+            0 => None,
+            max_size => Some(IggyByteSize::from(max_size)),
+        };
+        let max_topics = map_limit(u32::from_le_bytes(bytes[8..12].try_into()?));
+        let max_partitions = map_limit(u32::from_le_bytes(bytes[12..16].try_into()?));
+        let max_consumer_groups = map_limit(u32::from_le_bytes(bytes[16..20].try_into()?));
+        Ok(StreamQuotas {
+            max_size,
+            max_topics,
+            max_partitions,
+            max_consumer_groups,
+        })
+    }
+}
+
+fn map_limit(value: u32) -> Option<u32> {
+    match value {
+        0 => None,
+        value => Some(value),
+    }
+}
+
+impl Display for StreamQuotas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.max_size.map_or(0, |max_size| max_size.as_bytes_u64()),
+            self.max_topics.unwrap_or(0),
+            self.max_partitions.unwrap_or(0),
+            self.max_consumer_groups.unwrap_or(0)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let quotas = StreamQuotas {
+            max_size: Some(IggyByteSize::from(1_000_000)),
+            max_topics: Some(10),
+            max_partitions: None,
+            max_consumer_groups: Some(5),
+        };
+
+        let bytes = quotas.as_bytes();
+        let deserialized_quotas = StreamQuotas::from_bytes(&bytes).unwrap();
+
+        assert_eq!(bytes.len(), quotas.get_size_bytes() as usize);
+        assert_eq!(deserialized_quotas, quotas);
+    }
+}
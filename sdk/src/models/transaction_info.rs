@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// `TransactionInfo` represents the information about the started transaction.
+/// It consists of the following fields:
+/// - `transaction_id`: the unique transaction ID assigned by the server.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransactionInfo {
+    /// The unique transaction ID assigned by the server.
+    pub transaction_id: u64,
+}
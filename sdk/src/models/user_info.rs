@@ -1,4 +1,5 @@
 use crate::models::permissions::Permissions;
+use crate::models::user_quotas::UserQuotas;
 use crate::models::user_status::UserStatus;
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::AtomicU32;
@@ -34,6 +35,10 @@ pub struct UserInfo {
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
 /// - `permissions`: the optional permissions of the user.
+/// - `quotas`: the optional throughput quotas of the user.
+/// - `roles`: the unique identifiers (numeric) of the roles assigned to the user.
+/// - `failed_login_attempts`: the number of consecutive failed login attempts.
+/// - `locked_until`: the optional timestamp until which the user is locked out.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserInfoDetails {
     /// The unique identifier (numeric) of the user.
@@ -46,4 +51,16 @@ pub struct UserInfoDetails {
     pub username: String,
     /// The optional permissions of the user.
     pub permissions: Option<Permissions>,
+    /// The optional throughput quotas of the user.
+    #[serde(default)]
+    pub quotas: Option<UserQuotas>,
+    /// The unique identifiers (numeric) of the roles assigned to the user.
+    #[serde(default)]
+    pub roles: Vec<u32>,
+    /// The number of consecutive failed login attempts.
+    #[serde(default)]
+    pub failed_login_attempts: u32,
+    /// The optional timestamp until which the user is locked out.
+    #[serde(default)]
+    pub locked_until: Option<u64>,
 }
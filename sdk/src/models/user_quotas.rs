@@ -0,0 +1,85 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UserQuotas` is used to limit the throughput of a user, the value equal to 0 means no limit.
+/// It consists of the following fields:
+/// - `produce_bytes_per_second`: the maximum number of bytes sent per second.
+/// - `consume_bytes_per_second`: the maximum number of bytes polled per second.
+/// - `requests_per_second`: the maximum number of requests per second.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Default)]
+pub struct UserQuotas {
+    /// The maximum number of bytes sent per second.
+    pub produce_bytes_per_second: u64,
+    /// The maximum number of bytes polled per second.
+    pub consume_bytes_per_second: u64,
+    /// The maximum number of requests per second.
+    pub requests_per_second: u32,
+}
+
+impl UserQuotas {
+    /// Get the size of the quotas in bytes.
+    pub fn get_size_bytes(&self) -> u32 {
+        20
+    }
+}
+
+impl BytesSerializable for UserQuotas {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.get_size_bytes() as usize);
+        bytes.put_u64_le(self.produce_bytes_per_second);
+        bytes.put_u64_le(self.consume_bytes_per_second);
+        bytes.put_u32_le(self.requests_per_second);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        if bytes.len() < 20 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let produce_bytes_per_second = u64::from_le_bytes(bytes[..8].try_into()?);
+        let consume_bytes_per_second = u64::from_le_bytes(bytes[8..16].try_into()?);
+        let requests_per_second = u32::from_le_bytes(bytes[16..20].try_into()?);
+        Ok(UserQuotas {
+            produce_bytes_per_second,
+            consume_bytes_per_second,
+            requests_per_second,
+        })
+    }
+}
+
+impl Display for UserQuotas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.produce_bytes_per_second, self.consume_bytes_per_second, self.requests_per_second
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let quotas = UserQuotas {
+            produce_bytes_per_second: 1000,
+            consume_bytes_per_second: 2000,
+            requests_per_second: 10,
+        };
+
+        let bytes = quotas.as_bytes();
+        let deserialized_quotas = UserQuotas::from_bytes(&bytes).unwrap();
+
+        assert_eq!(bytes.len(), quotas.get_size_bytes() as usize);
+        assert_eq!(deserialized_quotas, quotas);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::IggyError;
+use crate::models::permissions::Permissions;
 use crate::users::defaults::*;
 use crate::utils::text;
 use crate::validatable::Validatable;
@@ -13,12 +14,16 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `name` - unique name of the token, must be between 3 and 3 characters long.
 /// - `expiry` - expiry in seconds (optional), if provided, must be between 1 and 4294967295. Otherwise, the token will never expire.
+/// - `scope` - permissions of the token (optional), if provided, the token is only granted the permissions of the user which are also granted by the scope. Otherwise, the token has all the permissions of the user.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreatePersonalAccessToken {
     /// Unique name of the token, must be between 3 and 3 characters long.
     pub name: String,
     /// Expiry in seconds (optional), if provided, must be between 1 and 4294967295. Otherwise, the token will never expire.
     pub expiry: Option<u32>,
+    /// Permissions of the token (optional), if provided, the token is only granted the permissions of the user which are also granted by the scope.
+    #[serde(default)]
+    pub scope: Option<Permissions>,
 }
 
 impl CommandPayload for CreatePersonalAccessToken {}
@@ -28,6 +33,7 @@ impl Default for CreatePersonalAccessToken {
         CreatePersonalAccessToken {
             name: "token".to_string(),
             expiry: None,
+            scope: None,
         }
     }
 }
@@ -56,6 +62,15 @@ impl BytesSerializable for CreatePersonalAccessToken {
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
         bytes.put_u32_le(self.expiry.unwrap_or(0));
+        if let Some(scope) = &self.scope {
+            bytes.put_u8(1);
+            let scope = scope.as_bytes();
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(scope.len() as u32);
+            bytes.extend(scope);
+        } else {
+            bytes.put_u8(0);
+        }
         bytes
     }
 
@@ -77,7 +92,22 @@ impl BytesSerializable for CreatePersonalAccessToken {
             _ => Some(expiry),
         };
 
-        let command = CreatePersonalAccessToken { name, expiry };
+        // The scope is optional, as it is not sent by the older clients.
+        let position = position + 4;
+        let mut scope = None;
+        if bytes.len() > position && bytes[position] == 1 {
+            let scope_length =
+                u32::from_le_bytes(bytes[position + 1..position + 5].try_into()?) as usize;
+            scope = Some(Permissions::from_bytes(
+                &bytes[position + 5..position + 5 + scope_length],
+            )?);
+        }
+
+        let command = CreatePersonalAccessToken {
+            name,
+            expiry,
+            scope,
+        };
         command.validate()?;
         Ok(command)
     }
@@ -92,12 +122,14 @@ impl Display for CreatePersonalAccessToken {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::permissions::GlobalPermissions;
 
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = CreatePersonalAccessToken {
             name: "test".to_string(),
             expiry: Some(100),
+            scope: None,
         };
 
         let bytes = command.as_bytes();
@@ -134,5 +166,26 @@ mod tests {
         let command = command.unwrap();
         assert_eq!(command.name, name);
         assert_eq!(command.expiry, Some(expiry));
+        assert_eq!(command.scope, None);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_scope() {
+        let command = CreatePersonalAccessToken {
+            name: "ci".to_string(),
+            expiry: None,
+            scope: Some(Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        };
+
+        let bytes = command.as_bytes();
+        let deserialized_command = CreatePersonalAccessToken::from_bytes(&bytes).unwrap();
+
+        assert_eq!(command, deserialized_command);
     }
 }
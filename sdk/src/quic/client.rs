@@ -1,4 +1,5 @@
 use crate::binary::binary_client::{BinaryClient, ClientState};
+use crate::binary::map_error;
 use crate::client::Client;
 use crate::error::IggyError;
 use crate::quic::config::QuicClientConfig;
@@ -6,7 +7,9 @@ use async_trait::async_trait;
 use bytes::BufMut;
 use quinn::{ClientConfig, Connection, Endpoint, IdleTimeout, RecvStream, VarInt};
 use rustls::client::{ServerCertVerified, ServerCertVerifier};
-use rustls::{Certificate, ServerName};
+use rustls::{Certificate, PrivateKey, RootCertStore, ServerName};
+use std::fs::File;
+use std::io::BufReader;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
@@ -18,6 +21,7 @@ const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
 const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
 const EMPTY_RESPONSE: Vec<u8> = vec![];
 const NAME: &str = "Iggy";
+const QUOTA_EXCEEDED_RETRIES: u32 = 3;
 
 /// QUIC client for interacting with the Iggy API.
 #[derive(Debug)]
@@ -114,6 +118,28 @@ impl BinaryClient for QuicClient {
     }
 
     async fn send_with_response(&self, command: u32, payload: &[u8]) -> Result<Vec<u8>, IggyError> {
+        let mut retry = 0;
+        loop {
+            match self.send_raw_with_response(command, payload).await {
+                Err(IggyError::QuotaExceeded(retry_after)) if retry < QUOTA_EXCEEDED_RETRIES => {
+                    retry += 1;
+                    info!(
+                        "Quota exceeded, retrying in: {retry_after} ms, attempt: {retry}/{QUOTA_EXCEEDED_RETRIES}..."
+                    );
+                    sleep(Duration::from_millis(retry_after)).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl QuicClient {
+    async fn send_raw_with_response(
+        &self,
+        command: u32,
+        payload: &[u8],
+    ) -> Result<Vec<u8>, IggyError> {
         if self.get_state().await == ClientState::Disconnected {
             return Err(IggyError::NotConnected);
         }
@@ -136,9 +162,7 @@ impl BinaryClient for QuicClient {
         error!("Cannot send data. Client is not connected.");
         Err(IggyError::NotConnected)
     }
-}
 
-impl QuicClient {
     /// Creates a new QUIC client for the provided client and server addresses.
     pub fn new(
         client_address: &str,
@@ -196,6 +220,10 @@ impl QuicClient {
 
         let status = u32::from_le_bytes(buffer[..4].try_into().unwrap());
         if status != 0 {
+            if let Some(error) = map_error(status, &buffer[RESPONSE_INITIAL_BYTES_LENGTH..]) {
+                return Err(error);
+            }
+
             error!(
                 "Received an invalid response with status: {} ({}).",
                 status,
@@ -253,19 +281,75 @@ fn configure(config: &QuicClientConfig) -> Result<ClientConfig, IggyError> {
         transport.max_idle_timeout(Some(max_idle_timeout.unwrap()));
     }
 
-    let mut client_config = match config.validate_certificate {
-        true => ClientConfig::with_native_roots(),
-        false => ClientConfig::new(Arc::new(
+    let client_identity = load_client_identity(config)?;
+    let mut client_config = match (config.validate_certificate, client_identity) {
+        (true, None) => ClientConfig::with_native_roots(),
+        (false, None) => ClientConfig::new(Arc::new(
             rustls::ClientConfig::builder()
                 .with_safe_defaults()
                 .with_custom_certificate_verifier(SkipServerVerification::new())
                 .with_no_client_auth(),
         )),
+        (true, Some((certificates, key))) => {
+            let mut roots = RootCertStore::empty();
+            let native_certificates = rustls_native_certs::load_native_certs()?;
+            roots.add_parsable_certificates(
+                &native_certificates
+                    .into_iter()
+                    .map(|certificate| certificate.0)
+                    .collect::<Vec<_>>(),
+            );
+            let tls_config = rustls::ClientConfig::builder()
+                .with_safe_defaults()
+                .with_root_certificates(roots)
+                .with_client_auth_cert(certificates, key);
+            if tls_config.is_err() {
+                error!("Cannot use the client certificate for the mutual TLS.");
+                return Err(IggyError::InvalidClientCertificate);
+            }
+            ClientConfig::new(Arc::new(tls_config.unwrap()))
+        }
+        (false, Some((certificates, key))) => {
+            let tls_config = rustls::ClientConfig::builder()
+                .with_safe_defaults()
+                .with_custom_certificate_verifier(SkipServerVerification::new())
+                .with_client_auth_cert(certificates, key);
+            if tls_config.is_err() {
+                error!("Cannot use the client certificate for the mutual TLS.");
+                return Err(IggyError::InvalidClientCertificate);
+            }
+            ClientConfig::new(Arc::new(tls_config.unwrap()))
+        }
     };
     client_config.transport_config(Arc::new(transport));
     Ok(client_config)
 }
 
+fn load_client_identity(
+    config: &QuicClientConfig,
+) -> Result<Option<(Vec<Certificate>, PrivateKey)>, IggyError> {
+    if config.client_certificate.is_none() || config.client_key.is_none() {
+        return Ok(None);
+    }
+
+    let mut certificate_reader =
+        BufReader::new(File::open(config.client_certificate.as_ref().unwrap())?);
+    let certificates = rustls_pemfile::certs(&mut certificate_reader)
+        .map(|certificate| certificate.map(|certificate| Certificate(certificate.to_vec())))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut key_reader = BufReader::new(File::open(config.client_key.as_ref().unwrap())?);
+    let key = rustls_pemfile::private_key(&mut key_reader)?;
+    if certificates.is_empty() || key.is_none() {
+        error!("Cannot load the client certificate or its private key.");
+        return Err(IggyError::InvalidClientCertificate);
+    }
+
+    Ok(Some((
+        certificates,
+        PrivateKey(key.unwrap().secret_der().to_vec()),
+    )))
+}
+
 #[derive(Debug)]
 struct SkipServerVerification;
 
@@ -0,0 +1,171 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::models::permissions::Permissions;
+use crate::roles::{MAX_NAME_LENGTH, MIN_NAME_LENGTH};
+use crate::utils::text;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `CreateRole` command is used to create a new role, which can be assigned to the users to grant them its permissions.
+/// It has additional payload:
+/// - `name` - unique name of the role, must be between 3 and 50 characters long.
+/// - `permissions` - permissions granted to the users having the role.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateRole {
+    /// Unique name of the role, must be between 3 and 50 characters long.
+    pub name: String,
+    /// Permissions granted to the users having the role.
+    pub permissions: Permissions,
+}
+
+impl CommandPayload for CreateRole {}
+
+impl Default for CreateRole {
+    fn default() -> Self {
+        CreateRole {
+            name: "role".to_string(),
+            permissions: Permissions::default(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for CreateRole {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.len() > MAX_NAME_LENGTH || self.name.len() < MIN_NAME_LENGTH {
+            return Err(IggyError::InvalidRoleName);
+        }
+
+        if !text::is_resource_name_valid(&self.name) {
+            return Err(IggyError::InvalidRoleName);
+        }
+
+        self.permissions.validate()?;
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CreateRole {
+    fn as_bytes(&self) -> Vec<u8> {
+        let permissions = self.permissions.as_bytes();
+        let mut bytes = Vec::with_capacity(5 + self.name.len() + permissions.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.extend(self.name.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(permissions.len() as u32);
+        bytes.extend(permissions);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<CreateRole, IggyError> {
+        if bytes.len() < 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        if bytes.len() < 5 + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[1..1 + name_length])?.to_string();
+        let position = 1 + name_length;
+        let permissions_length =
+            u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+        if bytes.len() != position + 4 + permissions_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let permissions = Permissions::from_bytes(&bytes[position + 4..])?;
+        let command = CreateRole { name, permissions };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for CreateRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.name, self.permissions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::permissions::GlobalPermissions;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = CreateRole {
+            name: "readers".to_string(),
+            permissions: get_permissions(),
+        };
+
+        let bytes = command.as_bytes();
+        let name_length = bytes[0] as usize;
+        let name = from_utf8(&bytes[1..1 + name_length]).unwrap();
+        let position = 1 + name_length;
+        let permissions_length =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap()) as usize;
+        let permissions =
+            Permissions::from_bytes(&bytes[position + 4..position + 4 + permissions_length])
+                .unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(name, command.name);
+        assert_eq!(permissions, command.permissions);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let name = "readers";
+        let permissions = get_permissions();
+        let permissions_bytes = permissions.as_bytes();
+        let mut bytes = Vec::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.extend(name.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(permissions_bytes.len() as u32);
+        bytes.extend(permissions_bytes);
+
+        let command = CreateRole::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.name, name);
+        assert_eq!(command.permissions, permissions);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_with_too_short_name() {
+        let name = "r";
+        let permissions_bytes = get_permissions().as_bytes();
+        let mut bytes = Vec::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.extend(name.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(permissions_bytes.len() as u32);
+        bytes.extend(permissions_bytes);
+
+        let command = CreateRole::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+
+    fn get_permissions() -> Permissions {
+        Permissions {
+            global: GlobalPermissions {
+                read_streams: true,
+                read_topics: true,
+                poll_messages: true,
+                ..Default::default()
+            },
+            streams: None,
+            stream_patterns: None,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetRole` command is used to retrieve the information about a role by unique ID.
+/// It has additional payload:
+/// - `role_id` - unique role ID (numeric or name).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct GetRole {
+    /// Unique role ID (numeric or name).
+    #[serde(skip)]
+    pub role_id: Identifier,
+}
+
+impl CommandPayload for GetRole {}
+
+impl Validatable<IggyError> for GetRole {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetRole {
+    fn as_bytes(&self) -> Vec<u8> {
+        self.role_id.as_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<GetRole, IggyError> {
+        if bytes.len() < 3 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let role_id = Identifier::from_bytes(bytes)?;
+        let command = GetRole { role_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.role_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetRole {
+            role_id: Identifier::numeric(1).unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let role_id = Identifier::from_bytes(&bytes).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(role_id, command.role_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let role_id = Identifier::named("readers").unwrap();
+        let bytes = role_id.as_bytes();
+        let command = GetRole::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.role_id, role_id);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::IggyError;
+use crate::models::stream_quotas::StreamQuotas;
 use crate::streams::MAX_NAME_LENGTH;
 use crate::utils::text;
 use crate::validatable::Validatable;
@@ -13,12 +14,16 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric)
 /// - `name` - unique stream name (string), max length is 255 characters.
+/// - `quotas` - optional limits of the resources consumed by the stream.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateStream {
     /// Unique stream ID (numeric), if None is provided then the server will automatically assign it.
     pub stream_id: Option<u32>,
     /// Unique stream name (string), max length is 255 characters.
     pub name: String,
+    /// Optional limits of the resources consumed by the stream, if `None` then the stream is unlimited.
+    #[serde(default)]
+    pub quotas: Option<StreamQuotas>,
 }
 
 impl CommandPayload for CreateStream {}
@@ -28,6 +33,7 @@ impl Default for CreateStream {
         CreateStream {
             stream_id: Some(1),
             name: "stream".to_string(),
+            quotas: None,
         }
     }
 }
@@ -59,6 +65,12 @@ impl BytesSerializable for CreateStream {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        if let Some(quotas) = &self.quotas {
+            bytes.put_u8(1);
+            bytes.extend(quotas.as_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
         bytes
     }
 
@@ -79,7 +91,19 @@ impl BytesSerializable for CreateStream {
             return Err(IggyError::InvalidCommand);
         }
 
-        let command = CreateStream { stream_id, name };
+        // The quotas are optional, so that the command sent by the older clients is still valid.
+        let position = 5 + name_length as usize;
+        let quotas = if bytes.len() > position && bytes[position] == 1 {
+            Some(StreamQuotas::from_bytes(&bytes[position + 1..])?)
+        } else {
+            None
+        };
+
+        let command = CreateStream {
+            stream_id,
+            name,
+            quotas,
+        };
         command.validate()?;
         Ok(command)
     }
@@ -87,29 +111,47 @@ impl BytesSerializable for CreateStream {
 
 impl Display for CreateStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id.unwrap_or(0), self.name)
+        let quotas = if let Some(quotas) = &self.quotas {
+            quotas.to_string()
+        } else {
+            "unlimited".to_string()
+        };
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id.unwrap_or(0),
+            self.name,
+            quotas
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::byte_size::IggyByteSize;
 
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = CreateStream {
             stream_id: Some(1),
             name: "test".to_string(),
+            quotas: Some(get_quotas()),
         };
 
         let bytes = command.as_bytes();
         let stream_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
         let name_length = bytes[4];
         let name = from_utf8(&bytes[5..5 + name_length as usize]).unwrap();
+        let position = 5 + name_length as usize;
+        let has_quotas = bytes[position];
+        let quotas = StreamQuotas::from_bytes(&bytes[position + 1..]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id.unwrap());
         assert_eq!(name, command.name);
+        assert_eq!(has_quotas, 1);
+        assert_eq!(quotas, command.quotas.unwrap());
     }
 
     #[test]
@@ -127,5 +169,36 @@ mod tests {
         let command = command.unwrap();
         assert_eq!(command.stream_id.unwrap(), stream_id);
         assert_eq!(command.name, name);
+        assert!(command.quotas.is_none());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_quotas() {
+        let stream_id = 1u32;
+        let name = "test".to_string();
+        let quotas = get_quotas();
+        let mut bytes = Vec::new();
+        bytes.put_u32_le(stream_id);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.extend(name.as_bytes());
+        bytes.put_u8(1);
+        bytes.extend(quotas.as_bytes());
+        let command = CreateStream::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id.unwrap(), stream_id);
+        assert_eq!(command.name, name);
+        assert_eq!(command.quotas.unwrap(), quotas);
+    }
+
+    fn get_quotas() -> StreamQuotas {
+        StreamQuotas {
+            max_size: Some(IggyByteSize::from(1_000_000)),
+            max_topics: Some(10),
+            max_partitions: Some(100),
+            max_consumer_groups: None,
+        }
     }
 }
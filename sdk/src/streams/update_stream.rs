@@ -2,6 +2,7 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::stream_quotas::StreamQuotas;
 use crate::streams::MAX_NAME_LENGTH;
 use crate::utils::text;
 use crate::validatable::Validatable;
@@ -14,6 +15,7 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `name` - unique stream name (string), max length is 255 characters.
+/// - `quotas` - optional new limits of the resources consumed by the stream.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct UpdateStream {
     /// Unique stream ID (numeric or name).
@@ -21,6 +23,10 @@ pub struct UpdateStream {
     pub stream_id: Identifier,
     /// Unique stream name (string), max length is 255 characters.
     pub name: String,
+    /// Optional new limits of the resources consumed by the stream, if `None` then the current ones are kept.
+    /// To remove the limits, provide the quotas with all the values set to `None`.
+    #[serde(default)]
+    pub quotas: Option<StreamQuotas>,
 }
 
 impl CommandPayload for UpdateStream {}
@@ -30,6 +36,7 @@ impl Default for UpdateStream {
         UpdateStream {
             stream_id: Identifier::default(),
             name: "stream".to_string(),
+            quotas: None,
         }
     }
 }
@@ -56,6 +63,12 @@ impl BytesSerializable for UpdateStream {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        if let Some(quotas) = &self.quotas {
+            bytes.put_u8(1);
+            bytes.extend(quotas.as_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
         bytes
     }
 
@@ -74,7 +87,19 @@ impl BytesSerializable for UpdateStream {
             return Err(IggyError::InvalidCommand);
         }
 
-        let command = UpdateStream { stream_id, name };
+        // The quotas are optional, so that the command sent by the older clients is still valid.
+        position += 1 + name_length as usize;
+        let quotas = if bytes.len() > position && bytes[position] == 1 {
+            Some(StreamQuotas::from_bytes(&bytes[position + 1..])?)
+        } else {
+            None
+        };
+
+        let command = UpdateStream {
+            stream_id,
+            name,
+            quotas,
+        };
         command.validate()?;
         Ok(command)
     }
@@ -82,7 +107,12 @@ impl BytesSerializable for UpdateStream {
 
 impl Display for UpdateStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id, self.name)
+        let quotas = if let Some(quotas) = &self.quotas {
+            quotas.to_string()
+        } else {
+            "unchanged".to_string()
+        };
+        write!(f, "{}|{}|{}", self.stream_id, self.name, quotas)
     }
 }
 
@@ -95,6 +125,10 @@ mod tests {
         let command = UpdateStream {
             stream_id: Identifier::numeric(1).unwrap(),
             name: "test".to_string(),
+            quotas: Some(StreamQuotas {
+                max_topics: Some(10),
+                ..Default::default()
+            }),
         };
 
         let bytes = command.as_bytes();
@@ -105,10 +139,15 @@ mod tests {
         let name = from_utf8(&bytes[position + 1..position + 1 + name_length as usize])
             .unwrap()
             .to_string();
+        position += 1 + name_length as usize;
+        let has_quotas = bytes[position];
+        let quotas = StreamQuotas::from_bytes(&bytes[position + 1..]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(name, command.name);
+        assert_eq!(has_quotas, 1);
+        assert_eq!(quotas, command.quotas.unwrap());
     }
 
     #[test]
@@ -128,5 +167,6 @@ mod tests {
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.name, name);
+        assert!(command.quotas.is_none());
     }
 }
@@ -0,0 +1,120 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const MAX_COUNT: u32 = 1000;
+
+/// `GetAuditLog` command is used to get the entries of the audit log recorded by the server.
+/// It has additional payload:
+/// - `offset` - the identifier of the first entry to return.
+/// - `count` - the maximum number of entries to return, at most 1000.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetAuditLog {
+    /// The identifier of the first entry to return.
+    #[serde(default)]
+    pub offset: u64,
+    /// The maximum number of entries to return, at most 1000.
+    #[serde(default = "default_count")]
+    pub count: u32,
+}
+
+impl CommandPayload for GetAuditLog {}
+
+impl Default for GetAuditLog {
+    fn default() -> Self {
+        GetAuditLog {
+            offset: 0,
+            count: default_count(),
+        }
+    }
+}
+
+fn default_count() -> u32 {
+    100
+}
+
+impl Validatable<IggyError> for GetAuditLog {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.count == 0 || self.count > MAX_COUNT {
+            return Err(IggyError::InvalidAuditLogEntriesCount(self.count));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetAuditLog {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12);
+        bytes.put_u64_le(self.offset);
+        bytes.put_u32_le(self.count);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<GetAuditLog, IggyError> {
+        if bytes.len() != 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let offset = u64::from_le_bytes(bytes[..8].try_into()?);
+        let count = u32::from_le_bytes(bytes[8..12].try_into()?);
+        let command = GetAuditLog { offset, count };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetAuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.offset, self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetAuditLog {
+            offset: 10,
+            count: 50,
+        };
+
+        let bytes = command.as_bytes();
+        let offset = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+        let count = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(offset, command.offset);
+        assert_eq!(count, command.count);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let offset = 10u64;
+        let count = 50u32;
+        let mut bytes = Vec::with_capacity(12);
+        bytes.put_u64_le(offset);
+        bytes.put_u32_le(count);
+        let command = GetAuditLog::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.offset, offset);
+        assert_eq!(command.count, count);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_with_too_many_entries() {
+        let mut bytes = Vec::with_capacity(12);
+        bytes.put_u64_le(0);
+        bytes.put_u32_le(MAX_COUNT + 1);
+        let command = GetAuditLog::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+}
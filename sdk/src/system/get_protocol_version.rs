@@ -0,0 +1,85 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::tcp::pipelining::PROTOCOL_VERSION;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetProtocolVersion` command is used to exchange the versions of the binary protocol supported by the client and the server,
+/// e.g. to check whether the server supports the pipelined requests before sending them.
+/// It has additional payload:
+/// - `client_version` - the version of the binary protocol supported by the client, the server uses the features supported by both of them.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetProtocolVersion {
+    /// The version of the binary protocol supported by the client.
+    pub client_version: u32,
+}
+
+impl Default for GetProtocolVersion {
+    fn default() -> Self {
+        GetProtocolVersion {
+            client_version: PROTOCOL_VERSION,
+        }
+    }
+}
+
+impl CommandPayload for GetProtocolVersion {}
+
+impl Validatable<IggyError> for GetProtocolVersion {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetProtocolVersion {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4);
+        bytes.put_u32_le(self.client_version);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<GetProtocolVersion, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let client_version = u32::from_le_bytes(bytes.try_into()?);
+        let command = GetProtocolVersion { client_version };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetProtocolVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.client_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetProtocolVersion { client_version: 2 };
+        let bytes = command.as_bytes();
+        assert_eq!(bytes, 2u32.to_le_bytes());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let bytes = 2u32.to_le_bytes();
+        let command = GetProtocolVersion::from_bytes(&bytes).unwrap();
+        assert_eq!(command.client_version, 2);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_empty_bytes() {
+        let bytes: Vec<u8> = vec![];
+        let command = GetProtocolVersion::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+}
@@ -1,5 +1,10 @@
+pub mod get_audit_log;
 pub mod get_client;
 pub mod get_clients;
+pub mod get_health;
 pub mod get_me;
+pub mod get_protocol_version;
 pub mod get_stats;
 pub mod ping;
+pub mod reload_config;
+pub mod set_log_filter;
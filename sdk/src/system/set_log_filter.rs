@@ -0,0 +1,142 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::from_utf8;
+
+const MAX_FILTER_LENGTH: usize = 1000;
+
+/// `SetLogFilter` command is used to change the log filter of the server at runtime.
+/// It has additional payload:
+/// - `filter` - the filter directives (e.g. `info,server::streaming=debug`), must be between 1 and 1000 characters long.
+/// - `revert_after` - the time in seconds (optional), after which the filter is reverted to the configured log level. Otherwise, the filter remains active until it's changed again.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct SetLogFilter {
+    /// The filter directives (e.g. `info,server::streaming=debug`), must be between 1 and 1000 characters long.
+    pub filter: String,
+    /// The time in seconds (optional), after which the filter is reverted to the configured log level.
+    pub revert_after: Option<u32>,
+}
+
+impl CommandPayload for SetLogFilter {}
+
+impl Default for SetLogFilter {
+    fn default() -> Self {
+        SetLogFilter {
+            filter: "info".to_string(),
+            revert_after: None,
+        }
+    }
+}
+
+impl Validatable<IggyError> for SetLogFilter {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.filter.trim().is_empty() || self.filter.len() > MAX_FILTER_LENGTH {
+            return Err(IggyError::InvalidLogFilter(self.filter.clone()));
+        }
+
+        if self.revert_after == Some(0) {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for SetLogFilter {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 + self.filter.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(self.filter.len() as u32);
+        bytes.extend(self.filter.as_bytes());
+        bytes.put_u32_le(self.revert_after.unwrap_or(0));
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<SetLogFilter, IggyError> {
+        if bytes.len() < 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let filter_length = u32::from_le_bytes(bytes[..4].try_into()?) as usize;
+        if bytes.len() != 8 + filter_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let filter = from_utf8(&bytes[4..4 + filter_length])?.to_string();
+        let position = 4 + filter_length;
+        let revert_after = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let revert_after = match revert_after {
+            0 => None,
+            _ => Some(revert_after),
+        };
+
+        let command = SetLogFilter {
+            filter,
+            revert_after,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for SetLogFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.filter, self.revert_after.unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = SetLogFilter {
+            filter: "info,server=debug".to_string(),
+            revert_after: Some(60),
+        };
+
+        let bytes = command.as_bytes();
+        let filter_length = u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize;
+        let filter = from_utf8(&bytes[4..4 + filter_length]).unwrap();
+        let revert_after = u32::from_le_bytes(
+            bytes[4 + filter_length..8 + filter_length]
+                .try_into()
+                .unwrap(),
+        );
+
+        assert!(!bytes.is_empty());
+        assert_eq!(filter, command.filter);
+        assert_eq!(revert_after, command.revert_after.unwrap());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let filter = "info,server=debug";
+        let mut bytes = Vec::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(filter.len() as u32);
+        bytes.extend(filter.as_bytes());
+        bytes.put_u32_le(0);
+
+        let command = SetLogFilter::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.filter, filter);
+        assert!(command.revert_after.is_none());
+    }
+
+    #[test]
+    fn empty_filter_should_be_invalid() {
+        let command = SetLogFilter {
+            filter: " ".to_string(),
+            revert_after: None,
+        };
+        assert!(command.validate().is_err());
+    }
+}
@@ -1,26 +1,37 @@
 use crate::binary::binary_client::{BinaryClient, ClientState};
+use crate::binary::map_error;
+use crate::bytes_serializable::BytesSerializable;
 use crate::client::Client;
+use crate::command::GET_PROTOCOL_VERSION_CODE;
 use crate::error::IggyError;
+use crate::system::get_protocol_version::GetProtocolVersion;
 use crate::tcp::config::TcpClientConfig;
+use crate::tcp::pipelining::{
+    PendingRequests, PIPELINING_PROTOCOL_VERSION, REQUEST_ID_FLAG, REQUEST_ID_LENGTH,
+};
 use async_trait::async_trait;
 use bytes::BufMut;
 use std::fmt::Debug;
+use std::io::ErrorKind;
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, WriteHalf};
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
-use tokio_native_tls::native_tls::TlsConnector;
+use tokio_native_tls::native_tls::{Identity, TlsConnector};
 use tokio_native_tls::TlsStream;
 use tracing::log::trace;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
 const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
 const EMPTY_RESPONSE: Vec<u8> = vec![];
 const NAME: &str = "Iggy";
+const QUOTA_EXCEEDED_RETRIES: u32 = 3;
 
 /// TCP client for interacting with the Iggy API.
 /// It requires a valid server address.
@@ -29,7 +40,10 @@ pub struct TcpClient {
     pub(crate) server_address: SocketAddr,
     pub(crate) stream: Mutex<Option<Box<dyn ConnectionStream>>>,
     pub(crate) config: Arc<TcpClientConfig>,
-    pub(crate) state: Mutex<ClientState>,
+    pub(crate) state: Arc<Mutex<ClientState>>,
+    pub(crate) pipelining: AtomicBool,
+    pub(crate) pending_requests: Arc<PendingRequests>,
+    pub(crate) responses_reader: Mutex<Option<JoinHandle<()>>>,
 }
 
 unsafe impl Send for TcpClient {}
@@ -51,6 +65,12 @@ struct TcpTlsConnectionStream {
     stream: TlsStream<TcpStream>,
 }
 
+/// Write half of the pipelined connection, the responses are read by the separate task.
+#[derive(Debug)]
+struct PipelinedConnectionStream<S> {
+    writer: WriteHalf<S>,
+}
+
 unsafe impl Send for TcpConnectionStream {}
 unsafe impl Sync for TcpConnectionStream {}
 
@@ -99,6 +119,26 @@ impl ConnectionStream for TcpTlsConnectionStream {
     }
 }
 
+#[async_trait]
+impl<S> ConnectionStream for PipelinedConnectionStream<S>
+where
+    S: AsyncRead + AsyncWrite + Debug + Send + 'static,
+{
+    async fn read(&mut self, _buf: &mut [u8]) -> Result<usize, IggyError> {
+        // The responses are read only by the task started when connecting.
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<(), IggyError> {
+        let result = self.writer.write_all(buf).await;
+        if let Err(error) = result {
+            return Err(IggyError::from(error));
+        }
+
+        Ok(())
+    }
+}
+
 impl Default for TcpClient {
     fn default() -> Self {
         TcpClient::create(Arc::new(TcpClientConfig::default())).unwrap()
@@ -113,6 +153,8 @@ impl Client for TcpClient {
         }
 
         let tls_enabled = self.config.tls_enabled;
+        let mut negotiate_protocol = true;
+        let mut pipelining = false;
         let mut retry_count = 0;
         let connection_stream: Box<dyn ConnectionStream>;
         let remote_address;
@@ -144,28 +186,54 @@ impl Client for TcpClient {
                 return Err(IggyError::NotConnected);
             }
 
-            let stream = connection.unwrap();
+            let mut stream = connection.unwrap();
             remote_address = stream.peer_addr()?;
 
             if !tls_enabled {
-                connection_stream = Box::new(TcpConnectionStream { stream });
+                if negotiate_protocol {
+                    match negotiate_protocol_version(&mut stream).await {
+                        Some(server_version) => pipelining = self.can_pipeline(server_version),
+                        None => {
+                            negotiate_protocol = false;
+                            continue;
+                        }
+                    }
+                }
+
+                connection_stream = match pipelining {
+                    true => self.start_pipelining(stream).await,
+                    false => Box::new(TcpConnectionStream { stream }),
+                };
                 break;
             }
 
-            let connector =
-                tokio_native_tls::TlsConnector::from(TlsConnector::builder().build().unwrap());
-            let stream = tokio_native_tls::TlsConnector::connect(
+            let connector = tokio_native_tls::TlsConnector::from(self.create_tls_connector()?);
+            let mut stream = tokio_native_tls::TlsConnector::connect(
                 &connector,
                 &self.config.tls_domain,
                 stream,
             )
             .await
             .unwrap();
-            connection_stream = Box::new(TcpTlsConnectionStream { stream });
+            if negotiate_protocol {
+                match negotiate_protocol_version(&mut stream).await {
+                    Some(server_version) => pipelining = self.can_pipeline(server_version),
+                    None => {
+                        negotiate_protocol = false;
+                        continue;
+                    }
+                }
+            }
+
+            connection_stream = match pipelining {
+                true => self.start_pipelining(stream).await,
+                false => Box::new(TcpTlsConnectionStream { stream }),
+            };
             break;
         }
 
         self.stream.lock().await.replace(connection_stream);
+        self.pipelining.store(pipelining, Ordering::SeqCst);
         self.set_state(ClientState::Connected).await;
 
         info!(
@@ -184,6 +252,10 @@ impl Client for TcpClient {
         info!("{} client is disconnecting from server...", NAME);
         self.set_state(ClientState::Disconnected).await;
         self.stream.lock().await.take();
+        if let Some(responses_reader) = self.responses_reader.lock().await.take() {
+            responses_reader.abort();
+        }
+        self.pending_requests.clear();
         info!("{} client has disconnected from server.", NAME);
         Ok(())
     }
@@ -200,10 +272,36 @@ impl BinaryClient for TcpClient {
     }
 
     async fn send_with_response(&self, command: u32, payload: &[u8]) -> Result<Vec<u8>, IggyError> {
+        let mut retry = 0;
+        loop {
+            match self.send_raw_with_response(command, payload).await {
+                Err(IggyError::QuotaExceeded(retry_after)) if retry < QUOTA_EXCEEDED_RETRIES => {
+                    retry += 1;
+                    info!(
+                        "Quota exceeded, retrying in: {retry_after} ms, attempt: {retry}/{QUOTA_EXCEEDED_RETRIES}..."
+                    );
+                    sleep(Duration::from_millis(retry_after)).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl TcpClient {
+    async fn send_raw_with_response(
+        &self,
+        command: u32,
+        payload: &[u8],
+    ) -> Result<Vec<u8>, IggyError> {
         if self.get_state().await == ClientState::Disconnected {
             return Err(IggyError::NotConnected);
         }
 
+        if self.pipelining.load(Ordering::SeqCst) {
+            return self.send_pipelined_with_response(command, payload).await;
+        }
+
         let mut stream = self.stream.lock().await;
         if let Some(stream) = stream.as_mut() {
             let payload_length = payload.len() + 4;
@@ -232,9 +330,80 @@ impl BinaryClient for TcpClient {
         error!("Cannot send data. Client is not connected.");
         Err(IggyError::NotConnected)
     }
-}
 
-impl TcpClient {
+    async fn send_pipelined_with_response(
+        &self,
+        command: u32,
+        payload: &[u8],
+    ) -> Result<Vec<u8>, IggyError> {
+        let (request_id, response) = self.pending_requests.register();
+        let payload_length = payload.len() + 4;
+        let mut buffer =
+            Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + REQUEST_ID_LENGTH + payload_length);
+        #[allow(clippy::cast_possible_truncation)]
+        buffer.put_u32_le(payload_length as u32 | REQUEST_ID_FLAG);
+        buffer.put_u64_le(request_id);
+        buffer.put_u32_le(command);
+        buffer.extend(payload);
+
+        // The stream is locked only for writing the request, so that the next ones can be sent before the response is received.
+        {
+            let mut stream = self.stream.lock().await;
+            if stream.is_none() {
+                self.pending_requests.remove(request_id);
+                error!("Cannot send data. Client is not connected.");
+                return Err(IggyError::NotConnected);
+            }
+
+            trace!("Sending a pipelined TCP request with ID: {request_id}...");
+            if let Err(error) = stream.as_mut().unwrap().write(&buffer).await {
+                self.pending_requests.remove(request_id);
+                return Err(error);
+            }
+        }
+
+        trace!("Sent a pipelined TCP request with ID: {request_id}, waiting for a response...");
+        match response.await {
+            Ok(response) => response,
+            Err(_) => {
+                error!("Connection has been closed before receiving the response to the request with ID: {request_id}.");
+                Err(IggyError::NotConnected)
+            }
+        }
+    }
+
+    fn can_pipeline(&self, server_version: u32) -> bool {
+        if !self.config.pipelining {
+            return false;
+        }
+
+        if server_version < PIPELINING_PROTOCOL_VERSION {
+            warn!("Server supports the protocol version: {server_version}, which does not support the pipelined requests.");
+            return false;
+        }
+
+        true
+    }
+
+    /// Splits the stream, so that the pipelined requests can be written while the responses are read by the separate task.
+    async fn start_pipelining<S>(&self, stream: S) -> Box<dyn ConnectionStream>
+    where
+        S: AsyncRead + AsyncWrite + Debug + Send + 'static,
+    {
+        let (reader, writer) = tokio::io::split(stream);
+        let responses_reader = tokio::spawn(read_pipelined_responses(
+            reader,
+            self.pending_requests.clone(),
+            self.state.clone(),
+        ));
+        if let Some(previous_reader) = self.responses_reader.lock().await.replace(responses_reader)
+        {
+            previous_reader.abort();
+        }
+
+        Box::new(PipelinedConnectionStream { writer })
+    }
+
     /// Create a new TCP client for the provided server address.
     pub fn new(server_address: &str) -> Result<Self, IggyError> {
         Self::create(Arc::new(TcpClientConfig {
@@ -253,6 +422,32 @@ impl TcpClient {
         }))
     }
 
+    fn create_tls_connector(&self) -> Result<TlsConnector, IggyError> {
+        let mut builder = TlsConnector::builder();
+        if let (Some(certificate), Some(key)) = (
+            &self.config.tls_client_certificate,
+            &self.config.tls_client_key,
+        ) {
+            let certificate = std::fs::read(certificate)?;
+            let key = std::fs::read(key)?;
+            let identity = Identity::from_pkcs8(&certificate, &key);
+            if identity.is_err() {
+                error!("Cannot create the TLS identity from the client certificate.");
+                return Err(IggyError::InvalidClientCertificate);
+            }
+
+            builder.identity(identity.unwrap());
+        }
+
+        match builder.build() {
+            Ok(connector) => Ok(connector),
+            Err(error) => {
+                error!("Cannot create the TLS connector: {error}");
+                Err(IggyError::InvalidClientCertificate)
+            }
+        }
+    }
+
     /// Create a new TCP client based on the provided configuration.
     pub fn create(config: Arc<TcpClientConfig>) -> Result<Self, IggyError> {
         let server_address = config.server_address.parse::<SocketAddr>()?;
@@ -261,7 +456,10 @@ impl TcpClient {
             config,
             server_address,
             stream: Mutex::new(None),
-            state: Mutex::new(ClientState::Disconnected),
+            state: Arc::new(Mutex::new(ClientState::Disconnected)),
+            pipelining: AtomicBool::new(false),
+            pending_requests: Arc::new(PendingRequests::default()),
+            responses_reader: Mutex::new(None),
         })
     }
 
@@ -271,22 +469,114 @@ impl TcpClient {
         length: u32,
         stream: &mut dyn ConnectionStream,
     ) -> Result<Vec<u8>, IggyError> {
-        if status != 0 {
-            error!(
-                "Received an invalid response with status: {} ({}).",
-                status,
-                IggyError::from_code_as_string(status)
-            );
-            return Err(IggyError::InvalidResponse(status));
+        let mut payload = vec![0u8; length as usize];
+        if length > 0 {
+            stream.read(&mut payload).await?;
+        }
+
+        map_response(status, payload)
+    }
+}
+
+fn map_response(status: u32, payload: Vec<u8>) -> Result<Vec<u8>, IggyError> {
+    if status != 0 {
+        if let Some(error) = map_error(status, &payload) {
+            return Err(error);
+        }
+
+        error!(
+            "Received an invalid response with status: {} ({}).",
+            status,
+            IggyError::from_code_as_string(status)
+        );
+        return Err(IggyError::InvalidResponse(status));
+    }
+
+    trace!("Status: OK. Response length: {}", payload.len());
+    if payload.len() <= 1 {
+        return Ok(EMPTY_RESPONSE);
+    }
+
+    Ok(payload)
+}
+
+/// Sends the protocol version supported by the client and returns the one supported by the server,
+/// so that only the features supported by both of them are used (e.g. the pipelined requests).
+/// The older servers close the connection once receiving the unknown command, thus the client has to connect again.
+async fn negotiate_protocol_version<S>(stream: &mut S) -> Option<u32>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    match get_protocol_version(stream).await {
+        Ok(server_version) => Some(server_version),
+        Err(error) => {
+            warn!("Cannot get the protocol version supported by the server, reconnecting with the legacy protocol... Error: {error}");
+            None
+        }
+    }
+}
+
+async fn get_protocol_version<S>(stream: &mut S) -> Result<u32, IggyError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let payload = GetProtocolVersion::default().as_bytes();
+    let payload_length = payload.len() + 4;
+    let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
+    #[allow(clippy::cast_possible_truncation)]
+    buffer.put_u32_le(payload_length as u32);
+    buffer.put_u32_le(GET_PROTOCOL_VERSION_CODE);
+    buffer.extend(payload);
+    stream.write_all(&buffer).await?;
+
+    let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
+    stream.read_exact(&mut response_buffer).await?;
+    let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
+    let length = u32::from_le_bytes(response_buffer[4..].try_into().unwrap());
+    let mut payload = vec![0u8; length as usize];
+    stream.read_exact(&mut payload).await?;
+    let payload = map_response(status, payload)?;
+    if payload.len() != 4 {
+        return Err(IggyError::EmptyResponse);
+    }
+
+    Ok(u32::from_le_bytes(payload.try_into().unwrap()))
+}
+
+/// Reads the responses to the pipelined requests until the connection is closed, and completes the matching pending requests.
+/// Once the connection is closed, the client is marked as disconnected, so that it can connect again.
+async fn read_pipelined_responses<R>(
+    mut reader: R,
+    pending_requests: Arc<PendingRequests>,
+    state: Arc<Mutex<ClientState>>,
+) where
+    R: AsyncRead + Unpin,
+{
+    let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH + REQUEST_ID_LENGTH];
+    loop {
+        if let Err(error) = reader.read_exact(&mut response_buffer).await {
+            if error.kind() != ErrorKind::UnexpectedEof {
+                error!("Cannot read the response to the pipelined request: {error}");
+            }
+            break;
         }
 
-        trace!("Status: OK. Response length: {}", length);
-        if length <= 1 {
-            return Ok(EMPTY_RESPONSE);
+        let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
+        let length = u32::from_le_bytes(response_buffer[4..8].try_into().unwrap());
+        let request_id = u64::from_le_bytes(response_buffer[8..].try_into().unwrap());
+        let mut payload = vec![0u8; length as usize];
+        if let Err(error) = reader.read_exact(&mut payload).await {
+            error!(
+                "Cannot read the response to the pipelined request with ID: {request_id}: {error}"
+            );
+            break;
         }
 
-        let mut response_buffer = vec![0u8; length as usize];
-        stream.read(&mut response_buffer).await?;
-        Ok(response_buffer)
+        trace!("Received a response to the pipelined request with ID: {request_id}.");
+        pending_requests.complete(request_id, map_response(status, payload));
     }
+
+    *state.lock().await = ClientState::Disconnected;
+    pending_requests.clear();
+    info!("{NAME} client has been disconnected from server.");
 }
@@ -11,6 +11,14 @@ pub struct TcpClientConfig {
     pub tls_enabled: bool,
     /// The domain to use for TLS when connecting to the server.
     pub tls_domain: String,
+    /// The optional path to the PEM client certificate used for the mutual TLS authentication.
+    pub tls_client_certificate: Option<String>,
+    /// The optional path to the PEM (PKCS #8) private key of the client certificate.
+    pub tls_client_key: Option<String>,
+    /// Whether to pipeline the requests, i.e. send them with the request ID without waiting for the previous responses.
+    /// The protocol version supported by the server is checked when connecting, and the client falls back
+    /// to the regular requests if the server does not support the pipelined ones (version 2 of the protocol).
+    pub pipelining: bool,
 }
 
 impl Default for TcpClientConfig {
@@ -21,6 +29,9 @@ impl Default for TcpClientConfig {
             reconnection_interval: 1000,
             tls_enabled: false,
             tls_domain: "localhost".to_string(),
+            tls_client_certificate: None,
+            tls_client_key: None,
+            pipelining: false,
         }
     }
 }
@@ -0,0 +1,90 @@
+use crate::error::IggyError;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tokio::sync::oneshot;
+
+/// The most significant bit of the request length marks the frame carrying the request ID (version 2 of the TCP protocol).
+/// Such requests can be pipelined on a single connection and handled concurrently by the server,
+/// which includes the request ID in the response, right after the status and length.
+/// The frames without this bit are handled as before, one at a time, thus the older clients keep working.
+pub const REQUEST_ID_FLAG: u32 = 1 << 31;
+/// Version of the binary protocol, returned by the server for the `GetProtocolVersion` command.
+/// The client checks it before sending any pipelined requests, as the older servers do not support them.
+pub const PROTOCOL_VERSION: u32 = 2;
+/// Version of the binary protocol assumed for the clients which have not sent their version.
+pub const LEGACY_PROTOCOL_VERSION: u32 = 1;
+/// The first version of the binary protocol supporting the pipelined requests.
+pub const PIPELINING_PROTOCOL_VERSION: u32 = 2;
+/// The first version of the binary protocol in which the error responses can include the additional payload
+/// (e.g. the retry-after time of the exceeded quota), the older clients do not read it.
+pub const ERROR_PAYLOAD_PROTOCOL_VERSION: u32 = 2;
+/// Length of the request ID following the request length (and the response status and length).
+pub const REQUEST_ID_LENGTH: usize = 8;
+
+type PendingResponse = oneshot::Sender<Result<Vec<u8>, IggyError>>;
+
+/// Requests sent on the pipelined connection, awaiting their responses matched by the request ID.
+#[derive(Debug, Default)]
+pub(crate) struct PendingRequests {
+    next_request_id: AtomicU64,
+    requests: Mutex<HashMap<u64, PendingResponse>>,
+}
+
+impl PendingRequests {
+    /// Registers the new request, returns its ID and the receiver of the response.
+    pub fn register(&self) -> (u64, oneshot::Receiver<Result<Vec<u8>, IggyError>>) {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::SeqCst);
+        let (sender, receiver) = oneshot::channel();
+        self.requests.lock().unwrap().insert(request_id, sender);
+        (request_id, receiver)
+    }
+
+    pub fn complete(&self, request_id: u64, response: Result<Vec<u8>, IggyError>) {
+        let request = self.requests.lock().unwrap().remove(&request_id);
+        if let Some(request) = request {
+            let _ = request.send(response);
+        }
+    }
+
+    pub fn remove(&self, request_id: u64) {
+        self.requests.lock().unwrap().remove(&request_id);
+    }
+
+    /// Drops all the pending requests, e.g. when the connection has been closed, so that their callers are notified.
+    pub fn clear(&self) {
+        self.requests.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn responses_should_be_matched_by_request_id_regardless_of_order() {
+        let pending_requests = PendingRequests::default();
+        let (first_request_id, first_response) = pending_requests.register();
+        let (second_request_id, second_response) = pending_requests.register();
+        assert_ne!(first_request_id, second_request_id);
+
+        pending_requests.complete(second_request_id, Ok(vec![2]));
+        pending_requests.complete(first_request_id, Err(IggyError::Unauthorized));
+
+        assert_eq!(second_response.await.unwrap().unwrap(), vec![2]);
+        assert!(matches!(
+            first_response.await.unwrap(),
+            Err(IggyError::Unauthorized)
+        ));
+    }
+
+    #[tokio::test]
+    async fn pending_requests_should_be_notified_once_cleared() {
+        let pending_requests = PendingRequests::default();
+        let (_, response) = pending_requests.register();
+
+        pending_requests.clear();
+
+        assert!(response.await.is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use crate::error::IggyError;
+use crate::messages::send_messages::Message;
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::messages::Message as PolledMessage;
+use opentelemetry::propagation::{Extractor, Injector};
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry::{global, Context};
+use std::collections::HashMap;
+
+/// The W3C trace context header, which holds the trace ID and the ID of the parent span.
+pub const TRACE_PARENT_HEADER: &str = "traceparent";
+/// The W3C trace context header, which holds the vendor-specific trace information.
+pub const TRACE_STATE_HEADER: &str = "tracestate";
+
+/// Injects the current trace context into the message headers, using the globally configured text map propagator
+/// (e.g. `TraceContextPropagator`), so that the consumer of the message can continue the same trace.
+/// The headers are not modified if there's no active span.
+pub fn inject_current_context(message: &mut Message) -> Result<(), IggyError> {
+    let context = Context::current();
+    if !context.span().span_context().is_valid() {
+        return Ok(());
+    }
+
+    inject_context(&context, message.headers.get_or_insert_with(HashMap::new))
+}
+
+/// Injects the given trace context into the headers, using the globally configured text map propagator.
+pub fn inject_context(
+    context: &Context,
+    headers: &mut HashMap<HeaderKey, HeaderValue>,
+) -> Result<(), IggyError> {
+    let mut injector = HeadersInjector {
+        headers,
+        error: None,
+    };
+    global::get_text_map_propagator(|propagator| propagator.inject_context(context, &mut injector));
+    match injector.error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Extracts the trace context from the headers of the polled message, using the globally configured text map propagator.
+/// The returned context can be used as the parent of the span processing the message.
+pub fn extract_context(message: &PolledMessage) -> Context {
+    extract_context_from_headers(message.headers.as_ref())
+}
+
+/// Extracts the trace context from the headers, using the globally configured text map propagator.
+pub fn extract_context_from_headers(headers: Option<&HashMap<HeaderKey, HeaderValue>>) -> Context {
+    if headers.is_none() {
+        return Context::new();
+    }
+
+    let extractor = HeadersExtractor {
+        headers: headers.unwrap(),
+    };
+    global::get_text_map_propagator(|propagator| propagator.extract(&extractor))
+}
+
+struct HeadersInjector<'a> {
+    headers: &'a mut HashMap<HeaderKey, HeaderValue>,
+    error: Option<IggyError>,
+}
+
+impl Injector for HeadersInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        match (HeaderKey::new(key), value.parse::<HeaderValue>()) {
+            (Ok(key), Ok(value)) => {
+                self.headers.insert(key, value);
+            }
+            (Err(error), _) | (_, Err(error)) => self.error = Some(error),
+        }
+    }
+}
+
+struct HeadersExtractor<'a> {
+    headers: &'a HashMap<HeaderKey, HeaderValue>,
+}
+
+impl Extractor for HeadersExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        let key = HeaderKey::new(key).ok()?;
+        self.headers.get(&key)?.as_str().ok()
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.headers.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use opentelemetry::trace::{SpanContext, SpanId, TraceFlags, TraceId, TraceState};
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+
+    #[test]
+    fn trace_context_should_be_injected_into_headers_and_extracted_from_message() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        );
+        let context = Context::new().with_remote_span_context(span_context.clone());
+        let mut message = Message::new(None, Bytes::from("test"), None);
+
+        let _guard = context.attach();
+        inject_current_context(&mut message).unwrap();
+
+        let headers = message.headers.unwrap();
+        let trace_parent = headers
+            .get(&HeaderKey::new(TRACE_PARENT_HEADER).unwrap())
+            .unwrap();
+        assert_eq!(
+            trace_parent.as_str().unwrap(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+
+        let extracted_context = extract_context_from_headers(Some(&headers));
+        let extracted_span_context = extracted_context.span().span_context().clone();
+        assert_eq!(extracted_span_context.trace_id(), span_context.trace_id());
+        assert_eq!(extracted_span_context.span_id(), span_context.span_id());
+        assert!(extracted_span_context.is_remote());
+    }
+
+    #[test]
+    fn headers_should_not_be_created_without_active_span() {
+        let mut message = Message::new(None, Bytes::from("test"), None);
+        inject_current_context(&mut message).unwrap();
+        assert!(message.headers.is_none());
+    }
+}
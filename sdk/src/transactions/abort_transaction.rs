@@ -0,0 +1,93 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `AbortTransaction` command is used to abort the transaction, which hides all its messages from the consumers reading only the committed data and discards its consumer offsets.
+/// It has additional payload:
+/// - `transaction_id` - unique ID of the transaction returned by `BeginTransaction` command.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AbortTransaction {
+    /// Unique ID of the transaction returned by `BeginTransaction` command.
+    pub transaction_id: u64,
+}
+
+impl CommandPayload for AbortTransaction {}
+
+impl Default for AbortTransaction {
+    fn default() -> Self {
+        AbortTransaction { transaction_id: 1 }
+    }
+}
+
+impl Validatable<IggyError> for AbortTransaction {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.transaction_id == 0 {
+            return Err(IggyError::InvalidTransactionId);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for AbortTransaction {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8);
+        bytes.put_u64_le(self.transaction_id);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<AbortTransaction, IggyError> {
+        if bytes.len() != 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let transaction_id = u64::from_le_bytes(bytes.try_into()?);
+        let command = AbortTransaction { transaction_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for AbortTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.transaction_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = AbortTransaction { transaction_id: 1 };
+
+        let bytes = command.as_bytes();
+        let transaction_id = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(transaction_id, command.transaction_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let transaction_id = 1u64;
+        let bytes = transaction_id.to_le_bytes();
+        let command = AbortTransaction::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.transaction_id, transaction_id);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_zero_transaction_id() {
+        let bytes = 0u64.to_le_bytes();
+        let command = AbortTransaction::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+}
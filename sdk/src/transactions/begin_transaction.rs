@@ -0,0 +1,68 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `BeginTransaction` command is used to start a new transaction for the currently connected client.
+/// All the messages sent and the consumer offsets stored by the client until the transaction is committed or aborted are part of the transaction.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct BeginTransaction {}
+
+impl CommandPayload for BeginTransaction {}
+
+impl Validatable<IggyError> for BeginTransaction {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for BeginTransaction {
+    fn as_bytes(&self) -> Vec<u8> {
+        Vec::with_capacity(0)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<BeginTransaction, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = BeginTransaction {};
+        command.validate()?;
+        Ok(BeginTransaction {})
+    }
+}
+
+impl Display for BeginTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = BeginTransaction {};
+        let bytes = command.as_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let bytes: Vec<u8> = vec![];
+        let command = BeginTransaction::from_bytes(&bytes);
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let bytes: Vec<u8> = vec![0];
+        let command = BeginTransaction::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+}
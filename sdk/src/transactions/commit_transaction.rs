@@ -0,0 +1,93 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `CommitTransaction` command is used to commit the transaction, which makes all its messages visible to the consumers reading only the committed data and stores its consumer offsets.
+/// It has additional payload:
+/// - `transaction_id` - unique ID of the transaction returned by `BeginTransaction` command.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CommitTransaction {
+    /// Unique ID of the transaction returned by `BeginTransaction` command.
+    pub transaction_id: u64,
+}
+
+impl CommandPayload for CommitTransaction {}
+
+impl Default for CommitTransaction {
+    fn default() -> Self {
+        CommitTransaction { transaction_id: 1 }
+    }
+}
+
+impl Validatable<IggyError> for CommitTransaction {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.transaction_id == 0 {
+            return Err(IggyError::InvalidTransactionId);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CommitTransaction {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8);
+        bytes.put_u64_le(self.transaction_id);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<CommitTransaction, IggyError> {
+        if bytes.len() != 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let transaction_id = u64::from_le_bytes(bytes.try_into()?);
+        let command = CommitTransaction { transaction_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for CommitTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.transaction_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = CommitTransaction { transaction_id: 1 };
+
+        let bytes = command.as_bytes();
+        let transaction_id = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(transaction_id, command.transaction_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let transaction_id = 1u64;
+        let bytes = transaction_id.to_le_bytes();
+        let command = CommitTransaction::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.transaction_id, transaction_id);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_zero_transaction_id() {
+        let bytes = 0u64.to_le_bytes();
+        let command = CommitTransaction::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+}
@@ -62,6 +62,10 @@ impl Validatable<IggyError> for CreateUser {
             return Err(IggyError::InvalidPassword);
         }
 
+        if let Some(permissions) = &self.permissions {
+            permissions.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -178,6 +182,7 @@ mod tests {
                     send_messages: true,
                 },
                 streams: None,
+                stream_patterns: None,
             }),
         };
 
@@ -229,6 +234,7 @@ mod tests {
                 send_messages: true,
             },
             streams: None,
+            stream_patterns: None,
         };
         let mut bytes = Vec::new();
         #[allow(clippy::cast_possible_truncation)]
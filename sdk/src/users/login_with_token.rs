@@ -0,0 +1,114 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::users::defaults::*;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::from_utf8;
+
+/// `LoginWithToken` command is used to login the user with a JWT issued by the external identity provider (OIDC).
+/// It has additional payload:
+/// - `token` - JWT issued by the external identity provider
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct LoginWithToken {
+    /// JWT issued by the external identity provider
+    pub token: String,
+}
+
+impl CommandPayload for LoginWithToken {}
+
+impl Default for LoginWithToken {
+    fn default() -> Self {
+        LoginWithToken {
+            token: "token".to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for LoginWithToken {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.token.is_empty() || self.token.len() > MAX_TOKEN_LENGTH {
+            return Err(IggyError::InvalidAccessToken);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for LoginWithToken {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + self.token.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(self.token.len() as u32);
+        bytes.extend(self.token.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<LoginWithToken, IggyError> {
+        if bytes.len() < 5 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let token_length = u32::from_le_bytes(bytes[..4].try_into()?) as usize;
+        if bytes.len() != 4 + token_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let token = from_utf8(&bytes[4..4 + token_length])?.to_string();
+        let command = LoginWithToken { token };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for LoginWithToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = LoginWithToken {
+            token: "header.payload.signature".to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let token_length = u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize;
+        let token = from_utf8(&bytes[4..4 + token_length]).unwrap();
+        assert!(!bytes.is_empty());
+        assert_eq!(token, command.token);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let token = "a".repeat(1000);
+        let mut bytes = Vec::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(token.len() as u32);
+        bytes.extend(token.as_bytes());
+
+        let command = LoginWithToken::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.token, token);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_invalid_token_length() {
+        let token = "test";
+        let mut bytes = Vec::new();
+        bytes.put_u32_le(10);
+        bytes.extend(token.as_bytes());
+
+        let command = LoginWithToken::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+}
@@ -5,6 +5,10 @@ pub mod delete_user;
 pub mod get_user;
 pub mod get_users;
 pub mod login_user;
+pub mod login_with_token;
 pub mod logout_user;
+pub mod unlock_user;
 pub mod update_permissions;
+pub mod update_quotas;
 pub mod update_user;
+pub mod update_user_roles;
@@ -0,0 +1,77 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UnlockUser` command is used to unlock a user locked out after too many failed login attempts, and reset its counter of failed login attempts.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UnlockUser {
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+}
+
+impl CommandPayload for UnlockUser {}
+
+impl Validatable<IggyError> for UnlockUser {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UnlockUser {
+    fn as_bytes(&self) -> Vec<u8> {
+        self.user_id.as_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<UnlockUser, IggyError> {
+        if bytes.len() < 3 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes)?;
+        let command = UnlockUser { user_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UnlockUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UnlockUser {
+            user_id: Identifier::numeric(1).unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let user_id = Identifier::from_bytes(&bytes).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(user_id, command.user_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let user_id = Identifier::numeric(1).unwrap();
+        let bytes = user_id.as_bytes();
+        let command = UnlockUser::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.user_id, user_id);
+    }
+}
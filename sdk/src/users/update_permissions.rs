@@ -25,6 +25,10 @@ impl CommandPayload for UpdatePermissions {}
 
 impl Validatable<IggyError> for UpdatePermissions {
     fn validate(&self) -> Result<(), IggyError> {
+        if let Some(permissions) = &self.permissions {
+            permissions.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -152,6 +156,7 @@ mod tests {
                 send_messages: false,
             },
             streams: None,
+            stream_patterns: None,
         }
     }
 }
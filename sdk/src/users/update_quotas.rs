@@ -0,0 +1,142 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::user_quotas::UserQuotas;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateQuotas` command is used to update a user's throughput quotas.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name).
+/// - `quotas` - new quotas (optional)
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateQuotas {
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+    /// New quotas if `None` is provided, then the default quotas from the server configuration will be applied.
+    pub quotas: Option<UserQuotas>,
+}
+
+impl CommandPayload for UpdateQuotas {}
+
+impl Validatable<IggyError> for UpdateQuotas {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateQuotas {
+    fn as_bytes(&self) -> Vec<u8> {
+        let user_id_bytes = self.user_id.as_bytes();
+        let mut bytes = Vec::new();
+        bytes.extend(user_id_bytes);
+        if let Some(quotas) = &self.quotas {
+            bytes.put_u8(1);
+            bytes.extend(quotas.as_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<UpdateQuotas, IggyError> {
+        if bytes.len() < 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes)?;
+        let mut position = user_id.get_size_bytes() as usize;
+        let has_quotas = bytes[position];
+        if has_quotas > 1 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        position += 1;
+        let quotas = if has_quotas == 1 {
+            Some(UserQuotas::from_bytes(&bytes[position..])?)
+        } else {
+            None
+        };
+
+        let command = UpdateQuotas { user_id, quotas };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateQuotas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let quotas = if let Some(quotas) = &self.quotas {
+            quotas.to_string()
+        } else {
+            "default_quotas".to_string()
+        };
+        write!(f, "{}|{}", self.user_id, quotas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateQuotas {
+            user_id: Identifier::numeric(1).unwrap(),
+            quotas: Some(get_quotas()),
+        };
+        let bytes = command.as_bytes();
+        let user_id = Identifier::from_bytes(&bytes).unwrap();
+        let mut position = user_id.get_size_bytes() as usize;
+        let has_quotas = bytes[position];
+        position += 1;
+        let quotas = UserQuotas::from_bytes(&bytes[position..]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(user_id, command.user_id);
+        assert_eq!(has_quotas, 1);
+        assert_eq!(quotas, command.quotas.unwrap());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let user_id = Identifier::numeric(1).unwrap();
+        let quotas = get_quotas();
+        let mut bytes = Vec::new();
+        bytes.extend(user_id.as_bytes());
+        bytes.put_u8(1);
+        bytes.extend(quotas.as_bytes());
+
+        let command = UpdateQuotas::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.user_id, user_id);
+        assert_eq!(command.quotas.unwrap(), quotas);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_quotas() {
+        let user_id = Identifier::numeric(1).unwrap();
+        let mut bytes = Vec::new();
+        bytes.extend(user_id.as_bytes());
+        bytes.put_u8(0);
+
+        let command = UpdateQuotas::from_bytes(&bytes).unwrap();
+        assert_eq!(command.user_id, user_id);
+        assert!(command.quotas.is_none());
+    }
+
+    fn get_quotas() -> UserQuotas {
+        UserQuotas {
+            produce_bytes_per_second: 1000,
+            consume_bytes_per_second: 2000,
+            requests_per_second: 10,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateUserRoles` command is used to assign the roles to a user, replacing the previously assigned ones.
+/// The effective permissions of the user are the union of its own permissions and the permissions of its roles.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name).
+/// - `role_ids` - unique IDs (numeric) of the roles, if empty, then all the roles will be unassigned.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateUserRoles {
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+    /// Unique IDs (numeric) of the roles, if empty, then all the roles will be unassigned.
+    pub role_ids: Vec<u32>,
+}
+
+impl CommandPayload for UpdateUserRoles {}
+
+impl Validatable<IggyError> for UpdateUserRoles {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateUserRoles {
+    fn as_bytes(&self) -> Vec<u8> {
+        let user_id_bytes = self.user_id.as_bytes();
+        let mut bytes = Vec::with_capacity(user_id_bytes.len() + 4 * self.role_ids.len());
+        bytes.extend(user_id_bytes);
+        for role_id in &self.role_ids {
+            bytes.put_u32_le(*role_id);
+        }
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<UpdateUserRoles, IggyError> {
+        if bytes.len() < 3 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes)?;
+        let position = user_id.get_size_bytes() as usize;
+        let role_ids_bytes = &bytes[position..];
+        if role_ids_bytes.len() % 4 != 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut role_ids = Vec::with_capacity(role_ids_bytes.len() / 4);
+        for role_id in role_ids_bytes.chunks_exact(4) {
+            role_ids.push(u32::from_le_bytes(role_id.try_into()?));
+        }
+
+        let command = UpdateUserRoles { user_id, role_ids };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateUserRoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let role_ids = self
+            .role_ids
+            .iter()
+            .map(|role_id| role_id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}|{role_ids}", self.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateUserRoles {
+            user_id: Identifier::numeric(1).unwrap(),
+            role_ids: vec![1, 2],
+        };
+
+        let bytes = command.as_bytes();
+        let user_id = Identifier::from_bytes(&bytes).unwrap();
+        let position = user_id.get_size_bytes() as usize;
+        let first_role_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let second_role_id =
+            u32::from_le_bytes(bytes[position + 4..position + 8].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(user_id, command.user_id);
+        assert_eq!(bytes.len(), position + 8);
+        assert_eq!(first_role_id, 1);
+        assert_eq!(second_role_id, 2);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let user_id = Identifier::numeric(1).unwrap();
+        let mut bytes = Vec::new();
+        bytes.extend(user_id.as_bytes());
+        bytes.put_u32_le(1);
+        bytes.put_u32_le(2);
+
+        let command = UpdateUserRoles::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.user_id, user_id);
+        assert_eq!(command.role_ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_bytes_with_invalid_role_ids() {
+        let user_id = Identifier::numeric(1).unwrap();
+        let mut bytes = Vec::new();
+        bytes.extend(user_id.as_bytes());
+        bytes.put_u16_le(1);
+
+        let command = UpdateUserRoles::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+}
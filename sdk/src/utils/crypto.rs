@@ -3,8 +3,12 @@ use crate::utils::text;
 use aes_gcm::aead::generic_array::GenericArray;
 use aes_gcm::aead::{Aead, OsRng};
 use aes_gcm::{AeadCore, Aes256Gcm, KeyInit};
+use std::collections::HashMap;
 use std::fmt::Debug;
 
+const NONCE_SIZE: usize = 12;
+const KEY_ID_SIZE: usize = 4;
+
 pub trait Encryptor: Send + Sync + Debug {
     fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError>;
     fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError>;
@@ -36,6 +40,11 @@ impl Aes256GcmEncryptor {
     pub fn from_base64_key(key: &str) -> Result<Self, IggyError> {
         Self::new(&text::from_base64_as_bytes(key)?)
     }
+
+    /// Generates the random 32 bytes length key, which can be used to create the new encryptor.
+    pub fn generate_key() -> Vec<u8> {
+        Aes256Gcm::generate_key(&mut OsRng).to_vec()
+    }
 }
 
 impl Encryptor for Aes256GcmEncryptor {
@@ -50,8 +59,12 @@ impl Encryptor for Aes256GcmEncryptor {
     }
 
     fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
-        let nonce = GenericArray::from_slice(&data[0..12]);
-        let payload = self.cipher.decrypt(nonce, &data[12..]);
+        if data.len() < NONCE_SIZE {
+            return Err(IggyError::CannotDecryptData);
+        }
+
+        let nonce = GenericArray::from_slice(&data[0..NONCE_SIZE]);
+        let payload = self.cipher.decrypt(nonce, &data[NONCE_SIZE..]);
         if payload.is_err() {
             return Err(IggyError::CannotDecryptData);
         }
@@ -59,6 +72,91 @@ impl Encryptor for Aes256GcmEncryptor {
     }
 }
 
+/// The encryptor supporting multiple AES-256-GCM keys, each identified by the unique ID,
+/// which is stored as the prefix (u32) of the encrypted payload. The data is always encrypted with the active key,
+/// while the decryption picks the key based on the ID stored alongside the payload.
+/// The optional legacy key is used to decrypt the payloads stored without the key ID (encrypted before the key rotation),
+/// and to encrypt the new ones if there's no active key.
+#[derive(Debug)]
+pub struct KeyRingEncryptor {
+    active_key_id: Option<u32>,
+    keys: HashMap<u32, Aes256GcmEncryptor>,
+    legacy_key: Option<Aes256GcmEncryptor>,
+}
+
+impl KeyRingEncryptor {
+    pub fn new(
+        active_key_id: Option<u32>,
+        keys: HashMap<u32, Aes256GcmEncryptor>,
+        legacy_key: Option<Aes256GcmEncryptor>,
+    ) -> Result<Self, IggyError> {
+        match active_key_id {
+            Some(key_id) if !keys.contains_key(&key_id) => {
+                return Err(IggyError::InvalidEncryptionKey);
+            }
+            None if legacy_key.is_none() => {
+                return Err(IggyError::InvalidEncryptionKey);
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            active_key_id,
+            keys,
+            legacy_key,
+        })
+    }
+
+    pub fn get_active_key_id(&self) -> Option<u32> {
+        self.active_key_id
+    }
+
+    /// Decrypts the data and encrypts it again with the active key, if it was encrypted with any other key.
+    /// Returns `None` if the data is already encrypted with the active key.
+    pub fn re_encrypt(&self, data: &[u8]) -> Result<Option<Vec<u8>>, IggyError> {
+        let (key_id, payload) = self.decrypt_with_key_id(data)?;
+        if key_id == self.active_key_id {
+            return Ok(None);
+        }
+
+        Ok(Some(self.encrypt(&payload)?))
+    }
+
+    fn decrypt_with_key_id(&self, data: &[u8]) -> Result<(Option<u32>, Vec<u8>), IggyError> {
+        if data.len() > KEY_ID_SIZE {
+            let key_id = u32::from_le_bytes(data[..KEY_ID_SIZE].try_into()?);
+            if let Some(key) = self.keys.get(&key_id) {
+                if let Ok(payload) = key.decrypt(&data[KEY_ID_SIZE..]) {
+                    return Ok((Some(key_id), payload));
+                }
+            }
+        }
+
+        // The payload stored without the key ID, or the one which prefix happens to match any key ID by accident.
+        match &self.legacy_key {
+            Some(key) => Ok((None, key.decrypt(data)?)),
+            None => Err(IggyError::CannotDecryptData),
+        }
+    }
+}
+
+impl Encryptor for KeyRingEncryptor {
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        if self.active_key_id.is_none() {
+            return self.legacy_key.as_ref().unwrap().encrypt(data);
+        }
+
+        let key_id = self.active_key_id.unwrap();
+        let payload = self.keys.get(&key_id).unwrap().encrypt(data)?;
+        Ok([&key_id.to_le_bytes(), payload.as_slice()].concat())
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        let (_, payload) = self.decrypt_with_key_id(data)?;
+        Ok(payload)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,4 +190,85 @@ mod tests {
         let error = decrypted_data.err().unwrap();
         assert_eq!(error.as_code(), IggyError::CannotDecryptData.as_code());
     }
+
+    #[test]
+    fn generated_keys_should_be_valid_and_unique() {
+        let first_key = Aes256GcmEncryptor::generate_key();
+        let second_key = Aes256GcmEncryptor::generate_key();
+        assert_eq!(first_key.len(), 32);
+        assert_ne!(first_key, second_key);
+        assert!(Aes256GcmEncryptor::new(&first_key).is_ok());
+    }
+
+    #[test]
+    fn given_the_key_ring_data_should_be_decrypted_with_the_key_used_for_encryption() {
+        let old_key_ring = create_key_ring(1, &[(1, [1; 32])], None);
+        let new_key_ring = create_key_ring(2, &[(1, [1; 32]), (2, [2; 32])], None);
+        let data = b"Hello World!";
+        let encrypted_data = old_key_ring.encrypt(data).unwrap();
+        assert_eq!(
+            u32::from_le_bytes(encrypted_data[..4].try_into().unwrap()),
+            1
+        );
+
+        let decrypted_data = new_key_ring.decrypt(&encrypted_data).unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+        let encrypted_data = new_key_ring.encrypt(data).unwrap();
+        assert_eq!(
+            u32::from_le_bytes(encrypted_data[..4].try_into().unwrap()),
+            2
+        );
+        assert!(old_key_ring.decrypt(&encrypted_data).is_err());
+    }
+
+    #[test]
+    fn given_the_legacy_key_data_encrypted_without_key_id_should_be_decrypted() {
+        let legacy_encryptor = Aes256GcmEncryptor::new(&[1; 32]).unwrap();
+        let data = b"Hello World!";
+        let encrypted_data = legacy_encryptor.encrypt(data).unwrap();
+        let key_ring = create_key_ring(2, &[(2, [2; 32])], Some([1; 32]));
+
+        let decrypted_data = key_ring.decrypt(&encrypted_data).unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+    }
+
+    #[test]
+    fn given_the_data_encrypted_with_other_key_it_should_be_re_encrypted_with_the_active_key() {
+        let key_ring = create_key_ring(2, &[(1, [1; 32]), (2, [2; 32])], Some([3; 32]));
+        let data = b"Hello World!";
+        let legacy_data = Aes256GcmEncryptor::new(&[3; 32])
+            .unwrap()
+            .encrypt(data)
+            .unwrap();
+        let old_data = create_key_ring(1, &[(1, [1; 32])], None)
+            .encrypt(data)
+            .unwrap();
+        let active_data = key_ring.encrypt(data).unwrap();
+
+        for encrypted_data in [legacy_data, old_data] {
+            let re_encrypted_data = key_ring.re_encrypt(&encrypted_data).unwrap().unwrap();
+            assert_eq!(
+                u32::from_le_bytes(re_encrypted_data[..4].try_into().unwrap()),
+                2
+            );
+            assert_eq!(
+                data,
+                key_ring.decrypt(&re_encrypted_data).unwrap().as_slice()
+            );
+        }
+        assert!(key_ring.re_encrypt(&active_data).unwrap().is_none());
+    }
+
+    fn create_key_ring(
+        active_key_id: u32,
+        keys: &[(u32, [u8; 32])],
+        legacy_key: Option<[u8; 32]>,
+    ) -> KeyRingEncryptor {
+        let keys = keys
+            .iter()
+            .map(|(id, key)| (*id, Aes256GcmEncryptor::new(key).unwrap()))
+            .collect();
+        let legacy_key = legacy_key.map(|key| Aes256GcmEncryptor::new(&key).unwrap());
+        KeyRingEncryptor::new(Some(active_key_id), keys, legacy_key).unwrap()
+    }
 }
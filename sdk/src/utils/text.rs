@@ -20,6 +20,39 @@ pub fn is_resource_name_valid(value: &str) -> bool {
     RESOURCE_NAME_REGEX.is_match(value)
 }
 
+/// Checks if the value matches the glob pattern, in which `*` matches any sequence of characters
+/// and `?` matches any single character. A pattern without wildcards matches only the exact value.
+pub fn matches_pattern(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<char>>();
+    let value = value.chars().collect::<Vec<char>>();
+    let mut pattern_position = 0;
+    let mut value_position = 0;
+    let mut last_wildcard: Option<(usize, usize)> = None;
+    while value_position < value.len() {
+        if pattern_position < pattern.len()
+            && (pattern[pattern_position] == '?'
+                || pattern[pattern_position] == value[value_position])
+        {
+            pattern_position += 1;
+            value_position += 1;
+        } else if pattern_position < pattern.len() && pattern[pattern_position] == '*' {
+            last_wildcard = Some((pattern_position, value_position));
+            pattern_position += 1;
+        } else if let Some((wildcard_position, matched_position)) = last_wildcard {
+            // Let the last wildcard match one more character and try again.
+            last_wildcard = Some((wildcard_position, matched_position + 1));
+            pattern_position = wildcard_position + 1;
+            value_position = matched_position + 1;
+        } else {
+            return false;
+        }
+    }
+
+    pattern[pattern_position..]
+        .iter()
+        .all(|character| *character == '*')
+}
+
 pub fn from_base64_as_bytes(value: &str) -> Result<Vec<u8>, IggyError> {
     let result = general_purpose::STANDARD.decode(value);
     if result.is_err() {
@@ -32,3 +65,30 @@ pub fn from_base64_as_bytes(value: &str) -> Result<Vec<u8>, IggyError> {
 pub fn as_base64(value: &[u8]) -> String {
     general_purpose::STANDARD.encode(value)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_should_match_the_pattern() {
+        assert!(matches_pattern("orders", "orders"));
+        assert!(matches_pattern("orders-*", "orders-"));
+        assert!(matches_pattern("orders-*", "orders-eu"));
+        assert!(matches_pattern("*-eu", "orders-eu"));
+        assert!(matches_pattern("*", "orders"));
+        assert!(matches_pattern("orders-??", "orders-eu"));
+        assert!(matches_pattern("o*s-*-e?", "orders-test-eu"));
+        assert!(matches_pattern("*a*b", "aaab"));
+    }
+
+    #[test]
+    fn value_should_not_match_the_pattern() {
+        assert!(!matches_pattern("orders", "orders-eu"));
+        assert!(!matches_pattern("orders-*", "order"));
+        assert!(!matches_pattern("orders-?", "orders-eu"));
+        assert!(!matches_pattern("*-eu", "orders-us"));
+        assert!(!matches_pattern("*a*b", "aaba"));
+        assert!(!matches_pattern("", "orders"));
+    }
+}
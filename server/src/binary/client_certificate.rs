@@ -0,0 +1,183 @@
+use crate::configs::server::{
+    ClientCertificateConfig, COMMON_NAME_FIELD, DNS_NAME_FIELD, EMAIL_FIELD, URI_FIELD,
+};
+use rustls::server::{
+    AllowAnyAnonymousOrAuthenticatedClient, AllowAnyAuthenticatedClient, ClientCertVerifier,
+};
+use rustls::{Certificate, PrivateKey, RootCertStore};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use x509_parser::extensions::GeneralName;
+use x509_parser::parse_x509_certificate;
+
+/// The user to be logged in with the verified client certificate.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ClientCertificateIdentity {
+    pub username: String,
+    pub allow_root: bool,
+}
+
+/// Maps the configured field of the client certificate to the username, either directly
+/// or using the explicit mapping of the identities to the usernames (if configured).
+#[derive(Debug, Clone)]
+pub(crate) struct ClientCertificateMapper {
+    username_field: String,
+    usernames: HashMap<String, String>,
+    allow_root: bool,
+}
+
+impl ClientCertificateMapper {
+    pub fn new(config: &ClientCertificateConfig) -> Self {
+        Self {
+            username_field: config.username_field.clone(),
+            usernames: config.usernames.clone(),
+            allow_root: config.allow_root,
+        }
+    }
+
+    pub fn map(&self, certificates: &[Certificate]) -> Option<ClientCertificateIdentity> {
+        let identity = get_identity(certificates, &self.username_field)?;
+        let username = match self.usernames.is_empty() {
+            true => identity,
+            false => self.usernames.get(&identity)?.clone(),
+        };
+        Some(ClientCertificateIdentity {
+            username,
+            allow_root: self.allow_root,
+        })
+    }
+}
+
+/// Creates the verifier of the client certificates issued by the configured CA.
+/// When the client certificate is not required, the clients without any certificate are accepted as well.
+pub(crate) fn create_verifier(
+    config: &ClientCertificateConfig,
+) -> Result<Arc<dyn ClientCertVerifier>, Box<dyn Error>> {
+    let mut ca_reader = BufReader::new(File::open(&config.ca_file)?);
+    let mut roots = RootCertStore::empty();
+    for certificate in rustls_pemfile::certs(&mut ca_reader) {
+        roots.add(&Certificate(certificate?.to_vec()))?;
+    }
+
+    let verifier = match config.required {
+        true => AllowAnyAuthenticatedClient::new(roots).boxed(),
+        false => AllowAnyAnonymousOrAuthenticatedClient::new(roots).boxed(),
+    };
+    Ok(verifier)
+}
+
+pub(crate) fn load_certificates(
+    cert_file: &str,
+    key_file: &str,
+) -> Result<(Vec<Certificate>, PrivateKey), Box<dyn Error>> {
+    let mut cert_chain_reader = BufReader::new(File::open(cert_file)?);
+    let mut certs = Vec::new();
+    for certificate in rustls_pemfile::certs(&mut cert_chain_reader) {
+        certs.push(Certificate(certificate?.to_vec()));
+    }
+    if certs.is_empty() {
+        return Err(format!("No certificates found in file: {cert_file}").into());
+    }
+
+    // The key might be encoded as PKCS #1, PKCS #8 or SEC1, all of them are supported by rustls.
+    let mut key_reader = BufReader::new(File::open(key_file)?);
+    let key = rustls_pemfile::private_key(&mut key_reader)?
+        .ok_or_else(|| format!("No private key found in file: {key_file}"))?;
+    Ok((certs, PrivateKey(key.secret_der().to_vec())))
+}
+
+/// Returns the identity of the client from the first value of the field of its certificate, which is either
+/// the subject common name, or the DNS name, email or URI from the subject alternative names.
+fn get_identity(certificates: &[Certificate], field: &str) -> Option<String> {
+    let (_, certificate) = parse_x509_certificate(&certificates.first()?.0).ok()?;
+    if field == COMMON_NAME_FIELD {
+        return certificate
+            .subject()
+            .iter_common_name()
+            .find_map(|common_name| common_name.as_str().ok())
+            .map(|common_name| common_name.to_string());
+    }
+
+    let alternative_names = certificate.subject_alternative_name().ok()??;
+    alternative_names
+        .value
+        .general_names
+        .iter()
+        .find_map(|name| match (field, name) {
+            (DNS_NAME_FIELD, GeneralName::DNSName(name))
+            | (EMAIL_FIELD, GeneralName::RFC822Name(name))
+            | (URI_FIELD, GeneralName::URI(name)) => Some(name.to_string()),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rcgen::{CertificateParams, DnType, SanType};
+
+    fn create_certificate() -> Certificate {
+        let mut params = CertificateParams::new(vec!["orders-service".to_string()]);
+        params
+            .subject_alt_names
+            .push(SanType::Rfc822Name("orders@iggy.rs".to_string()));
+        params
+            .distinguished_name
+            .push(DnType::CommonName, "orders-producer");
+        let certificate = rcgen::Certificate::from_params(params).unwrap();
+        Certificate(certificate.serialize_der().unwrap())
+    }
+
+    fn create_mapper(username_field: &str, usernames: &[(&str, &str)]) -> ClientCertificateMapper {
+        ClientCertificateMapper::new(&ClientCertificateConfig {
+            username_field: username_field.to_string(),
+            usernames: usernames
+                .iter()
+                .map(|(identity, username)| (identity.to_string(), username.to_string()))
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn username_should_be_read_from_configured_field() {
+        let certificate = create_certificate();
+
+        let common_name = create_mapper(COMMON_NAME_FIELD, &[]).map(&[certificate.clone()]);
+        let dns_name = create_mapper(DNS_NAME_FIELD, &[]).map(&[certificate.clone()]);
+        let email = create_mapper(EMAIL_FIELD, &[]).map(&[certificate.clone()]);
+        let uri = create_mapper(URI_FIELD, &[]).map(&[certificate]);
+
+        assert_eq!(
+            common_name,
+            Some(ClientCertificateIdentity {
+                username: "orders-producer".to_string(),
+                allow_root: false,
+            })
+        );
+        assert_eq!(dns_name.unwrap().username, "orders-service");
+        assert_eq!(email.unwrap().username, "orders@iggy.rs");
+        assert!(uri.is_none());
+    }
+
+    #[test]
+    fn username_should_be_mapped_only_given_configured_identity() {
+        let certificate = create_certificate();
+
+        let mapped =
+            create_mapper(EMAIL_FIELD, &[("orders@iggy.rs", "orders")]).map(&[certificate.clone()]);
+        let unmapped =
+            create_mapper(COMMON_NAME_FIELD, &[("orders@iggy.rs", "orders")]).map(&[certificate]);
+
+        assert_eq!(mapped.unwrap().username, "orders");
+        assert!(unmapped.is_none());
+    }
+
+    #[test]
+    fn username_should_not_be_mapped_given_no_certificates() {
+        assert!(create_mapper(COMMON_NAME_FIELD, &[]).map(&[]).is_none());
+    }
+}
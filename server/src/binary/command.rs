@@ -9,35 +9,96 @@ use crate::binary::handlers::personal_access_tokens::{
     create_personal_access_token_handler, delete_personal_access_token_handler,
     get_personal_access_tokens_handler, login_with_personal_access_token_handler,
 };
+use crate::binary::handlers::roles::{
+    create_role_handler, delete_role_handler, get_role_handler, get_roles_handler,
+    update_role_handler,
+};
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
+use crate::binary::handlers::transactions::{
+    abort_transaction_handler, begin_transaction_handler, commit_transaction_handler,
+};
 use crate::binary::handlers::users::{
     change_password_handler, create_user_handler, delete_user_handler, get_user_handler,
-    get_users_handler, login_user_handler, logout_user_handler, update_permissions_handler,
-    update_user_handler,
+    get_users_handler, login_user_handler, login_with_token_handler, logout_user_handler,
+    unlock_user_handler, update_permissions_handler, update_quotas_handler, update_user_handler,
+    update_user_roles_handler,
 };
+use crate::binary::mapper;
 use crate::binary::sender::Sender;
+use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use iggy::command::Command;
 use iggy::error::IggyError;
-use tracing::{debug, error};
+use iggy::messages::send_messages;
+use iggy::telemetry;
+use opentelemetry::trace::TraceContextExt;
+use tokio::time::Instant;
+use tracing::{debug, error, field, info_span, Instrument, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 pub async fn handle(
     command: &Command,
     sender: &mut dyn Sender,
     session: &Session,
     system: SharedSystem,
+    transport: Transport,
 ) -> Result<(), IggyError> {
-    match try_handle(command, sender, session, &system).await {
+    // Tracks the request until the response is sent, so that the graceful shutdown can wait for it.
+    let in_flight_request = system.read().shutdown_signal.start_request();
+    if in_flight_request.is_none() {
+        debug!("Rejected the command, as the server is shutting down, session: {session}.");
+        return sender
+            .send_error_response(IggyError::ShuttingDown, &[])
+            .await;
+    }
+    // The span is exported as the OpenTelemetry one, if the telemetry is enabled.
+    let span = info_span!(
+        "command",
+        otel.name = command.get_name(),
+        otel.kind = "server",
+        otel.status_code = field::Empty,
+        client_id = session.client_id,
+        user_id = session.get_user_id(),
+    );
+    if let Command::SendMessages(command) = command {
+        set_trace_parent(&span, &command.messages);
+    }
+    let now = Instant::now();
+    let result = try_handle(command, sender, session, &system)
+        .instrument(span.clone())
+        .await;
+    system
+        .read()
+        .metrics
+        .record_request(command.get_name(), &transport.to_string(), now.elapsed());
+    match result {
         Ok(_) => {
             debug!("Command was handled successfully, session: {session}.");
             Ok(())
         }
         Err(error) => {
+            span.record("otel.status_code", "ERROR");
             error!("Command was not handled successfully, session: {session}, error: {error}");
-            sender.send_error_response(error).await
+            // The older clients do not read the payload of the error response, so it's sent only to the ones supporting it.
+            let payload = match session.supports_error_payload() {
+                true => mapper::map_error_payload(&error),
+                false => Vec::new(),
+            };
+            sender.send_error_response(error, &payload).await
+        }
+    }
+}
+
+/// Continues the trace of the producer, if the sent messages carry the trace context in their headers.
+fn set_trace_parent(span: &Span, messages: &[send_messages::Message]) {
+    for message in messages {
+        let context = telemetry::extract_context_from_headers(message.headers.as_ref());
+        if context.span().span_context().is_valid() {
+            span.set_parent(context);
+            return;
         }
     }
 }
@@ -49,8 +110,15 @@ async fn try_handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("Handling command '{command}', session: {session}...");
+    system.read().quota_manager.check_request(session)?;
     match command {
         Command::Ping(command) => ping_handler::handle(command, sender, session).await,
+        Command::GetHealth(command) => {
+            get_health_handler::handle(command, sender, session, system).await
+        }
+        Command::GetProtocolVersion(command) => {
+            get_protocol_version_handler::handle(command, sender, session).await
+        }
         Command::GetStats(command) => {
             get_stats_handler::handle(command, sender, session, system).await
         }
@@ -61,6 +129,15 @@ async fn try_handle(
         Command::GetClients(command) => {
             get_clients_handler::handle(command, sender, session, system).await
         }
+        Command::GetAuditLog(command) => {
+            get_audit_log_handler::handle(command, sender, session, system).await
+        }
+        Command::ReloadConfig(command) => {
+            reload_config_handler::handle(command, sender, session, system).await
+        }
+        Command::SetLogFilter(command) => {
+            set_log_filter_handler::handle(command, sender, session, system).await
+        }
         Command::GetUser(command) => {
             get_user_handler::handle(command, sender, session, system).await
         }
@@ -79,6 +156,33 @@ async fn try_handle(
         Command::UpdatePermissions(command) => {
             update_permissions_handler::handle(command, sender, session, system).await
         }
+        Command::UpdateQuotas(command) => {
+            update_quotas_handler::handle(command, sender, session, system).await
+        }
+        Command::UpdateUserRoles(command) => {
+            update_user_roles_handler::handle(command, sender, session, system).await
+        }
+        Command::LoginWithToken(command) => {
+            login_with_token_handler::handle(command, sender, session, system).await
+        }
+        Command::UnlockUser(command) => {
+            unlock_user_handler::handle(command, sender, session, system).await
+        }
+        Command::GetRole(command) => {
+            get_role_handler::handle(command, sender, session, system).await
+        }
+        Command::GetRoles(command) => {
+            get_roles_handler::handle(command, sender, session, system).await
+        }
+        Command::CreateRole(command) => {
+            create_role_handler::handle(command, sender, session, system).await
+        }
+        Command::DeleteRole(command) => {
+            delete_role_handler::handle(command, sender, session, system).await
+        }
+        Command::UpdateRole(command) => {
+            update_role_handler::handle(command, sender, session, system).await
+        }
         Command::ChangePassword(command) => {
             change_password_handler::handle(command, sender, session, system).await
         }
@@ -106,6 +210,9 @@ async fn try_handle(
         Command::PollMessages(command) => {
             poll_messages_handler::handle(command, sender, session, system).await
         }
+        Command::InitProducer(command) => {
+            init_producer_handler::handle(command, sender, session, system).await
+        }
         Command::GetConsumerOffset(command) => {
             get_consumer_offset_handler::handle(command, sender, session, system).await
         }
@@ -172,5 +279,14 @@ async fn try_handle(
         Command::LeaveConsumerGroup(command) => {
             leave_consumer_group_handler::handle(command, sender, session, system).await
         }
+        Command::BeginTransaction(command) => {
+            begin_transaction_handler::handle(command, sender, session, system).await
+        }
+        Command::CommitTransaction(command) => {
+            commit_transaction_handler::handle(command, sender, session, system).await
+        }
+        Command::AbortTransaction(command) => {
+            abort_transaction_handler::handle(command, sender, session, system).await
+        }
     }
 }
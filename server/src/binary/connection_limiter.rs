@@ -0,0 +1,171 @@
+use crate::configs::server::ConnectionLimitsConfig;
+use dashmap::DashMap;
+use iggy::error::IggyError;
+use iggy::utils::duration::IggyDuration;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Reason of closing the connection, reported as the `violation` label of the metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConnectionViolation {
+    FrameTooLarge,
+    MalformedFrame,
+    ReadTimeout,
+    IdleTimeout,
+    TooManyConnections,
+    TlsHandshakeFailed,
+}
+
+impl ConnectionViolation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConnectionViolation::FrameTooLarge => "frame_too_large",
+            ConnectionViolation::MalformedFrame => "malformed_frame",
+            ConnectionViolation::ReadTimeout => "read_timeout",
+            ConnectionViolation::IdleTimeout => "idle_timeout",
+            ConnectionViolation::TooManyConnections => "too_many_connections",
+            ConnectionViolation::TlsHandshakeFailed => "tls_handshake_failed",
+        }
+    }
+}
+
+impl Display for ConnectionViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Enforces the configured limits of the binary protocol connections (TCP or QUIC),
+/// shared by all the connections accepted by the single listener.
+#[derive(Debug, Clone)]
+pub(crate) struct ConnectionLimiter {
+    config: ConnectionLimitsConfig,
+    connections: Arc<DashMap<IpAddr, u32>>,
+}
+
+/// Releases the connection slot of the IP address once dropped, i.e. when the connection is closed.
+#[derive(Debug)]
+pub(crate) struct ConnectionPermit {
+    ip_address: IpAddr,
+    connections: Arc<DashMap<IpAddr, u32>>,
+}
+
+impl ConnectionLimiter {
+    pub fn new(config: ConnectionLimitsConfig) -> Self {
+        Self {
+            config,
+            connections: Arc::new(DashMap::new()),
+        }
+    }
+
+    pub fn read_timeout(&self) -> IggyDuration {
+        self.config.read_timeout
+    }
+
+    pub fn idle_timeout(&self) -> IggyDuration {
+        self.config.idle_timeout
+    }
+
+    pub fn max_concurrent_requests(&self) -> usize {
+        self.config.max_concurrent_requests as usize
+    }
+
+    pub fn max_frame_size(&self) -> u64 {
+        self.config.max_frame_size.as_bytes_u64()
+    }
+
+    /// Acquires the connection slot for the IP address, unless the limit of the concurrent connections is reached.
+    pub fn try_acquire(&self, ip_address: IpAddr) -> Result<ConnectionPermit, IggyError> {
+        let max_connections = self.config.max_connections_per_ip;
+        let mut connections = self.connections.entry(ip_address).or_insert(0);
+        if max_connections > 0 && *connections >= max_connections {
+            return Err(IggyError::TooManyConnections(
+                ip_address.to_string(),
+                max_connections,
+            ));
+        }
+
+        *connections += 1;
+        Ok(ConnectionPermit {
+            ip_address,
+            connections: self.connections.clone(),
+        })
+    }
+
+    pub fn validate_frame_size(&self, frame_size: u64) -> Result<(), IggyError> {
+        let max_frame_size = self.max_frame_size();
+        if frame_size > max_frame_size {
+            return Err(IggyError::FrameTooLarge(frame_size, max_frame_size));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        if let Some(mut connections) = self.connections.get_mut(&self.ip_address) {
+            *connections = connections.saturating_sub(1);
+        }
+        self.connections
+            .remove_if(&self.ip_address, |_, connections| *connections == 0);
+    }
+}
+
+/// Awaits the future for at most the given time, returns `None` if it has elapsed.
+/// The zero timeout is treated as disabled.
+pub(crate) async fn with_timeout<F: Future>(timeout: IggyDuration, future: F) -> Option<F::Output> {
+    if timeout.is_zero() {
+        return Some(future.await);
+    }
+
+    tokio::time::timeout(timeout.get_duration(), future)
+        .await
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_limiter(max_connections_per_ip: u32) -> ConnectionLimiter {
+        ConnectionLimiter::new(ConnectionLimitsConfig {
+            max_frame_size: "1 KB".parse().unwrap(),
+            read_timeout: "1s".parse().unwrap(),
+            idle_timeout: "1s".parse().unwrap(),
+            max_connections_per_ip,
+            max_concurrent_requests: 1,
+        })
+    }
+
+    #[test]
+    fn connection_should_be_rejected_once_the_limit_per_ip_address_is_reached() {
+        let limiter = create_limiter(2);
+        let ip_address: IpAddr = "127.0.0.1".parse().unwrap();
+        let other_ip_address: IpAddr = "127.0.0.2".parse().unwrap();
+        let first_permit = limiter.try_acquire(ip_address).unwrap();
+        let _second_permit = limiter.try_acquire(ip_address).unwrap();
+
+        assert!(matches!(
+            limiter.try_acquire(ip_address),
+            Err(IggyError::TooManyConnections(_, 2))
+        ));
+        assert!(limiter.try_acquire(other_ip_address).is_ok());
+
+        drop(first_permit);
+        assert!(limiter.try_acquire(ip_address).is_ok());
+    }
+
+    #[test]
+    fn frame_larger_than_the_max_size_should_be_rejected() {
+        let limiter = create_limiter(0);
+
+        assert!(limiter.validate_frame_size(1000).is_ok());
+        assert!(matches!(
+            limiter.validate_frame_size(1001),
+            Err(IggyError::FrameTooLarge(1001, 1000))
+        ));
+    }
+}
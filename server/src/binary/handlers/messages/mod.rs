@@ -1,2 +1,3 @@
+pub mod init_producer_handler;
 pub mod poll_messages_handler;
 pub mod send_messages_handler;
@@ -3,8 +3,8 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use iggy::error::IggyError;
-use iggy::messages::send_messages::SendMessages;
-use tracing::debug;
+use iggy::messages::send_messages::{AckLevel, SendMessages};
+use tracing::{debug, error};
 
 pub async fn handle(
     command: &SendMessages,
@@ -13,16 +13,33 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
+    // The fire-and-forget producer is acknowledged before the messages are appended.
+    if command.ack == AckLevel::None {
+        sender.send_empty_ok_response().await?;
+    }
+
     let system = system.read();
-    system
+    let result = system
         .append_messages(
             session,
             &command.stream_id,
             &command.topic_id,
             &command.partitioning,
+            command.producer.as_ref(),
+            command.ack,
             &command.messages,
         )
-        .await?;
+        .await;
+    if command.ack == AckLevel::None {
+        if let Err(error) = result {
+            error!(
+                "Failed to append the fire-and-forget messages, session: {session}, error: {error}"
+            );
+        }
+        return Ok(());
+    }
+
+    result?;
     sender.send_empty_ok_response().await?;
     Ok(())
 }
@@ -14,9 +14,14 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
-    let system = system.read();
+    let mut system = system.write();
     let token = system
-        .create_personal_access_token(session, &command.name, command.expiry)
+        .create_personal_access_token(
+            session,
+            &command.name,
+            command.expiry,
+            command.scope.clone(),
+        )
         .await?;
     let bytes = mapper::map_raw_pat(&token);
     sender.send_ok_response(bytes.as_slice()).await?;
@@ -15,7 +15,7 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let system = system.read();
-    let user = system
+    let (user, _) = system
         .login_with_personal_access_token(&command.token, Some(session))
         .await?;
     let identity_info = mapper::map_identity_info(user.id);
@@ -0,0 +1,20 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::roles::delete_role::DeleteRole;
+use tracing::debug;
+
+pub async fn handle(
+    command: &DeleteRole,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system.delete_role(session, &command.role_id).await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
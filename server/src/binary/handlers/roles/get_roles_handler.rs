@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::roles::get_roles::GetRoles;
+use tracing::log::debug;
+
+pub async fn handle(
+    command: &GetRoles,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let roles = system.get_roles(session).await?;
+    let roles = mapper::map_roles(&roles);
+    sender.send_ok_response(roles.as_slice()).await?;
+    Ok(())
+}
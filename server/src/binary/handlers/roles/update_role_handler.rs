@@ -0,0 +1,27 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::roles::update_role::UpdateRole;
+use tracing::debug;
+
+pub async fn handle(
+    command: &UpdateRole,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .update_role(
+            session,
+            &command.role_id,
+            command.name.clone(),
+            command.permissions.clone(),
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -15,7 +15,7 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let mut system = system.write();
     system
-        .create_stream(session, command.stream_id, &command.name)
+        .create_stream(session, command.stream_id, &command.name, command.quotas)
         .await?;
     sender.send_empty_ok_response().await?;
     Ok(())
@@ -0,0 +1,23 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::system::get_audit_log::GetAuditLog;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetAuditLog,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let entries = system
+        .get_audit_log(session, command.offset, command.count)
+        .await?;
+    let entries = mapper::map_audit_log(&entries);
+    sender.send_ok_response(entries.as_slice()).await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::system::get_health::GetHealth;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetHealth,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let health = system.get_health().await;
+    let bytes = mapper::map_health(&health);
+    sender.send_ok_response(bytes.as_slice()).await?;
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use iggy::error::IggyError;
+use iggy::system::get_protocol_version::GetProtocolVersion;
+use iggy::tcp::pipelining::PROTOCOL_VERSION;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetProtocolVersion,
+    sender: &mut dyn Sender,
+    session: &Session,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    session.set_protocol_version(command.client_version.min(PROTOCOL_VERSION));
+    sender
+        .send_ok_response(&PROTOCOL_VERSION.to_le_bytes())
+        .await?;
+    Ok(())
+}
@@ -1,5 +1,10 @@
+pub mod get_audit_log_handler;
 pub mod get_client_handler;
 pub mod get_clients_handler;
+pub mod get_health_handler;
 pub mod get_me_handler;
+pub mod get_protocol_version_handler;
 pub mod get_stats_handler;
 pub mod ping_handler;
+pub mod reload_config_handler;
+pub mod set_log_filter_handler;
@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::system::reload_config::ReloadConfig;
+use tracing::debug;
+
+pub async fn handle(
+    command: &ReloadConfig,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let config_reload = system.reload_config(session).await?;
+    let config_reload = mapper::map_config_reload(&config_reload);
+    sender.send_ok_response(config_reload.as_slice()).await?;
+    Ok(())
+}
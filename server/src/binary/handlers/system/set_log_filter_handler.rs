@@ -0,0 +1,21 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::system::set_log_filter::SetLogFilter;
+use tracing::debug;
+
+pub async fn handle(
+    command: &SetLogFilter,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    system
+        .set_log_filter(session, &command.filter, command.revert_after)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
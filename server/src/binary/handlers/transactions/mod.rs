@@ -0,0 +1,3 @@
+pub mod abort_transaction_handler;
+pub mod begin_transaction_handler;
+pub mod commit_transaction_handler;
@@ -0,0 +1,48 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::audit::audit_action::AuditAction;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::users::login_with_token::LoginWithToken;
+use tracing::{debug, error};
+
+pub async fn handle(
+    command: &LoginWithToken,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    // The token is validated without locking the system, as the keys might need to be fetched from the identity provider.
+    let validator = {
+        let system = system.read();
+        if !system.config.oidc.binary_login_enabled {
+            error!("Login with token is disabled for the binary protocols.");
+            return Err(IggyError::FeatureUnavailable);
+        }
+        system.get_oidc_validator()?
+    };
+
+    let identity = validator.validate(&command.token).await;
+    if let Err(error) = identity {
+        system
+            .read()
+            .audit_login_failure(
+                Some(session),
+                AuditAction::LoginWithToken,
+                "invalid token".to_string(),
+            )
+            .await;
+        return Err(error);
+    }
+
+    let mut system = system.write();
+    let user = system
+        .login_with_oidc_identity(&identity.unwrap(), session)
+        .await?;
+    let identity_info = mapper::map_identity_info(user.id);
+    sender.send_ok_response(identity_info.as_slice()).await?;
+    Ok(())
+}
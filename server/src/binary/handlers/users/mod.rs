@@ -4,6 +4,10 @@ pub mod delete_user_handler;
 pub mod get_user_handler;
 pub mod get_users_handler;
 pub mod login_user_handler;
+pub mod login_with_token_handler;
 pub mod logout_user_handler;
+pub mod unlock_user_handler;
 pub mod update_permissions_handler;
+pub mod update_quotas_handler;
 pub mod update_user_handler;
+pub mod update_user_roles_handler;
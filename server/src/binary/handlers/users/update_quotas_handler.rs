@@ -0,0 +1,22 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::users::update_quotas::UpdateQuotas;
+use tracing::debug;
+
+pub async fn handle(
+    command: &UpdateQuotas,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    system
+        .update_quotas(session, &command.user_id, command.quotas)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
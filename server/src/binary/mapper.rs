@@ -2,13 +2,18 @@ use crate::streaming::clients::client_manager::{Client, Transport};
 use crate::streaming::models::messages::PolledMessages;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::roles::role::Role;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
 use bytes::BufMut;
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::models::audit_log_entry::AuditLogEntry;
+use iggy::models::config_reload::ConfigReload;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::health::Health;
 use iggy::models::stats::Stats;
 use iggy::models::user_info::UserId;
 use std::sync::Arc;
@@ -72,6 +77,62 @@ pub async fn map_clients(clients: &[Arc<RwLock<Client>>]) -> Vec<u8> {
     bytes
 }
 
+pub fn map_audit_log(entries: &[AuditLogEntry]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for entry in entries {
+        bytes.put_u64_le(entry.id);
+        bytes.put_u64_le(entry.timestamp);
+        bytes.put_u32_le(entry.user_id);
+        bytes.put_u32_le(entry.client_id);
+        bytes.put_u8(u8::from(entry.succeeded));
+        for value in [
+            &entry.address,
+            &entry.transport,
+            &entry.action,
+            &entry.resource,
+            &entry.previous_hash,
+            &entry.hash,
+        ] {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(value.len() as u32);
+            bytes.put_slice(value.as_bytes());
+        }
+    }
+    bytes
+}
+
+pub fn map_config_reload(config_reload: &ConfigReload) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for changes in [
+        &config_reload.applied_changes,
+        &config_reload.restart_required_changes,
+    ] {
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(changes.len() as u32);
+        for change in changes {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(change.len() as u32);
+            bytes.put_slice(change.as_bytes());
+        }
+    }
+    bytes
+}
+
+pub fn map_health(health: &Health) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u32_le(health.checks.len() as u32);
+    for check in &health.checks {
+        bytes.put_u8(u8::from(check.healthy));
+        for value in [&check.name, &check.details] {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(value.len() as u32);
+            bytes.put_slice(value.as_bytes());
+        }
+    }
+    bytes
+}
+
 pub fn map_user(user: &User) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_user(user, &mut bytes);
@@ -82,8 +143,21 @@ pub fn map_user(user: &User) -> Vec<u8> {
         bytes.put_u32_le(permissions.len() as u32);
         bytes.extend(permissions);
     } else {
-        bytes.put_u32_le(0);
+        bytes.put_u8(0);
     }
+    if let Some(quotas) = &user.quotas {
+        bytes.put_u8(1);
+        bytes.extend(quotas.as_bytes());
+    } else {
+        bytes.put_u8(0);
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u32_le(user.roles.len() as u32);
+    for role_id in &user.roles {
+        bytes.put_u32_le(*role_id);
+    }
+    bytes.put_u32_le(user.failed_login_attempts);
+    bytes.put_u64_le(user.locked_until);
     bytes
 }
 
@@ -95,12 +169,45 @@ pub fn map_users(users: &[User]) -> Vec<u8> {
     bytes
 }
 
+pub fn map_role(role: &Role) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    extend_role(role, &mut bytes);
+    bytes
+}
+
+pub fn map_roles(roles: &[Role]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for role in roles {
+        extend_role(role, &mut bytes);
+    }
+    bytes
+}
+
 pub fn map_identity_info(user_id: UserId) -> Vec<u8> {
     let mut bytes = Vec::with_capacity(4);
     bytes.put_u32_le(user_id);
     bytes
 }
 
+pub fn map_producer_info(producer_id: u64) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8);
+    bytes.put_u64_le(producer_id);
+    bytes
+}
+
+pub fn map_transaction_info(transaction_id: u64) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8);
+    bytes.put_u64_le(transaction_id);
+    bytes
+}
+
+pub fn map_error_payload(error: &IggyError) -> Vec<u8> {
+    match error {
+        IggyError::QuotaExceeded(retry_after) => retry_after.to_le_bytes().to_vec(),
+        _ => Vec::new(),
+    }
+}
+
 pub fn map_raw_pat(token: &str) -> Vec<u8> {
     let mut bytes = Vec::with_capacity(1 + token.len());
     bytes.put_u8(token.len() as u8);
@@ -141,6 +248,11 @@ pub async fn map_stream(stream: &Stream) -> Vec<u8> {
     for topic in stream.get_topics() {
         extend_topic(topic, &mut bytes).await;
     }
+    // The quotas are appended only if set, to keep the payload readable by the older clients.
+    if let Some(quotas) = &stream.quotas {
+        bytes.put_u8(1);
+        bytes.extend(quotas.as_bytes());
+    }
     bytes
 }
 
@@ -263,8 +375,27 @@ fn extend_user(user: &User, bytes: &mut Vec<u8>) {
     bytes.extend(user.username.as_bytes());
 }
 
+fn extend_role(role: &Role, bytes: &mut Vec<u8>) {
+    bytes.put_u32_le(role.id);
+    bytes.put_u64_le(role.created_at);
+    bytes.put_u8(role.name.len() as u8);
+    bytes.extend(role.name.as_bytes());
+    let permissions = role.permissions.as_bytes();
+    bytes.put_u32_le(permissions.len() as u32);
+    bytes.extend(permissions);
+}
+
 fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut Vec<u8>) {
     bytes.put_u8(personal_access_token.name.len() as u8);
     bytes.extend(personal_access_token.name.as_bytes());
     bytes.put_u64_le(personal_access_token.expiry.unwrap_or(0));
+    if let Some(scope) = &personal_access_token.scope {
+        bytes.put_u8(1);
+        let scope = scope.as_bytes();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(scope.len() as u32);
+        bytes.extend(scope);
+    } else {
+        bytes.put_u8(0);
+    }
 }
@@ -1,4 +1,6 @@
+pub(crate) mod client_certificate;
 pub mod command;
+pub(crate) mod connection_limiter;
 mod handlers;
-mod mapper;
+pub(crate) mod mapper;
 pub mod sender;
@@ -6,5 +6,16 @@ pub trait Sender: Sync + Send {
     async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError>;
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError>;
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError>;
-    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError>;
+    /// Sends the error response with the additional payload (if any), which should be empty for the clients not supporting it.
+    async fn send_error_response(
+        &mut self,
+        error: IggyError,
+        payload: &[u8],
+    ) -> Result<(), IggyError>;
+
+    /// Creates the sender of the response to the pipelined request with the given ID,
+    /// which can be used concurrently with this one. Returns `None` if the transport doesn't support pipelining.
+    fn create_pipelined_sender(&self, _request_id: u64) -> Option<Box<dyn Sender>> {
+        None
+    }
 }
@@ -0,0 +1,109 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ServerConfig;
+use crate::configs::system::TransactionConfig;
+use crate::streaming::diagnostics::background_tasks::BackgroundTasks;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info};
+
+pub struct TransactionsExpirer {
+    interval: IggyDuration,
+    sender: Sender<AbortExpiredTransactionsCommand>,
+    background_tasks: BackgroundTasks,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AbortExpiredTransactionsCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct AbortExpiredTransactionsExecutor;
+
+impl TransactionsExpirer {
+    pub fn new(
+        config: &TransactionConfig,
+        sender: Sender<AbortExpiredTransactionsCommand>,
+        background_tasks: BackgroundTasks,
+    ) -> Self {
+        Self {
+            interval: config.check_interval,
+            sender,
+            background_tasks,
+        }
+    }
+
+    pub fn start(&self) {
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Transactions expirer has started, expired transactions will be aborted every: {:?}.",
+            interval
+        );
+
+        let task = self.background_tasks.register("transactions_expirer");
+        tokio::spawn(async move {
+            let _task = task;
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(AbortExpiredTransactionsCommand)
+                    .unwrap_or_else(|error| {
+                        error!(
+                            "Failed to send AbortExpiredTransactionsCommand. Error: {}",
+                            error
+                        );
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<AbortExpiredTransactionsCommand> for AbortExpiredTransactionsExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: AbortExpiredTransactionsCommand) {
+        let aborted_transactions = system.read().abort_expired_transactions().await;
+        if aborted_transactions == 0 {
+            debug!("No expired transactions to abort.");
+            return;
+        }
+
+        info!("Aborted {aborted_transactions} expired transactions.");
+    }
+
+    fn start_command_sender(
+        &mut self,
+        system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<AbortExpiredTransactionsCommand>,
+    ) {
+        let transactions_expirer = TransactionsExpirer::new(
+            &config.system.transaction,
+            sender,
+            system.read().background_tasks.clone(),
+        );
+        transactions_expirer.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: flume::Receiver<AbortExpiredTransactionsCommand>,
+    ) {
+        let task = system
+            .read()
+            .background_tasks
+            .register("transactions_expirer_executor");
+        tokio::spawn(async move {
+            let _task = task;
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Transactions expirer receiver stopped.");
+        });
+    }
+}
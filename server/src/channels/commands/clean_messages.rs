@@ -1,3 +1,6 @@
+use crate::configs::reloader::wait_for_interval_update;
+use crate::configs::server::ServerConfig;
+use crate::streaming::diagnostics::background_tasks::BackgroundTasks;
 use crate::streaming::systems::system::SharedSystem;
 use crate::streaming::topics::topic::Topic;
 use crate::{channels::server_command::ServerCommand, configs::server::MessageCleanerConfig};
@@ -6,7 +9,9 @@ use flume::Sender;
 use iggy::error::IggyError;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
-use tokio::time;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tokio::time::{self, Instant};
 use tracing::{error, info};
 
 struct DeletedSegments {
@@ -17,7 +22,9 @@ struct DeletedSegments {
 pub struct MessagesCleaner {
     enabled: bool,
     interval: IggyDuration,
+    config_updates: Option<watch::Receiver<Arc<ServerConfig>>>,
     sender: Sender<CleanMessagesCommand>,
+    background_tasks: BackgroundTasks,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -27,11 +34,18 @@ pub struct CleanMessagesCommand;
 pub struct CleanMessagesExecutor;
 
 impl MessagesCleaner {
-    pub fn new(config: &MessageCleanerConfig, sender: Sender<CleanMessagesCommand>) -> Self {
+    pub fn new(
+        config: &MessageCleanerConfig,
+        sender: Sender<CleanMessagesCommand>,
+        config_updates: Option<watch::Receiver<Arc<ServerConfig>>>,
+        background_tasks: BackgroundTasks,
+    ) -> Self {
         Self {
             enabled: config.enabled,
             interval: config.interval,
+            config_updates,
             sender,
+            background_tasks,
         }
     }
 
@@ -41,20 +55,41 @@ impl MessagesCleaner {
             return;
         }
 
-        let interval = self.interval;
+        let mut interval = self.interval;
+        let mut config_updates = self.config_updates.clone();
         let sender = self.sender.clone();
         info!(
             "Message cleaner is enabled, expired messages will be deleted every: {:?}.",
             interval
         );
 
+        let task = self.background_tasks.register("message_cleaner");
         tokio::spawn(async move {
+            let _task = task;
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
-                interval_timer.tick().await;
-                sender.send(CleanMessagesCommand).unwrap_or_else(|err| {
-                    error!("Failed to send CleanMessagesCommand. Error: {}", err);
-                });
+                tokio::select! {
+                    _ = interval_timer.tick() => {
+                        sender.send(CleanMessagesCommand).unwrap_or_else(|err| {
+                            error!("Failed to send CleanMessagesCommand. Error: {}", err);
+                        });
+                    }
+                    updated_interval = wait_for_interval_update(
+                        &mut config_updates,
+                        interval,
+                        |config| config.message_cleaner.interval,
+                    ) => {
+                        info!(
+                            "Message cleaner interval has been changed to: {:?}.",
+                            updated_interval
+                        );
+                        interval = updated_interval;
+                        interval_timer = time::interval_at(
+                            Instant::now() + interval.get_duration(),
+                            interval.get_duration(),
+                        );
+                    }
+                }
             }
         });
     }
@@ -89,26 +124,37 @@ impl ServerCommand<CleanMessagesCommand> for CleanMessagesExecutor {
                         .decrement_messages(deleted_segments.messages_count);
                 }
             }
+            stream.refresh_size_bytes().await;
         }
     }
 
     fn start_command_sender(
         &mut self,
-        _system: SharedSystem,
-        config: &crate::configs::server::ServerConfig,
+        system: SharedSystem,
+        config: &ServerConfig,
         sender: Sender<CleanMessagesCommand>,
     ) {
-        let messages_cleaner = MessagesCleaner::new(&config.message_cleaner, sender);
+        let messages_cleaner = MessagesCleaner::new(
+            &config.message_cleaner,
+            sender,
+            system.read().subscribe_config_updates(),
+            system.read().background_tasks.clone(),
+        );
         messages_cleaner.start();
     }
 
     fn start_command_consumer(
         mut self,
         system: SharedSystem,
-        _config: &crate::configs::server::ServerConfig,
+        _config: &ServerConfig,
         receiver: flume::Receiver<CleanMessagesCommand>,
     ) {
+        let task = system
+            .read()
+            .background_tasks
+            .register("message_cleaner_executor");
         tokio::spawn(async move {
+            let _task = task;
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
                 self.execute(&system, command).await;
@@ -1,17 +1,24 @@
 use crate::channels::server_command::ServerCommand;
+use crate::configs::reloader::wait_for_interval_update;
 use crate::configs::server::PersonalAccessTokenCleanerConfig;
+use crate::configs::server::ServerConfig;
+use crate::streaming::diagnostics::background_tasks::BackgroundTasks;
 use crate::streaming::systems::system::SharedSystem;
 use async_trait::async_trait;
 use flume::Sender;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
-use tokio::time;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tokio::time::{self, Instant};
 use tracing::{debug, error, info};
 
 pub struct PersonalAccessTokenCleaner {
     enabled: bool,
     interval: IggyDuration,
+    config_updates: Option<watch::Receiver<Arc<ServerConfig>>>,
     sender: Sender<CleanPersonalAccessTokensCommand>,
+    background_tasks: BackgroundTasks,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -24,11 +31,15 @@ impl PersonalAccessTokenCleaner {
     pub fn new(
         config: &PersonalAccessTokenCleanerConfig,
         sender: Sender<CleanPersonalAccessTokensCommand>,
+        config_updates: Option<watch::Receiver<Arc<ServerConfig>>>,
+        background_tasks: BackgroundTasks,
     ) -> Self {
         Self {
             enabled: config.enabled,
             interval: config.interval,
+            config_updates,
             sender,
+            background_tasks,
         }
     }
 
@@ -38,25 +49,48 @@ impl PersonalAccessTokenCleaner {
             return;
         }
 
-        let interval = self.interval;
+        let mut interval = self.interval;
+        let mut config_updates = self.config_updates.clone();
         let sender = self.sender.clone();
         info!(
             "Personal access token cleaner is enabled, expired tokens will be deleted every: {:?}.",
             interval
         );
 
+        let task = self
+            .background_tasks
+            .register("personal_access_token_cleaner");
         tokio::spawn(async move {
+            let _task = task;
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
-                interval_timer.tick().await;
-                sender
-                    .send(CleanPersonalAccessTokensCommand)
-                    .unwrap_or_else(|error| {
-                        error!(
-                            "Failed to send CleanPersonalAccessTokensCommand. Error: {}",
-                            error
+                tokio::select! {
+                    _ = interval_timer.tick() => {
+                        sender
+                            .send(CleanPersonalAccessTokensCommand)
+                            .unwrap_or_else(|error| {
+                                error!(
+                                    "Failed to send CleanPersonalAccessTokensCommand. Error: {}",
+                                    error
+                                );
+                            });
+                    }
+                    updated_interval = wait_for_interval_update(
+                        &mut config_updates,
+                        interval,
+                        |config| config.personal_access_token.cleaner.interval,
+                    ) => {
+                        info!(
+                            "Personal access token cleaner interval has been changed to: {:?}.",
+                            updated_interval
                         );
-                    });
+                        interval = updated_interval;
+                        interval_timer = time::interval_at(
+                            Instant::now() + interval.get_duration(),
+                            interval.get_duration(),
+                        );
+                    }
+                }
             }
         });
     }
@@ -120,22 +154,31 @@ impl ServerCommand<CleanPersonalAccessTokensCommand> for CleanPersonalAccessToke
 
     fn start_command_sender(
         &mut self,
-        _system: SharedSystem,
-        config: &crate::configs::server::ServerConfig,
+        system: SharedSystem,
+        config: &ServerConfig,
         sender: Sender<CleanPersonalAccessTokensCommand>,
     ) {
-        let personal_access_token_cleaner =
-            PersonalAccessTokenCleaner::new(&config.personal_access_token.cleaner, sender);
+        let personal_access_token_cleaner = PersonalAccessTokenCleaner::new(
+            &config.personal_access_token.cleaner,
+            sender,
+            system.read().subscribe_config_updates(),
+            system.read().background_tasks.clone(),
+        );
         personal_access_token_cleaner.start();
     }
 
     fn start_command_consumer(
         mut self,
         system: SharedSystem,
-        _config: &crate::configs::server::ServerConfig,
+        _config: &ServerConfig,
         receiver: flume::Receiver<CleanPersonalAccessTokensCommand>,
     ) {
+        let task = system
+            .read()
+            .background_tasks
+            .register("personal_access_token_cleaner_executor");
         tokio::spawn(async move {
+            let _task = task;
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
                 self.execute(&system, command).await;
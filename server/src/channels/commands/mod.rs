@@ -1,3 +1,5 @@
+pub mod abort_expired_transactions;
 pub mod clean_messages;
 pub mod clean_personal_access_tokens;
+pub mod re_encrypt_messages;
 pub mod save_messages;
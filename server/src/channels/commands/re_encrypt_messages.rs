@@ -0,0 +1,222 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::EncryptionConfig;
+use crate::streaming::diagnostics::background_tasks::BackgroundTasks;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::identifier::Identifier;
+use iggy::utils::crypto::KeyRingEncryptor;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{error, info};
+
+/// Number of segments re-encrypted while holding the system lock.
+const SEGMENTS_BATCH_SIZE: usize = 10;
+
+pub struct MessagesReEncryptor {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<ReEncryptMessagesCommand>,
+    background_tasks: BackgroundTasks,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ReEncryptMessagesCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct ReEncryptMessagesExecutor;
+
+impl MessagesReEncryptor {
+    pub fn new(
+        config: &EncryptionConfig,
+        sender: Sender<ReEncryptMessagesCommand>,
+        background_tasks: BackgroundTasks,
+    ) -> Self {
+        Self {
+            enabled: config.enabled && config.re_encryption.enabled,
+            interval: config.re_encryption.interval,
+            sender,
+            background_tasks,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Messages re-encryptor is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Messages re-encryptor is enabled, messages encrypted with the previous keys will be re-encrypted every: {:?}.",
+            interval
+        );
+
+        let task = self.background_tasks.register("messages_re_encryptor");
+        tokio::spawn(async move {
+            let _task = task;
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(ReEncryptMessagesCommand)
+                    .unwrap_or_else(|error| {
+                        error!("Failed to send ReEncryptMessagesCommand. Error: {}", error);
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<ReEncryptMessagesCommand> for ReEncryptMessagesExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: ReEncryptMessagesCommand) {
+        if system.read().encryptor.is_none() {
+            return;
+        }
+
+        match system.write().re_wrap_stream_encryption_keys().await {
+            Ok(re_wrapped_keys) => {
+                if re_wrapped_keys > 0 {
+                    info!(
+                        "Re-wrapped {re_wrapped_keys} stream encryption keys with the active key."
+                    );
+                }
+            }
+            Err(error) => error!("Failed to re-wrap stream encryption keys. Error: {}", error),
+        }
+
+        let (encryptor, partitions) = {
+            let system = system.read();
+            let encryptor = system.encryptor.clone().unwrap();
+            let mut partitions = Vec::new();
+            for stream in system.get_streams() {
+                // The messages of the stream having its own data key are not encrypted with the master key.
+                if stream.encryption_key.is_some() {
+                    continue;
+                }
+
+                for topic in stream.get_topics() {
+                    for partition_id in topic.partitions.keys() {
+                        partitions.push((stream.stream_id, topic.topic_id, *partition_id));
+                    }
+                }
+            }
+            (encryptor, partitions)
+        };
+
+        let mut re_encrypted_messages = 0;
+        let mut failed_segments = 0;
+        for partition in partitions {
+            let mut start_index = 0;
+            while let Some(batch) =
+                re_encrypt_segments_batch(system, &encryptor, partition, start_index).await
+            {
+                re_encrypted_messages += batch.messages_count;
+                failed_segments += batch.failed_segments;
+                start_index += batch.segments_count;
+            }
+        }
+
+        if failed_segments > 0 {
+            error!("Re-encrypted {re_encrypted_messages} messages, failed to re-encrypt {failed_segments} segments.");
+            return;
+        }
+
+        // The messages of the segments which are still open will be re-encrypted once the segments get closed.
+        info!(
+            "Re-encrypted {re_encrypted_messages} messages, all the closed segments are encrypted with the active key with ID: {}.",
+            encryptor.get_active_key_id().unwrap_or_default()
+        );
+    }
+
+    fn start_command_sender(
+        &mut self,
+        system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<ReEncryptMessagesCommand>,
+    ) {
+        let messages_re_encryptor = MessagesReEncryptor::new(
+            &config.system.encryption,
+            sender,
+            system.read().background_tasks.clone(),
+        );
+        messages_re_encryptor.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<ReEncryptMessagesCommand>,
+    ) {
+        let task = system
+            .read()
+            .background_tasks
+            .register("messages_re_encryptor_executor");
+        tokio::spawn(async move {
+            let _task = task;
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Messages re-encryptor receiver stopped.");
+        });
+    }
+}
+
+struct ReEncryptedBatch {
+    segments_count: usize,
+    messages_count: u64,
+    failed_segments: u32,
+}
+
+/// Re-encrypts the batch of segments of the partition starting from the given index, holding the system lock
+/// only for this batch, so that the other requests are not blocked for the whole rotation. Returns `None`
+/// once there are no more segments, or the partition (or the stream using the master key) no longer exists.
+/// The segments deleted or added in the meantime are skipped and handled on the next run.
+async fn re_encrypt_segments_batch(
+    system: &SharedSystem,
+    encryptor: &KeyRingEncryptor,
+    (stream_id, topic_id, partition_id): (u32, u32, u32),
+    start_index: usize,
+) -> Option<ReEncryptedBatch> {
+    let system = system.read();
+    let stream = system
+        .get_stream(&Identifier::numeric(stream_id).ok()?)
+        .ok()?;
+    if stream.encryption_key.is_some() {
+        return None;
+    }
+
+    let topic = stream
+        .get_topic(&Identifier::numeric(topic_id).ok()?)
+        .ok()?;
+    let partition = topic.get_partition(partition_id).ok()?;
+    let mut partition = partition.write().await;
+    let segments = partition.get_segments_mut();
+    if start_index >= segments.len() {
+        return None;
+    }
+
+    let end_index = segments.len().min(start_index + SEGMENTS_BATCH_SIZE);
+    let mut batch = ReEncryptedBatch {
+        segments_count: end_index - start_index,
+        messages_count: 0,
+        failed_segments: 0,
+    };
+    for segment in &mut segments[start_index..end_index] {
+        match segment.re_encrypt_messages(encryptor).await {
+            Ok(messages_count) => batch.messages_count += messages_count,
+            Err(error) => {
+                batch.failed_segments += 1;
+                error!(
+                    "Failed to re-encrypt messages in segment with start offset: {} for stream ID: {}, topic ID: {}, partition ID: {}. Error: {}",
+                    segment.start_offset, segment.stream_id, segment.topic_id, segment.partition_id, error
+                );
+            }
+        }
+    }
+    Some(batch)
+}
@@ -1,17 +1,23 @@
 use crate::channels::server_command::ServerCommand;
+use crate::configs::reloader::wait_for_interval_update;
 use crate::configs::server::MessageSaverConfig;
 use crate::configs::server::ServerConfig;
+use crate::streaming::diagnostics::background_tasks::BackgroundTasks;
 use crate::streaming::systems::system::SharedSystem;
 use async_trait::async_trait;
 use flume::{Receiver, Sender};
 use iggy::utils::duration::IggyDuration;
-use tokio::time;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tokio::time::{self, Instant};
 use tracing::{error, info, warn};
 
 pub struct MessagesSaver {
     enforce_fsync: bool,
     interval: IggyDuration,
+    config_updates: Option<watch::Receiver<Arc<ServerConfig>>>,
     sender: Sender<SaveMessagesCommand>,
+    background_tasks: BackgroundTasks,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -23,11 +29,18 @@ pub struct SaveMessagesCommand {
 pub struct SaveMessagesExecutor;
 
 impl MessagesSaver {
-    pub fn new(config: &MessageSaverConfig, sender: Sender<SaveMessagesCommand>) -> Self {
+    pub fn new(
+        config: &MessageSaverConfig,
+        sender: Sender<SaveMessagesCommand>,
+        config_updates: Option<watch::Receiver<Arc<ServerConfig>>>,
+        background_tasks: BackgroundTasks,
+    ) -> Self {
         Self {
             enforce_fsync: config.enforce_fsync,
             interval: config.interval,
+            config_updates,
             sender,
+            background_tasks,
         }
     }
 
@@ -38,21 +51,42 @@ impl MessagesSaver {
         }
 
         let enforce_fsync = self.enforce_fsync;
-        let interval = self.interval;
+        let mut interval = self.interval;
+        let mut config_updates = self.config_updates.clone();
         let sender = self.sender.clone();
         info!(
             "Message saver is enabled, buffered messages will be automatically saved every: {:?}, enforce fsync: {:?}.",
             interval, enforce_fsync
         );
 
+        let task = self.background_tasks.register("message_saver");
         tokio::spawn(async move {
+            let _task = task;
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
-                interval_timer.tick().await;
-                let command = SaveMessagesCommand { enforce_fsync };
-                sender.send(command).unwrap_or_else(|error| {
-                    error!("Failed to send SaveMessagesCommand. Error: {}", error);
-                });
+                tokio::select! {
+                    _ = interval_timer.tick() => {
+                        let command = SaveMessagesCommand { enforce_fsync };
+                        sender.send(command).unwrap_or_else(|error| {
+                            error!("Failed to send SaveMessagesCommand. Error: {}", error);
+                        });
+                    }
+                    updated_interval = wait_for_interval_update(
+                        &mut config_updates,
+                        interval,
+                        |config| config.message_saver.interval,
+                    ) => {
+                        info!(
+                            "Message saver interval has been changed to: {:?}.",
+                            updated_interval
+                        );
+                        interval = updated_interval;
+                        interval_timer = time::interval_at(
+                            Instant::now() + interval.get_duration(),
+                            interval.get_duration(),
+                        );
+                    }
+                }
             }
         });
     }
@@ -73,11 +107,16 @@ impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
 
     fn start_command_sender(
         &mut self,
-        _system: SharedSystem,
+        system: SharedSystem,
         config: &ServerConfig,
         sender: Sender<SaveMessagesCommand>,
     ) {
-        let messages_saver = MessagesSaver::new(&config.message_saver, sender);
+        let messages_saver = MessagesSaver::new(
+            &config.message_saver,
+            sender,
+            system.read().subscribe_config_updates(),
+            system.read().background_tasks.clone(),
+        );
         messages_saver.start();
     }
 
@@ -87,7 +126,12 @@ impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
         _config: &ServerConfig,
         receiver: Receiver<SaveMessagesCommand>,
     ) {
+        let task = system
+            .read()
+            .background_tasks
+            .register("message_saver_executor");
         tokio::spawn(async move {
+            let _task = task;
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
                 self.execute(&system, command).await;
@@ -1,3 +1,4 @@
+use crate::configs::secrets::{is_secret_entry, redact};
 use crate::configs::server::ServerConfig;
 use crate::server_error::ServerError;
 use async_trait::async_trait;
@@ -14,7 +15,7 @@ const DEFAULT_CONFIG_PROVIDER: &str = "file";
 const DEFAULT_CONFIG_PATH: &str = "configs/server.toml";
 
 #[async_trait]
-pub trait ConfigProvider {
+pub trait ConfigProvider: Send + Sync {
     async fn load_config(&self) -> Result<ServerConfig, ServerError>;
 }
 
@@ -166,9 +167,13 @@ impl Provider for CustomEnvProvider {
                 .map(|k| k.to_lowercase())
                 .collect();
             let env_var_value = Self::try_parse_value(&value);
+            let logged_value = match is_secret_entry(&env_key) {
+                true => redact(&value),
+                false => value.as_str(),
+            };
             info!(
                 "{} value changed to: {:?} from environment variable",
-                env_key, value
+                env_key, logged_value
             );
             Self::insert_overridden_values_from_env(
                 &source_dict,
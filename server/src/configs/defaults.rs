@@ -3,15 +3,20 @@ use crate::configs::http::{
 };
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    MessageCleanerConfig, MessageSaverConfig, PersonalAccessTokenCleanerConfig,
-    PersonalAccessTokenConfig, ServerConfig,
+    ClientCertificateConfig, ConnectionLimitsConfig, MessageCleanerConfig, MessageSaverConfig,
+    PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig, ServerConfig, ShutdownConfig,
+    COMMON_NAME_FIELD,
 };
 use crate::configs::system::{
-    CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LoggingConfig,
-    MessageDeduplicationConfig, PartitionConfig, RetentionPolicyConfig, RuntimeConfig,
-    SegmentConfig, StreamConfig, SystemConfig, TopicConfig,
+    AuditConfig, CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, HealthConfig,
+    LoggingConfig, LoginLockoutConfig, MessageDeduplicationConfig, OidcConfig, PartitionConfig,
+    PasswordPolicyConfig, QuotaLimitsConfig, QuotasConfig, ReEncryptionConfig,
+    RetentionPolicyConfig, RuntimeConfig, SegmentConfig, StreamConfig, SystemConfig, TopicConfig,
+    TransactionConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use crate::configs::telemetry::{TelemetryConfig, OTLP_EXPORTER};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 impl Default for ServerConfig {
@@ -24,6 +29,21 @@ impl Default for ServerConfig {
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
             http: HttpConfig::default(),
+            telemetry: TelemetryConfig::default(),
+            shutdown: ShutdownConfig::default(),
+        }
+    }
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> TelemetryConfig {
+        TelemetryConfig {
+            enabled: false,
+            service_name: "iggy-server".to_string(),
+            exporter: OTLP_EXPORTER.to_string(),
+            endpoint: "http://localhost:4317".to_string(),
+            path: "traces/traces.json".to_string(),
+            sampling_ratio: 1.0,
         }
     }
 }
@@ -41,6 +61,8 @@ impl Default for QuicConfig {
             keep_alive_interval: "5s".parse().unwrap(),
             max_idle_timeout: "10s".parse().unwrap(),
             certificate: QuicCertificateConfig::default(),
+            client_certificate: ClientCertificateConfig::default(),
+            limits: ConnectionLimitsConfig::default(),
         }
     }
 }
@@ -55,12 +77,51 @@ impl Default for QuicCertificateConfig {
     }
 }
 
+impl Default for ClientCertificateConfig {
+    fn default() -> ClientCertificateConfig {
+        ClientCertificateConfig {
+            enabled: false,
+            required: true,
+            ca_file: "certs/iggy_ca_cert.pem".to_string(),
+            username_field: COMMON_NAME_FIELD.to_string(),
+            usernames: HashMap::new(),
+            allow_root: false,
+        }
+    }
+}
+
+impl Default for ConnectionLimitsConfig {
+    fn default() -> ConnectionLimitsConfig {
+        ConnectionLimitsConfig {
+            max_frame_size: "64 MB".parse().unwrap(),
+            read_timeout: "10s".parse().unwrap(),
+            idle_timeout: "5m".parse().unwrap(),
+            max_connections_per_ip: 1000,
+            max_concurrent_requests: 100,
+        }
+    }
+}
+
+impl Default for TcpTlsConfig {
+    fn default() -> TcpTlsConfig {
+        TcpTlsConfig {
+            enabled: false,
+            certificate: "certs/iggy.pfx".to_string(),
+            password: "iggy123".to_string(),
+            cert_file: "certs/iggy_cert.pem".to_string(),
+            key_file: "certs/iggy_key.pem".to_string(),
+            client_certificate: ClientCertificateConfig::default(),
+        }
+    }
+}
+
 impl Default for TcpConfig {
     fn default() -> TcpConfig {
         TcpConfig {
             enabled: true,
             address: "127.0.0.1:8090".to_string(),
             tls: TcpTlsConfig::default(),
+            limits: ConnectionLimitsConfig::default(),
         }
     }
 }
@@ -93,6 +154,10 @@ impl Default for HttpJwtConfig {
             encoding_secret: "top_secret$iggy.rs$_jwt_HS256_key#!".to_string(),
             decoding_secret: "top_secret$iggy.rs$_jwt_HS256_key#!".to_string(),
             use_base64_secret: false,
+            key_id: "".to_string(),
+            encoding_key_file: "".to_string(),
+            decoding_key_file: "".to_string(),
+            decoding_keys: vec![],
         }
     }
 }
@@ -116,6 +181,15 @@ impl Default for MessageSaverConfig {
     }
 }
 
+impl Default for ShutdownConfig {
+    fn default() -> ShutdownConfig {
+        ShutdownConfig {
+            grace_period: "5s".parse().unwrap(),
+            timeout: "30s".parse().unwrap(),
+        }
+    }
+}
+
 impl Default for PersonalAccessTokenConfig {
     fn default() -> PersonalAccessTokenConfig {
         PersonalAccessTokenConfig {
@@ -150,6 +224,13 @@ impl Default for SystemConfig {
             segment: SegmentConfig::default(),
             compression: CompressionConfig::default(),
             message_deduplication: MessageDeduplicationConfig::default(),
+            quotas: QuotasConfig::default(),
+            audit: AuditConfig::default(),
+            oidc: OidcConfig::default(),
+            password_policy: PasswordPolicyConfig::default(),
+            login_lockout: LoginLockoutConfig::default(),
+            health: HealthConfig::default(),
+            transaction: TransactionConfig::default(),
         }
     }
 }
@@ -254,3 +335,112 @@ impl Default for MessageDeduplicationConfig {
         }
     }
 }
+
+impl Default for QuotasConfig {
+    fn default() -> QuotasConfig {
+        QuotasConfig {
+            enabled: false,
+            user: QuotaLimitsConfig::default(),
+            client: QuotaLimitsConfig::default(),
+        }
+    }
+}
+
+impl Default for QuotaLimitsConfig {
+    fn default() -> QuotaLimitsConfig {
+        QuotaLimitsConfig {
+            produce_bytes_per_second: "0".parse().unwrap(),
+            consume_bytes_per_second: "0".parse().unwrap(),
+            requests_per_second: 0,
+        }
+    }
+}
+
+impl Default for EncryptionConfig {
+    fn default() -> EncryptionConfig {
+        EncryptionConfig {
+            enabled: false,
+            key: "".to_string(),
+            active_key_id: 0,
+            keys: vec![],
+            per_stream_keys: false,
+            re_encryption: ReEncryptionConfig::default(),
+        }
+    }
+}
+
+impl Default for ReEncryptionConfig {
+    fn default() -> ReEncryptionConfig {
+        ReEncryptionConfig {
+            enabled: false,
+            interval: "1h".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for AuditConfig {
+    fn default() -> AuditConfig {
+        AuditConfig {
+            enabled: false,
+            path: "audit.log".to_string(),
+        }
+    }
+}
+
+impl Default for OidcConfig {
+    fn default() -> OidcConfig {
+        OidcConfig {
+            enabled: false,
+            binary_login_enabled: false,
+            jwks: "certs/jwks.json".to_string(),
+            jwks_refresh_interval: "5m".parse().unwrap(),
+            valid_issuers: vec![],
+            valid_audiences: vec![],
+            clock_skew: "5s".parse().unwrap(),
+            username_claim: "preferred_username".to_string(),
+            roles_claim: "".to_string(),
+        }
+    }
+}
+
+impl Default for PasswordPolicyConfig {
+    fn default() -> PasswordPolicyConfig {
+        PasswordPolicyConfig {
+            min_length: 0,
+            require_uppercase: false,
+            require_lowercase: false,
+            require_digit: false,
+            require_special_character: false,
+        }
+    }
+}
+
+impl Default for TransactionConfig {
+    fn default() -> TransactionConfig {
+        TransactionConfig {
+            timeout: "1m".parse().unwrap(),
+            check_interval: "5s".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for HealthConfig {
+    fn default() -> HealthConfig {
+        HealthConfig {
+            min_free_disk_space: "1 GB".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for LoginLockoutConfig {
+    fn default() -> LoginLockoutConfig {
+        LoginLockoutConfig {
+            enabled: false,
+            max_failed_attempts: 5,
+            lockout_duration: "15m".parse().unwrap(),
+            ip_failed_attempts_threshold: 10,
+            ip_backoff: "1s".parse().unwrap(),
+            ip_max_backoff: "5m".parse().unwrap(),
+        }
+    }
+}
@@ -1,15 +1,23 @@
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
-use crate::configs::system::MessageDeduplicationConfig;
+use crate::configs::system::{
+    AuditConfig, HealthConfig, LoginLockoutConfig, MessageDeduplicationConfig, OidcConfig,
+    PasswordPolicyConfig, QuotaLimitsConfig, QuotasConfig, ReEncryptionConfig, TransactionConfig,
+};
 use crate::configs::{
     http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
     resource_quota::MemoryResourceQuota,
-    server::{MessageCleanerConfig, MessageSaverConfig, ServerConfig},
+    secrets::redact,
+    server::{
+        ClientCertificateConfig, ConnectionLimitsConfig, MessageCleanerConfig, MessageSaverConfig,
+        ServerConfig, ShutdownConfig,
+    },
     system::{
         CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LoggingConfig,
         PartitionConfig, RetentionPolicyConfig, SegmentConfig, StreamConfig, SystemConfig,
         TopicConfig,
     },
     tcp::{TcpConfig, TcpTlsConfig},
+    telemetry::TelemetryConfig,
 };
 use std::fmt::{Display, Formatter};
 
@@ -37,8 +45,21 @@ impl Display for HttpJwtConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ algorithm: {}, audience: {}, expiry: {}, use_base64_secret: {} }}",
-            self.algorithm, self.audience, self.access_token_expiry, self.use_base64_secret
+            "{{ algorithm: {}, audience: {}, expiry: {}, encoding_secret: {}, decoding_secret: {}, use_base64_secret: {}, key_id: {}, encoding_key_file: {}, decoding_key_file: {}, decoding_keys: {} }}",
+            self.algorithm,
+            self.audience,
+            self.access_token_expiry,
+            redact(&self.encoding_secret),
+            redact(&self.decoding_secret),
+            self.use_base64_secret,
+            self.key_id,
+            self.encoding_key_file,
+            self.decoding_key_file,
+            self.decoding_keys
+                .iter()
+                .map(|key| format!("{}:{}", key.key_id, key.algorithm))
+                .collect::<Vec<_>>()
+                .join(", ")
         )
     }
 }
@@ -67,7 +88,7 @@ impl Display for QuicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {} }}",
+          "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {}, client_certificate: {}, limits: {} }}",
           self.enabled,
           self.address,
           self.max_concurrent_bidi_streams,
@@ -77,11 +98,42 @@ impl Display for QuicConfig {
           self.receive_window,
           self.keep_alive_interval,
           self.max_idle_timeout,
-          self.certificate
+          self.certificate,
+          self.client_certificate,
+          self.limits
       )
     }
 }
 
+impl Display for ClientCertificateConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, required: {}, ca_file: {}, username_field: {}, usernames: {}, allow_root: {} }}",
+            self.enabled,
+            self.required,
+            self.ca_file,
+            self.username_field,
+            self.usernames.len(),
+            self.allow_root
+        )
+    }
+}
+
+impl Display for ConnectionLimitsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ max_frame_size: {}, read_timeout: {}, idle_timeout: {}, max_connections_per_ip: {}, max_concurrent_requests: {} }}",
+            self.max_frame_size,
+            self.read_timeout,
+            self.idle_timeout,
+            self.max_connections_per_ip,
+            self.max_concurrent_requests
+        )
+    }
+}
+
 impl Display for QuicCertificateConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -115,8 +167,30 @@ impl Display for ServerConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ message_cleaner: {}, message_saver: {}, system: {}, quic: {}, tcp: {}, http: {} }}",
-            self.message_cleaner, self.message_saver, self.system, self.quic, self.tcp, self.http
+            "{{ message_cleaner: {}, message_saver: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {}, shutdown: {} }}",
+            self.message_cleaner,
+            self.message_saver,
+            self.system,
+            self.quic,
+            self.tcp,
+            self.http,
+            self.telemetry,
+            self.shutdown
+        )
+    }
+}
+
+impl Display for TelemetryConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, service_name: {}, exporter: {}, endpoint: {}, path: {}, sampling_ratio: {} }}",
+            self.enabled,
+            self.service_name,
+            self.exporter,
+            self.endpoint,
+            self.path,
+            self.sampling_ratio
         )
     }
 }
@@ -131,6 +205,16 @@ impl Display for MessageCleanerConfig {
     }
 }
 
+impl Display for ShutdownConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ grace_period: {}, timeout: {} }}",
+            self.grace_period, self.timeout
+        )
+    }
+}
+
 impl Display for MessageSaverConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -166,7 +250,26 @@ impl Display for RetentionPolicyConfig {
 
 impl Display for EncryptionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ enabled: {} }}", self.enabled)
+        write!(
+            f,
+            "{{ enabled: {}, key: {}, active_key_id: {}, key_ids: {:?}, per_stream_keys: {}, re_encryption: {} }}",
+            self.enabled,
+            redact(&self.key),
+            self.active_key_id,
+            self.keys.iter().map(|key| key.id).collect::<Vec<_>>(),
+            self.per_stream_keys,
+            self.re_encryption
+        )
+    }
+}
+
+impl Display for ReEncryptionConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {} }}",
+            self.enabled, self.interval
+        )
     }
 }
 
@@ -205,6 +308,97 @@ impl Display for MessageDeduplicationConfig {
     }
 }
 
+impl Display for QuotasConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, user: {}, client: {} }}",
+            self.enabled, self.user, self.client
+        )
+    }
+}
+
+impl Display for AuditConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ enabled: {}, path: {} }}", self.enabled, self.path)
+    }
+}
+
+impl Display for OidcConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, binary_login_enabled: {}, jwks: {}, jwks_refresh_interval: {}, valid_issuers: {:?}, valid_audiences: {:?}, clock_skew: {}, username_claim: {}, roles_claim: {} }}",
+            self.enabled,
+            self.binary_login_enabled,
+            self.jwks,
+            self.jwks_refresh_interval,
+            self.valid_issuers,
+            self.valid_audiences,
+            self.clock_skew,
+            self.username_claim,
+            self.roles_claim
+        )
+    }
+}
+
+impl Display for PasswordPolicyConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ min_length: {}, require_uppercase: {}, require_lowercase: {}, require_digit: {}, require_special_character: {} }}",
+            self.min_length,
+            self.require_uppercase,
+            self.require_lowercase,
+            self.require_digit,
+            self.require_special_character
+        )
+    }
+}
+
+impl Display for HealthConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ min_free_disk_space: {} }}", self.min_free_disk_space)
+    }
+}
+
+impl Display for TransactionConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ timeout: {}, check_interval: {} }}",
+            self.timeout, self.check_interval
+        )
+    }
+}
+
+impl Display for LoginLockoutConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, max_failed_attempts: {}, lockout_duration: {}, ip_failed_attempts_threshold: {}, ip_backoff: {}, ip_max_backoff: {} }}",
+            self.enabled,
+            self.max_failed_attempts,
+            self.lockout_duration,
+            self.ip_failed_attempts_threshold,
+            self.ip_backoff,
+            self.ip_max_backoff
+        )
+    }
+}
+
+impl Display for QuotaLimitsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ produce_bytes_per_second: {}, consume_bytes_per_second: {}, requests_per_second: {} }}",
+            self.produce_bytes_per_second.as_human_string_with_zero_as_unlimited(),
+            self.consume_bytes_per_second.as_human_string_with_zero_as_unlimited(),
+            self.requests_per_second
+        )
+    }
+}
+
 impl Display for SegmentConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -232,8 +426,8 @@ impl Display for TcpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, tls: {} }}",
-            self.enabled, self.address, self.tls
+            "{{ enabled: {}, address: {}, tls: {}, limits: {} }}",
+            self.enabled, self.address, self.tls, self.limits
         )
     }
 }
@@ -242,8 +436,13 @@ impl Display for TcpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, certificate: {} }}",
-            self.enabled, self.certificate
+            "{{ enabled: {}, certificate: {}, password: {}, cert_file: {}, key_file: {}, client_certificate: {} }}",
+            self.enabled,
+            self.certificate,
+            redact(&self.password),
+            self.cert_file,
+            self.key_file,
+            self.client_certificate
         )
     }
 }
@@ -252,7 +451,7 @@ impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, database: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {} }}",
+          "{{ path: {}, database: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, quotas: {}, audit: {}, oidc: {}, password_policy: {}, login_lockout: {}, health: {}, transaction: {} }}",
           self.path,
           self.database,
           self.logging,
@@ -261,7 +460,14 @@ impl Display for SystemConfig {
           self.topic,
           self.partition,
           self.segment,
-          self.encryption
+          self.encryption,
+          self.quotas,
+          self.audit,
+          self.oidc,
+          self.password_policy,
+          self.login_lockout,
+          self.health,
+          self.transaction
       )
     }
 }
@@ -1,9 +1,11 @@
+use crate::configs::secrets::deserialize_secret;
 use iggy::error::IggyError;
 use iggy::utils::duration::IggyDuration;
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use tracing::error;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct HttpConfig {
@@ -42,9 +44,25 @@ pub struct HttpJwtConfig {
     pub clock_skew: IggyDuration,
     #[serde_as(as = "DisplayFromStr")]
     pub not_before: IggyDuration,
+    #[serde(deserialize_with = "deserialize_secret")]
     pub encoding_secret: String,
+    #[serde(deserialize_with = "deserialize_secret")]
     pub decoding_secret: String,
     pub use_base64_secret: bool,
+    pub key_id: String,
+    pub encoding_key_file: String,
+    pub decoding_key_file: String,
+    pub decoding_keys: Vec<HttpJwtDecodingKeyConfig>,
+}
+
+/// The additional key used only to validate the tokens, e.g. the previous key kept during the rotation.
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct HttpJwtDecodingKeyConfig {
+    pub key_id: String,
+    pub algorithm: String,
+    #[serde(deserialize_with = "deserialize_secret")]
+    pub secret: String,
+    pub key_file: String,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
@@ -68,15 +86,11 @@ pub struct HttpTlsConfig {
 
 impl HttpJwtConfig {
     pub fn get_algorithm(&self) -> Result<Algorithm, IggyError> {
-        match self.algorithm.as_str() {
-            "HS256" => Ok(Algorithm::HS256),
-            "HS384" => Ok(Algorithm::HS384),
-            "HS512" => Ok(Algorithm::HS512),
-            "RS256" => Ok(Algorithm::RS256),
-            "RS384" => Ok(Algorithm::RS384),
-            "RS512" => Ok(Algorithm::RS512),
-            _ => Err(IggyError::InvalidJwtAlgorithm(self.algorithm.clone())),
-        }
+        parse_algorithm(&self.algorithm)
+    }
+
+    pub fn get_key_id(&self) -> Option<String> {
+        map_key_id(&self.key_id)
     }
 
     pub fn get_decoding_secret(&self) -> JwtSecret {
@@ -88,19 +102,44 @@ impl HttpJwtConfig {
     }
 
     pub fn get_decoding_key(&self) -> Result<DecodingKey, IggyError> {
-        if self.decoding_secret.is_empty() {
-            return Err(IggyError::InvalidJwtSecret);
-        }
+        self.create_decoding_key(
+            self.get_algorithm()?,
+            &self.decoding_secret,
+            &self.decoding_key_file,
+        )
+    }
 
-        Ok(match self.get_decoding_secret() {
-            JwtSecret::Default(ref secret) => DecodingKey::from_secret(secret.as_ref()),
-            JwtSecret::Base64(ref secret) => {
-                DecodingKey::from_base64_secret(secret).map_err(|_| IggyError::InvalidJwtSecret)?
-            }
-        })
+    /// Returns the additional decoding keys with their IDs and algorithms.
+    pub fn get_decoding_keys(
+        &self,
+    ) -> Result<Vec<(Option<String>, Algorithm, DecodingKey)>, IggyError> {
+        let mut keys = Vec::with_capacity(self.decoding_keys.len());
+        for key in &self.decoding_keys {
+            let algorithm = parse_algorithm(&key.algorithm)?;
+            let decoding_key = self.create_decoding_key(algorithm, &key.secret, &key.key_file)?;
+            keys.push((map_key_id(&key.key_id), algorithm, decoding_key));
+        }
+        Ok(keys)
     }
 
     pub fn get_encoding_key(&self) -> Result<EncodingKey, IggyError> {
+        let algorithm = self.get_algorithm()?;
+        if !is_hmac(algorithm) {
+            let pem = read_key_file(&self.encoding_key_file)?;
+            return match algorithm {
+                Algorithm::ES256 | Algorithm::ES384 => EncodingKey::from_ec_pem(&pem),
+                Algorithm::EdDSA => EncodingKey::from_ed_pem(&pem),
+                _ => EncodingKey::from_rsa_pem(&pem),
+            }
+            .map_err(|error| {
+                error!(
+                    "Invalid JWT encoding key file: {}, error: {error}",
+                    self.encoding_key_file
+                );
+                IggyError::InvalidJwtSecret
+            });
+        }
+
         if self.encoding_secret.is_empty() {
             return Err(IggyError::InvalidJwtSecret);
         }
@@ -113,6 +152,37 @@ impl HttpJwtConfig {
         })
     }
 
+    fn create_decoding_key(
+        &self,
+        algorithm: Algorithm,
+        secret: &str,
+        key_file: &str,
+    ) -> Result<DecodingKey, IggyError> {
+        if !is_hmac(algorithm) {
+            let pem = read_key_file(key_file)?;
+            return match algorithm {
+                Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(&pem),
+                Algorithm::EdDSA => DecodingKey::from_ed_pem(&pem),
+                _ => DecodingKey::from_rsa_pem(&pem),
+            }
+            .map_err(|error| {
+                error!("Invalid JWT decoding key file: {key_file}, error: {error}");
+                IggyError::InvalidJwtSecret
+            });
+        }
+
+        if secret.is_empty() {
+            return Err(IggyError::InvalidJwtSecret);
+        }
+
+        Ok(match self.get_secret(secret) {
+            JwtSecret::Default(ref secret) => DecodingKey::from_secret(secret.as_ref()),
+            JwtSecret::Base64(ref secret) => {
+                DecodingKey::from_base64_secret(secret).map_err(|_| IggyError::InvalidJwtSecret)?
+            }
+        })
+    }
+
     fn get_secret(&self, secret: &str) -> JwtSecret {
         if self.use_base64_secret {
             JwtSecret::Base64(secret.to_string())
@@ -121,3 +191,42 @@ impl HttpJwtConfig {
         }
     }
 }
+
+fn parse_algorithm(algorithm: &str) -> Result<Algorithm, IggyError> {
+    match algorithm {
+        "HS256" => Ok(Algorithm::HS256),
+        "HS384" => Ok(Algorithm::HS384),
+        "HS512" => Ok(Algorithm::HS512),
+        "RS256" => Ok(Algorithm::RS256),
+        "RS384" => Ok(Algorithm::RS384),
+        "RS512" => Ok(Algorithm::RS512),
+        "PS256" => Ok(Algorithm::PS256),
+        "PS384" => Ok(Algorithm::PS384),
+        "PS512" => Ok(Algorithm::PS512),
+        "ES256" => Ok(Algorithm::ES256),
+        "ES384" => Ok(Algorithm::ES384),
+        "EdDSA" => Ok(Algorithm::EdDSA),
+        _ => Err(IggyError::InvalidJwtAlgorithm(algorithm.to_string())),
+    }
+}
+
+fn is_hmac(algorithm: Algorithm) -> bool {
+    matches!(
+        algorithm,
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+    )
+}
+
+fn map_key_id(key_id: &str) -> Option<String> {
+    match key_id.is_empty() {
+        true => None,
+        false => Some(key_id.to_string()),
+    }
+}
+
+fn read_key_file(path: &str) -> Result<Vec<u8>, IggyError> {
+    std::fs::read(path).map_err(|error| {
+        error!("Cannot read JWT key file: {path}, error: {error}");
+        IggyError::InvalidJwtSecret
+    })
+}
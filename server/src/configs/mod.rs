@@ -4,9 +4,12 @@ pub mod system;
 pub mod http;
 pub mod quic;
 pub mod tcp;
+pub mod telemetry;
 
 pub mod config_provider;
 pub mod defaults;
 pub mod displays;
+pub mod reloader;
 pub mod resource_quota;
+pub mod secrets;
 pub mod validators;
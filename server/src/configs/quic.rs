@@ -1,3 +1,4 @@
+use crate::configs::server::{ClientCertificateConfig, ConnectionLimitsConfig};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use serde::{Deserialize, Serialize};
@@ -19,6 +20,8 @@ pub struct QuicConfig {
     #[serde_as(as = "DisplayFromStr")]
     pub max_idle_timeout: IggyDuration,
     pub certificate: QuicCertificateConfig,
+    pub client_certificate: ClientCertificateConfig,
+    pub limits: ConnectionLimitsConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
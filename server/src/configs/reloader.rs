@@ -0,0 +1,293 @@
+use crate::configs::config_provider::ConfigProvider;
+use crate::configs::server::ServerConfig;
+use crate::logging::LogLevelUpdater;
+use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use iggy::error::IggyError;
+use iggy::models::config_reload::ConfigReload;
+use iggy::utils::duration::IggyDuration;
+use std::fmt::{Debug, Formatter};
+use std::future;
+use std::sync::Arc;
+use tokio::sync::{watch, Mutex};
+use toml::Value;
+use tracing::{error, info, warn};
+
+/// The settings which can be changed at runtime, any other change requires the server restart.
+/// Besides these, the TLS certificates are always reloaded from their files, so that they can be rotated in place.
+const RELOADABLE_SETTINGS: &[&str] = &[
+    "system.logging.level",
+    "system.cache.size",
+    "message_cleaner.interval",
+    "message_saver.interval",
+    "personal_access_token.cleaner.interval",
+    "tcp.tls.certificate",
+    "tcp.tls.password",
+    "tcp.tls.cert_file",
+    "tcp.tls.key_file",
+    "quic.certificate.cert_file",
+    "quic.certificate.key_file",
+    "http.tls.cert_file",
+    "http.tls.key_file",
+    "http.cors.enabled",
+    "http.cors.allowed_methods",
+    "http.cors.allowed_origins",
+    "http.cors.allowed_headers",
+    "http.cors.exposed_headers",
+    "http.cors.allow_credentials",
+    "http.cors.allow_private_network",
+];
+
+/// Reloads the server configuration on demand (SIGHUP or the admin command),
+/// validates it and applies the settings which are safe to change at runtime.
+/// The components depending on such settings (background tasks, TLS listeners) subscribe to the config updates.
+pub struct ConfigReloader {
+    config_provider: Box<dyn ConfigProvider>,
+    config: Mutex<ServerConfig>,
+    updates: watch::Sender<Arc<ServerConfig>>,
+    log_level_updater: Option<LogLevelUpdater>,
+}
+
+impl Debug for ConfigReloader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfigReloader").finish()
+    }
+}
+
+impl ConfigReloader {
+    pub fn new(
+        config_provider: Box<dyn ConfigProvider>,
+        config: ServerConfig,
+        log_level_updater: Option<LogLevelUpdater>,
+    ) -> Self {
+        let (updates, _) = watch::channel(Arc::new(config.clone()));
+        Self {
+            config_provider,
+            config: Mutex::new(config),
+            updates,
+            log_level_updater,
+        }
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<Arc<ServerConfig>> {
+        self.updates.subscribe()
+    }
+
+    pub async fn reload(&self) -> Result<ConfigReload, IggyError> {
+        let mut config = self.config.lock().await;
+        let reloaded_config = ServerConfig::load(self.config_provider.as_ref())
+            .await
+            .map_err(|error| IggyError::CannotReloadConfiguration(error.to_string()))?;
+        let current = to_toml(&config)?;
+        let reloaded = to_toml(&reloaded_config)?;
+        let mut changes = Vec::new();
+        collect_changes("", &current, &reloaded, &mut changes);
+        let (applied_changes, restart_required_changes): (Vec<String>, Vec<String>) = changes
+            .into_iter()
+            .partition(|change| RELOADABLE_SETTINGS.contains(&change.as_str()));
+
+        // Only the reloadable settings are applied, the rest of the running configuration remains unchanged.
+        let mut updated = current;
+        for change in &applied_changes {
+            set_value(&mut updated, change, get_value(&reloaded, change).cloned());
+        }
+        let updated_config: ServerConfig =
+            updated.try_into().map_err(|error: toml::de::Error| {
+                IggyError::CannotReloadConfiguration(error.to_string())
+            })?;
+
+        self.apply(&applied_changes, &updated_config);
+        *config = updated_config.clone();
+        self.updates.send_replace(Arc::new(updated_config));
+
+        if !applied_changes.is_empty() {
+            info!(
+                "Applied configuration changes: {}.",
+                applied_changes.join(", ")
+            );
+        }
+        if !restart_required_changes.is_empty() {
+            warn!(
+                "Configuration changes requiring the server restart: {}.",
+                restart_required_changes.join(", ")
+            );
+        }
+        info!("Configuration reloaded.");
+
+        Ok(ConfigReload {
+            applied_changes,
+            restart_required_changes,
+        })
+    }
+
+    fn apply(&self, changes: &[String], config: &ServerConfig) {
+        if changes
+            .iter()
+            .any(|change| change == "system.logging.level")
+        {
+            if let Some(log_level_updater) = &self.log_level_updater {
+                if let Err(error) = log_level_updater.update(&config.system.logging) {
+                    error!("Failed to change the log level. Error: {}", error);
+                }
+            }
+        }
+
+        if changes.iter().any(|change| change == "system.cache.size") {
+            if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
+                memory_tracker.set_limit(config.system.cache.size.clone());
+            }
+        }
+    }
+}
+
+/// Waits for the reloaded configuration, never completes if the reload is not available.
+pub async fn wait_for_config_update(
+    updates: &mut Option<watch::Receiver<Arc<ServerConfig>>>,
+) -> Arc<ServerConfig> {
+    if updates.is_none() {
+        return future::pending().await;
+    }
+
+    let updates = updates.as_mut().unwrap();
+    if updates.changed().await.is_err() {
+        return future::pending().await;
+    }
+
+    updates.borrow_and_update().clone()
+}
+
+/// Waits for the reloaded configuration with the interval (returned by the provided function) different from the current one.
+pub async fn wait_for_interval_update(
+    updates: &mut Option<watch::Receiver<Arc<ServerConfig>>>,
+    current_interval: IggyDuration,
+    get_interval: fn(&ServerConfig) -> IggyDuration,
+) -> IggyDuration {
+    loop {
+        let config = wait_for_config_update(updates).await;
+        let interval = get_interval(&config);
+        if interval.as_micros() != current_interval.as_micros() {
+            return interval;
+        }
+    }
+}
+
+fn to_toml(config: &ServerConfig) -> Result<Value, IggyError> {
+    Value::try_from(config).map_err(|error| IggyError::CannotReloadConfiguration(error.to_string()))
+}
+
+fn collect_changes(path: &str, current: &Value, reloaded: &Value, changes: &mut Vec<String>) {
+    match (current, reloaded) {
+        (Value::Table(current), Value::Table(reloaded)) => {
+            for (key, value) in current {
+                let key_path = match path.is_empty() {
+                    true => key.clone(),
+                    false => format!("{path}.{key}"),
+                };
+                match reloaded.get(key) {
+                    Some(reloaded_value) => {
+                        collect_changes(&key_path, value, reloaded_value, changes)
+                    }
+                    None => changes.push(key_path),
+                }
+            }
+            for key in reloaded.keys() {
+                if !current.contains_key(key) {
+                    changes.push(match path.is_empty() {
+                        true => key.clone(),
+                        false => format!("{path}.{key}"),
+                    });
+                }
+            }
+        }
+        (current, reloaded) => {
+            if current != reloaded {
+                changes.push(path.to_string());
+            }
+        }
+    }
+}
+
+fn get_value<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |value, key| value.as_table()?.get(key))
+}
+
+fn set_value(value: &mut Value, path: &str, new_value: Option<Value>) {
+    let mut keys = path.split('.').collect::<Vec<_>>();
+    let last_key = keys.pop().unwrap();
+    let mut table = value.as_table_mut();
+    for key in keys {
+        table = table.and_then(|table| table.get_mut(key)?.as_table_mut());
+    }
+
+    if let Some(table) = table {
+        match new_value {
+            Some(new_value) => table.insert(last_key.to_string(), new_value),
+            None => table.remove(last_key),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changed_settings_should_be_collected_as_paths() {
+        let current: Value = toml::from_str(
+            r#"
+            [system.logging]
+            level = "info"
+            path = "logs"
+
+            [tcp]
+            address = "0.0.0.0:8090"
+            "#,
+        )
+        .unwrap();
+        let reloaded: Value = toml::from_str(
+            r#"
+            [system.logging]
+            level = "debug"
+            path = "logs"
+
+            [tcp]
+            address = "0.0.0.0:8091"
+            "#,
+        )
+        .unwrap();
+
+        let mut changes = Vec::new();
+        collect_changes("", &current, &reloaded, &mut changes);
+
+        assert_eq!(changes, vec!["system.logging.level", "tcp.address"]);
+    }
+
+    #[test]
+    fn only_reloadable_settings_should_be_applied() {
+        let config = ServerConfig::default();
+        let mut reloaded = to_toml(&config).unwrap();
+        set_value(
+            &mut reloaded,
+            "system.logging.level",
+            Some(Value::String("debug".to_string())),
+        );
+        set_value(
+            &mut reloaded,
+            "tcp.address",
+            Some(Value::String("127.0.0.1:1".to_string())),
+        );
+
+        let mut changes = Vec::new();
+        collect_changes("", &to_toml(&config).unwrap(), &reloaded, &mut changes);
+        let (applied_changes, restart_required_changes): (Vec<String>, Vec<String>) = changes
+            .into_iter()
+            .partition(|change| RELOADABLE_SETTINGS.contains(&change.as_str()));
+
+        assert_eq!(applied_changes, vec!["system.logging.level"]);
+        assert_eq!(restart_required_changes, vec!["tcp.address"]);
+        assert_eq!(
+            get_value(&reloaded, "system.logging.level"),
+            Some(&Value::String("debug".to_string()))
+        );
+    }
+}
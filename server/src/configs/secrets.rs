@@ -0,0 +1,92 @@
+use serde::{Deserialize, Deserializer};
+use std::{env, fs};
+
+const FILE_PREFIX: &str = "file:";
+const ENV_PREFIX: &str = "env:";
+const REDACTED: &str = "<redacted>";
+
+/// Resolves the secret value, which can be provided either as a literal string,
+/// or as a reference to the file (`file:/run/secrets/jwt`) or the environment variable (`env:IGGY_JWT_SECRET`).
+/// The trailing line break is removed from the file content.
+pub fn resolve_secret(value: &str) -> Result<String, String> {
+    if let Some(path) = value.strip_prefix(FILE_PREFIX) {
+        return match fs::read_to_string(path) {
+            Ok(secret) => Ok(secret.trim_end_matches(['\r', '\n']).to_string()),
+            Err(error) => Err(format!("Cannot read secret from file: '{path}'. {error}")),
+        };
+    }
+
+    if let Some(name) = value.strip_prefix(ENV_PREFIX) {
+        return match env::var(name) {
+            Ok(secret) => Ok(secret),
+            Err(error) => Err(format!(
+                "Cannot read secret from environment variable: '{name}'. {error}"
+            )),
+        };
+    }
+
+    Ok(value.to_string())
+}
+
+/// Deserializes the secret field, resolving its value at the time of loading the configuration.
+pub fn deserialize_secret<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    resolve_secret(&value).map_err(serde::de::Error::custom)
+}
+
+/// Returns the placeholder in place of the non-empty secret, so that it can be safely logged.
+pub fn redact(secret: &str) -> &str {
+    if secret.is_empty() {
+        return secret;
+    }
+
+    REDACTED
+}
+
+/// Determines whether the configuration entry (e.g. the overriding environment variable) might hold a secret.
+pub fn is_secret_entry(name: &str) -> bool {
+    let name = name.to_uppercase();
+    ["SECRET", "PASSWORD", "KEY"]
+        .iter()
+        .any(|part| name.contains(part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_secret_should_be_resolved_as_is() {
+        assert_eq!(resolve_secret("secret").unwrap(), "secret");
+        assert_eq!(resolve_secret("").unwrap(), "");
+    }
+
+    #[test]
+    fn secret_should_be_resolved_from_environment_variable() {
+        env::set_var("IGGY_TEST_SECRET_REF", "env_secret");
+        assert_eq!(
+            resolve_secret("env:IGGY_TEST_SECRET_REF").unwrap(),
+            "env_secret"
+        );
+        assert!(resolve_secret("env:IGGY_TEST_MISSING_SECRET_REF").is_err());
+    }
+
+    #[test]
+    fn secret_should_be_resolved_from_file_without_trailing_line_break() {
+        let path = env::temp_dir().join(format!("iggy_test_secret_{}", std::process::id()));
+        fs::write(&path, "file_secret\n").unwrap();
+        let secret = resolve_secret(&format!("file:{}", path.display()));
+        fs::remove_file(&path).unwrap();
+        assert_eq!(secret.unwrap(), "file_secret");
+        assert!(resolve_secret("file:/missing/iggy_secret").is_err());
+    }
+
+    #[test]
+    fn non_empty_secret_should_be_redacted() {
+        assert_eq!(redact("secret"), REDACTED);
+        assert_eq!(redact(""), "");
+    }
+}
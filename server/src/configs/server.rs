@@ -3,14 +3,22 @@ use crate::configs::http::HttpConfig;
 use crate::configs::quic::QuicConfig;
 use crate::configs::system::SystemConfig;
 use crate::configs::tcp::TcpConfig;
+use crate::configs::telemetry::TelemetryConfig;
 use crate::server_error::ServerError;
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+pub const COMMON_NAME_FIELD: &str = "common_name";
+pub const DNS_NAME_FIELD: &str = "dns_name";
+pub const EMAIL_FIELD: &str = "email";
+pub const URI_FIELD: &str = "uri";
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ServerConfig {
     pub message_cleaner: MessageCleanerConfig,
@@ -20,6 +28,30 @@ pub struct ServerConfig {
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
     pub http: HttpConfig,
+    pub telemetry: TelemetryConfig,
+    pub shutdown: ShutdownConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClientCertificateConfig {
+    pub enabled: bool,
+    pub required: bool,
+    pub ca_file: String,
+    pub username_field: String,
+    pub usernames: HashMap<String, String>,
+    pub allow_root: bool,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct ConnectionLimitsConfig {
+    pub max_frame_size: IggyByteSize,
+    #[serde_as(as = "DisplayFromStr")]
+    pub read_timeout: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub idle_timeout: IggyDuration,
+    pub max_connections_per_ip: u32,
+    pub max_concurrent_requests: u32,
 }
 
 #[serde_as]
@@ -53,6 +85,15 @@ pub struct PersonalAccessTokenCleanerConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct ShutdownConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub grace_period: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+}
+
 impl ServerConfig {
     pub async fn load(config_provider: &dyn ConfigProvider) -> Result<ServerConfig, ServerError> {
         let server_config = config_provider.load_config().await?;
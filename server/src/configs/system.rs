@@ -1,11 +1,15 @@
 use crate::configs::resource_quota::MemoryResourceQuota;
+use crate::configs::secrets::deserialize_secret;
+use iggy::error::IggyError;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::crypto::{Aes256GcmEncryptor, KeyRingEncryptor};
 use iggy::{
     compression::compression_algorithm::CompressionAlgorithm, utils::duration::IggyDuration,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::collections::HashMap;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SystemConfig {
@@ -22,6 +26,13 @@ pub struct SystemConfig {
     pub encryption: EncryptionConfig,
     pub compression: CompressionConfig,
     pub message_deduplication: MessageDeduplicationConfig,
+    pub quotas: QuotasConfig,
+    pub audit: AuditConfig,
+    pub oidc: OidcConfig,
+    pub password_policy: PasswordPolicyConfig,
+    pub login_lockout: LoginLockoutConfig,
+    pub health: HealthConfig,
+    pub transaction: TransactionConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -64,10 +75,30 @@ pub struct RetentionPolicyConfig {
     pub max_topic_size: IggyByteSize,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct EncryptionConfig {
     pub enabled: bool,
+    #[serde(deserialize_with = "deserialize_secret")]
     pub key: String,
+    pub active_key_id: u32,
+    pub keys: Vec<EncryptionKeyConfig>,
+    pub per_stream_keys: bool,
+    pub re_encryption: ReEncryptionConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EncryptionKeyConfig {
+    pub id: u32,
+    #[serde(deserialize_with = "deserialize_secret")]
+    pub key: String,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReEncryptionConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -97,6 +128,79 @@ pub struct MessageDeduplicationConfig {
     pub expiry: IggyDuration,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct QuotasConfig {
+    pub enabled: bool,
+    pub user: QuotaLimitsConfig,
+    pub client: QuotaLimitsConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct QuotaLimitsConfig {
+    pub produce_bytes_per_second: IggyByteSize,
+    pub consume_bytes_per_second: IggyByteSize,
+    pub requests_per_second: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuditConfig {
+    pub enabled: bool,
+    pub path: String,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OidcConfig {
+    pub enabled: bool,
+    pub binary_login_enabled: bool,
+    pub jwks: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub jwks_refresh_interval: IggyDuration,
+    pub valid_issuers: Vec<String>,
+    pub valid_audiences: Vec<String>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub clock_skew: IggyDuration,
+    pub username_claim: String,
+    pub roles_claim: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PasswordPolicyConfig {
+    pub min_length: u32,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    pub require_special_character: bool,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LoginLockoutConfig {
+    pub enabled: bool,
+    pub max_failed_attempts: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub lockout_duration: IggyDuration,
+    pub ip_failed_attempts_threshold: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub ip_backoff: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub ip_max_backoff: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TransactionConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub check_interval: IggyDuration,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HealthConfig {
+    pub min_free_disk_space: IggyByteSize,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SegmentConfig {
     pub size: IggyByteSize,
@@ -104,6 +208,28 @@ pub struct SegmentConfig {
     pub cache_time_indexes: bool,
 }
 
+impl EncryptionConfig {
+    /// Creates the encryptor based on the configured keys, where the single `key` is used as the legacy one.
+    pub fn create_encryptor(&self) -> Result<KeyRingEncryptor, IggyError> {
+        let legacy_key = match self.key.is_empty() {
+            true => None,
+            false => Some(Aes256GcmEncryptor::from_base64_key(&self.key)?),
+        };
+        let mut keys = HashMap::with_capacity(self.keys.len());
+        for key in &self.keys {
+            if key.id == 0 || keys.contains_key(&key.id) {
+                return Err(IggyError::InvalidEncryptionKey);
+            }
+            keys.insert(key.id, Aes256GcmEncryptor::from_base64_key(&key.key)?);
+        }
+        let active_key_id = match self.active_key_id {
+            0 => None,
+            key_id => Some(key_id),
+        };
+        KeyRingEncryptor::new(active_key_id, keys, legacy_key)
+    }
+}
+
 impl SystemConfig {
     pub fn get_system_path(&self) -> String {
         self.path.to_string()
@@ -117,6 +243,10 @@ impl SystemConfig {
         format!("{}/{}", self.get_system_path(), self.runtime.path)
     }
 
+    pub fn get_audit_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.audit.path)
+    }
+
     pub fn get_streams_path(&self) -> String {
         format!("{}/{}", self.get_system_path(), self.stream.path)
     }
@@ -1,3 +1,5 @@
+use crate::configs::secrets::deserialize_secret;
+use crate::configs::server::{ClientCertificateConfig, ConnectionLimitsConfig};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -5,11 +7,16 @@ pub struct TcpConfig {
     pub enabled: bool,
     pub address: String,
     pub tls: TcpTlsConfig,
+    pub limits: ConnectionLimitsConfig,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TcpTlsConfig {
     pub enabled: bool,
     pub certificate: String,
+    #[serde(deserialize_with = "deserialize_secret")]
     pub password: String,
+    pub cert_file: String,
+    pub key_file: String,
+    pub client_certificate: ClientCertificateConfig,
 }
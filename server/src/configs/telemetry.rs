@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+pub const OTLP_EXPORTER: &str = "otlp";
+pub const STDOUT_EXPORTER: &str = "stdout";
+pub const FILE_EXPORTER: &str = "file";
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TelemetryConfig {
+    pub enabled: bool,
+    pub service_name: String,
+    pub exporter: String,
+    pub endpoint: String,
+    pub path: String,
+    pub sampling_ratio: f64,
+}
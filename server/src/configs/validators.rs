@@ -2,11 +2,19 @@ extern crate sysinfo;
 
 use super::server::{MessageCleanerConfig, MessageSaverConfig};
 use super::system::CompressionConfig;
-use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
-use crate::configs::system::{CacheConfig, RetentionPolicyConfig, SegmentConfig};
+use crate::configs::server::{
+    ClientCertificateConfig, ConnectionLimitsConfig, PersonalAccessTokenConfig, ServerConfig,
+    ShutdownConfig, COMMON_NAME_FIELD, DNS_NAME_FIELD, EMAIL_FIELD, URI_FIELD,
+};
+use crate::configs::system::{
+    CacheConfig, EncryptionConfig, LoginLockoutConfig, OidcConfig, RetentionPolicyConfig,
+    SegmentConfig, TransactionConfig,
+};
+use crate::configs::telemetry::{TelemetryConfig, FILE_EXPORTER, OTLP_EXPORTER, STDOUT_EXPORTER};
 use crate::server_error::ServerError;
 use crate::streaming::segments::segment;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::tcp::pipelining::REQUEST_ID_FLAG;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::validatable::Validatable;
 use sysinfo::System;
@@ -18,12 +26,56 @@ impl Validatable<ServerError> for ServerConfig {
         self.system.cache.validate()?;
         self.system.retention_policy.validate()?;
         self.system.compression.validate()?;
+        self.system.encryption.validate()?;
+        self.system.oidc.validate()?;
+        self.system.login_lockout.validate()?;
+        self.system.transaction.validate()?;
         self.personal_access_token.validate()?;
+        self.telemetry.validate()?;
+        self.shutdown.validate()?;
+        self.tcp.limits.validate()?;
+        self.tcp.tls.client_certificate.validate()?;
+        self.quic.limits.validate()?;
+        self.quic.client_certificate.validate()?;
 
         Ok(())
     }
 }
 
+impl Validatable<ServerError> for TelemetryConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.service_name.is_empty() {
+            error!("Telemetry service name cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if !(0.0..=1.0).contains(&self.sampling_ratio) {
+            error!("Telemetry sampling ratio must be between 0 and 1.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        match self.exporter.as_str() {
+            OTLP_EXPORTER if self.endpoint.is_empty() => {
+                error!("Telemetry OTLP exporter endpoint cannot be empty.");
+                Err(ServerError::InvalidConfiguration)
+            }
+            FILE_EXPORTER if self.path.is_empty() => {
+                error!("Telemetry file exporter path cannot be empty.");
+                Err(ServerError::InvalidConfiguration)
+            }
+            OTLP_EXPORTER | STDOUT_EXPORTER | FILE_EXPORTER => Ok(()),
+            exporter => {
+                error!("Telemetry exporter: {exporter} is not supported.");
+                Err(ServerError::InvalidConfiguration)
+            }
+        }
+    }
+}
+
 impl Validatable<ServerError> for CompressionConfig {
     fn validate(&self) -> Result<(), ServerError> {
         let compression_alg = &self.default_algorithm;
@@ -112,6 +164,71 @@ impl Validatable<ServerError> for MessageSaverConfig {
     }
 }
 
+impl Validatable<ServerError> for ShutdownConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.timeout.is_zero() {
+            error!("Shutdown timeout cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.grace_period.as_micros() > self.timeout.as_micros() {
+            error!(
+                "Shutdown grace period: {} cannot be greater than the shutdown timeout: {}.",
+                self.grace_period, self.timeout
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for ClientCertificateConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if ![COMMON_NAME_FIELD, DNS_NAME_FIELD, EMAIL_FIELD, URI_FIELD]
+            .contains(&self.username_field.as_str())
+        {
+            error!(
+                "Client certificate username field: {} is not supported.",
+                self.username_field
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for ConnectionLimitsConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.max_frame_size.as_bytes_u64() == 0 {
+            error!("Max frame size cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        // The most significant bit of the frame length marks the pipelined request.
+        let max_frame_size = (REQUEST_ID_FLAG - 1) as u64;
+        if self.max_frame_size.as_bytes_u64() > max_frame_size {
+            error!(
+                "Max frame size: {} cannot be greater than: {max_frame_size} bytes.",
+                self.max_frame_size
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.max_concurrent_requests == 0 {
+            error!("Max concurrent requests cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for MessageCleanerConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.enabled && self.interval.is_zero() {
@@ -140,3 +257,84 @@ impl Validatable<ServerError> for PersonalAccessTokenConfig {
         Ok(())
     }
 }
+
+impl Validatable<ServerError> for EncryptionConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if let Err(error) = self.create_encryptor() {
+            error!("Invalid encryption keys configuration, the active key must be configured, and each key must have the unique ID greater than 0. Error: {error}");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for OidcConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.jwks.is_empty() {
+            error!("OIDC JWKS location cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.valid_issuers.is_empty() {
+            error!("OIDC valid issuers cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.valid_audiences.is_empty() {
+            error!("OIDC valid audiences cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.username_claim.is_empty() {
+            error!("OIDC username claim cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for LoginLockoutConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.max_failed_attempts == 0 {
+            error!("Login lockout max failed attempts must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.ip_backoff.as_micros() > self.ip_max_backoff.as_micros() {
+            error!("Login lockout IP backoff cannot be greater than the max IP backoff.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for TransactionConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.timeout.is_zero() {
+            error!("Transaction timeout cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.check_interval.is_zero() {
+            error!("Transaction check interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
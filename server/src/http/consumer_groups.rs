@@ -2,7 +2,6 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
-use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::get;
@@ -36,7 +35,7 @@ async fn get_consumer_group(
     let consumer_group_id = Identifier::from_str_value(&consumer_group_id)?;
     let system = state.system.read();
     let consumer_group = system.get_consumer_group(
-        &Session::stateless(identity.user_id, identity.ip_address),
+        &identity.session(),
         &stream_id,
         &topic_id,
         &consumer_group_id,
@@ -54,11 +53,7 @@ async fn get_consumer_groups(
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
     let system = state.system.read();
-    let consumer_groups = system.get_consumer_groups(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-        &topic_id,
-    )?;
+    let consumer_groups = system.get_consumer_groups(&identity.session(), &stream_id, &topic_id)?;
     let consumer_groups = mapper::map_consumer_groups(&consumer_groups).await;
     Ok(Json(consumer_groups))
 }
@@ -75,7 +70,7 @@ async fn create_consumer_group(
     let mut system = state.system.write();
     system
         .create_consumer_group(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.stream_id,
             &command.topic_id,
             command.consumer_group_id,
@@ -96,7 +91,7 @@ async fn delete_consumer_group(
     let mut system = state.system.write();
     system
         .delete_consumer_group(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &stream_id,
             &topic_id,
             &consumer_group_id,
@@ -2,7 +2,6 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::AppState;
 use crate::streaming::polling_consumer::PollingConsumer;
-use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::routing::get;
@@ -37,7 +36,7 @@ async fn get_consumer_offset(
     let system = state.system.read();
     let offset = system
         .get_consumer_offset(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             consumer,
             &query.stream_id,
             &query.topic_id,
@@ -60,7 +59,7 @@ async fn store_consumer_offset(
     let system = state.system.read();
     system
         .store_consumer_offset(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             consumer,
             &command.stream_id,
             &command.topic_id,
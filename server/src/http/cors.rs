@@ -0,0 +1,115 @@
+use crate::configs::http::HttpCorsConfig;
+use crate::configs::reloader::wait_for_config_update;
+use crate::streaming::systems::system::SharedSystem;
+use axum::body::Body;
+use axum::{
+    extract::State,
+    http::{HeaderName, HeaderValue, Method, Request},
+    middleware::Next,
+    response::Response,
+};
+use std::sync::{Arc, RwLock};
+use tower::ServiceExt;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower_layer::Layer;
+use tracing::{error, info};
+
+/// The CORS layer currently in use, `None` if CORS is disabled. It's replaced when the configuration is reloaded.
+pub type SharedCors = Arc<RwLock<Option<CorsLayer>>>;
+
+pub async fn cors(
+    State(cors_layer): State<SharedCors>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let cors_layer = cors_layer.read().unwrap().clone();
+    match cors_layer {
+        Some(cors_layer) => cors_layer
+            .layer(next)
+            .oneshot(request)
+            .await
+            .unwrap_or_else(|error| match error {}),
+        None => next.run(request).await,
+    }
+}
+
+pub fn start_cors_reloader(system: &SharedSystem, cors_layer: SharedCors) {
+    let mut config_updates = system.read().subscribe_config_updates();
+    if config_updates.is_none() {
+        return;
+    }
+
+    tokio::task::spawn(async move {
+        loop {
+            let config = wait_for_config_update(&mut config_updates).await;
+            match build_cors_layer(&config.http.cors) {
+                Ok(layer) => {
+                    *cors_layer.write().unwrap() = layer;
+                    info!("HTTP CORS configuration has been reloaded.");
+                }
+                Err(error) => error!(
+                    "Unable to reload HTTP CORS configuration, the previous one remains in use. Error: {error}"
+                ),
+            }
+        }
+    });
+}
+
+pub fn build_cors_layer(config: &HttpCorsConfig) -> Result<Option<CorsLayer>, String> {
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    let allowed_origins = match &config.allowed_origins {
+        origins if origins.is_empty() => AllowOrigin::default(),
+        origins if origins.first().unwrap() == "*" => AllowOrigin::any(),
+        origins => AllowOrigin::list(
+            origins
+                .iter()
+                .map(|s| {
+                    s.parse::<HeaderValue>()
+                        .map_err(|_| format!("Invalid allowed origin: {s}"))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+    };
+
+    let allowed_headers = parse_headers(&config.allowed_headers)?;
+    let exposed_headers = parse_headers(&config.exposed_headers)?;
+    let allowed_methods = config
+        .allowed_methods
+        .iter()
+        .map(|s| match s.to_uppercase().as_str() {
+            "GET" => Ok(Method::GET),
+            "POST" => Ok(Method::POST),
+            "PUT" => Ok(Method::PUT),
+            "DELETE" => Ok(Method::DELETE),
+            "HEAD" => Ok(Method::HEAD),
+            "OPTIONS" => Ok(Method::OPTIONS),
+            "CONNECT" => Ok(Method::CONNECT),
+            "PATCH" => Ok(Method::PATCH),
+            "TRACE" => Ok(Method::TRACE),
+            _ => Err(format!("Invalid HTTP method: {s}")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Some(
+        CorsLayer::new()
+            .allow_methods(allowed_methods)
+            .allow_origin(allowed_origins)
+            .allow_headers(allowed_headers)
+            .expose_headers(exposed_headers)
+            .allow_credentials(config.allow_credentials)
+            .allow_private_network(config.allow_private_network),
+    ))
+}
+
+fn parse_headers(headers: &[String]) -> Result<Vec<HeaderName>, String> {
+    headers
+        .iter()
+        .map(|s| {
+            s.parse::<HeaderName>()
+                .map_err(|_| format!("Invalid header: {s}"))
+        })
+        .collect()
+}
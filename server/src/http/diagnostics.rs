@@ -1,21 +1,32 @@
-use crate::http::shared::RequestDetails;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::streaming::utils::random_id;
 use axum::body::Body;
 use axum::{
-    extract::ConnectInfo,
-    http::{Request, StatusCode},
+    extract::{ConnectInfo, MatchedPath, State},
+    http::{HeaderMap, Request, StatusCode},
     middleware::Next,
     response::Response,
 };
+use opentelemetry::global;
+use opentelemetry::propagation::Extractor;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use tokio::time::Instant;
-use tracing::{debug, error};
+use tracing::{debug, error, field, info_span, Instrument};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 pub async fn request_diagnostics(
+    State(state): State<Arc<AppState>>,
     ConnectInfo(ip_address): ConnectInfo<SocketAddr>,
     mut request: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
+    // Tracks the request until the response is returned, so that the graceful shutdown can wait for it.
+    let in_flight_request = state.system.read().shutdown_signal.start_request();
+    if in_flight_request.is_none() {
+        debug!("Rejected the request, as the server is shutting down, IP address: {ip_address}.");
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
     let request_id = random_id::get_ulid();
     let path_and_query = request
         .uri()
@@ -31,11 +42,33 @@ pub async fn request_diagnostics(
         request_id,
         ip_address,
     });
+    // The matched route (e.g. `/streams/:stream_id`) is used as the span name, so that the names have a bounded set of values.
+    let name = match request.extensions().get::<MatchedPath>() {
+        Some(path) => format!("{} {}", request.method(), path.as_str()),
+        None => format!("{} unmatched", request.method()),
+    };
+    // The span is exported as the OpenTelemetry one, if the telemetry is enabled.
+    let span = info_span!(
+        "request",
+        otel.name = name,
+        otel.kind = "server",
+        otel.status_code = field::Empty,
+        http.method = request.method().as_str(),
+        http.target = request.uri().path(),
+        http.status_code = field::Empty,
+    );
+    // Continues the trace of the caller, if the request carries the W3C trace context headers.
+    let context = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeadersExtractor(request.headers()))
+    });
+    span.set_parent(context);
     let now = Instant::now();
-    let result = Ok(next.run(request).await);
+    let result = Ok(next.run(request).instrument(span.clone()).await);
     if let Ok(response) = &result {
         let status = response.status();
+        span.record("http.status_code", status.as_u16());
         if status >= StatusCode::BAD_REQUEST {
+            span.record("otel.status_code", "ERROR");
             error!("Returning an invalid status code: {status}, IP address: {ip_address}, request ID: {request_id}");
         }
     }
@@ -46,3 +79,15 @@ pub async fn request_diagnostics(
     );
     result
 }
+
+struct HeadersExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeadersExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key)?.to_str().ok()
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
@@ -1,3 +1,4 @@
+use axum::http::header::RETRY_AFTER;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
@@ -23,6 +24,17 @@ pub struct ErrorResponse {
 impl IntoResponse for CustomError {
     fn into_response(self) -> Response {
         match self {
+            CustomError::Error(IggyError::QuotaExceeded(retry_after)) => {
+                let error = IggyError::QuotaExceeded(retry_after);
+                // The Retry-After header is expressed in seconds, so it's rounded up.
+                let retry_after = retry_after.div_ceil(1000).to_string();
+                (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(RETRY_AFTER, retry_after)],
+                    Json(ErrorResponse::from_error(error)),
+                )
+                    .into_response()
+            }
             CustomError::Error(error) => {
                 error!("There was an error: {error}");
                 let status_code = match error {
@@ -36,6 +48,7 @@ impl IntoResponse for CustomError {
                     IggyError::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::CannotLoadResource(_) => StatusCode::NOT_FOUND,
                     IggyError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::RoleNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::IoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::WriteError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::CannotParseInt(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -43,12 +56,12 @@ impl IntoResponse for CustomError {
                     IggyError::CannotParseUtf8(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::Unauthenticated => StatusCode::UNAUTHORIZED,
                     IggyError::Unauthorized => StatusCode::FORBIDDEN,
+                    IggyError::TooManyLoginAttempts(_) => StatusCode::TOO_MANY_REQUESTS,
                     _ => StatusCode::BAD_REQUEST,
                 };
-                (status_code, Json(ErrorResponse::from_error(error)))
+                (status_code, Json(ErrorResponse::from_error(error))).into_response()
             }
         }
-        .into_response()
     }
 }
 
@@ -80,6 +93,8 @@ impl ErrorResponse {
                 IggyError::ConsumerGroupNameAlreadyExists(_, _) => Some("name".to_string()),
                 IggyError::UserAlreadyExists => Some("username".to_string()),
                 IggyError::PersonalAccessTokenAlreadyExists(_, _) => Some("name".to_string()),
+                IggyError::InvalidRoleName => Some("name".to_string()),
+                IggyError::RoleAlreadyExists(_) => Some("name".to_string()),
                 _ => None,
             },
         }
@@ -1,4 +1,6 @@
-use crate::configs::http::{HttpConfig, HttpCorsConfig};
+use crate::configs::http::HttpConfig;
+use crate::configs::reloader::wait_for_config_update;
+use crate::http::cors::{build_cors_layer, cors, start_cors_reloader};
 use crate::http::diagnostics::request_diagnostics;
 use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
@@ -6,14 +8,14 @@ use crate::http::jwt::middleware::jwt_auth;
 use crate::http::metrics::metrics;
 use crate::http::shared::AppState;
 use crate::http::*;
+use crate::streaming::shutdown::wait_for_shutdown;
 use crate::streaming::systems::system::SharedSystem;
-use axum::http::Method;
 use axum::{middleware, Router};
 use axum_server::tls_rustls::RustlsConfig;
+use axum_server::Handle;
 use std::net::SocketAddr;
 use std::path::PathBuf;
-use std::sync::Arc;
-use tower_http::cors::{AllowOrigin, CorsLayer};
+use std::sync::{Arc, RwLock};
 use tracing::{error, info};
 
 /// Starts the HTTP API server.
@@ -30,6 +32,7 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(system::router(app_state.clone(), &config.metrics))
         .merge(personal_access_tokens::router(app_state.clone()))
         .merge(users::router(app_state.clone()))
+        .merge(roles::router(app_state.clone()))
         .merge(streams::router(app_state.clone()))
         .merge(topics::router(app_state.clone()))
         .merge(consumer_groups::router(app_state.clone()))
@@ -38,16 +41,23 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(messages::router(app_state.clone()))
         .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
 
-    if config.cors.enabled {
-        app = app.layer(configure_cors(config.cors));
-    }
+    // The CORS layer is always installed, so that CORS can also be enabled or changed by the configuration reload.
+    let cors_layer = build_cors_layer(&config.cors)
+        .unwrap_or_else(|error| panic!("Invalid HTTP CORS configuration. Error: {error}"));
+    let cors_layer = Arc::new(RwLock::new(cors_layer));
+    start_cors_reloader(&app_state.system, cors_layer.clone());
+    app = app.layer(middleware::from_fn_with_state(cors_layer, cors));
 
     if config.metrics.enabled {
         app = app.layer(middleware::from_fn_with_state(app_state.clone(), metrics));
     }
 
     start_expired_tokens_cleaner(app_state.clone());
-    app = app.layer(middleware::from_fn(request_diagnostics));
+    app = app.layer(middleware::from_fn_with_state(
+        app_state.clone(),
+        request_diagnostics,
+    ));
+    let mut shutdown = app_state.system.read().shutdown_signal.subscribe();
 
     if !config.tls.enabled {
         let listener = tokio::net::TcpListener::bind(config.address.clone())
@@ -62,6 +72,10 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
                 listener,
                 app.into_make_service_with_connect_info::<SocketAddr>(),
             )
+            .with_graceful_shutdown(async move {
+                wait_for_shutdown(&mut shutdown).await;
+                info!("{api_name} has stopped accepting new connections.");
+            })
             .await
             {
                 error!("Failed to start {api_name} server, error {}", error);
@@ -76,6 +90,7 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         )
         .await
         .unwrap();
+        start_tls_certificate_reloader(&app_state.system, tls_config.clone());
 
         let listener = std::net::TcpListener::bind(config.address).unwrap();
         let address = listener
@@ -84,8 +99,19 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
 
         info!("Started {api_name} on: {address}");
 
+        // The server stops accepting new connections and waits for the active ones,
+        // the whole shutdown process is bounded by the configured timeout anyway.
+        let handle = Handle::new();
+        let shutdown_handle = handle.clone();
+        tokio::task::spawn(async move {
+            wait_for_shutdown(&mut shutdown).await;
+            info!("{api_name} has stopped accepting new connections.");
+            shutdown_handle.graceful_shutdown(None);
+        });
+
         tokio::task::spawn(async move {
             if let Err(error) = axum_server::from_tcp_rustls(listener, tls_config)
+                .handle(handle)
                 .serve(app.into_make_service_with_connect_info::<SocketAddr>())
                 .await
             {
@@ -97,6 +123,29 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
     }
 }
 
+fn start_tls_certificate_reloader(system: &SharedSystem, tls_config: RustlsConfig) {
+    let mut config_updates = system.read().subscribe_config_updates();
+    if config_updates.is_none() {
+        return;
+    }
+
+    tokio::task::spawn(async move {
+        loop {
+            let config = wait_for_config_update(&mut config_updates).await;
+            match tls_config
+                .reload_from_pem_file(
+                    PathBuf::from(&config.http.tls.cert_file),
+                    PathBuf::from(&config.http.tls.key_file),
+                )
+                .await
+            {
+                Ok(()) => info!("HTTP TLS certificate has been reloaded."),
+                Err(error) => error!("Unable to reload HTTP TLS certificate, error: {error}"),
+            }
+        }
+    });
+}
+
 async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppState> {
     let db;
     {
@@ -123,47 +172,3 @@ async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppSt
         system,
     })
 }
-fn configure_cors(config: HttpCorsConfig) -> CorsLayer {
-    let allowed_origins = match config.allowed_origins {
-        origins if origins.is_empty() => AllowOrigin::default(),
-        origins if origins.first().unwrap() == "*" => AllowOrigin::any(),
-        origins => AllowOrigin::list(origins.iter().map(|s| s.parse().unwrap())),
-    };
-
-    let allowed_headers = config
-        .allowed_headers
-        .iter()
-        .map(|s| s.parse().unwrap())
-        .collect::<Vec<_>>();
-
-    let exposed_headers = config
-        .exposed_headers
-        .iter()
-        .map(|s| s.parse().unwrap())
-        .collect::<Vec<_>>();
-
-    let allowed_methods = config
-        .allowed_methods
-        .iter()
-        .map(|s| match s.to_uppercase().as_str() {
-            "GET" => Method::GET,
-            "POST" => Method::POST,
-            "PUT" => Method::PUT,
-            "DELETE" => Method::DELETE,
-            "HEAD" => Method::HEAD,
-            "OPTIONS" => Method::OPTIONS,
-            "CONNECT" => Method::CONNECT,
-            "PATCH" => Method::PATCH,
-            "TRACE" => Method::TRACE,
-            _ => panic!("Invalid HTTP method: {}", s),
-        })
-        .collect::<Vec<_>>();
-
-    CorsLayer::new()
-        .allow_methods(allowed_methods)
-        .allow_origin(allowed_origins)
-        .allow_headers(allowed_headers)
-        .expose_headers(exposed_headers)
-        .allow_credentials(config.allow_credentials)
-        .allow_private_network(config.allow_private_network)
-}
@@ -1,3 +1,4 @@
+use crate::streaming::session::Session;
 use iggy::models::user_info::UserId;
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
@@ -7,9 +8,19 @@ pub struct Identity {
     pub token_id: String,
     pub token_expiry: u64,
     pub user_id: UserId,
+    pub scope_id: u32,
     pub ip_address: SocketAddr,
 }
 
+impl Identity {
+    /// Returns the stateless session of the identity, restricted by the personal access token scope if there is one.
+    pub fn session(&self) -> Session {
+        let session = Session::stateless(self.user_id, self.ip_address);
+        session.set_scope_id(self.scope_id);
+        session
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JwtClaims {
     pub jti: String,
@@ -19,6 +30,8 @@ pub struct JwtClaims {
     pub iat: u64,
     pub exp: u64,
     pub nbf: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pat: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
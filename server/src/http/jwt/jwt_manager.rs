@@ -20,6 +20,7 @@ pub struct IssuerOptions {
     pub refresh_token_expiry: IggyDuration,
     pub not_before: IggyDuration,
     pub key: EncodingKey,
+    pub key_id: Option<String>,
     pub algorithm: Algorithm,
 }
 
@@ -27,6 +28,13 @@ pub struct ValidatorOptions {
     pub valid_audiences: Vec<String>,
     pub valid_issuers: Vec<String>,
     pub clock_skew: IggyDuration,
+    pub keys: Vec<DecodingKeyOptions>,
+}
+
+/// The key used to validate the tokens signed with the given algorithm and key ID (`kid` header).
+pub struct DecodingKeyOptions {
+    pub key_id: Option<String>,
+    pub algorithm: Algorithm,
     pub key: DecodingKey,
 }
 
@@ -44,15 +52,20 @@ impl JwtManager {
         validator: ValidatorOptions,
         db: Arc<Db>,
     ) -> Result<Self, IggyError> {
-        let validation = JwtManager::create_validation(
-            issuer.algorithm,
-            &validator.valid_issuers,
-            &validator.valid_audiences,
-            validator.clock_skew,
-        );
+        let mut validations = HashMap::new();
+        for key in &validator.keys {
+            validations.entry(key.algorithm).or_insert_with(|| {
+                JwtManager::create_validation(
+                    key.algorithm,
+                    &validator.valid_issuers,
+                    &validator.valid_audiences,
+                    validator.clock_skew,
+                )
+            });
+        }
 
         Ok(Self {
-            validations: vec![(issuer.algorithm, validation)].into_iter().collect(),
+            validations,
             issuer,
             validator,
             tokens_storage: TokenStorage::new(db),
@@ -69,13 +82,26 @@ impl JwtManager {
             refresh_token_expiry: config.refresh_token_expiry,
             not_before: config.not_before,
             key: config.get_encoding_key()?,
+            key_id: config.get_key_id(),
             algorithm,
         };
+        let mut keys = vec![DecodingKeyOptions {
+            key_id: config.get_key_id(),
+            algorithm,
+            key: config.get_decoding_key()?,
+        }];
+        for (key_id, algorithm, key) in config.get_decoding_keys()? {
+            keys.push(DecodingKeyOptions {
+                key_id,
+                algorithm,
+                key,
+            });
+        }
         let validator = ValidatorOptions {
             valid_audiences: config.valid_audiences.clone(),
             valid_issuers: config.valid_issuers.clone(),
             clock_skew: config.clock_skew,
-            key: config.get_decoding_key()?,
+            keys,
         };
         JwtManager::new(issuer, validator, db)
     }
@@ -163,8 +189,15 @@ impl JwtManager {
         Ok(())
     }
 
-    pub fn generate(&self, user_id: UserId) -> Result<GeneratedTokens, IggyError> {
-        let header = Header::new(self.issuer.algorithm);
+    /// Generates the tokens for the user, optionally bound to the personal access token used to log in,
+    /// so that its scope still applies to the requests authenticated with them.
+    pub fn generate(
+        &self,
+        user_id: UserId,
+        personal_access_token: Option<&str>,
+    ) -> Result<GeneratedTokens, IggyError> {
+        let mut header = Header::new(self.issuer.algorithm);
+        header.kid = self.issuer.key_id.clone();
         let now = IggyTimestamp::now().to_secs();
         let iat = now;
         let exp = iat + self.issuer.access_token_expiry.as_secs() as u64;
@@ -177,6 +210,7 @@ impl JwtManager {
             iat,
             exp,
             nbf,
+            pat: personal_access_token.map(|name| name.to_string()),
         };
 
         let access_token = encode::<JwtClaims>(&header, &claims, &self.issuer.key);
@@ -185,11 +219,12 @@ impl JwtManager {
             return Err(IggyError::CannotGenerateJwt);
         }
 
-        let (refresh_token, raw_refresh_token) = RefreshToken::new(
+        let (mut refresh_token, raw_refresh_token) = RefreshToken::new(
             user_id,
             now,
             self.issuer.refresh_token_expiry.as_secs() as u64,
         );
+        refresh_token.personal_access_token = personal_access_token.map(|name| name.to_string());
         self.tokens_storage.save_refresh_token(&refresh_token)?;
 
         Ok(GeneratedTokens {
@@ -219,39 +254,37 @@ impl JwtManager {
             return Err(IggyError::RefreshTokenExpired);
         }
 
-        self.generate(refresh_token.user_id)
+        self.generate(
+            refresh_token.user_id,
+            refresh_token.personal_access_token.as_deref(),
+        )
     }
 
+    /// Decodes the token with the key matching its ID (`kid` header), or when the token has no key ID,
+    /// with any of the keys of the same algorithm, so that the tokens signed with the previous keys remain valid.
     pub fn decode(
         &self,
         token: &str,
         algorithm: Algorithm,
+        key_id: Option<&str>,
     ) -> Result<TokenData<JwtClaims>, IggyError> {
         let validation = self.validations.get(&algorithm);
         if validation.is_none() {
-            return Err(IggyError::InvalidJwtAlgorithm(
-                Self::map_algorithm_to_string(algorithm),
-            ));
+            return Err(IggyError::InvalidJwtAlgorithm(format!("{algorithm:?}")));
         }
 
         let validation = validation.unwrap();
-        match jsonwebtoken::decode::<JwtClaims>(token, &self.validator.key, validation) {
-            Ok(claims) => Ok(claims),
-            _ => Err(IggyError::Unauthenticated),
-        }
-    }
+        for key in &self.validator.keys {
+            if key.algorithm != algorithm || (key_id.is_some() && key.key_id.as_deref() != key_id) {
+                continue;
+            }
 
-    fn map_algorithm_to_string(algorithm: Algorithm) -> String {
-        match algorithm {
-            Algorithm::HS256 => "HS256",
-            Algorithm::HS384 => "HS384",
-            Algorithm::HS512 => "HS512",
-            Algorithm::RS256 => "RS256",
-            Algorithm::RS384 => "RS384",
-            Algorithm::RS512 => "RS512",
-            _ => "Unknown",
+            if let Ok(claims) = jsonwebtoken::decode::<JwtClaims>(token, &key.key, validation) {
+                return Ok(claims);
+            }
         }
-        .to_string()
+
+        Err(IggyError::Unauthenticated)
     }
 
     pub async fn revoke_token(&self, token_id: &str, expiry: u64) -> Result<(), IggyError> {
@@ -271,3 +304,104 @@ impl JwtManager {
         revoked_tokens.contains_key(token_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::utils::test_key::TestKey;
+
+    fn create_issuer(key: &TestKey) -> IssuerOptions {
+        IssuerOptions {
+            issuer: "iggy".to_string(),
+            audience: "iggy".to_string(),
+            access_token_expiry: "1h".parse().unwrap(),
+            refresh_token_expiry: "1d".parse().unwrap(),
+            not_before: "0s".parse().unwrap(),
+            key: key.encoding_key(),
+            key_id: Some(key.id.clone()),
+            algorithm: Algorithm::ES256,
+        }
+    }
+
+    fn create_decoding_key(key: &TestKey) -> DecodingKeyOptions {
+        DecodingKeyOptions {
+            key_id: Some(key.id.clone()),
+            algorithm: Algorithm::ES256,
+            key: key.decoding_key(),
+        }
+    }
+
+    fn create_jwt_manager(issuer: IssuerOptions, keys: Vec<DecodingKeyOptions>) -> JwtManager {
+        let validator = ValidatorOptions {
+            valid_audiences: vec!["iggy".to_string()],
+            valid_issuers: vec!["iggy".to_string()],
+            clock_skew: "5s".parse().unwrap(),
+            keys,
+        };
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        JwtManager::new(issuer, validator, Arc::new(db)).unwrap()
+    }
+
+    fn decode(jwt_manager: &JwtManager, token: &str) -> Result<TokenData<JwtClaims>, IggyError> {
+        let header = jsonwebtoken::decode_header(token).unwrap();
+        jwt_manager.decode(token, header.alg, header.kid.as_deref())
+    }
+
+    #[test]
+    fn token_should_be_signed_with_key_id_and_decoded_with_matching_key() {
+        let key = TestKey::generate("key-1");
+        let jwt_manager = create_jwt_manager(create_issuer(&key), vec![create_decoding_key(&key)]);
+
+        let tokens = jwt_manager.generate(1, None).unwrap();
+        let header = jsonwebtoken::decode_header(&tokens.access_token).unwrap();
+        let claims = decode(&jwt_manager, &tokens.access_token).unwrap().claims;
+
+        assert_eq!(header.alg, Algorithm::ES256);
+        assert_eq!(header.kid, Some(key.id));
+        assert_eq!(claims.sub, 1);
+    }
+
+    #[test]
+    fn token_signed_with_previous_key_should_be_valid_after_rotation() {
+        let previous_key = TestKey::generate("key-1");
+        let current_key = TestKey::generate("key-2");
+        let previous_jwt_manager = create_jwt_manager(
+            create_issuer(&previous_key),
+            vec![create_decoding_key(&previous_key)],
+        );
+        let token = previous_jwt_manager.generate(1, None).unwrap().access_token;
+
+        let jwt_manager = create_jwt_manager(
+            create_issuer(&current_key),
+            vec![
+                create_decoding_key(&current_key),
+                create_decoding_key(&previous_key),
+            ],
+        );
+        let current_token = jwt_manager.generate(2, None).unwrap().access_token;
+
+        assert_eq!(decode(&jwt_manager, &token).unwrap().claims.sub, 1);
+        assert_eq!(decode(&jwt_manager, &current_token).unwrap().claims.sub, 2);
+    }
+
+    #[test]
+    fn token_signed_with_removed_key_should_be_rejected() {
+        let previous_key = TestKey::generate("key-1");
+        let current_key = TestKey::generate("key-2");
+        let previous_jwt_manager = create_jwt_manager(
+            create_issuer(&previous_key),
+            vec![create_decoding_key(&previous_key)],
+        );
+        let token = previous_jwt_manager.generate(1, None).unwrap().access_token;
+
+        let jwt_manager = create_jwt_manager(
+            create_issuer(&current_key),
+            vec![create_decoding_key(&current_key)],
+        );
+
+        assert!(matches!(
+            decode(&jwt_manager, &token),
+            Err(IggyError::Unauthenticated)
+        ));
+    }
+}
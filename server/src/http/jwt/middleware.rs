@@ -1,12 +1,15 @@
+use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::{AppState, RequestDetails};
+use crate::streaming::session::Session;
 use axum::body::Body;
 use axum::{
     extract::State,
     http::{Request, StatusCode},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
 };
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 const AUTHORIZATION: &str = "authorization";
@@ -15,16 +18,19 @@ const UNAUTHORIZED: StatusCode = StatusCode::UNAUTHORIZED;
 
 const UNAUTHORIZED_PATHS: &[&str] = &[
     "/",
+    "/health/live",
+    "/health/ready",
     "/metrics",
     "/ping",
     "/users/login",
+    "/users/login-with-token",
     "/users/refresh-token",
     "/personal-access-tokens/login",
 ];
 
 pub async fn jwt_auth(
     State(state): State<Arc<AppState>>,
-    mut request: Request<Body>,
+    request: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
     if UNAUTHORIZED_PATHS.contains(&request.uri().path()) {
@@ -42,12 +48,23 @@ pub async fn jwt_auth(
         return Err(StatusCode::UNAUTHORIZED);
     }
 
-    let jwt_token = &bearer[BEARER.len()..];
-    let token_header = jsonwebtoken::decode_header(jwt_token).map_err(|_| UNAUTHORIZED)?;
-    let jwt_claims = state
-        .jwt_manager
-        .decode(jwt_token, token_header.alg)
-        .map_err(|_| UNAUTHORIZED)?;
+    let jwt_token = bearer[BEARER.len()..].to_string();
+    let token_header = jsonwebtoken::decode_header(&jwt_token).map_err(|_| UNAUTHORIZED)?;
+    let ip_address = request
+        .extensions()
+        .get::<RequestDetails>()
+        .unwrap()
+        .ip_address;
+    let jwt_claims =
+        state
+            .jwt_manager
+            .decode(&jwt_token, token_header.alg, token_header.kid.as_deref());
+    if jwt_claims.is_err() {
+        let identity = get_external_identity(&state, &jwt_token, ip_address).await?;
+        return authorize(&state, identity, request, next).await;
+    }
+
+    let jwt_claims = jwt_claims.unwrap();
     if state
         .jwt_manager
         .is_token_revoked(&jwt_claims.claims.jti)
@@ -56,13 +73,84 @@ pub async fn jwt_auth(
         return Err(StatusCode::UNAUTHORIZED);
     }
 
-    let request_details = request.extensions().get::<RequestDetails>().unwrap();
+    let mut scope_id = 0;
+    if let Some(name) = &jwt_claims.claims.pat {
+        scope_id = state
+            .system
+            .read()
+            .permissioner
+            .get_personal_access_token_scope(jwt_claims.claims.sub, name)
+            .ok_or(UNAUTHORIZED)?;
+    }
+
     let identity = Identity {
         token_id: jwt_claims.claims.jti,
         token_expiry: jwt_claims.claims.exp,
         user_id: jwt_claims.claims.sub,
-        ip_address: request_details.ip_address,
+        scope_id,
+        ip_address,
     };
+    authorize(&state, identity, request, next).await
+}
+
+async fn authorize(
+    state: &AppState,
+    identity: Identity,
+    mut request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let session = identity.session();
+    if let Err(error) = state.system.read().quota_manager.check_request(&session) {
+        return Ok(CustomError::from(error).into_response());
+    }
+
     request.extensions_mut().insert(identity);
     Ok(next.run(request).await)
 }
+
+/// Validates the token issued by the external identity provider (if OIDC is enabled), maps it to the existing user
+/// and synchronizes the roles of the user with the token claims, if they have changed.
+async fn get_external_identity(
+    state: &AppState,
+    token: &str,
+    ip_address: SocketAddr,
+) -> Result<Identity, StatusCode> {
+    let validator = state.system.read().oidc.clone().ok_or(UNAUTHORIZED)?;
+    let oidc_identity = validator.validate(token).await.map_err(|_| UNAUTHORIZED)?;
+    if !oidc_identity.token_id.is_empty()
+        && state
+            .jwt_manager
+            .is_token_revoked(&oidc_identity.token_id)
+            .await
+    {
+        return Err(UNAUTHORIZED);
+    }
+
+    let (user, role_ids) = state
+        .system
+        .read()
+        .authenticate_with_oidc_identity(&oidc_identity)
+        .await
+        .map_err(|_| UNAUTHORIZED)?;
+    let user_id = user.id;
+    if let Some(role_ids) = role_ids {
+        state
+            .system
+            .write()
+            .sync_user_roles_with_oidc_identity(
+                &Session::stateless(user_id, ip_address),
+                user,
+                role_ids,
+            )
+            .await
+            .map_err(|_| UNAUTHORIZED)?;
+    }
+
+    Ok(Identity {
+        token_id: oidc_identity.token_id,
+        token_expiry: oidc_identity.expiry,
+        user_id,
+        scope_id: 0,
+        ip_address,
+    })
+}
@@ -12,6 +12,8 @@ pub struct RefreshToken {
     pub token_hash: String,
     pub user_id: u32,
     pub expiry: u64,
+    #[serde(default)]
+    pub personal_access_token: Option<String>,
 }
 
 impl RefreshToken {
@@ -27,6 +29,7 @@ impl RefreshToken {
                 token_hash: hash,
                 user_id,
                 expiry,
+                personal_access_token: None,
             },
             token,
         )
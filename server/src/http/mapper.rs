@@ -1,6 +1,7 @@
 use crate::http::jwt::json_web_token::GeneratedTokens;
 use crate::streaming::clients::client_manager::Client;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::roles::role::Role;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
@@ -9,6 +10,7 @@ use iggy::models::client_info::ConsumerGroupInfo;
 use iggy::models::consumer_group::{ConsumerGroupDetails, ConsumerGroupMember};
 use iggy::models::identity_info::{IdentityInfo, IdentityTokens, TokenInfo};
 use iggy::models::personal_access_token::PersonalAccessTokenInfo;
+use iggy::models::role_info::RoleInfo;
 use iggy::models::stream::StreamDetails;
 use iggy::models::topic::TopicDetails;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
@@ -25,6 +27,7 @@ pub async fn map_stream(stream: &Stream) -> StreamDetails {
         size_bytes: stream.get_size().await.as_bytes_u64(),
         messages_count: stream.get_messages_count().await,
         topics,
+        quotas: stream.quotas,
     };
     stream_details.topics.sort_by(|a, b| a.id.cmp(&b.id));
     stream_details
@@ -105,9 +108,28 @@ pub fn map_user(user: &User) -> UserInfoDetails {
         created_at: user.created_at,
         status: user.status,
         permissions: user.permissions.clone(),
+        quotas: user.quotas,
+        roles: user.roles.clone(),
+        failed_login_attempts: user.failed_login_attempts,
+        locked_until: user.get_locked_until(),
     }
 }
 
+pub fn map_role(role: &Role) -> RoleInfo {
+    RoleInfo {
+        id: role.id,
+        created_at: role.created_at,
+        name: role.name.clone(),
+        permissions: role.permissions.clone(),
+    }
+}
+
+pub fn map_roles(roles: &[Role]) -> Vec<RoleInfo> {
+    let mut roles_data = roles.iter().map(map_role).collect::<Vec<_>>();
+    roles_data.sort_by(|a, b| a.id.cmp(&b.id));
+    roles_data
+}
+
 pub fn map_users(users: &[User]) -> Vec<UserInfo> {
     let mut users_data = Vec::with_capacity(users.len());
     for user in users {
@@ -131,6 +153,7 @@ pub fn map_personal_access_tokens(
         let personal_access_token = PersonalAccessTokenInfo {
             name: personal_access_token.name.clone(),
             expiry: personal_access_token.expiry,
+            scope: personal_access_token.scope.clone(),
         };
         personal_access_tokens_data.push(personal_access_token);
     }
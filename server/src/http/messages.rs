@@ -5,15 +5,19 @@ use crate::streaming;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
+use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollMessages;
-use iggy::messages::send_messages::SendMessages;
+use iggy::messages::send_messages::{AckLevel, SendMessages};
+use iggy::models::producer_info::ProducerInfo;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
+use tracing::error;
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -21,6 +25,7 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/messages",
             get(poll_messages).post(send_messages),
         )
+        .route("/producers", post(init_producer))
         .with_state(state)
 }
 
@@ -40,11 +45,16 @@ async fn poll_messages(
     let system = state.system.read();
     let polled_messages = system
         .poll_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             consumer,
             &query.stream_id,
             &query.topic_id,
-            PollingArgs::new(query.strategy, query.count, query.auto_commit),
+            PollingArgs::new(
+                query.strategy,
+                query.count,
+                query.auto_commit,
+                query.read_committed,
+            ),
         )
         .await?;
     Ok(Json(polled_messages))
@@ -61,15 +71,46 @@ async fn send_messages(
     command.partitioning.length = command.partitioning.value.len() as u8;
     command.validate()?;
 
-    let system = state.system.read();
+    let session = identity.session();
+    // The fire-and-forget producer is acknowledged before the messages are appended.
+    if command.ack == AckLevel::None {
+        let system = state.system.clone();
+        tokio::spawn(async move {
+            if let Err(error) = append_messages(&system, &session, &command).await {
+                error!("Failed to append the fire-and-forget messages, session: {session}, error: {error}");
+            }
+        });
+        return Ok(StatusCode::ACCEPTED);
+    }
+
+    append_messages(&state.system, &session, &command).await?;
+    Ok(StatusCode::CREATED)
+}
+
+async fn append_messages(
+    system: &SharedSystem,
+    session: &Session,
+    command: &SendMessages,
+) -> Result<(), IggyError> {
+    let system = system.read();
     system
         .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            session,
             &command.stream_id,
             &command.topic_id,
             &command.partitioning,
+            command.producer.as_ref(),
+            command.ack,
             &command.messages,
         )
-        .await?;
-    Ok(StatusCode::CREATED)
+        .await
+}
+
+async fn init_producer(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<ProducerInfo>, CustomError> {
+    let system = state.system.read();
+    let producer_id = system.init_producer(&identity.session()).await?;
+    Ok(Json(ProducerInfo { producer_id }))
 }
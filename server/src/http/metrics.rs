@@ -1,18 +1,33 @@
 use crate::http::shared::AppState;
 use axum::body::Body;
 use axum::{
-    extract::State,
+    extract::{MatchedPath, State},
     http::{Request, StatusCode},
     middleware::Next,
     response::Response,
 };
 use std::sync::Arc;
+use tokio::time::Instant;
+
+const TRANSPORT: &str = "HTTP";
 
 pub async fn metrics(
     State(state): State<Arc<AppState>>,
     request: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
+    // The matched route (e.g. `/streams/:stream_id`) is used, so that the command label has a bounded set of values.
+    let command = match request.extensions().get::<MatchedPath>() {
+        Some(path) => format!("{} {}", request.method(), path.as_str()),
+        None => format!("{} unmatched", request.method()),
+    };
     state.system.read().metrics.increment_http_requests();
-    Ok(next.run(request).await)
+    let now = Instant::now();
+    let response = next.run(request).await;
+    state
+        .system
+        .read()
+        .metrics
+        .record_request(&command, TRANSPORT, now.elapsed());
+    Ok(response)
 }
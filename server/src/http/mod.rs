@@ -1,5 +1,6 @@
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod cors;
 pub mod diagnostics;
 pub mod error;
 pub mod http_server;
@@ -9,6 +10,7 @@ pub mod messages;
 pub mod metrics;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod roles;
 mod shared;
 pub mod streams;
 pub mod system;
@@ -1,7 +1,6 @@
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::AppState;
-use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::routing::post;
@@ -33,7 +32,7 @@ async fn create_partitions(
     let mut system = state.system.write();
     system
         .create_partitions(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.stream_id,
             &command.topic_id,
             command.partitions_count,
@@ -54,7 +53,7 @@ async fn delete_partitions(
     let mut system = state.system.write();
     system
         .delete_partitions(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &query.stream_id,
             &query.topic_id,
             query.partitions_count,
@@ -2,7 +2,7 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_tokens_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -38,7 +38,7 @@ async fn get_personal_access_tokens(
 ) -> Result<Json<Vec<PersonalAccessTokenInfo>>, CustomError> {
     let system = state.system.read();
     let personal_access_tokens = system
-        .get_personal_access_tokens(&Session::stateless(identity.user_id, identity.ip_address))
+        .get_personal_access_tokens(&identity.session())
         .await?;
     let personal_access_tokens = mapper::map_personal_access_tokens(&personal_access_tokens);
     Ok(Json(personal_access_tokens))
@@ -50,12 +50,13 @@ async fn create_personal_access_token(
     Json(command): Json<CreatePersonalAccessToken>,
 ) -> Result<Json<RawPersonalAccessToken>, CustomError> {
     command.validate()?;
-    let system = state.system.read();
+    let mut system = state.system.write();
     let token = system
         .create_personal_access_token(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.name,
             command.expiry,
+            command.scope,
         )
         .await?;
     Ok(Json(RawPersonalAccessToken { token }))
@@ -66,25 +67,32 @@ async fn delete_personal_access_token(
     Extension(identity): Extension<Identity>,
     Path(name): Path<String>,
 ) -> Result<StatusCode, CustomError> {
-    let system = state.system.read();
+    let mut system = state.system.write();
     system
-        .delete_personal_access_token(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &name,
-        )
+        .delete_personal_access_token(&identity.session(), &name)
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
 async fn login_with_personal_access_token(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginWithPersonalAccessToken>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
     let system = state.system.read();
-    let user = system
-        .login_with_personal_access_token(&command.token, None)
+    let (user, personal_access_token) = system
+        .login_with_personal_access_token(
+            &command.token,
+            Some(&Session::stateless(0, request_details.ip_address)),
+        )
         .await?;
-    let tokens = state.jwt_manager.generate(user.id)?;
+    let personal_access_token_name = personal_access_token
+        .scope
+        .as_ref()
+        .map(|_| personal_access_token.name.as_str());
+    let tokens = state
+        .jwt_manager
+        .generate(user.id, personal_access_token_name)?;
     Ok(Json(map_generated_tokens_to_identity_info(tokens)))
 }
@@ -0,0 +1,90 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::shared::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use iggy::identifier::Identifier;
+use iggy::models::role_info::RoleInfo;
+use iggy::roles::create_role::CreateRole;
+use iggy::roles::update_role::UpdateRole;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/roles", get(get_roles).post(create_role))
+        .route(
+            "/roles/:role_id",
+            get(get_role).put(update_role).delete(delete_role),
+        )
+        .with_state(state)
+}
+
+async fn get_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(role_id): Path<String>,
+) -> Result<Json<RoleInfo>, CustomError> {
+    let role_id = Identifier::from_str_value(&role_id)?;
+    let system = state.system.read();
+    let role = system.find_role(&identity.session(), &role_id).await?;
+    let role = mapper::map_role(&role);
+    Ok(Json(role))
+}
+
+async fn get_roles(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<RoleInfo>>, CustomError> {
+    let system = state.system.read();
+    let roles = system.get_roles(&identity.session()).await?;
+    let roles = mapper::map_roles(&roles);
+    Ok(Json(roles))
+}
+
+async fn create_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateRole>,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+    let mut system = state.system.write();
+    system
+        .create_role(&identity.session(), &command.name, command.permissions)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn update_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(role_id): Path<String>,
+    Json(mut command): Json<UpdateRole>,
+) -> Result<StatusCode, CustomError> {
+    command.role_id = Identifier::from_str_value(&role_id)?;
+    command.validate()?;
+    let mut system = state.system.write();
+    system
+        .update_role(
+            &identity.session(),
+            &command.role_id,
+            command.name,
+            command.permissions,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn delete_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(role_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let role_id = Identifier::from_str_value(&role_id)?;
+    let mut system = state.system.write();
+    system.delete_role(&identity.session(), &role_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -2,7 +2,6 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
-use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get};
@@ -32,10 +31,7 @@ async fn get_stream(
 ) -> Result<Json<StreamDetails>, CustomError> {
     let system = state.system.read();
     let stream_id = Identifier::from_str_value(&stream_id)?;
-    let stream = system.find_stream(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-    )?;
+    let stream = system.find_stream(&identity.session(), &stream_id)?;
     let stream = mapper::map_stream(stream).await;
     Ok(Json(stream))
 }
@@ -45,8 +41,7 @@ async fn get_streams(
     Extension(identity): Extension<Identity>,
 ) -> Result<Json<Vec<Stream>>, CustomError> {
     let system = state.system.read();
-    let streams =
-        system.find_streams(&Session::stateless(identity.user_id, identity.ip_address))?;
+    let streams = system.find_streams(&identity.session())?;
     let streams = mapper::map_streams(&streams).await;
     Ok(Json(streams))
 }
@@ -60,9 +55,10 @@ async fn create_stream(
     let mut system = state.system.write();
     system
         .create_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             command.stream_id,
             &command.name,
+            command.quotas,
         )
         .await?;
     Ok(StatusCode::CREATED)
@@ -79,9 +75,10 @@ async fn update_stream(
     let mut system = state.system.write();
     system
         .update_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.stream_id,
             &command.name,
+            command.quotas,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
@@ -95,10 +92,7 @@ async fn delete_stream(
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let mut system = state.system.write();
     system
-        .delete_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &stream_id,
-        )
+        .delete_stream(&identity.session(), &stream_id)
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -110,11 +104,6 @@ async fn purge_stream(
 ) -> Result<StatusCode, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let system = state.system.read();
-    system
-        .purge_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &stream_id,
-        )
-        .await?;
+    system.purge_stream(&identity.session(), &stream_id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -3,12 +3,18 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
-use crate::streaming::session::Session;
-use axum::extract::{Path, State};
-use axum::routing::get;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post, put};
 use axum::{Extension, Json, Router};
+use iggy::models::audit_log_entry::AuditLogEntry;
 use iggy::models::client_info::{ClientInfo, ClientInfoDetails};
+use iggy::models::config_reload::ConfigReload;
+use iggy::models::health::Health;
 use iggy::models::stats::Stats;
+use iggy::system::get_audit_log::GetAuditLog;
+use iggy::system::set_log_filter::SetLogFilter;
+use iggy::validatable::Validatable;
 use std::sync::Arc;
 
 const NAME: &str = "Iggy HTTP";
@@ -18,9 +24,14 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
     let mut router = Router::new()
         .route("/", get(|| async { NAME }))
         .route("/ping", get(|| async { PONG }))
+        .route("/health/live", get(get_liveness))
+        .route("/health/ready", get(get_readiness))
         .route("/stats", get(get_stats))
         .route("/clients", get(get_clients))
-        .route("/clients/:client_id", get(get_client));
+        .route("/clients/:client_id", get(get_client))
+        .route("/audit-log", get(get_audit_log))
+        .route("/config/reload", post(reload_config))
+        .route("/logging/filter", put(set_log_filter));
     if metrics_config.enabled {
         router = router.route(&metrics_config.endpoint, get(get_metrics));
     }
@@ -30,7 +41,25 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
 
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read();
-    Ok(system.metrics.get_formatted_output())
+    Ok(system.get_formatted_metrics().await)
+}
+
+async fn get_liveness(State(state): State<Arc<AppState>>) -> (StatusCode, Json<Health>) {
+    let health = state.system.read().get_liveness();
+    (map_health_status(&health), Json(health))
+}
+
+async fn get_readiness(State(state): State<Arc<AppState>>) -> (StatusCode, Json<Health>) {
+    let system = state.system.read();
+    let health = system.get_health().await;
+    (map_health_status(&health), Json(health))
+}
+
+fn map_health_status(health: &Health) -> StatusCode {
+    match health.is_healthy() {
+        true => StatusCode::OK,
+        false => StatusCode::SERVICE_UNAVAILABLE,
+    }
 }
 
 async fn get_stats(
@@ -38,9 +67,7 @@ async fn get_stats(
     Extension(identity): Extension<Identity>,
 ) -> Result<Json<Stats>, CustomError> {
     let system = state.system.read();
-    let stats = system
-        .get_stats(&Session::stateless(identity.user_id, identity.ip_address))
-        .await?;
+    let stats = system.get_stats(&identity.session()).await?;
     Ok(Json(stats))
 }
 
@@ -50,12 +77,7 @@ async fn get_client(
     Path(client_id): Path<u32>,
 ) -> Result<Json<ClientInfoDetails>, CustomError> {
     let system = state.system.read();
-    let client = system
-        .get_client(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            client_id,
-        )
-        .await?;
+    let client = system.get_client(&identity.session(), client_id).await?;
     let client = client.read().await;
     let client = mapper::map_client(&client).await;
     Ok(Json(client))
@@ -66,9 +88,42 @@ async fn get_clients(
     Extension(identity): Extension<Identity>,
 ) -> Result<Json<Vec<ClientInfo>>, CustomError> {
     let system = state.system.read();
-    let clients = system
-        .get_clients(&Session::stateless(identity.user_id, identity.ip_address))
-        .await?;
+    let clients = system.get_clients(&identity.session()).await?;
     let clients = mapper::map_clients(&clients).await;
     Ok(Json(clients))
 }
+
+async fn get_audit_log(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<GetAuditLog>,
+) -> Result<Json<Vec<AuditLogEntry>>, CustomError> {
+    query.validate()?;
+    let system = state.system.read();
+    let entries = system
+        .get_audit_log(&identity.session(), query.offset, query.count)
+        .await?;
+    Ok(Json(entries))
+}
+
+async fn reload_config(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<ConfigReload>, CustomError> {
+    let system = state.system.read();
+    let config_reload = system.reload_config(&identity.session()).await?;
+    Ok(Json(config_reload))
+}
+
+async fn set_log_filter(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<SetLogFilter>,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+    let system = state.system.read();
+    system
+        .set_log_filter(&identity.session(), &command.filter, command.revert_after)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
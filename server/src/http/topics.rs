@@ -2,7 +2,6 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
-use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get};
@@ -39,11 +38,7 @@ async fn get_topic(
     let system = state.system.read();
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
-    let topic = system.find_topic(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-        &topic_id,
-    )?;
+    let topic = system.find_topic(&identity.session(), &stream_id, &topic_id)?;
     let topic = mapper::map_topic(topic).await;
     Ok(Json(topic))
 }
@@ -55,10 +50,7 @@ async fn get_topics(
 ) -> Result<Json<Vec<Topic>>, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let system = state.system.read();
-    let topics = system.find_topics(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-    )?;
+    let topics = system.find_topics(&identity.session(), &stream_id)?;
     let topics = mapper::map_topics(&topics).await;
     Ok(Json(topics))
 }
@@ -74,7 +66,7 @@ async fn create_topic(
     let mut system = state.system.write();
     system
         .create_topic(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.stream_id,
             command.topic_id,
             &command.name,
@@ -99,7 +91,7 @@ async fn update_topic(
     let mut system = state.system.write();
     system
         .update_topic(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.stream_id,
             &command.topic_id,
             &command.name,
@@ -120,11 +112,7 @@ async fn delete_topic(
     let topic_id = Identifier::from_str_value(&topic_id)?;
     let mut system = state.system.write();
     system
-        .delete_topic(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &stream_id,
-            &topic_id,
-        )
+        .delete_topic(&identity.session(), &stream_id, &topic_id)
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -138,11 +126,7 @@ async fn purge_topic(
     let topic_id = Identifier::from_str_value(&topic_id)?;
     let system = state.system.read();
     system
-        .purge_topic(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &stream_id,
-            &topic_id,
-        )
+        .purge_topic(&identity.session(), &stream_id, &topic_id)
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
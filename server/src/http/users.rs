@@ -2,7 +2,8 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_tokens_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
+use crate::streaming::audit::audit_action::AuditAction;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -14,9 +15,12 @@ use iggy::models::user_info::{UserInfo, UserInfoDetails};
 use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
 use iggy::users::login_user::LoginUser;
+use iggy::users::login_with_token::LoginWithToken;
 use iggy::users::logout_user::LogoutUser;
 use iggy::users::update_permissions::UpdatePermissions;
+use iggy::users::update_quotas::UpdateQuotas;
 use iggy::users::update_user::UpdateUser;
+use iggy::users::update_user_roles::UpdateUserRoles;
 use iggy::validatable::Validatable;
 use serde::Deserialize;
 use std::sync::Arc;
@@ -29,8 +33,12 @@ pub fn router(state: Arc<AppState>) -> Router {
             get(get_user).put(update_user).delete(delete_user),
         )
         .route("/users/:user_id/permissions", put(update_permissions))
+        .route("/users/:user_id/quotas", put(update_quotas))
+        .route("/users/:user_id/roles", put(update_user_roles))
         .route("/users/:user_id/password", put(change_password))
+        .route("/users/:user_id/unlock", post(unlock_user))
         .route("/users/login", post(login_user))
+        .route("/users/login-with-token", post(login_with_token))
         .route("/users/logout", post(logout_user))
         .route("/users/refresh-token", post(refresh_token))
         .with_state(state)
@@ -43,12 +51,7 @@ async fn get_user(
 ) -> Result<Json<UserInfoDetails>, CustomError> {
     let user_id = Identifier::from_str_value(&user_id)?;
     let system = state.system.read();
-    let user = system
-        .find_user(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &user_id,
-        )
-        .await?;
+    let user = system.find_user(&identity.session(), &user_id).await?;
     let user = mapper::map_user(&user);
     Ok(Json(user))
 }
@@ -58,9 +61,7 @@ async fn get_users(
     Extension(identity): Extension<Identity>,
 ) -> Result<Json<Vec<UserInfo>>, CustomError> {
     let system = state.system.read();
-    let users = system
-        .get_users(&Session::stateless(identity.user_id, identity.ip_address))
-        .await?;
+    let users = system.get_users(&identity.session()).await?;
     let users = mapper::map_users(&users);
     Ok(Json(users))
 }
@@ -74,7 +75,7 @@ async fn create_user(
     let mut system = state.system.write();
     system
         .create_user(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.username,
             &command.password,
             command.status,
@@ -95,7 +96,7 @@ async fn update_user(
     let system = state.system.read();
     system
         .update_user(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.user_id,
             command.username,
             command.status,
@@ -114,15 +115,52 @@ async fn update_permissions(
     command.validate()?;
     let mut system = state.system.write();
     system
-        .update_permissions(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &command.user_id,
-            command.permissions,
-        )
+        .update_permissions(&identity.session(), &command.user_id, command.permissions)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn update_quotas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    Json(mut command): Json<UpdateQuotas>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.validate()?;
+    let system = state.system.read();
+    system
+        .update_quotas(&identity.session(), &command.user_id, command.quotas)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn update_user_roles(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    Json(mut command): Json<UpdateUserRoles>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.validate()?;
+    let mut system = state.system.write();
+    system
+        .update_user_roles(&identity.session(), &command.user_id, command.role_ids)
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+async fn unlock_user(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let user_id = Identifier::from_str_value(&user_id)?;
+    let system = state.system.read();
+    system.unlock_user(&identity.session(), &user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 async fn change_password(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -134,7 +172,7 @@ async fn change_password(
     let system = state.system.read();
     system
         .change_password(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.user_id,
             &command.current_password,
             &command.new_password,
@@ -150,25 +188,55 @@ async fn delete_user(
 ) -> Result<StatusCode, CustomError> {
     let user_id = Identifier::from_str_value(&user_id)?;
     let mut system = state.system.write();
-    system
-        .delete_user(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &user_id,
-        )
-        .await?;
+    system.delete_user(&identity.session(), &user_id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
 async fn login_user(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginUser>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
     let system = state.system.read();
     let user = system
-        .login_user(&command.username, &command.password, None)
+        .login_user(
+            &command.username,
+            &command.password,
+            Some(&Session::stateless(0, request_details.ip_address)),
+        )
+        .await?;
+    let tokens = state.jwt_manager.generate(user.id, None)?;
+    Ok(Json(map_generated_tokens_to_identity_info(tokens)))
+}
+
+async fn login_with_token(
+    State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
+    Json(command): Json<LoginWithToken>,
+) -> Result<Json<IdentityInfo>, CustomError> {
+    command.validate()?;
+    let session = Session::stateless(0, request_details.ip_address);
+    let validator = state.system.read().get_oidc_validator()?;
+    let identity = validator.validate(&command.token).await;
+    if let Err(error) = identity {
+        state
+            .system
+            .read()
+            .audit_login_failure(
+                Some(&session),
+                AuditAction::LoginWithToken,
+                "invalid token".to_string(),
+            )
+            .await;
+        return Err(error.into());
+    }
+
+    let mut system = state.system.write();
+    let user = system
+        .login_with_oidc_identity(&identity.unwrap(), &session)
         .await?;
-    let tokens = state.jwt_manager.generate(user.id)?;
+    let tokens = state.jwt_manager.generate(user.id, None)?;
     Ok(Json(map_generated_tokens_to_identity_info(tokens)))
 }
 
@@ -179,13 +247,14 @@ async fn logout_user(
 ) -> Result<StatusCode, CustomError> {
     command.validate()?;
     let system = state.system.read();
-    system
-        .logout_user(&Session::stateless(identity.user_id, identity.ip_address))
-        .await?;
-    state
-        .jwt_manager
-        .revoke_token(&identity.token_id, identity.token_expiry)
-        .await?;
+    system.logout_user(&identity.session()).await?;
+    // The externally issued tokens might not have the ID, thus cannot be revoked.
+    if !identity.token_id.is_empty() {
+        state
+            .jwt_manager
+            .revoke_token(&identity.token_id, identity.token_expiry)
+            .await?;
+    }
     Ok(StatusCode::NO_CONTENT)
 }
 
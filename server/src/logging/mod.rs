@@ -1,5 +1,14 @@
 use crate::configs::system::LoggingConfig;
+use crate::configs::telemetry::{TelemetryConfig, FILE_EXPORTER, OTLP_EXPORTER};
 use crate::server_error::ServerError;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{self as sdktrace, Sampler, Tracer, TracerProvider};
+use opentelemetry_sdk::{runtime, Resource};
+use std::fmt::{Debug, Formatter};
+use std::fs::{self, File};
 use std::io::{self, Write};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -7,7 +16,8 @@ use std::sync::{Arc, Mutex};
 use tracing::{event, info, trace, Level};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{
-    filter::LevelFilter, fmt, fmt::MakeWriter, prelude::*, reload, reload::Handle, Layer, Registry,
+    filter::LevelFilter, fmt, fmt::MakeWriter, layer::Identity, prelude::*, reload, reload::Handle,
+    EnvFilter, Layer, Registry,
 };
 
 const IGGY_LOG_FILE_PREFIX: &str = "iggy-server.log";
@@ -85,10 +95,98 @@ pub struct Logging {
 
     filtering_stdout_reload_handle: Option<ReloadHandle>,
     filtering_file_reload_handle: Option<ReloadHandle>,
+    filtering_level: LevelFilter,
+
+    telemetry_reload_handle: Option<ReloadHandle>,
 
     early_logs_buffer: Arc<Mutex<Vec<String>>>,
 }
 
+/// Changes the log level at runtime, e.g. after reloading the configuration,
+/// or overrides it with the custom filter directives (e.g. `info,server::streaming=debug`) until the filter is reset.
+#[derive(Clone)]
+pub struct LogLevelUpdater {
+    filtering_stdout_reload_handle: ReloadHandle,
+    filtering_file_reload_handle: ReloadHandle,
+    state: Arc<Mutex<LogFilterState>>,
+}
+
+struct LogFilterState {
+    level: LevelFilter,
+    filter: Option<String>,
+    version: u64,
+}
+
+impl LogLevelUpdater {
+    // RUST_LOG still takes precedence over config
+    pub fn update(&self, config: &LoggingConfig) -> Result<(), ServerError> {
+        let filtering_level = Logging::get_filtering_level(Some(config));
+        let mut state = self.state.lock().unwrap();
+        state.level = filtering_level;
+        if let Some(filter) = &state.filter {
+            info!(
+                "Log level changed to: {}, it will be applied once the active log filter: {} is reset.",
+                filtering_level, filter
+            );
+            return Ok(());
+        }
+
+        self.apply_level(filtering_level)?;
+        info!("Log level changed to: {}.", filtering_level);
+        Ok(())
+    }
+
+    /// Overrides the log level with the given filter directives.
+    /// Returns the version of the filter, which can be used to reset only this particular filter.
+    pub fn set_filter(&self, filter: &str) -> Result<u64, ServerError> {
+        // The filter layer is not cloneable, thus it has to be created for each output separately.
+        let stdout_filter = EnvFilter::try_new(filter)
+            .map_err(|error| ServerError::InvalidLogFilter(error.to_string()))?;
+        let file_filter = EnvFilter::try_new(filter)
+            .map_err(|error| ServerError::InvalidLogFilter(error.to_string()))?;
+        let mut state = self.state.lock().unwrap();
+        self.filtering_stdout_reload_handle
+            .modify(|layer| *layer = stdout_filter.boxed())
+            .map_err(|_| ServerError::FilterReloadFailure)?;
+        self.filtering_file_reload_handle
+            .modify(|layer| *layer = file_filter.boxed())
+            .map_err(|_| ServerError::FilterReloadFailure)?;
+        state.filter = Some(filter.to_string());
+        state.version += 1;
+        info!("Log filter changed to: {}.", filter);
+        Ok(state.version)
+    }
+
+    /// Resets the active filter to the configured log level, if the filter has the given version (or any, if not provided).
+    pub fn reset_filter(&self, version: Option<u64>) -> Result<(), ServerError> {
+        let mut state = self.state.lock().unwrap();
+        if state.filter.is_none() || version.is_some_and(|version| version != state.version) {
+            return Ok(());
+        }
+
+        self.apply_level(state.level)?;
+        state.filter = None;
+        info!("Log filter reset to the log level: {}.", state.level);
+        Ok(())
+    }
+
+    fn apply_level(&self, filtering_level: LevelFilter) -> Result<(), ServerError> {
+        self.filtering_stdout_reload_handle
+            .modify(|layer| *layer = filtering_level.boxed())
+            .map_err(|_| ServerError::FilterReloadFailure)?;
+        self.filtering_file_reload_handle
+            .modify(|layer| *layer = filtering_level.boxed())
+            .map_err(|_| ServerError::FilterReloadFailure)?;
+        Ok(())
+    }
+}
+
+impl Debug for LogLevelUpdater {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogLevelUpdater").finish()
+    }
+}
+
 impl Logging {
     pub fn new() -> Self {
         Self {
@@ -98,6 +196,8 @@ impl Logging {
             file_reload_handle: None,
             filtering_stdout_reload_handle: None,
             filtering_file_reload_handle: None,
+            filtering_level: LevelFilter::INFO,
+            telemetry_reload_handle: None,
             early_logs_buffer: Arc::new(Mutex::new(vec![])),
         }
     }
@@ -108,6 +208,7 @@ impl Logging {
         // Second layer will just consume drain log entries and has first layer as a dependency
         // Third layer will write to a safe buffer and has first layer as a dependency
         // All layers will be replaced during late_init
+        // Fourth layer does nothing until it's replaced with the OpenTelemetry one during init_telemetry
         let mut layers = vec![];
 
         let filtering_level = Self::get_filtering_level(None);
@@ -122,7 +223,7 @@ impl Logging {
         let stdout_layer = fmt::Layer::default().with_writer(|| NullWriter);
         let (stdout_layer, stdout_layer_reload_handle) = reload::Layer::new(stdout_layer.boxed());
         self.stdout_reload_handle = Some(stdout_layer_reload_handle);
-        layers.push(stdout_layer.and_then(filtering_stdout_layer));
+        layers.push(stdout_layer.and_then(filtering_stdout_layer).boxed());
 
         let file_layer = fmt::Layer::default()
             .with_target(true)
@@ -130,7 +231,12 @@ impl Logging {
             .with_ansi(true);
         let (file_layer, file_layer_reload_handle) = reload::Layer::new(file_layer.boxed());
         self.file_reload_handle = Some(file_layer_reload_handle);
-        layers.push(file_layer.and_then(filtering_file_layer));
+        layers.push(file_layer.and_then(filtering_file_layer).boxed());
+
+        let (telemetry_layer, telemetry_reload_handle) =
+            reload::Layer::new(Identity::new().boxed());
+        self.telemetry_reload_handle = Some(telemetry_reload_handle);
+        layers.push(telemetry_layer.boxed());
 
         let subscriber = tracing_subscriber::registry().with(layers);
 
@@ -167,6 +273,7 @@ impl Logging {
         trace!("Logging config: {}", config);
 
         let filtering_level = Self::get_filtering_level(Some(config));
+        self.filtering_level = filtering_level;
 
         self.filtering_stdout_reload_handle
             .as_ref()
@@ -234,6 +341,104 @@ impl Logging {
         Ok(())
     }
 
+    /// Exports the spans with the configured OpenTelemetry exporter and sets the W3C trace context propagator,
+    /// so that the trace context can be passed through the message headers.
+    /// The spans are subject to the same log level (or filter) as the log entries.
+    pub fn init_telemetry(
+        &mut self,
+        base_directory: &str,
+        config: &TelemetryConfig,
+    ) -> Result<(), ServerError> {
+        if !config.enabled {
+            return Ok(());
+        }
+
+        let tracer = Self::create_tracer(base_directory, config)?;
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let telemetry_layer = tracing_opentelemetry::layer().with_tracer(tracer).boxed();
+        self.telemetry_reload_handle
+            .as_ref()
+            .ok_or(ServerError::TelemetryReloadFailure)?
+            .modify(|layer| *layer = telemetry_layer)
+            .map_err(|_| ServerError::TelemetryReloadFailure)?;
+
+        info!(
+            "Telemetry initialized, spans will be exported using: {} exporter.",
+            config.exporter
+        );
+        Ok(())
+    }
+
+    /// Flushes the remaining spans, if the telemetry is enabled.
+    pub fn shutdown_telemetry(&self) {
+        global::shutdown_tracer_provider();
+    }
+
+    fn create_tracer(
+        base_directory: &str,
+        config: &TelemetryConfig,
+    ) -> Result<Tracer, ServerError> {
+        let trace_config = sdktrace::config()
+            .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+                config.sampling_ratio,
+            ))))
+            .with_resource(Resource::new(vec![KeyValue::new(
+                "service.name",
+                config.service_name.clone(),
+            )]));
+
+        if config.exporter == OTLP_EXPORTER {
+            return opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(
+                    opentelemetry_otlp::new_exporter()
+                        .tonic()
+                        .with_endpoint(&config.endpoint),
+                )
+                .with_trace_config(trace_config)
+                .install_batch(runtime::Tokio)
+                .map_err(|error| ServerError::CannotInitializeTelemetry(error.to_string()));
+        }
+
+        let exporter = opentelemetry_stdout::SpanExporter::builder();
+        let exporter = match config.exporter.as_str() {
+            FILE_EXPORTER => {
+                let path = PathBuf::from(base_directory).join(&config.path);
+                if let Some(directory) = path.parent() {
+                    fs::create_dir_all(directory).map_err(|error| {
+                        ServerError::CannotInitializeTelemetry(error.to_string())
+                    })?;
+                }
+                let file = File::create(&path)
+                    .map_err(|error| ServerError::CannotInitializeTelemetry(error.to_string()))?;
+                exporter.with_writer(file)
+            }
+            _ => exporter.with_writer(io::stdout()),
+        };
+
+        let provider = TracerProvider::builder()
+            .with_batch_exporter(exporter.build(), runtime::Tokio)
+            .with_config(trace_config)
+            .build();
+        let tracer = provider.tracer(config.service_name.clone());
+        global::set_tracer_provider(provider);
+        Ok(tracer)
+    }
+
+    /// Returns the updater of the log level, available once the logging is initialized.
+    /// All the clones of the updater share the same state, thus it should be created only once.
+    pub fn get_level_updater(&self) -> Option<LogLevelUpdater> {
+        Some(LogLevelUpdater {
+            filtering_stdout_reload_handle: self.filtering_stdout_reload_handle.clone()?,
+            filtering_file_reload_handle: self.filtering_file_reload_handle.clone()?,
+            state: Arc::new(Mutex::new(LogFilterState {
+                level: self.filtering_level,
+                filter: None,
+                version: 0,
+            })),
+        })
+    }
+
     // RUST_LOG always takes precedence over config
     fn get_filtering_level(config: Option<&LoggingConfig>) -> LevelFilter {
         if let Ok(rust_log) = std::env::var("RUST_LOG") {
@@ -2,11 +2,14 @@ use anyhow::Result;
 use clap::Parser;
 use figlet_rs::FIGfont;
 use server::args::Args;
+use server::channels::commands::abort_expired_transactions::AbortExpiredTransactionsExecutor;
 use server::channels::commands::clean_messages::CleanMessagesExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use server::channels::commands::re_encrypt_messages::ReEncryptMessagesExecutor;
 use server::channels::commands::save_messages::SaveMessagesExecutor;
 use server::channels::handler::ServerCommandHandler;
 use server::configs::config_provider;
+use server::configs::reloader::ConfigReloader;
 use server::configs::server::ServerConfig;
 use server::http::http_server;
 use server::logging::Logging;
@@ -15,8 +18,9 @@ use server::server_error::ServerError;
 
 use server::streaming::systems::system::{SharedSystem, System};
 use server::tcp::tcp_server;
+use std::sync::Arc;
 use tokio::time::Instant;
-use tracing::info;
+use tracing::{error, info, warn};
 
 #[tokio::main]
 async fn main() -> Result<(), ServerError> {
@@ -36,25 +40,56 @@ async fn main() -> Result<(), ServerError> {
     let config = ServerConfig::load(config_provider.as_ref()).await?;
 
     logging.late_init(config.system.get_system_path(), &config.system.logging)?;
-
-    let mut system = System::new(config.system.clone(), None, config.personal_access_token);
+    logging.init_telemetry(&config.system.get_system_path(), &config.telemetry)?;
+
+    let log_level_updater = logging.get_level_updater();
+    let config_reloader = Arc::new(ConfigReloader::new(
+        config_provider,
+        config.clone(),
+        log_level_updater.clone(),
+    ));
+    let mut system = System::new(
+        config.system.clone(),
+        None,
+        config.personal_access_token.clone(),
+    );
+    system.set_config_reloader(config_reloader.clone());
+    if let Some(log_level_updater) = log_level_updater {
+        system.set_log_level_updater(log_level_updater);
+    }
 
     system.init().await?;
     let system = SharedSystem::new(system);
     let _command_handler = ServerCommandHandler::new(system.clone(), &config)
         .install_handler(SaveMessagesExecutor)
         .install_handler(CleanMessagesExecutor)
-        .install_handler(CleanPersonalAccessTokensExecutor);
+        .install_handler(CleanPersonalAccessTokensExecutor)
+        .install_handler(ReEncryptMessagesExecutor)
+        .install_handler(AbortExpiredTransactionsExecutor);
 
     #[cfg(unix)]
-    let (mut ctrl_c, mut sigterm) = {
+    let (mut ctrl_c, mut sigterm, mut sighup) = {
         use tokio::signal::unix::{signal, SignalKind};
         (
             signal(SignalKind::interrupt())?,
             signal(SignalKind::terminate())?,
+            signal(SignalKind::hangup())?,
         )
     };
 
+    #[cfg(unix)]
+    tokio::spawn(async move {
+        while sighup.recv().await.is_some() {
+            info!("Received SIGHUP. Reloading Iggy server configuration...");
+            if let Err(error) = config_reloader.reload().await {
+                error!(
+                    "Failed to reload Iggy server configuration. Error: {}",
+                    error
+                );
+            }
+        }
+    });
+
     let mut current_config = config.clone();
 
     if config.http.enabled {
@@ -106,8 +141,39 @@ async fn main() -> Result<(), ServerError> {
     }
 
     let shutdown_timestamp = Instant::now();
-    let mut system = system.write();
-    system.shutdown().await?;
+    let shutdown_config = config.shutdown;
+    let shutdown_signal = system.read().get_shutdown_signal();
+    shutdown_signal.trigger();
+    let shutdown = async {
+        if tokio::time::timeout(
+            shutdown_config.grace_period.get_duration(),
+            shutdown_signal.wait_for_in_flight_requests(),
+        )
+        .await
+        .is_err()
+        {
+            warn!(
+                "{} in-flight request(s) have not completed within the grace period: {}.",
+                shutdown_signal.get_in_flight_requests(),
+                shutdown_config.grace_period
+            );
+        }
+
+        system.read().shutdown().await
+    };
+    let result = tokio::time::timeout(shutdown_config.timeout.get_duration(), shutdown).await;
+    logging.shutdown_telemetry();
+    if result.is_err() {
+        error!(
+            "Iggy server has not shutdown within the timeout: {}.",
+            shutdown_config.timeout
+        );
+        return Err(ServerError::ShutdownTimeout(
+            shutdown_config.timeout.to_string(),
+        ));
+    }
+
+    result.unwrap()?;
     let elapsed_time = shutdown_timestamp.elapsed();
 
     info!(
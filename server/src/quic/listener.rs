@@ -1,34 +1,69 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use crate::binary::client_certificate::ClientCertificateMapper;
 use crate::binary::command;
+use crate::binary::connection_limiter::{with_timeout, ConnectionLimiter, ConnectionViolation};
 use crate::quic::quic_sender::QuicSender;
 use crate::server_error::ServerError;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
+use crate::streaming::shutdown::wait_for_shutdown;
 use crate::streaming::systems::system::SharedSystem;
-use anyhow::{anyhow, Context};
+use anyhow::Context;
+use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::Command;
-use iggy::{bytes_serializable::BytesSerializable, messages::MAX_PAYLOAD_SIZE};
-use quinn::{Connection, Endpoint, RecvStream, SendStream};
-use tracing::{debug, error, info};
+use iggy::error::IggyError;
+use iggy::tcp::pipelining::PROTOCOL_VERSION;
+use quinn::{Connection, Endpoint, ReadExactError, ReadToEndError, RecvStream, SendStream, VarInt};
+use tokio::sync::{watch, Semaphore};
+use tracing::{debug, error, info, warn};
 
 const LISTENERS_COUNT: u32 = 10;
 const INITIAL_BYTES_LENGTH: usize = 4;
 
-pub fn start(endpoint: Endpoint, system: SharedSystem) {
+pub fn start(
+    endpoint: Endpoint,
+    limiter: ConnectionLimiter,
+    client_certificate_mapper: ClientCertificateMapper,
+    system: SharedSystem,
+) {
     for _ in 0..LISTENERS_COUNT {
         let endpoint = endpoint.clone();
+        let limiter = limiter.clone();
+        let client_certificate_mapper = client_certificate_mapper.clone();
         let system = system.clone();
         tokio::spawn(async move {
-            while let Some(incoming_connection) = endpoint.accept().await {
+            let mut shutdown = system.read().shutdown_signal.subscribe();
+            loop {
+                let incoming_connection = tokio::select! {
+                    incoming_connection = endpoint.accept() => incoming_connection,
+                    _ = wait_for_shutdown(&mut shutdown) => {
+                        info!("QUIC listener has stopped accepting new connections.");
+                        break;
+                    }
+                };
+                if incoming_connection.is_none() {
+                    break;
+                }
+
+                let incoming_connection = incoming_connection.unwrap();
                 info!(
                     "Incoming connection from client: {}",
                     incoming_connection.remote_address()
                 );
+                let limiter = limiter.clone();
+                let client_certificate_mapper = client_certificate_mapper.clone();
                 let system = system.clone();
                 tokio::spawn(async move {
-                    if let Err(error) = handle_connection(incoming_connection, system).await {
+                    if let Err(error) = handle_connection(
+                        incoming_connection,
+                        limiter,
+                        &client_certificate_mapper,
+                        system,
+                    )
+                    .await
+                    {
                         error!("Connection has failed: {error}");
                     }
                 });
@@ -39,20 +74,59 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
 
 async fn handle_connection(
     incoming_connection: quinn::Connecting,
+    limiter: ConnectionLimiter,
+    client_certificate_mapper: &ClientCertificateMapper,
     system: SharedSystem,
 ) -> Result<(), ServerError> {
     let connection = incoming_connection.await?;
     let address = connection.remote_address();
+    let _permit = match limiter.try_acquire(address.ip()) {
+        Ok(permit) => permit,
+        Err(error) => {
+            close_connection(
+                &connection,
+                &system,
+                ConnectionViolation::TooManyConnections,
+                error,
+            );
+            return Ok(());
+        }
+    };
+
     info!("Client has connected: {address}");
     let client_id = system.read().add_client(&address, Transport::Quic).await;
     let session = Arc::new(Session::from_client_id(client_id, address));
+    // Each QUIC response is read until the end of its stream, so the payload of the error response is always safe to send.
+    session.set_protocol_version(PROTOCOL_VERSION);
+    let client_certificate_identity = connection
+        .peer_identity()
+        .and_then(|identity| identity.downcast::<Vec<rustls::Certificate>>().ok())
+        .and_then(|certificates| client_certificate_mapper.map(&certificates));
+    if let Some(identity) = client_certificate_identity {
+        if let Err(error) = system
+            .read()
+            .login_with_client_certificate(&identity.username, identity.allow_root, &session)
+            .await
+        {
+            warn!("Client: {address} was not authenticated with its certificate: {error}");
+        }
+    }
 
-    while let Some(stream) = accept_stream(&connection, &system, &address).await? {
+    let concurrent_requests = Arc::new(Semaphore::new(limiter.max_concurrent_requests()));
+    let mut shutdown = system.read().shutdown_signal.subscribe();
+    while let Some(stream) =
+        accept_stream(&connection, &limiter, &system, &address, &mut shutdown).await?
+    {
+        // The accepted stream waits for the permit, once the limit of the concurrent requests is reached.
+        let permit = concurrent_requests.clone().acquire_owned().await.unwrap();
+        let connection = connection.clone();
+        let limiter = limiter.clone();
         let system = system.clone();
         let session = session.clone();
 
         let handle_stream_task = async move {
-            if let Err(err) = handle_stream(stream, system, session).await {
+            let _permit = permit;
+            if let Err(err) = handle_stream(stream, &connection, &limiter, system, session).await {
                 error!("Error when handling QUIC stream: {:?}", err)
             }
         };
@@ -65,15 +139,38 @@ type BiStream = (SendStream, RecvStream);
 
 async fn accept_stream(
     connection: &Connection,
+    limiter: &ConnectionLimiter,
     system: &SharedSystem,
     address: &SocketAddr,
+    shutdown: &mut watch::Receiver<bool>,
 ) -> Result<Option<BiStream>, ServerError> {
-    match connection.accept_bi().await {
+    let stream = tokio::select! {
+        stream = with_timeout(limiter.idle_timeout(), connection.accept_bi()) => stream,
+        _ = wait_for_shutdown(shutdown) => {
+            info!("Closing the QUIC connection: {address} as the server is shutting down.");
+            system.read().delete_client(address).await;
+            return Ok(None);
+        }
+    };
+
+    if stream.is_none() {
+        let error = IggyError::IdleTimeout(limiter.idle_timeout().to_string());
+        close_connection(connection, system, ConnectionViolation::IdleTimeout, error);
+        system.read().delete_client(address).await;
+        return Ok(None);
+    }
+
+    match stream.unwrap() {
         Err(quinn::ConnectionError::ApplicationClosed { .. }) => {
             info!("Connection closed");
             system.read().delete_client(address).await;
             Ok(None)
         }
+        Err(quinn::ConnectionError::LocallyClosed) => {
+            info!("Connection has been closed by the server.");
+            system.read().delete_client(address).await;
+            Ok(None)
+        }
         Err(error) => {
             error!("Error when handling QUIC stream: {:?}", error);
             system.read().delete_client(address).await;
@@ -85,29 +182,61 @@ async fn accept_stream(
 
 async fn handle_stream(
     stream: BiStream,
+    connection: &Connection,
+    limiter: &ConnectionLimiter,
     system: SharedSystem,
     session: impl AsRef<Session>,
 ) -> anyhow::Result<()> {
     let (send_stream, mut recv_stream) = stream;
-    let request = recv_stream
-        .read_to_end(MAX_PAYLOAD_SIZE as usize)
-        .await
-        .with_context(|| "Error when reading the QUIC request.")?;
-
-    if request.len() < INITIAL_BYTES_LENGTH {
-        return Err(anyhow!(
-            "Unable to read the QUIC request length, expected: {INITIAL_BYTES_LENGTH} bytes, received: {} bytes.",
-            request.len()
-        ));
-    }
+    let request = with_timeout(
+        limiter.read_timeout(),
+        read_request(&mut recv_stream, limiter),
+    )
+    .await;
+    let (length, request) = match request {
+        Some(Ok(request)) => request,
+        Some(Err(error @ IggyError::FrameTooLarge(..))) => {
+            close_connection(
+                connection,
+                &system,
+                ConnectionViolation::FrameTooLarge,
+                error,
+            );
+            return Ok(());
+        }
+        Some(Err(error @ IggyError::MalformedFrame(_))) => {
+            close_connection(
+                connection,
+                &system,
+                ConnectionViolation::MalformedFrame,
+                error,
+            );
+            return Ok(());
+        }
+        Some(Err(error)) => {
+            return Err(error).with_context(|| "Error when reading the QUIC request.");
+        }
+        None => {
+            let error = IggyError::ReadTimeout(limiter.read_timeout().to_string());
+            close_connection(connection, &system, ConnectionViolation::ReadTimeout, error);
+            return Ok(());
+        }
+    };
 
     debug!("Trying to read command...");
-    let length = request[..INITIAL_BYTES_LENGTH]
-        .try_into()
-        .map(u32::from_le_bytes)
-        .unwrap_or_default();
-    let command = Command::from_bytes(&request[INITIAL_BYTES_LENGTH..])
-        .with_context(|| "Error when reading the QUIC request command.")?;
+    let command = match Command::from_bytes(&request) {
+        Ok(command) => command,
+        Err(error) => {
+            let error = IggyError::MalformedFrame(error.to_string());
+            close_connection(
+                connection,
+                &system,
+                ConnectionViolation::MalformedFrame,
+                error,
+            );
+            return Ok(());
+        }
+    };
 
     debug!("Received a QUIC command: {command}, payload size: {length}");
 
@@ -115,7 +244,64 @@ async fn handle_stream(
         send: send_stream,
         recv: recv_stream,
     };
-    command::handle(&command, &mut sender, session.as_ref(), system.clone())
+    command::handle(
+        &command,
+        &mut sender,
+        session.as_ref(),
+        system.clone(),
+        Transport::Quic,
+    )
+    .await
+    .with_context(|| "Error when handling the QUIC request.")
+}
+
+/// Reads the request length and then the command, the frame size is validated before reading the command,
+/// so that the client cannot exhaust the memory.
+async fn read_request(
+    recv_stream: &mut RecvStream,
+    limiter: &ConnectionLimiter,
+) -> Result<(u32, Vec<u8>), IggyError> {
+    let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
+    if let Err(error) = recv_stream.read_exact(&mut initial_buffer).await {
+        return Err(match error {
+            ReadExactError::FinishedEarly => IggyError::MalformedFrame(format!(
+                "expected: {INITIAL_BYTES_LENGTH} bytes of the request length"
+            )),
+            ReadExactError::ReadError(error) => IggyError::ReadError(error),
+        });
+    }
+
+    let length = u32::from_le_bytes(initial_buffer);
+    limiter.validate_frame_size(length as u64)?;
+    let request = recv_stream
+        .read_to_end(length as usize)
         .await
-        .with_context(|| "Error when handling the QUIC request.")
+        .map_err(|error| match error {
+            ReadToEndError::TooLong => IggyError::MalformedFrame(format!(
+                "request is longer than its declared length: {length} bytes"
+            )),
+            ReadToEndError::Read(error) => IggyError::ReadError(error),
+        })?;
+    Ok((length, request))
+}
+
+/// Closes the connection with the error code and reason describing the violation, and records it.
+fn close_connection(
+    connection: &Connection,
+    system: &SharedSystem,
+    violation: ConnectionViolation,
+    error: IggyError,
+) {
+    warn!(
+        "Closing the QUIC connection: {} due to {violation}, error: {error}",
+        connection.remote_address()
+    );
+    system
+        .read()
+        .metrics
+        .record_connection_violation(&Transport::Quic.to_string(), violation.as_str());
+    connection.close(
+        VarInt::from_u32(error.as_code()),
+        error.to_string().as_bytes(),
+    );
 }
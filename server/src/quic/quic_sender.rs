@@ -34,8 +34,12 @@ impl Sender for QuicSender {
         self.send_response(STATUS_OK, payload).await
     }
 
-    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        self.send_response(&error.as_code().to_le_bytes(), &[])
+    async fn send_error_response(
+        &mut self,
+        error: IggyError,
+        payload: &[u8],
+    ) -> Result<(), IggyError> {
+        self.send_response(&error.as_code().to_le_bytes(), payload)
             .await
     }
 }
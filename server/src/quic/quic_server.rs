@@ -1,14 +1,16 @@
+use crate::binary::client_certificate;
+use crate::binary::client_certificate::ClientCertificateMapper;
+use crate::binary::connection_limiter::ConnectionLimiter;
 use crate::configs::quic::QuicConfig;
+use crate::configs::reloader::wait_for_config_update;
 use crate::quic::listener;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use quinn::{Endpoint, IdleTimeout, VarInt};
 use std::error::Error;
-use std::fs::File;
-use std::io::BufReader;
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{error, info};
 
 /// Starts the QUIC server.
 /// Returns the address the server is listening on.
@@ -21,18 +23,62 @@ pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
 
     let endpoint = Endpoint::server(quic_config.unwrap(), config.address.parse().unwrap()).unwrap();
     let addr = endpoint.local_addr().unwrap();
-    listener::start(endpoint, system);
+    if !config.certificate.self_signed {
+        start_certificate_reloader(&system, endpoint.clone());
+    }
+    listener::start(
+        endpoint,
+        ConnectionLimiter::new(config.limits),
+        ClientCertificateMapper::new(&config.client_certificate),
+        system,
+    );
     info!("Iggy QUIC server has started on: {:?}", addr);
     addr
 }
 
+fn start_certificate_reloader(system: &SharedSystem, endpoint: Endpoint) {
+    let mut config_updates = system.read().subscribe_config_updates();
+    if config_updates.is_none() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        loop {
+            let config = wait_for_config_update(&mut config_updates).await;
+            match configure_quic(&config.quic) {
+                Ok(server_config) => {
+                    endpoint.set_server_config(Some(server_config));
+                    info!("QUIC certificate has been reloaded.");
+                }
+                Err(error) => error!("Unable to reload QUIC certificate, error: {error}"),
+            }
+        }
+    });
+}
+
 fn configure_quic(config: &QuicConfig) -> Result<quinn::ServerConfig, Box<dyn Error>> {
     let (certificate, key) = match config.certificate.self_signed {
         true => generate_self_signed_cert()?,
-        false => load_certificates(&config.certificate.cert_file, &config.certificate.key_file)?,
+        false => client_certificate::load_certificates(
+            &config.certificate.cert_file,
+            &config.certificate.key_file,
+        )?,
     };
 
-    let mut server_config = quinn::ServerConfig::with_single_cert(certificate, key)?;
+    let mut server_config = match config.client_certificate.enabled {
+        true => {
+            let tls_config = rustls::ServerConfig::builder()
+                .with_safe_default_cipher_suites()
+                .with_safe_default_kx_groups()
+                .with_protocol_versions(&[&rustls::version::TLS13])?
+                .with_client_cert_verifier(client_certificate::create_verifier(
+                    &config.client_certificate,
+                )?)
+                .with_single_cert(certificate, key)?;
+            quinn::ServerConfig::with_crypto(Arc::new(tls_config))
+        }
+        false => quinn::ServerConfig::with_single_cert(certificate, key)?,
+    };
     let mut transport = quinn::TransportConfig::default();
     transport.initial_mtu(config.initial_mtu.as_bytes_u64() as u16);
     transport.send_window(config.send_window.as_bytes_u64());
@@ -60,19 +106,3 @@ fn generate_self_signed_cert(
     let cert_chain = vec![rustls::Certificate(certificate_der)];
     Ok((cert_chain, private_key))
 }
-
-fn load_certificates(
-    cert_file: &str,
-    key_file: &str,
-) -> Result<(Vec<rustls::Certificate>, rustls::PrivateKey), Box<dyn Error>> {
-    let mut cert_chain_reader = BufReader::new(File::open(cert_file)?);
-    let certs = rustls_pemfile::certs(&mut cert_chain_reader)
-        .map(|x| rustls::Certificate(x.unwrap().to_vec()))
-        .collect();
-    let mut key_reader = BufReader::new(File::open(key_file)?);
-    let mut keys = rustls_pemfile::rsa_private_keys(&mut key_reader)
-        .map(|x| rustls::PrivateKey(x.unwrap().secret_pkcs1_der().to_vec()))
-        .collect::<Vec<_>>();
-    let key = rustls::PrivateKey(keys.remove(0).0);
-    Ok((certs, key))
-}
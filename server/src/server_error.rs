@@ -25,10 +25,18 @@ pub enum ServerError {
     TryFromSliceError(#[from] TryFromSliceError),
     #[error("Logging filter reload failure")]
     FilterReloadFailure,
+    #[error("Invalid logging filter: {0}")]
+    InvalidLogFilter(String),
     #[error("Logging stdout reload failure")]
     StdoutReloadFailure,
     #[error("Logging file reload failure")]
     FileReloadFailure,
+    #[error("Logging telemetry reload failure")]
+    TelemetryReloadFailure,
+    #[error("Cannot initialize telemetry: {0}")]
+    CannotInitializeTelemetry(String),
     #[error("Cache config validation failure: {0}")]
     CacheConfigValidationFailure(String),
+    #[error("Shutdown has not completed within the timeout: {0}")]
+    ShutdownTimeout(String),
 }
@@ -0,0 +1,63 @@
+use std::fmt::{Display, Formatter};
+
+/// The administrative and security actions recorded in the audit log.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuditAction {
+    CreateStream,
+    DeleteStream,
+    CreateTopic,
+    DeleteTopic,
+    CreatePartitions,
+    DeletePartitions,
+    CreateUser,
+    DeleteUser,
+    UpdatePermissions,
+    UpdateUserRoles,
+    UnlockUser,
+    CreateRole,
+    UpdateRole,
+    DeleteRole,
+    LoginUser,
+    LoginWithPersonalAccessToken,
+    LoginWithClientCertificate,
+    LoginWithToken,
+    CreatePersonalAccessToken,
+    DeletePersonalAccessToken,
+    ReloadConfig,
+    SetLogFilter,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::CreateStream => "create_stream",
+            AuditAction::DeleteStream => "delete_stream",
+            AuditAction::CreateTopic => "create_topic",
+            AuditAction::DeleteTopic => "delete_topic",
+            AuditAction::CreatePartitions => "create_partitions",
+            AuditAction::DeletePartitions => "delete_partitions",
+            AuditAction::CreateUser => "create_user",
+            AuditAction::DeleteUser => "delete_user",
+            AuditAction::UpdatePermissions => "update_permissions",
+            AuditAction::UpdateUserRoles => "update_user_roles",
+            AuditAction::UnlockUser => "unlock_user",
+            AuditAction::CreateRole => "create_role",
+            AuditAction::UpdateRole => "update_role",
+            AuditAction::DeleteRole => "delete_role",
+            AuditAction::LoginUser => "login_user",
+            AuditAction::LoginWithPersonalAccessToken => "login_with_personal_access_token",
+            AuditAction::LoginWithClientCertificate => "login_with_client_certificate",
+            AuditAction::LoginWithToken => "login_with_token",
+            AuditAction::CreatePersonalAccessToken => "create_personal_access_token",
+            AuditAction::DeletePersonalAccessToken => "delete_personal_access_token",
+            AuditAction::ReloadConfig => "reload_config",
+            AuditAction::SetLogFilter => "set_log_filter",
+        }
+    }
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,378 @@
+use crate::configs::system::SystemConfig;
+use crate::streaming::audit::audit_action::AuditAction;
+use crate::streaming::utils::{file, hash};
+use anyhow::Context;
+use iggy::error::IggyError;
+use iggy::models::audit_log_entry::AuditLogEntry;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::fs;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::Mutex;
+use tracing::{error, info, warn};
+
+/// The append-only log of the administrative and security events, stored as JSON lines.
+/// Each entry contains the hash of the previous one, so any modification or removal of the entries breaks the chain,
+/// which is verified when the log is loaded on startup. The ID and hash of the last entry (the chain head)
+/// are stored in the separate file, so that the removal of the last entries is detectable as well.
+#[derive(Debug)]
+pub struct AuditLog {
+    enabled: bool,
+    path: String,
+    head_path: String,
+    state: Mutex<AuditLogState>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AuditLogHead {
+    id: u64,
+    hash: String,
+}
+
+#[derive(Debug, Default)]
+struct AuditLogState {
+    next_id: u64,
+    last_hash: String,
+}
+
+/// The event to be recorded in the audit log, along with the details of the client which triggered it.
+#[derive(Debug)]
+pub struct AuditEvent {
+    pub user_id: u32,
+    pub client_id: u32,
+    pub address: String,
+    pub transport: String,
+    pub action: AuditAction,
+    pub resource: String,
+    pub succeeded: bool,
+}
+
+impl AuditLog {
+    pub fn new(config: &SystemConfig) -> Self {
+        Self {
+            enabled: config.audit.enabled,
+            path: config.get_audit_path(),
+            head_path: format!("{}.head", config.get_audit_path()),
+            state: Mutex::new(AuditLogState::default()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Creates the audit log file if it doesn't exist yet, otherwise verifies the chain of the existing entries.
+    pub async fn init(&self) -> Result<(), IggyError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if !Path::new(&self.path).exists() {
+            if Path::new(&self.head_path).exists() {
+                error!(
+                    "Audit log at: {} was not found, but its head exists, it might have been removed.",
+                    self.path
+                );
+            }
+            file::write(&self.path).await?;
+            info!("Created the audit log at: {}", self.path);
+            return Ok(());
+        }
+
+        let (entries, valid_length) = self.load_entries().await?;
+        let length = fs::metadata(&self.path).await?.len();
+        if valid_length < length {
+            warn!(
+                "Audit log at: {} ends with the partially written entry ({} bytes), which will be removed.",
+                self.path,
+                length - valid_length
+            );
+            let file = file::write(&self.path).await?;
+            file.set_len(valid_length).await?;
+            file.sync_all().await?;
+        }
+
+        let mut state = self.state.lock().await;
+        if let Err(error) = self.verify_chain(&entries).await {
+            error!(
+                "Audit log at: {} failed the verification, it might have been tampered with. {error}",
+                self.path
+            );
+        }
+
+        if let Some(entry) = entries.last() {
+            state.next_id = entry.id + 1;
+            state.last_hash = entry.hash.clone();
+        }
+        info!(
+            "Loaded {} audit log entries from: {}",
+            entries.len(),
+            self.path
+        );
+        Ok(())
+    }
+
+    /// Appends the event to the audit log, chaining it with the previous entry.
+    pub async fn append(&self, event: AuditEvent) -> Result<(), IggyError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let mut state = self.state.lock().await;
+        let mut entry = AuditLogEntry {
+            id: state.next_id,
+            timestamp: IggyTimestamp::now().to_micros(),
+            user_id: event.user_id,
+            client_id: event.client_id,
+            address: event.address,
+            transport: event.transport,
+            action: event.action.to_string(),
+            resource: event.resource,
+            succeeded: event.succeeded,
+            previous_hash: state.last_hash.clone(),
+            hash: String::new(),
+        };
+        entry.hash = calculate_hash(&entry)?;
+        let mut line = serde_json::to_vec(&entry)
+            .with_context(|| format!("Failed to serialize audit log entry with ID: {}", entry.id))
+            .map_err(IggyError::CannotSerializeResource)?;
+        line.push(b'\n');
+        let mut file = file::append(&self.path).await?;
+        file.write_all(&line).await?;
+        file.sync_data().await?;
+        state.next_id += 1;
+        state.last_hash = entry.hash.clone();
+        self.save_head(&AuditLogHead {
+            id: entry.id,
+            hash: entry.hash,
+        })
+        .await
+    }
+
+    /// Returns at most `count` entries, starting from the entry with the given ID.
+    pub async fn read(&self, offset: u64, count: u32) -> Result<Vec<AuditLogEntry>, IggyError> {
+        if !self.enabled {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let _state = self.state.lock().await;
+        let mut entries = Vec::new();
+        let mut lines = BufReader::new(file::open(&self.path).await?).lines();
+        let mut position = 0;
+        while let Some(line) = lines.next_line().await? {
+            if position < offset {
+                position += 1;
+                continue;
+            }
+
+            entries.push(parse_entry(line.as_bytes())?);
+            if entries.len() as u32 >= count {
+                break;
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Verifies the chain of all the entries stored in the audit log.
+    pub async fn verify(&self) -> Result<(), IggyError> {
+        if !self.enabled {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let _state = self.state.lock().await;
+        let (entries, _) = self.load_entries().await?;
+        self.verify_chain(&entries).await
+    }
+
+    async fn verify_chain(&self, entries: &[AuditLogEntry]) -> Result<(), IggyError> {
+        verify_entries(entries)?;
+        match self.load_head().await? {
+            Some(head) => verify_head(entries, &head),
+            None if entries.is_empty() => Ok(()),
+            None => {
+                warn!(
+                    "Audit log head at: {} was not found, the removal of the last entries cannot be detected.",
+                    self.head_path
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Loads the entries along with the length of the file they occupy. The partially written last entry
+    /// (e.g. due to the crash during the append) is ignored, as it has never been acknowledged.
+    async fn load_entries(&self) -> Result<(Vec<AuditLogEntry>, u64), IggyError> {
+        let mut entries = Vec::new();
+        let mut reader = BufReader::new(file::open(&self.path).await?);
+        let mut line = Vec::new();
+        let mut valid_length = 0;
+        loop {
+            line.clear();
+            let read_bytes = reader.read_until(b'\n', &mut line).await?;
+            if read_bytes == 0 {
+                break;
+            }
+
+            match parse_entry(&line) {
+                Ok(entry) => {
+                    entries.push(entry);
+                    valid_length += read_bytes as u64;
+                }
+                Err(error) => {
+                    if !reader.fill_buf().await?.is_empty() {
+                        return Err(error);
+                    }
+                    break;
+                }
+            }
+        }
+
+        Ok((entries, valid_length))
+    }
+
+    async fn load_head(&self) -> Result<Option<AuditLogHead>, IggyError> {
+        if !Path::new(&self.head_path).exists() {
+            return Ok(None);
+        }
+
+        let head = fs::read(&self.head_path).await?;
+        let head = serde_json::from_slice(&head)
+            .with_context(|| format!("Failed to deserialize audit log head: {}", self.head_path))
+            .map_err(IggyError::CannotDeserializeResource)?;
+        Ok(Some(head))
+    }
+
+    /// Replaces the head atomically, so that it's never partially written.
+    async fn save_head(&self, head: &AuditLogHead) -> Result<(), IggyError> {
+        let data = serde_json::to_vec(head)
+            .with_context(|| format!("Failed to serialize audit log head: {}", self.head_path))
+            .map_err(IggyError::CannotSerializeResource)?;
+        let temporary_path = format!("{}.tmp", self.head_path);
+        let mut file = file::overwrite(&temporary_path).await?;
+        file.write_all(&data).await?;
+        file.sync_all().await?;
+        fs::rename(&temporary_path, &self.head_path).await?;
+        Ok(())
+    }
+}
+
+fn parse_entry(line: &[u8]) -> Result<AuditLogEntry, IggyError> {
+    serde_json::from_slice(line)
+        .with_context(|| "Failed to deserialize audit log entry")
+        .map_err(IggyError::CannotDeserializeResource)
+}
+
+fn calculate_hash(entry: &AuditLogEntry) -> Result<String, IggyError> {
+    let entry = AuditLogEntry {
+        hash: String::new(),
+        ..entry.clone()
+    };
+    let data = serde_json::to_vec(&entry)
+        .with_context(|| format!("Failed to serialize audit log entry with ID: {}", entry.id))
+        .map_err(IggyError::CannotSerializeResource)?;
+    Ok(hash::calculate_256(&data))
+}
+
+fn verify_entries(entries: &[AuditLogEntry]) -> Result<(), IggyError> {
+    let mut previous_hash = "";
+    for (id, entry) in entries.iter().enumerate() {
+        if entry.id != id as u64
+            || entry.previous_hash != previous_hash
+            || entry.hash != calculate_hash(entry)?
+        {
+            return Err(IggyError::InvalidAuditLogEntry(id as u64));
+        }
+        previous_hash = &entry.hash;
+    }
+
+    Ok(())
+}
+
+fn verify_head(entries: &[AuditLogEntry], head: &AuditLogHead) -> Result<(), IggyError> {
+    match entries.get(head.id as usize) {
+        Some(entry) if entry.hash == head.hash => Ok(()),
+        _ => Err(IggyError::InvalidAuditLogEntry(head.id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_entries(count: u64) -> Vec<AuditLogEntry> {
+        let mut entries: Vec<AuditLogEntry> = Vec::new();
+        for id in 0..count {
+            let mut entry = AuditLogEntry {
+                id,
+                timestamp: id,
+                user_id: 1,
+                client_id: 1,
+                address: "127.0.0.1:1234".to_string(),
+                transport: "TCP".to_string(),
+                action: AuditAction::CreateStream.to_string(),
+                resource: format!("stream with ID: {id}"),
+                succeeded: true,
+                previous_hash: entries
+                    .last()
+                    .map(|entry| entry.hash.clone())
+                    .unwrap_or_default(),
+                hash: String::new(),
+            };
+            entry.hash = calculate_hash(&entry).unwrap();
+            entries.push(entry);
+        }
+        entries
+    }
+
+    #[test]
+    fn valid_chain_should_be_verified() {
+        let entries = create_entries(3);
+        assert!(verify_entries(&entries).is_ok());
+    }
+
+    #[test]
+    fn modified_entry_should_break_the_chain() {
+        let mut entries = create_entries(3);
+        entries[1].user_id = 2;
+        assert!(matches!(
+            verify_entries(&entries),
+            Err(IggyError::InvalidAuditLogEntry(1))
+        ));
+    }
+
+    #[test]
+    fn removed_entry_should_break_the_chain() {
+        let mut entries = create_entries(3);
+        entries.remove(1);
+        assert!(matches!(
+            verify_entries(&entries),
+            Err(IggyError::InvalidAuditLogEntry(1))
+        ));
+    }
+
+    #[test]
+    fn head_matching_the_entry_should_be_verified() {
+        let entries = create_entries(3);
+        let head = AuditLogHead {
+            id: 2,
+            hash: entries[2].hash.clone(),
+        };
+        assert!(verify_head(&entries, &head).is_ok());
+    }
+
+    #[test]
+    fn removed_last_entry_should_not_match_the_head() {
+        let mut entries = create_entries(3);
+        let head = AuditLogHead {
+            id: 2,
+            hash: entries[2].hash.clone(),
+        };
+        entries.pop();
+        assert!(matches!(
+            verify_head(&entries, &head),
+            Err(IggyError::InvalidAuditLogEntry(2))
+        ));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod audit_action;
+pub mod audit_log;
@@ -3,6 +3,7 @@ use atone::Vc;
 use iggy::sizeable::Sizeable;
 use std::fmt::Debug;
 use std::ops::Index;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -10,6 +11,8 @@ pub struct SmartCache<T: Sizeable + Debug> {
     current_size: u64,
     buffer: Vc<T>,
     memory_tracker: Arc<CacheMemoryTracker>,
+    hits: AtomicU64,
+    misses: AtomicU64,
 }
 
 impl<T> SmartCache<T>
@@ -25,6 +28,8 @@ where
             current_size,
             buffer,
             memory_tracker,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
         }
     }
 
@@ -99,6 +104,25 @@ where
     pub fn len(&self) -> usize {
         self.buffer.len()
     }
+
+    /// Records whether the read could be served from the cache.
+    pub fn record_read(&self, hit: bool) {
+        match hit {
+            true => self.hits.fetch_add(1, Ordering::Relaxed),
+            false => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+    }
+
+    /// Returns the ratio of the reads served from the cache, or `None` if there were no reads yet.
+    pub fn hit_ratio(&self) -> Option<f64> {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let reads = hits + self.misses.load(Ordering::Relaxed);
+        if reads == 0 {
+            return None;
+        }
+
+        Some(hits as f64 / reads as f64)
+    }
 }
 
 impl<T> Index<usize> for SmartCache<T>
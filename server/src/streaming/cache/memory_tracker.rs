@@ -13,7 +13,7 @@ static mut INSTANCE: Option<Arc<CacheMemoryTracker>> = None;
 #[derive(Debug)]
 pub struct CacheMemoryTracker {
     used_memory_bytes: AtomicU64,
-    limit_bytes: u64,
+    limit_bytes: AtomicU64,
 }
 
 type MessageSize = u64;
@@ -55,7 +55,7 @@ impl CacheMemoryTracker {
 
         CacheMemoryTracker {
             used_memory_bytes,
-            limit_bytes,
+            limit_bytes: AtomicU64::new(limit_bytes),
         }
     }
 
@@ -96,6 +96,17 @@ impl CacheMemoryTracker {
     }
 
     pub fn will_fit_into_cache(&self, requested_size: u64) -> bool {
-        self.used_memory_bytes.load(Ordering::SeqCst) + requested_size <= self.limit_bytes
+        self.used_memory_bytes.load(Ordering::SeqCst) + requested_size
+            <= self.limit_bytes.load(Ordering::SeqCst)
+    }
+
+    /// Changes the cache limit at runtime, the cache exceeding the new limit is cleaned on the next append.
+    pub fn set_limit(&self, limit: MemoryResourceQuota) {
+        let limit_bytes = limit.into();
+        self.limit_bytes.store(limit_bytes, Ordering::SeqCst);
+        info!(
+            "Cache memory tracker limit changed to: {} bytes.",
+            limit_bytes
+        );
     }
 }
@@ -1,6 +1,8 @@
 use crate::streaming::utils::hash;
 use iggy::error::IggyError;
 use iggy::models::user_info::UserId;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::net::SocketAddr;
@@ -19,6 +21,7 @@ pub struct Client {
     pub address: SocketAddr,
     pub transport: Transport,
     pub consumer_groups: Vec<ConsumerGroup>,
+    pub transaction: Option<Transaction>,
 }
 
 #[derive(Debug)]
@@ -28,6 +31,28 @@ pub struct ConsumerGroup {
     pub consumer_group_id: u32,
 }
 
+#[derive(Debug)]
+pub struct Transaction {
+    pub transaction_id: u64,
+    pub partitions: Vec<TransactionPartition>,
+    pub started_at: u64,
+    /// Set once the transaction has been aborted after exceeding the timeout, until the client ends it.
+    pub expired: bool,
+}
+
+impl Transaction {
+    pub fn is_expired(&self, now: u64, timeout: IggyDuration) -> bool {
+        self.expired || self.started_at + timeout.as_micros() <= now
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TransactionPartition {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Transport {
     Tcp,
@@ -52,6 +77,7 @@ impl ClientManager {
             address: *address,
             transport,
             consumer_groups: Vec::new(),
+            transaction: None,
         };
         self.clients
             .insert(client.client_id, Arc::new(RwLock::new(client)));
@@ -219,4 +245,162 @@ impl ClientManager {
             }
         }
     }
+
+    pub async fn begin_transaction(
+        &self,
+        client_id: u32,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        let client = self.get_client_by_id(client_id)?;
+        let mut client = client.write().await;
+        if let Some(transaction) = &client.transaction {
+            if !transaction.expired {
+                return Err(IggyError::TransactionAlreadyStarted(
+                    transaction.transaction_id,
+                ));
+            }
+        }
+
+        client.transaction = Some(Transaction {
+            transaction_id,
+            partitions: Vec::new(),
+            started_at: IggyTimestamp::now().to_micros(),
+            expired: false,
+        });
+        Ok(())
+    }
+
+    pub async fn get_transaction_id(&self, client_id: u32) -> Result<Option<u64>, IggyError> {
+        let client = self.get_client_by_id(client_id)?;
+        let client = client.read().await;
+        match &client.transaction {
+            Some(transaction) if transaction.expired => {
+                Err(IggyError::TransactionExpired(transaction.transaction_id))
+            }
+            Some(transaction) => Ok(Some(transaction.transaction_id)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn add_transaction_partition(
+        &self,
+        client_id: u32,
+        partition: TransactionPartition,
+    ) -> Result<(), IggyError> {
+        let client = self.get_client_by_id(client_id)?;
+        let mut client = client.write().await;
+        if let Some(transaction) = client.transaction.as_mut() {
+            if transaction.expired {
+                return Err(IggyError::TransactionExpired(transaction.transaction_id));
+            }
+
+            if !transaction.partitions.contains(&partition) {
+                transaction.partitions.push(partition);
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn take_transaction(
+        &self,
+        client_id: u32,
+        transaction_id: u64,
+    ) -> Result<Transaction, IggyError> {
+        let client = self.get_client_by_id(client_id)?;
+        let mut client = client.write().await;
+        let current_transaction_id = client
+            .transaction
+            .as_ref()
+            .map(|transaction| transaction.transaction_id);
+        if current_transaction_id != Some(transaction_id) {
+            return Err(IggyError::TransactionNotFound(transaction_id));
+        }
+
+        Ok(client.transaction.take().unwrap())
+    }
+
+    /// Marks the transactions exceeding the timeout as expired, returning them to be aborted.
+    /// The expired transaction is kept until the client ends it, so that its further requests are rejected.
+    pub async fn expire_transactions(&self, timeout: IggyDuration) -> Vec<(u32, Transaction)> {
+        let now = IggyTimestamp::now().to_micros();
+        let mut expired_transactions = Vec::new();
+        for client in self.clients.values() {
+            let mut client = client.write().await;
+            let client_id = client.client_id;
+            if let Some(transaction) = client.transaction.as_mut() {
+                if transaction.expired || !transaction.is_expired(now, timeout) {
+                    continue;
+                }
+
+                transaction.expired = true;
+                expired_transactions.push((
+                    client_id,
+                    Transaction {
+                        transaction_id: transaction.transaction_id,
+                        partitions: std::mem::take(&mut transaction.partitions),
+                        started_at: transaction.started_at,
+                        expired: true,
+                    },
+                ));
+            }
+        }
+        expired_transactions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, SocketAddrV4};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn expired_transaction_should_be_kept_until_the_next_one_is_started() {
+        let mut client_manager = ClientManager::default();
+        let address = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 1234));
+        let client_id = client_manager.add_client(&address, Transport::Tcp);
+        let partition = TransactionPartition {
+            stream_id: 1,
+            topic_id: 1,
+            partition_id: 1,
+        };
+        client_manager
+            .begin_transaction(client_id, 1)
+            .await
+            .unwrap();
+        client_manager
+            .add_transaction_partition(client_id, partition)
+            .await
+            .unwrap();
+
+        let not_expired_transactions = client_manager
+            .expire_transactions(IggyDuration::new(Duration::from_secs(60)))
+            .await;
+        assert!(not_expired_transactions.is_empty());
+
+        let expired_transactions = client_manager
+            .expire_transactions(IggyDuration::new(Duration::ZERO))
+            .await;
+        assert_eq!(expired_transactions.len(), 1);
+        assert_eq!(expired_transactions[0].1.partitions, vec![partition]);
+        assert!(matches!(
+            client_manager.get_transaction_id(client_id).await,
+            Err(IggyError::TransactionExpired(1))
+        ));
+        assert!(matches!(
+            client_manager
+                .add_transaction_partition(client_id, partition)
+                .await,
+            Err(IggyError::TransactionExpired(1))
+        ));
+
+        client_manager
+            .begin_transaction(client_id, 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            client_manager.get_transaction_id(client_id).await.unwrap(),
+            Some(2)
+        );
+    }
 }
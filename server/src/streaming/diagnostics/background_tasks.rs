@@ -0,0 +1,65 @@
+use dashmap::DashMap;
+use std::sync::Arc;
+
+/// Tracks the state of the background tasks (e.g. the message saver), so that the health check can report the stopped ones.
+#[derive(Debug, Default, Clone)]
+pub struct BackgroundTasks {
+    tasks: Arc<DashMap<&'static str, bool>>,
+}
+
+/// Marks the background task as stopped once dropped, i.e. when the task completes or panics.
+#[derive(Debug)]
+pub struct BackgroundTaskGuard {
+    name: &'static str,
+    tasks: Arc<DashMap<&'static str, bool>>,
+}
+
+impl BackgroundTasks {
+    /// Registers the running task, the returned guard should be moved into the task.
+    pub fn register(&self, name: &'static str) -> BackgroundTaskGuard {
+        self.tasks.insert(name, true);
+        BackgroundTaskGuard {
+            name,
+            tasks: self.tasks.clone(),
+        }
+    }
+
+    /// Returns the names of the registered tasks and whether they are still running.
+    pub fn get_states(&self) -> Vec<(&'static str, bool)> {
+        let mut states = self
+            .tasks
+            .iter()
+            .map(|task| (*task.key(), *task.value()))
+            .collect::<Vec<_>>();
+        states.sort();
+        states
+    }
+}
+
+impl Drop for BackgroundTaskGuard {
+    fn drop(&mut self) {
+        self.tasks.insert(self.name, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn task_should_be_reported_as_stopped_once_its_guard_is_dropped() {
+        let tasks = BackgroundTasks::default();
+        let saver = tasks.register("message_saver");
+        let _cleaner = tasks.register("message_cleaner");
+        assert_eq!(
+            tasks.get_states(),
+            vec![("message_cleaner", true), ("message_saver", true)]
+        );
+
+        drop(saver);
+        assert_eq!(
+            tasks.get_states(),
+            vec![("message_cleaner", true), ("message_saver", false)]
+        );
+    }
+}
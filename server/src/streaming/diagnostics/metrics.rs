@@ -1,9 +1,44 @@
 use prometheus_client::encoding::text::encode;
+use prometheus_client::encoding::EncodeLabelSet;
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
 use prometheus_client::registry::Registry;
+use std::sync::atomic::AtomicU64;
+use std::time::Duration;
 use tracing::error;
 
+type HistogramConstructor = fn() -> Histogram;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct PartitionLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct RequestLabels {
+    pub command: String,
+    pub transport: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct ConsumerLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub kind: String,
+    pub consumer_id: u32,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct ConnectionLabels {
+    pub transport: String,
+    pub violation: String,
+}
+
 #[derive(Debug)]
 pub(crate) struct Metrics {
     registry: Registry,
@@ -15,6 +50,17 @@ pub(crate) struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    requests: Family<RequestLabels, Counter>,
+    request_duration: Family<RequestLabels, Histogram, HistogramConstructor>,
+    appended_messages: Family<PartitionLabels, Counter>,
+    appended_bytes: Family<PartitionLabels, Counter>,
+    polled_messages: Family<PartitionLabels, Counter>,
+    polled_bytes: Family<PartitionLabels, Counter>,
+    partition_segments: Family<PartitionLabels, Gauge>,
+    cache_hit_ratio: Family<PartitionLabels, Gauge<f64, AtomicU64>>,
+    consumer_lag: Family<ConsumerLabels, Gauge>,
+    fsync_duration: Histogram,
+    connection_violations: Family<ConnectionLabels, Counter>,
 }
 
 impl Metrics {
@@ -29,19 +75,91 @@ impl Metrics {
             messages: Gauge::default(),
             users: Gauge::default(),
             clients: Gauge::default(),
+            requests: Family::default(),
+            request_duration: Family::new_with_constructor(Self::create_duration_histogram),
+            appended_messages: Family::default(),
+            appended_bytes: Family::default(),
+            polled_messages: Family::default(),
+            polled_bytes: Family::default(),
+            partition_segments: Family::default(),
+            cache_hit_ratio: Family::default(),
+            consumer_lag: Family::default(),
+            fsync_duration: Self::create_duration_histogram(),
+            connection_violations: Family::default(),
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
         metrics.register_gauge("streams", metrics.streams.clone());
+        metrics.register_gauge("topics", metrics.topics.clone());
         metrics.register_gauge("partitions", metrics.partitions.clone());
         metrics.register_gauge("segments", metrics.segments.clone());
         metrics.register_gauge("messages", metrics.messages.clone());
         metrics.register_gauge("users", metrics.users.clone());
         metrics.register_gauge("clients", metrics.clients.clone());
+        metrics.registry.register(
+            "requests",
+            "total count of requests by command and transport",
+            metrics.requests.clone(),
+        );
+        metrics.registry.register(
+            "request_duration_seconds",
+            "duration of requests by command and transport",
+            metrics.request_duration.clone(),
+        );
+        metrics.registry.register(
+            "appended_messages",
+            "total count of messages appended to the partition",
+            metrics.appended_messages.clone(),
+        );
+        metrics.registry.register(
+            "appended_bytes",
+            "total size of messages appended to the partition",
+            metrics.appended_bytes.clone(),
+        );
+        metrics.registry.register(
+            "polled_messages",
+            "total count of messages polled from the partition",
+            metrics.polled_messages.clone(),
+        );
+        metrics.registry.register(
+            "polled_bytes",
+            "total size of messages polled from the partition",
+            metrics.polled_bytes.clone(),
+        );
+        metrics.registry.register(
+            "partition_segments",
+            "count of segments in the partition",
+            metrics.partition_segments.clone(),
+        );
+        metrics.registry.register(
+            "cache_hit_ratio",
+            "ratio of polls served from the partition cache",
+            metrics.cache_hit_ratio.clone(),
+        );
+        metrics.registry.register(
+            "consumer_lag",
+            "count of messages not yet consumed by the consumer or consumer group",
+            metrics.consumer_lag.clone(),
+        );
+        metrics.registry.register(
+            "fsync_duration_seconds",
+            "duration of the file synchronization",
+            metrics.fsync_duration.clone(),
+        );
+        metrics.registry.register(
+            "connection_violations",
+            "total count of connections closed due to the violated limits or malformed frames",
+            metrics.connection_violations.clone(),
+        );
 
         metrics
     }
 
+    fn create_duration_histogram() -> Histogram {
+        // From 10 µs up to ~5 s.
+        Histogram::new(exponential_buckets(0.00001, 2.0, 20))
+    }
+
     fn register_counter(&mut self, name: &str, counter: Counter) {
         self.registry
             .register(name, format!("total count of {name}"), counter)
@@ -119,4 +237,95 @@ impl Metrics {
     pub fn decrement_clients(&self, count: u32) {
         self.clients.dec_by(count as i64);
     }
+
+    /// Returns the histogram of the file synchronization durations, shared with the persister.
+    pub fn get_fsync_duration(&self) -> Histogram {
+        self.fsync_duration.clone()
+    }
+
+    pub fn record_request(&self, command: &str, transport: &str, duration: Duration) {
+        let labels = RequestLabels {
+            command: command.to_string(),
+            transport: transport.to_string(),
+        };
+        self.requests.get_or_create(&labels).inc();
+        self.request_duration
+            .get_or_create(&labels)
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn record_appended_messages(&self, labels: &PartitionLabels, count: u64, size_bytes: u64) {
+        self.appended_messages.get_or_create(labels).inc_by(count);
+        self.appended_bytes.get_or_create(labels).inc_by(size_bytes);
+    }
+
+    pub fn record_polled_messages(&self, labels: &PartitionLabels, count: u64, size_bytes: u64) {
+        self.polled_messages.get_or_create(labels).inc_by(count);
+        self.polled_bytes.get_or_create(labels).inc_by(size_bytes);
+    }
+
+    pub fn set_segments(&self, count: u32) {
+        self.segments.set(count as i64);
+    }
+
+    pub fn set_partition_segments(&self, labels: &PartitionLabels, count: u32) {
+        self.partition_segments
+            .get_or_create(labels)
+            .set(count as i64);
+    }
+
+    pub fn set_cache_hit_ratio(&self, labels: &PartitionLabels, ratio: f64) {
+        self.cache_hit_ratio.get_or_create(labels).set(ratio);
+    }
+
+    pub fn set_consumer_lag(&self, labels: &ConsumerLabels, lag: u64) {
+        self.consumer_lag.get_or_create(labels).set(lag as i64);
+    }
+
+    pub fn record_connection_violation(&self, transport: &str, violation: &str) {
+        self.connection_violations
+            .get_or_create(&ConnectionLabels {
+                transport: transport.to_string(),
+                violation: violation.to_string(),
+            })
+            .inc();
+    }
+
+    /// Removes the metrics of the partitions and consumers, so that the deleted ones are not reported anymore.
+    pub fn clear_partition_state(&self) {
+        self.partition_segments.clear();
+        self.cache_hit_ratio.clear();
+        self.consumer_lag.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labeled_metrics_should_be_included_in_formatted_output() {
+        let metrics = Metrics::init();
+        metrics.record_request("stream.get", "TCP", Duration::from_millis(5));
+        metrics.record_appended_messages(
+            &PartitionLabels {
+                stream_id: 1,
+                topic_id: 2,
+                partition_id: 3,
+            },
+            10,
+            1000,
+        );
+
+        let output = metrics.get_formatted_output();
+
+        assert!(output.contains(r#"requests_total{command="stream.get",transport="TCP"} 1"#));
+        assert!(output
+            .contains(r#"request_duration_seconds_count{command="stream.get",transport="TCP"} 1"#));
+        assert!(output.contains(
+            r#"appended_messages_total{stream_id="1",topic_id="2",partition_id="3"} 10"#
+        ));
+        assert!(output
+            .contains(r#"appended_bytes_total{stream_id="1",topic_id="2",partition_id="3"} 1000"#));
+    }
 }
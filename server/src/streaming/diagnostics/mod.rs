@@ -1 +1,2 @@
+pub mod background_tasks;
 pub mod metrics;
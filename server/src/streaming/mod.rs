@@ -1,3 +1,4 @@
+pub mod audit;
 pub mod cache;
 pub mod clients;
 mod deduplication;
@@ -7,8 +8,11 @@ pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod quotas;
+pub mod roles;
 pub mod segments;
 pub mod session;
+pub mod shutdown;
 pub mod storage;
 pub mod streams;
 pub mod systems;
@@ -4,8 +4,9 @@ use crate::streaming::segments::segment::Segment;
 use crate::streaming::utils::random_id;
 use iggy::error::IggyError;
 use iggy::models::messages::Message;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
-use tracing::{trace, warn};
+use tracing::{info, trace, warn};
 
 const EMPTY_MESSAGES: Vec<Arc<Message>> = vec![];
 
@@ -218,6 +219,7 @@ impl Partition {
     ) -> Option<Vec<Arc<Message>>> {
         let cache = self.cache.as_ref()?;
         if cache.is_empty() || start_offset > end_offset || end_offset > self.current_offset {
+            cache.record_read(false);
             return None;
         }
 
@@ -229,9 +231,11 @@ impl Partition {
         );
 
         if start_offset >= first_buffered_offset {
+            cache.record_read(true);
             return Some(self.load_messages_from_cache(start_offset, end_offset));
         }
 
+        cache.record_read(false);
         None
     }
 
@@ -317,6 +321,77 @@ impl Partition {
         messages
     }
 
+    /// Refills the message deduplicator with the IDs of the messages appended within the deduplication expiry window.
+    pub async fn load_unique_message_ids(&self) -> Result<(), IggyError> {
+        let message_deduplicator = match &self.message_deduplicator {
+            Some(message_deduplicator) => message_deduplicator,
+            None => return Ok(()),
+        };
+
+        info!(
+            "Loading unique message IDs for partition with ID: {}...",
+            self.partition_id
+        );
+        let expiry = self.config.message_deduplication.expiry;
+        let min_timestamp = match expiry.is_zero() {
+            true => 0,
+            false => IggyTimestamp::now()
+                .to_micros()
+                .saturating_sub(expiry.as_micros()),
+        };
+        let max_entries = match self.config.message_deduplication.max_entries {
+            0 => usize::MAX,
+            max_entries => max_entries as usize,
+        };
+
+        // Read the segments starting from the newest one, until the expiry window or the max entries are exceeded.
+        let mut segments_message_ids = Vec::new();
+        let mut message_ids_count = 0;
+        for segment in self.segments.iter().rev() {
+            if segment.current_size_bytes == 0 {
+                continue;
+            }
+
+            let message_ids = segment
+                .storage
+                .segment
+                .load_message_ids(segment, min_timestamp)
+                .await?;
+            let messages_count = (segment.current_offset - segment.start_offset + 1) as usize;
+            let is_segment_within_expiry = message_ids.len() == messages_count;
+            message_ids_count += message_ids.len();
+            segments_message_ids.push(message_ids);
+            if !is_segment_within_expiry || message_ids_count >= max_entries {
+                break;
+            }
+        }
+
+        // Insert the IDs in the order of appending, so that only the newest ones are kept within the max entries.
+        let mut unique_message_ids_count = 0;
+        for message_ids in segments_message_ids.iter().rev() {
+            for message_id in message_ids
+                .iter()
+                .skip(message_ids_count.saturating_sub(max_entries))
+            {
+                if message_deduplicator.try_insert(message_id).await {
+                    unique_message_ids_count += 1;
+                } else {
+                    warn!(
+                        "Duplicated message ID: {} for partition with ID: {}.",
+                        message_id, self.partition_id
+                    );
+                }
+            }
+            message_ids_count = message_ids_count.saturating_sub(message_ids.len());
+        }
+
+        info!(
+            "Loaded: {} unique message IDs for partition with ID: {}.",
+            unique_message_ids_count, self.partition_id
+        );
+        Ok(())
+    }
+
     pub async fn append_messages(&mut self, messages: Vec<Message>) -> Result<(), IggyError> {
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
@@ -397,8 +472,20 @@ impl Partition {
             }
         }
 
+        if self.unsaved_messages_count == 0 {
+            self.persist_producer_states().await?;
+        }
+
         Ok(())
     }
+
+    /// Persists the buffered messages and syncs the segment files on disk.
+    pub async fn sync_messages(&mut self) -> Result<(), IggyError> {
+        let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+        last_segment.sync_messages().await?;
+        self.unsaved_messages_count = 0;
+        self.persist_producer_states().await
+    }
 }
 
 #[cfg(test)]
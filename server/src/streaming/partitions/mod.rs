@@ -6,8 +6,10 @@ pub mod consumer_offsets;
 pub mod messages;
 pub mod partition;
 pub mod persistence;
+pub mod producers;
 pub mod segments;
 pub mod storage;
+pub mod transactions;
 
 #[allow(dead_code)]
 fn create_messages() -> Vec<Message> {
@@ -34,3 +36,21 @@ fn create_message(offset: u64, id: u128, payload: &str) -> Message {
         None,
     )
 }
+
+#[cfg(test)]
+fn create_partition() -> partition::Partition {
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use std::sync::Arc;
+
+    let storage = Arc::new(get_test_system_storage());
+    partition::Partition::create(
+        1,
+        2,
+        3,
+        true,
+        Arc::new(SystemConfig::default()),
+        storage,
+        None,
+    )
+}
@@ -8,6 +8,8 @@ use dashmap::DashMap;
 use iggy::consumer::ConsumerKind;
 use iggy::models::messages::Message;
 use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -26,6 +28,9 @@ pub struct Partition {
     pub(crate) message_expiry: Option<u32>,
     pub(crate) consumer_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) consumer_group_offsets: DashMap<u32, ConsumerOffset>,
+    pub(crate) producer_states: HashMap<u64, ProducerState>,
+    pub(crate) unsaved_producer_ids: HashSet<u64>,
+    pub(crate) transactions: HashMap<u64, PartitionTransaction>,
     pub(crate) segments: Vec<Segment>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
@@ -69,6 +74,90 @@ impl ConsumerOffset {
     }
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProducerState {
+    pub producer_id: u64,
+    pub sequence: u64,
+    pub key: String,
+}
+
+impl ProducerState {
+    pub fn new(
+        producer_id: u64,
+        sequence: u64,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> ProducerState {
+        ProducerState {
+            key: format!(
+                "{}:{producer_id}",
+                Self::get_key_prefix(stream_id, topic_id, partition_id)
+            ),
+            producer_id,
+            sequence,
+        }
+    }
+
+    pub fn get_key_prefix(stream_id: u32, topic_id: u32, partition_id: u32) -> String {
+        format!("producers:{stream_id}:{topic_id}:{partition_id}")
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PartitionTransaction {
+    pub transaction_id: u64,
+    pub state: TransactionState,
+    pub offsets: Vec<TransactionOffsetRange>,
+    pub consumer_offsets: Vec<TransactionConsumerOffset>,
+    #[serde(skip)]
+    pub key: String,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum TransactionState {
+    Pending,
+    Committing,
+    Aborted,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct TransactionOffsetRange {
+    pub start_offset: u64,
+    pub end_offset: u64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct TransactionConsumerOffset {
+    pub kind: ConsumerKind,
+    pub consumer_id: u32,
+    pub offset: u64,
+}
+
+impl PartitionTransaction {
+    pub fn new(
+        transaction_id: u64,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> PartitionTransaction {
+        PartitionTransaction {
+            key: format!(
+                "{}:{transaction_id}",
+                Self::get_key_prefix(stream_id, topic_id, partition_id)
+            ),
+            transaction_id,
+            state: TransactionState::Pending,
+            offsets: Vec::new(),
+            consumer_offsets: Vec::new(),
+        }
+    }
+
+    pub fn get_key_prefix(stream_id: u32, topic_id: u32, partition_id: u32) -> String {
+        format!("transactions:{stream_id}:{topic_id}:{partition_id}")
+    }
+}
+
 impl Partition {
     pub fn create(
         stream_id: u32,
@@ -119,6 +208,9 @@ impl Partition {
             should_increment_offset: false,
             consumer_offsets: DashMap::new(),
             consumer_group_offsets: DashMap::new(),
+            producer_states: HashMap::new(),
+            unsaved_producer_ids: HashSet::new(),
+            transactions: HashMap::new(),
             config,
             storage,
             created_at: IggyTimestamp::now().to_micros(),
@@ -188,6 +280,9 @@ mod tests {
         let consumer_offsets = partition.consumer_offsets;
         assert_eq!(partition.message_expiry, message_expiry);
         assert!(consumer_offsets.is_empty());
+        assert!(partition.producer_states.is_empty());
+        assert!(partition.unsaved_producer_ids.is_empty());
+        assert!(partition.transactions.is_empty());
     }
 
     #[test]
@@ -45,6 +45,12 @@ impl Partition {
                 self.partition_id,
             )
             .await?;
+        // The offsets of the transactions messages are no longer valid after purging the partition.
+        self.transactions.clear();
+        self.storage
+            .partition
+            .delete_transactions(self.stream_id, self.topic_id, self.partition_id)
+            .await?;
         self.add_persisted_segment(0).await?;
         Ok(())
     }
@@ -0,0 +1,190 @@
+use crate::streaming::partitions::partition::{Partition, ProducerState};
+use iggy::error::IggyError;
+use iggy::messages::send_messages::ProducerBatch;
+use iggy::models::messages::Message;
+use tracing::{trace, warn};
+
+impl Partition {
+    pub async fn append_producer_messages(
+        &mut self,
+        producer: &ProducerBatch,
+        messages: Vec<Message>,
+    ) -> Result<(), IggyError> {
+        if !self.is_next_producer_sequence(producer)? {
+            warn!(
+                "Ignored the duplicated batch with sequence: {} for producer with ID: {} for partition with ID: {}.",
+                producer.sequence, producer.producer_id, self.partition_id
+            );
+            return Ok(());
+        }
+
+        self.append_messages(messages).await?;
+        self.store_producer_sequence(producer);
+        // Unless the appended messages have been already persisted, the sequence will be persisted along with them.
+        if self.unsaved_messages_count == 0 {
+            self.persist_producer_states().await?;
+        }
+        Ok(())
+    }
+
+    fn is_next_producer_sequence(&self, producer: &ProducerBatch) -> Result<bool, IggyError> {
+        let expected_sequence = match self.producer_states.get(&producer.producer_id) {
+            Some(producer_state) => {
+                if producer.sequence <= producer_state.sequence {
+                    return Ok(false);
+                }
+                producer_state.sequence + 1
+            }
+            None => 0,
+        };
+
+        if producer.sequence != expected_sequence {
+            return Err(IggyError::OutOfOrderSequence(
+                producer.sequence,
+                producer.producer_id,
+                expected_sequence,
+            ));
+        }
+
+        Ok(true)
+    }
+
+    fn store_producer_sequence(&mut self, producer: &ProducerBatch) {
+        let producer_state = ProducerState::new(
+            producer.producer_id,
+            producer.sequence,
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+        );
+        self.producer_states
+            .insert(producer_state.producer_id, producer_state);
+        self.unsaved_producer_ids.insert(producer.producer_id);
+    }
+
+    /// Persists the sequences of the producers, whose messages have been already persisted on disk,
+    /// so that the stored sequence never gets ahead of the messages, e.g. after the server crash.
+    pub(crate) async fn persist_producer_states(&mut self) -> Result<(), IggyError> {
+        let producer_ids = self
+            .unsaved_producer_ids
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+        for producer_id in producer_ids {
+            if let Some(producer_state) = self.producer_states.get(&producer_id) {
+                self.storage
+                    .partition
+                    .save_producer_state(producer_state)
+                    .await?;
+            }
+            self.unsaved_producer_ids.remove(&producer_id);
+        }
+        Ok(())
+    }
+
+    pub async fn load_producer_states(&mut self) -> Result<(), IggyError> {
+        trace!(
+            "Loading producer states for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        let producer_states = self
+            .storage
+            .partition
+            .load_producer_states(self.stream_id, self.topic_id, self.partition_id)
+            .await?;
+        for producer_state in producer_states {
+            trace!(
+                "Loaded producer state with sequence: {} for producer with ID: {} for partition with ID: {}.",
+                producer_state.sequence,
+                producer_state.producer_id,
+                self.partition_id
+            );
+            self.producer_states
+                .insert(producer_state.producer_id, producer_state);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::partitions::{create_messages, create_partition};
+
+    #[tokio::test]
+    async fn given_the_next_sequence_messages_should_be_appended() {
+        let mut partition = create_partition();
+        let messages_count = create_messages().len() as u32;
+        partition
+            .append_producer_messages(&ProducerBatch::new(1, 0), create_messages())
+            .await
+            .unwrap();
+        partition
+            .append_producer_messages(&ProducerBatch::new(1, 1), create_messages())
+            .await
+            .unwrap();
+
+        let loaded_messages = partition
+            .get_messages_by_offset(0, 2 * messages_count)
+            .await
+            .unwrap();
+        assert_eq!(loaded_messages.len(), 2 * messages_count as usize);
+        assert_eq!(partition.producer_states.get(&1).unwrap().sequence, 1);
+    }
+
+    #[tokio::test]
+    async fn given_the_duplicated_sequence_messages_should_be_ignored() {
+        let mut partition = create_partition();
+        let messages_count = create_messages().len() as u32;
+        let producer = ProducerBatch::new(1, 0);
+        partition
+            .append_producer_messages(&producer, create_messages())
+            .await
+            .unwrap();
+        partition
+            .append_producer_messages(&producer, create_messages())
+            .await
+            .unwrap();
+
+        let loaded_messages = partition
+            .get_messages_by_offset(0, 2 * messages_count)
+            .await
+            .unwrap();
+        assert_eq!(loaded_messages.len(), messages_count as usize);
+    }
+
+    #[tokio::test]
+    async fn given_the_out_of_order_sequence_messages_should_be_rejected() {
+        let mut partition = create_partition();
+        partition
+            .append_producer_messages(&ProducerBatch::new(1, 0), create_messages())
+            .await
+            .unwrap();
+        let result = partition
+            .append_producer_messages(&ProducerBatch::new(1, 2), create_messages())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(IggyError::OutOfOrderSequence(2, 1, 1))
+        ));
+    }
+
+    #[tokio::test]
+    async fn given_the_unsaved_messages_sequence_should_be_persisted_only_after_syncing_them() {
+        let mut partition = create_partition();
+        partition
+            .append_producer_messages(&ProducerBatch::new(1, 0), create_messages())
+            .await
+            .unwrap();
+        assert!(partition.unsaved_messages_count > 0);
+        assert!(partition.unsaved_producer_ids.contains(&1));
+
+        partition.sync_messages().await.unwrap();
+
+        assert!(partition.unsaved_producer_ids.is_empty());
+        assert_eq!(partition.producer_states.get(&1).unwrap().sequence, 0);
+    }
+}
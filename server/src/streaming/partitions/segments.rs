@@ -72,6 +72,11 @@ impl Partition {
         }
 
         self.segments.retain(|s| s.start_offset != start_offset);
+        let first_offset = match self.segments.first() {
+            Some(segment) => segment.start_offset,
+            None => deleted_segment.end_offset + 1,
+        };
+        self.prune_aborted_transactions(first_offset).await?;
         Ok(deleted_segment)
     }
 }
@@ -1,4 +1,6 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{
+    ConsumerOffset, Partition, PartitionTransaction, ProducerState,
+};
 use crate::streaming::segments::segment::{Segment, LOG_EXTENSION};
 use crate::streaming::storage::{PartitionStorage, Storage};
 use anyhow::Context;
@@ -6,12 +8,12 @@ use async_trait::async_trait;
 use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
 use serde::{Deserialize, Serialize};
-use sled::Db;
+use sled::{Batch, Db};
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs;
 use tokio::fs::create_dir;
-use tracing::{error, info, trace, warn};
+use tracing::{error, info, trace};
 
 #[derive(Debug)]
 pub struct FilePartitionStorage {
@@ -130,6 +132,223 @@ impl PartitionStorage for FilePartitionStorage {
 
         Ok(())
     }
+
+    async fn save_producer_state(&self, state: &ProducerState) -> Result<(), IggyError> {
+        // Same as for the consumer offsets, only the sequence number is stored as the value.
+        if let Err(err) = self
+            .db
+            .insert(&state.key, &state.sequence.to_be_bytes())
+            .with_context(|| {
+                format!(
+                    "Failed to save producer state: {}, key: {}",
+                    state.sequence, state.key
+                )
+            })
+        {
+            return Err(IggyError::CannotSaveResource(err));
+        }
+
+        trace!(
+            "Stored producer state sequence: {} for producer with ID: {}",
+            state.sequence,
+            state.producer_id
+        );
+        Ok(())
+    }
+
+    async fn load_producer_states(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<ProducerState>, IggyError> {
+        let mut producer_states = Vec::new();
+        let key_prefix = format!(
+            "{}:",
+            ProducerState::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+        for data in self.db.scan_prefix(&key_prefix) {
+            let producer_state = match data.with_context(|| {
+                format!(
+                    "Failed to load producer state, when searching by key: {}",
+                    key_prefix
+                )
+            }) {
+                Ok((key, value)) => match map_producer_state(&key, &value) {
+                    Ok(producer_state) => producer_state,
+                    Err(err) => {
+                        return Err(IggyError::CannotDeserializeResource(err));
+                    }
+                },
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            };
+            producer_states.push(producer_state);
+        }
+
+        Ok(producer_states)
+    }
+
+    async fn delete_producer_states(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), IggyError> {
+        let producer_state_key_prefix = format!(
+            "{}:",
+            ProducerState::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+
+        for data in self.db.scan_prefix(&producer_state_key_prefix) {
+            match data.with_context(|| {
+                format!(
+                    "Failed to delete producer state, when searching by key: {}",
+                    producer_state_key_prefix
+                )
+            }) {
+                Ok((key, _)) => {
+                    if let Err(err) = self
+                        .db
+                        .remove(&key)
+                        .with_context(|| format!("Failed to delete producer state, key: {:?}", key))
+                    {
+                        return Err(IggyError::CannotLoadResource(err));
+                    }
+                }
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn save_transactions(
+        &self,
+        transactions: &[PartitionTransaction],
+    ) -> Result<(), IggyError> {
+        // All the transactions are saved in a single batch, so that the commit decision is atomic for all the partitions.
+        let mut batch = Batch::default();
+        for transaction in transactions {
+            match rmp_serde::to_vec(&transaction).with_context(|| {
+                format!(
+                    "Failed to serialize transaction with key: {}",
+                    transaction.key
+                )
+            }) {
+                Ok(data) => batch.insert(transaction.key.as_bytes(), data),
+                Err(err) => {
+                    return Err(IggyError::CannotSerializeResource(err));
+                }
+            }
+        }
+
+        if let Err(err) = self
+            .db
+            .apply_batch(batch)
+            .with_context(|| "Failed to save transactions")
+        {
+            return Err(IggyError::CannotSaveResource(err));
+        }
+
+        Ok(())
+    }
+
+    async fn load_transactions(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<PartitionTransaction>, IggyError> {
+        let mut transactions = Vec::new();
+        let key_prefix = format!(
+            "{}:",
+            PartitionTransaction::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+        for data in self.db.scan_prefix(&key_prefix) {
+            let transaction = match data.with_context(|| {
+                format!(
+                    "Failed to load transaction, when searching by key: {}",
+                    key_prefix
+                )
+            }) {
+                Ok((key, value)) => {
+                    let key = String::from_utf8(key.to_vec()).unwrap();
+                    match rmp_serde::from_slice::<PartitionTransaction>(&value).with_context(|| {
+                        format!("Failed to deserialize transaction with key: {}", key)
+                    }) {
+                        Ok(mut transaction) => {
+                            transaction.key = key;
+                            transaction
+                        }
+                        Err(err) => {
+                            return Err(IggyError::CannotDeserializeResource(err));
+                        }
+                    }
+                }
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            };
+            transactions.push(transaction);
+        }
+
+        Ok(transactions)
+    }
+
+    async fn delete_transaction(
+        &self,
+        transaction: &PartitionTransaction,
+    ) -> Result<(), IggyError> {
+        if let Err(err) = self
+            .db
+            .remove(&transaction.key)
+            .with_context(|| format!("Failed to delete transaction, key: {}", transaction.key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        Ok(())
+    }
+
+    async fn delete_transactions(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), IggyError> {
+        let transaction_key_prefix = format!(
+            "{}:",
+            PartitionTransaction::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+
+        for data in self.db.scan_prefix(&transaction_key_prefix) {
+            match data.with_context(|| {
+                format!(
+                    "Failed to delete transaction, when searching by key: {}",
+                    transaction_key_prefix
+                )
+            }) {
+                Ok((key, _)) => {
+                    if let Err(err) = self
+                        .db
+                        .remove(&key)
+                        .with_context(|| format!("Failed to delete transaction, key: {:?}", key))
+                    {
+                        return Err(IggyError::CannotLoadResource(err));
+                    }
+                }
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -229,21 +448,6 @@ impl Storage<Partition> for FilePartitionStorage {
                 info!("Validated messages checksum for partition with ID: {} and segment with start offset: {}.", partition.partition_id, segment.start_offset);
             }
 
-            // Load the unique message IDs for the partition if the deduplication feature is enabled.
-            let mut unique_message_ids_count = 0;
-            if let Some(message_deduplicator) = &partition.message_deduplicator {
-                info!("Loading unique message IDs for partition with ID: {} and segment with start offset: {}...", partition.partition_id, segment.start_offset);
-                let message_ids = segment.storage.segment.load_message_ids(&segment).await?;
-                for message_id in message_ids {
-                    if message_deduplicator.try_insert(&message_id).await {
-                        unique_message_ids_count += 1;
-                    } else {
-                        warn!("Duplicated message ID: {} for partition with ID: {} and segment with start offset: {}.", message_id, partition.partition_id, segment.start_offset);
-                    }
-                }
-                info!("Loaded: {} unique message IDs for partition with ID: {} and segment with start offset: {}...", unique_message_ids_count, partition.partition_id, segment.start_offset);
-            }
-
             partition.segments.push(segment);
         }
 
@@ -276,7 +480,10 @@ impl Storage<Partition> for FilePartitionStorage {
             partition.current_offset = last_segment.current_offset;
         }
 
+        partition.load_unique_message_ids().await?;
         partition.load_consumer_offsets().await?;
+        partition.load_producer_states().await?;
+        partition.load_transactions().await?;
         info!(
             "Loaded partition with ID: {} for stream with ID: {} and topic with ID: {}, current offset: {}.",
             partition.partition_id, partition.stream_id, partition.topic_id, partition.current_offset
@@ -400,6 +607,38 @@ impl Storage<Partition> for FilePartitionStorage {
             ));
         }
 
+        if let Err(err) = self
+            .delete_producer_states(
+                partition.stream_id,
+                partition.topic_id,
+                partition.partition_id,
+            )
+            .await
+        {
+            error!("Cannot delete producer states for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", partition.partition_id, partition.topic_id, partition.stream_id, err);
+            return Err(IggyError::CannotDeletePartition(
+                partition.partition_id,
+                partition.topic_id,
+                partition.stream_id,
+            ));
+        }
+
+        if let Err(err) = self
+            .delete_transactions(
+                partition.stream_id,
+                partition.topic_id,
+                partition.partition_id,
+            )
+            .await
+        {
+            error!("Cannot delete transactions for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", partition.partition_id, partition.topic_id, partition.stream_id, err);
+            return Err(IggyError::CannotDeletePartition(
+                partition.partition_id,
+                partition.topic_id,
+                partition.stream_id,
+            ));
+        }
+
         if fs::remove_dir_all(&partition.path).await.is_err() {
             error!("Cannot delete partition directory: {} for partition with ID: {} for topic with ID: {} for stream with ID: {}.", partition.path, partition.partition_id, partition.topic_id, partition.stream_id);
             return Err(IggyError::CannotDeletePartitionDirectory(
@@ -416,6 +655,25 @@ impl Storage<Partition> for FilePartitionStorage {
     }
 }
 
+fn map_producer_state(key: &[u8], value: &[u8]) -> Result<ProducerState, anyhow::Error> {
+    let key = String::from_utf8(key.to_vec()).with_context(|| "Invalid producer state key")?;
+    let sequence = u64::from_be_bytes(
+        value
+            .try_into()
+            .with_context(|| format!("Invalid producer state sequence, key: {key}"))?,
+    );
+    let producer_id = key
+        .rsplit(':')
+        .next()
+        .and_then(|producer_id| producer_id.parse::<u64>().ok())
+        .with_context(|| format!("Invalid producer ID, key: {key}"))?;
+    Ok(ProducerState {
+        key,
+        producer_id,
+        sequence,
+    })
+}
+
 fn get_partition_key(stream_id: u32, topic_id: u32, partition_id: u32) -> String {
     format!(
         "streams:{}:topics:{}:partitions:{}",
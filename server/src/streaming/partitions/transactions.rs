@@ -0,0 +1,431 @@
+use crate::streaming::partitions::partition::{
+    Partition, PartitionTransaction, TransactionConsumerOffset, TransactionOffsetRange,
+    TransactionState,
+};
+use crate::streaming::polling_consumer::PollingConsumer;
+use bytes::Bytes;
+use iggy::consumer::ConsumerKind;
+use iggy::error::IggyError;
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::checksum;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::Arc;
+use tracing::{info, trace, warn};
+
+impl Partition {
+    pub fn get_next_message_offset(&self) -> u64 {
+        if self.should_increment_offset {
+            self.current_offset + 1
+        } else {
+            0
+        }
+    }
+
+    pub async fn add_transaction_messages(
+        &mut self,
+        transaction_id: u64,
+        start_offset: u64,
+    ) -> Result<(), IggyError> {
+        // All the messages might have been ignored e.g. by the deduplication.
+        if !self.should_increment_offset || self.current_offset < start_offset {
+            return Ok(());
+        }
+
+        let end_offset = self.current_offset;
+        let transaction = self.get_or_create_transaction(transaction_id);
+        match transaction.offsets.last_mut() {
+            Some(range) if range.end_offset + 1 == start_offset => range.end_offset = end_offset,
+            _ => transaction.offsets.push(TransactionOffsetRange {
+                start_offset,
+                end_offset,
+            }),
+        }
+
+        let transaction = transaction.clone();
+        self.storage
+            .partition
+            .save_transactions(&[transaction])
+            .await
+    }
+
+    pub async fn add_transaction_consumer_offset(
+        &mut self,
+        transaction_id: u64,
+        consumer: PollingConsumer,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        if offset > self.current_offset {
+            return Err(IggyError::InvalidOffset(offset));
+        }
+
+        let (kind, consumer_id) = match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => (ConsumerKind::Consumer, consumer_id),
+            PollingConsumer::ConsumerGroup(consumer_group_id, _) => {
+                (ConsumerKind::ConsumerGroup, consumer_group_id)
+            }
+        };
+
+        let transaction = self.get_or_create_transaction(transaction_id);
+        transaction.consumer_offsets.retain(|consumer_offset| {
+            consumer_offset.kind != kind || consumer_offset.consumer_id != consumer_id
+        });
+        transaction
+            .consumer_offsets
+            .push(TransactionConsumerOffset {
+                kind,
+                consumer_id,
+                offset,
+            });
+
+        let transaction = transaction.clone();
+        self.storage
+            .partition
+            .save_transactions(&[transaction])
+            .await
+    }
+
+    pub fn prepare_transaction_commit(
+        &mut self,
+        transaction_id: u64,
+    ) -> Option<PartitionTransaction> {
+        let transaction = self.transactions.get_mut(&transaction_id)?;
+        transaction.state = TransactionState::Committing;
+        Some(transaction.clone())
+    }
+
+    pub async fn complete_transaction(
+        &mut self,
+        transaction_id: u64,
+        committed: bool,
+    ) -> Result<(), IggyError> {
+        let transaction = self.transactions.remove(&transaction_id);
+        if transaction.is_none() {
+            return Ok(());
+        }
+
+        let mut transaction = transaction.unwrap();
+        if !transaction.offsets.is_empty() {
+            let state = match committed {
+                true => MessageState::TransactionCommitted,
+                false => MessageState::TransactionAborted,
+            };
+            let payload = Bytes::from(transaction_id.to_le_bytes().to_vec());
+            let checksum = checksum::calculate(&payload);
+            let marker = Message::empty(
+                IggyTimestamp::now().to_micros(),
+                state,
+                0,
+                payload,
+                checksum,
+                None,
+            );
+            self.append_messages(vec![marker]).await?;
+        }
+
+        if committed {
+            for consumer_offset in &transaction.consumer_offsets {
+                let consumer = match consumer_offset.kind {
+                    ConsumerKind::Consumer => {
+                        PollingConsumer::Consumer(consumer_offset.consumer_id, self.partition_id)
+                    }
+                    ConsumerKind::ConsumerGroup => {
+                        PollingConsumer::ConsumerGroup(consumer_offset.consumer_id, 0)
+                    }
+                };
+                self.store_consumer_offset(consumer, consumer_offset.offset)
+                    .await?;
+            }
+        }
+
+        // The aborted transaction is kept to filter out its messages for the consumers reading only the committed data.
+        if committed || transaction.offsets.is_empty() {
+            self.storage
+                .partition
+                .delete_transaction(&transaction)
+                .await?;
+        } else {
+            transaction.state = TransactionState::Aborted;
+            transaction.consumer_offsets.clear();
+            self.storage
+                .partition
+                .save_transactions(&[transaction.clone()])
+                .await?;
+            self.transactions.insert(transaction_id, transaction);
+        }
+
+        trace!(
+            "Completed transaction with ID: {}, committed: {} for partition with ID: {}.",
+            transaction_id,
+            committed,
+            self.partition_id
+        );
+        Ok(())
+    }
+
+    /// Skips the control records and, if only the committed data should be read, the messages of the aborted
+    /// and unfinished transactions, reading further messages until there's enough of them.
+    pub async fn get_visible_messages(
+        &self,
+        messages: Vec<Arc<Message>>,
+        count: u32,
+        read_committed: bool,
+    ) -> Result<Vec<Arc<Message>>, IggyError> {
+        let count = count as usize;
+        let first_unstable_offset = match read_committed {
+            true => self.get_first_unstable_offset(),
+            false => u64::MAX,
+        };
+        let mut visible_messages = Vec::with_capacity(count);
+        let mut messages = messages;
+        while let Some(last_offset) = messages.last().map(|message| message.offset) {
+            let mut skipped = false;
+            for message in messages {
+                if message.offset >= first_unstable_offset {
+                    return Ok(visible_messages);
+                }
+
+                if message.state.is_control()
+                    || (read_committed && self.is_aborted_message(&message))
+                {
+                    skipped = true;
+                    continue;
+                }
+
+                visible_messages.push(message);
+                if visible_messages.len() == count {
+                    return Ok(visible_messages);
+                }
+            }
+
+            if !skipped {
+                break;
+            }
+
+            messages = self
+                .get_messages_by_offset(last_offset + 1, (count - visible_messages.len()) as u32)
+                .await?;
+        }
+
+        Ok(visible_messages)
+    }
+
+    /// Removes the aborted transactions, which messages have been already deleted from the partition.
+    pub async fn prune_aborted_transactions(&mut self, first_offset: u64) -> Result<(), IggyError> {
+        let pruned_transactions = self
+            .transactions
+            .values()
+            .filter(|transaction| transaction.state == TransactionState::Aborted)
+            .filter(|transaction| {
+                transaction
+                    .offsets
+                    .iter()
+                    .all(|range| range.end_offset < first_offset)
+            })
+            .map(|transaction| transaction.transaction_id)
+            .collect::<Vec<_>>();
+        for transaction_id in pruned_transactions {
+            let transaction = self.transactions.remove(&transaction_id).unwrap();
+            self.storage
+                .partition
+                .delete_transaction(&transaction)
+                .await?;
+            trace!(
+                "Pruned aborted transaction with ID: {} for partition with ID: {}.",
+                transaction_id,
+                self.partition_id
+            );
+        }
+
+        Ok(())
+    }
+
+    fn get_first_unstable_offset(&self) -> u64 {
+        self.transactions
+            .values()
+            .filter(|transaction| transaction.state != TransactionState::Aborted)
+            .filter_map(|transaction| transaction.offsets.first())
+            .map(|range| range.start_offset)
+            .min()
+            .unwrap_or(u64::MAX)
+    }
+
+    fn is_aborted_message(&self, message: &Message) -> bool {
+        self.transactions
+            .values()
+            .filter(|transaction| transaction.state == TransactionState::Aborted)
+            .flat_map(|transaction| transaction.offsets.iter())
+            .any(|range| message.offset >= range.start_offset && message.offset <= range.end_offset)
+    }
+
+    pub async fn load_transactions(&mut self) -> Result<(), IggyError> {
+        trace!(
+            "Loading transactions for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        let transactions = self
+            .storage
+            .partition
+            .load_transactions(self.stream_id, self.topic_id, self.partition_id)
+            .await?;
+        for transaction in transactions {
+            let transaction_id = transaction.transaction_id;
+            let state = transaction.state;
+            self.transactions.insert(transaction_id, transaction);
+            match state {
+                TransactionState::Aborted => {}
+                // The commit has been already decided, so it has to be finished.
+                TransactionState::Committing => {
+                    info!(
+                        "Finishing the commit of transaction with ID: {} for partition with ID: {}...",
+                        transaction_id, self.partition_id
+                    );
+                    self.complete_transaction(transaction_id, true).await?;
+                }
+                // The client which started the transaction is gone, so it can't be committed anymore.
+                TransactionState::Pending => {
+                    warn!(
+                        "Aborting the unfinished transaction with ID: {} for partition with ID: {}...",
+                        transaction_id, self.partition_id
+                    );
+                    self.complete_transaction(transaction_id, false).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn get_or_create_transaction(&mut self, transaction_id: u64) -> &mut PartitionTransaction {
+        let (stream_id, topic_id, partition_id) =
+            (self.stream_id, self.topic_id, self.partition_id);
+        self.transactions.entry(transaction_id).or_insert_with(|| {
+            PartitionTransaction::new(transaction_id, stream_id, topic_id, partition_id)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::partitions::{create_messages, create_partition};
+
+    #[tokio::test]
+    async fn given_pending_transaction_its_messages_should_not_be_committed() {
+        let mut partition = create_partition();
+        partition.append_messages(create_messages()).await.unwrap();
+        append_transaction_messages(&mut partition, 1).await;
+
+        let messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+        let committed_messages = partition
+            .get_visible_messages(messages, 100, true)
+            .await
+            .unwrap();
+
+        assert_eq!(committed_messages.len(), create_messages().len());
+    }
+
+    #[tokio::test]
+    async fn given_committed_transaction_its_messages_should_be_committed() {
+        let mut partition = create_partition();
+        append_transaction_messages(&mut partition, 1).await;
+        partition.complete_transaction(1, true).await.unwrap();
+
+        let messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+        let committed_messages = partition
+            .get_visible_messages(messages, 100, true)
+            .await
+            .unwrap();
+
+        assert_eq!(committed_messages.len(), create_messages().len());
+        assert!(partition.transactions.is_empty());
+        let marker = partition
+            .get_messages_by_offset(partition.current_offset, 1)
+            .await
+            .unwrap();
+        assert_eq!(marker[0].state, MessageState::TransactionCommitted);
+    }
+
+    #[tokio::test]
+    async fn given_aborted_transaction_its_messages_should_not_be_committed() {
+        let mut partition = create_partition();
+        append_transaction_messages(&mut partition, 1).await;
+        partition.complete_transaction(1, false).await.unwrap();
+        partition.append_messages(create_messages()).await.unwrap();
+
+        let messages = partition.get_messages_by_offset(0, 1).await.unwrap();
+        let committed_messages = partition
+            .get_visible_messages(messages, 100, true)
+            .await
+            .unwrap();
+
+        assert_eq!(committed_messages.len(), create_messages().len());
+        assert_eq!(
+            committed_messages[0].offset,
+            create_messages().len() as u64 + 1
+        );
+    }
+
+    #[tokio::test]
+    async fn given_completed_transaction_its_marker_should_not_be_visible() {
+        let mut partition = create_partition();
+        append_transaction_messages(&mut partition, 1).await;
+        partition.complete_transaction(1, true).await.unwrap();
+        partition.append_messages(create_messages()).await.unwrap();
+
+        let messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+        let visible_messages = partition
+            .get_visible_messages(messages, 100, false)
+            .await
+            .unwrap();
+
+        assert_eq!(visible_messages.len(), 2 * create_messages().len());
+        assert!(visible_messages
+            .iter()
+            .all(|message| !message.state.is_control()));
+    }
+
+    #[tokio::test]
+    async fn given_deleted_messages_of_aborted_transaction_it_should_be_pruned() {
+        let mut partition = create_partition();
+        append_transaction_messages(&mut partition, 1).await;
+        partition.complete_transaction(1, false).await.unwrap();
+        let end_offset = create_messages().len() as u64 - 1;
+
+        partition
+            .prune_aborted_transactions(end_offset)
+            .await
+            .unwrap();
+        assert!(partition.transactions.contains_key(&1));
+
+        partition
+            .prune_aborted_transactions(end_offset + 1)
+            .await
+            .unwrap();
+        assert!(partition.transactions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn given_committed_transaction_its_consumer_offsets_should_be_stored() {
+        let mut partition = create_partition();
+        partition.append_messages(create_messages()).await.unwrap();
+        let consumer = PollingConsumer::Consumer(1, partition.partition_id);
+        partition
+            .add_transaction_consumer_offset(1, consumer, 3)
+            .await
+            .unwrap();
+
+        assert_eq!(partition.get_consumer_offset(consumer).await.unwrap(), 0);
+        partition.complete_transaction(1, true).await.unwrap();
+        assert_eq!(partition.get_consumer_offset(consumer).await.unwrap(), 3);
+    }
+
+    async fn append_transaction_messages(partition: &mut Partition, transaction_id: u64) {
+        let start_offset = partition.get_next_message_offset();
+        partition.append_messages(create_messages()).await.unwrap();
+        partition
+            .add_transaction_messages(transaction_id, start_offset)
+            .await
+            .unwrap();
+    }
+}
@@ -1,15 +1,19 @@
 use crate::streaming::utils::file;
 use async_trait::async_trait;
 use iggy::error::IggyError;
+use prometheus_client::metrics::histogram::Histogram;
 use std::fmt::Debug;
 use tokio::fs;
+use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
+use tokio::time::Instant;
 
 #[async_trait]
 pub trait Persister: Sync + Send {
     async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError>;
     async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError>;
     async fn delete(&self, path: &str) -> Result<(), IggyError>;
+    async fn sync(&self, path: &str) -> Result<(), IggyError>;
 }
 
 impl Debug for dyn Persister {
@@ -20,11 +24,41 @@ impl Debug for dyn Persister {
     }
 }
 
-#[derive(Debug)]
-pub struct FilePersister;
+#[derive(Debug, Default)]
+pub struct FilePersister {
+    fsync_duration: Option<Histogram>,
+}
+
+#[derive(Debug, Default)]
+pub struct FileWithSyncPersister {
+    fsync_duration: Option<Histogram>,
+}
 
-#[derive(Debug)]
-pub struct FileWithSyncPersister;
+impl FilePersister {
+    pub fn new(fsync_duration: Histogram) -> Self {
+        Self {
+            fsync_duration: Some(fsync_duration),
+        }
+    }
+}
+
+impl FileWithSyncPersister {
+    pub fn new(fsync_duration: Histogram) -> Self {
+        Self {
+            fsync_duration: Some(fsync_duration),
+        }
+    }
+}
+
+/// Synchronizes the file with the disk, recording the duration if the histogram is provided.
+async fn sync_file(file: &File, fsync_duration: &Option<Histogram>) -> Result<(), IggyError> {
+    let now = Instant::now();
+    file.sync_all().await?;
+    if let Some(fsync_duration) = fsync_duration {
+        fsync_duration.observe(now.elapsed().as_secs_f64());
+    }
+    Ok(())
+}
 
 unsafe impl Send for FilePersister {}
 unsafe impl Sync for FilePersister {}
@@ -50,6 +84,12 @@ impl Persister for FilePersister {
         fs::remove_file(path).await?;
         Ok(())
     }
+
+    async fn sync(&self, path: &str) -> Result<(), IggyError> {
+        let file = file::open(path).await?;
+        sync_file(&file, &self.fsync_duration).await?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -57,14 +97,14 @@ impl Persister for FileWithSyncPersister {
     async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError> {
         let mut file = file::append(path).await?;
         file.write_all(bytes).await?;
-        file.sync_all().await?;
+        sync_file(&file, &self.fsync_duration).await?;
         Ok(())
     }
 
     async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError> {
         let mut file = file::write(path).await?;
         file.write_all(bytes).await?;
-        file.sync_all().await?;
+        sync_file(&file, &self.fsync_duration).await?;
         Ok(())
     }
 
@@ -72,4 +112,9 @@ impl Persister for FileWithSyncPersister {
         fs::remove_file(path).await?;
         Ok(())
     }
+
+    async fn sync(&self, _path: &str) -> Result<(), IggyError> {
+        // The file is already synced on each write.
+        Ok(())
+    }
 }
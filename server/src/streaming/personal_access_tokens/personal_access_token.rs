@@ -1,4 +1,5 @@
 use crate::streaming::utils::hash;
+use iggy::models::permissions::Permissions;
 use iggy::models::user_info::UserId;
 use iggy::utils::text::as_base64;
 use ring::rand::SecureRandom;
@@ -12,11 +13,19 @@ pub struct PersonalAccessToken {
     pub name: String,
     pub token: String,
     pub expiry: Option<u64>,
+    #[serde(default)]
+    pub scope: Option<Permissions>,
 }
 
 impl PersonalAccessToken {
     // Raw token is generated and returned only once
-    pub fn new(user_id: UserId, name: &str, now: u64, expiry: Option<u32>) -> (Self, String) {
+    pub fn new(
+        user_id: UserId,
+        name: &str,
+        now: u64,
+        expiry: Option<u32>,
+        scope: Option<Permissions>,
+    ) -> (Self, String) {
         let mut buffer: [u8; SIZE] = [0; SIZE];
         let system_random = ring::rand::SystemRandom::new();
         system_random.fill(&mut buffer).unwrap();
@@ -29,6 +38,7 @@ impl PersonalAccessToken {
                 name: name.to_string(),
                 token: token_hash,
                 expiry,
+                scope,
             },
             token,
         )
@@ -55,7 +65,8 @@ mod tests {
         let user_id = 1;
         let now = IggyTimestamp::now().to_micros();
         let name = "test_token";
-        let (personal_access_token, raw_token) = PersonalAccessToken::new(user_id, name, now, None);
+        let (personal_access_token, raw_token) =
+            PersonalAccessToken::new(user_id, name, now, None, None);
         assert_eq!(personal_access_token.name, name);
         assert!(!personal_access_token.token.is_empty());
         assert!(!raw_token.is_empty());
@@ -72,7 +83,8 @@ mod tests {
         let now = IggyTimestamp::now().to_micros();
         let expiry = 1;
         let name = "test_token";
-        let (personal_access_token, _) = PersonalAccessToken::new(user_id, name, now, Some(expiry));
+        let (personal_access_token, _) =
+            PersonalAccessToken::new(user_id, name, now, Some(expiry), None);
         assert!(personal_access_token.is_expired(now + expiry as u64 * 1_000_000 + 1));
     }
 }
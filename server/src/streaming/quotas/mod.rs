@@ -0,0 +1,2 @@
+pub mod quota_manager;
+pub mod token_bucket;
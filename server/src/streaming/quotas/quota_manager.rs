@@ -0,0 +1,310 @@
+use crate::configs::system::{QuotaLimitsConfig, QuotasConfig};
+use crate::streaming::quotas::token_bucket::TokenBucket;
+use crate::streaming::session::Session;
+use dashmap::mapref::one::RefMut;
+use dashmap::DashMap;
+use iggy::error::IggyError;
+use iggy::models::user_quotas::UserQuotas;
+
+/// The quota manager tracks the throughput of the users and clients, and throttles them when exceeding the limits.
+/// The user limits are shared by all the connections of the same user, and can be overridden for the specific user,
+/// while the client limits are applied to every connection separately.
+#[derive(Debug)]
+pub struct QuotaManager {
+    enabled: bool,
+    default_user_quotas: UserQuotas,
+    client_quotas: UserQuotas,
+    user_quotas: DashMap<u32, UserQuotas>,
+    users: DashMap<u32, QuotaBuckets>,
+    clients: DashMap<u32, QuotaBuckets>,
+}
+
+#[derive(Debug)]
+struct QuotaBuckets {
+    produce: Option<TokenBucket>,
+    consume: Option<TokenBucket>,
+    requests: Option<TokenBucket>,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum QuotaKind {
+    Produce,
+    Consume,
+    Requests,
+}
+
+impl QuotaBuckets {
+    fn new(quotas: &UserQuotas) -> Self {
+        Self {
+            produce: Self::create_bucket(quotas.produce_bytes_per_second),
+            consume: Self::create_bucket(quotas.consume_bytes_per_second),
+            requests: Self::create_bucket(quotas.requests_per_second as u64),
+        }
+    }
+
+    fn create_bucket(rate: u64) -> Option<TokenBucket> {
+        match rate {
+            0 => None,
+            rate => Some(TokenBucket::new(rate)),
+        }
+    }
+
+    fn get_bucket(&mut self, kind: QuotaKind) -> Option<&mut TokenBucket> {
+        match kind {
+            QuotaKind::Produce => self.produce.as_mut(),
+            QuotaKind::Consume => self.consume.as_mut(),
+            QuotaKind::Requests => self.requests.as_mut(),
+        }
+    }
+}
+
+impl QuotaManager {
+    /// Creates a new quota manager based on the provided configuration.
+    pub fn new(config: &QuotasConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            default_user_quotas: map_quotas(&config.user),
+            client_quotas: map_quotas(&config.client),
+            user_quotas: DashMap::new(),
+            users: DashMap::new(),
+            clients: DashMap::new(),
+        }
+    }
+
+    /// Sets the quotas overriding the default ones for the given user, or restores the default ones if `None`.
+    pub fn set_user_quotas(&self, user_id: u32, quotas: Option<UserQuotas>) {
+        match quotas {
+            Some(quotas) => {
+                self.user_quotas.insert(user_id, quotas);
+            }
+            None => {
+                self.user_quotas.remove(&user_id);
+            }
+        }
+        self.users.remove(&user_id);
+    }
+
+    /// Removes all the state of the given user.
+    pub fn delete_user(&self, user_id: u32) {
+        self.user_quotas.remove(&user_id);
+        self.users.remove(&user_id);
+    }
+
+    /// Removes all the state of the given client.
+    pub fn delete_client(&self, client_id: u32) {
+        self.clients.remove(&client_id);
+    }
+
+    /// Checks if the next request can be handled for the given session, and if so, counts it.
+    pub fn check_request(&self, session: &Session) -> Result<(), IggyError> {
+        self.try_consume(session, QuotaKind::Requests, 1)
+    }
+
+    /// Checks if the messages of the given size can be appended for the given session, and if so, counts them.
+    pub fn check_produce(&self, session: &Session, size: u64) -> Result<(), IggyError> {
+        self.try_consume(session, QuotaKind::Produce, size)
+    }
+
+    /// Checks if the messages can be polled for the given session,
+    /// the actual size must be recorded afterwards by calling `record_consume()`.
+    pub fn check_consume(&self, session: &Session) -> Result<(), IggyError> {
+        self.try_consume(session, QuotaKind::Consume, 0)
+    }
+
+    /// Records the size of the messages polled for the given session.
+    pub fn record_consume(&self, session: &Session, size: u64) {
+        if !self.enabled || size == 0 {
+            return;
+        }
+
+        if let Some(mut buckets) = self.get_user_buckets(session.get_user_id()) {
+            if let Some(bucket) = buckets.get_bucket(QuotaKind::Consume) {
+                bucket.consume(size);
+            }
+        }
+        if let Some(mut buckets) = self.get_client_buckets(session.client_id) {
+            if let Some(bucket) = buckets.get_bucket(QuotaKind::Consume) {
+                bucket.consume(size);
+            }
+        }
+    }
+
+    fn try_consume(
+        &self,
+        session: &Session,
+        kind: QuotaKind,
+        amount: u64,
+    ) -> Result<(), IggyError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let mut user_buckets = self.get_user_buckets(session.get_user_id());
+        let mut client_buckets = self.get_client_buckets(session.client_id);
+        let mut user_bucket = user_buckets
+            .as_mut()
+            .and_then(|buckets| buckets.get_bucket(kind));
+        let mut client_bucket = client_buckets
+            .as_mut()
+            .and_then(|buckets| buckets.get_bucket(kind));
+
+        let user_retry_after = user_bucket
+            .as_mut()
+            .and_then(|bucket| bucket.get_retry_after());
+        let client_retry_after = client_bucket
+            .as_mut()
+            .and_then(|bucket| bucket.get_retry_after());
+        if let Some(retry_after) = user_retry_after.max(client_retry_after) {
+            return Err(IggyError::QuotaExceeded(retry_after));
+        }
+
+        if amount == 0 {
+            return Ok(());
+        }
+
+        if let Some(bucket) = user_bucket {
+            bucket.consume(amount);
+        }
+        if let Some(bucket) = client_bucket {
+            bucket.consume(amount);
+        }
+        Ok(())
+    }
+
+    fn get_user_buckets(&self, user_id: u32) -> Option<RefMut<u32, QuotaBuckets>> {
+        if user_id == 0 {
+            return None;
+        }
+
+        let quotas = self
+            .user_quotas
+            .get(&user_id)
+            .map(|quotas| *quotas)
+            .unwrap_or(self.default_user_quotas);
+        Some(
+            self.users
+                .entry(user_id)
+                .or_insert_with(|| QuotaBuckets::new(&quotas)),
+        )
+    }
+
+    fn get_client_buckets(&self, client_id: u32) -> Option<RefMut<u32, QuotaBuckets>> {
+        if client_id == 0 {
+            return None;
+        }
+
+        Some(
+            self.clients
+                .entry(client_id)
+                .or_insert_with(|| QuotaBuckets::new(&self.client_quotas)),
+        )
+    }
+}
+
+fn map_quotas(config: &QuotaLimitsConfig) -> UserQuotas {
+    UserQuotas {
+        produce_bytes_per_second: config.produce_bytes_per_second.as_bytes_u64(),
+        consume_bytes_per_second: config.consume_bytes_per_second.as_bytes_u64(),
+        requests_per_second: config.requests_per_second,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::byte_size::IggyByteSize;
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    fn create_session(user_id: u32, client_id: u32) -> Session {
+        Session::new(
+            client_id,
+            user_id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        )
+    }
+
+    fn create_config(enabled: bool) -> QuotasConfig {
+        QuotasConfig {
+            enabled,
+            user: QuotaLimitsConfig {
+                produce_bytes_per_second: IggyByteSize::from(1000),
+                consume_bytes_per_second: IggyByteSize::from(0),
+                requests_per_second: 3,
+            },
+            client: QuotaLimitsConfig {
+                produce_bytes_per_second: IggyByteSize::from(0),
+                consume_bytes_per_second: IggyByteSize::from(500),
+                requests_per_second: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn quotas_should_not_be_enforced_when_disabled() {
+        let quota_manager = QuotaManager::new(&create_config(false));
+        let session = create_session(1, 1);
+        for _ in 0..10 {
+            assert!(quota_manager.check_request(&session).is_ok());
+            assert!(quota_manager.check_produce(&session, 10_000).is_ok());
+        }
+    }
+
+    #[test]
+    fn requests_should_be_throttled_when_user_quota_is_exceeded() {
+        let quota_manager = QuotaManager::new(&create_config(true));
+        let session = create_session(1, 1);
+        for _ in 0..3 {
+            assert!(quota_manager.check_request(&session).is_ok());
+        }
+
+        let result = quota_manager.check_request(&session);
+        assert!(matches!(result, Err(IggyError::QuotaExceeded(_))));
+
+        let other_user_session = create_session(2, 2);
+        assert!(quota_manager.check_request(&other_user_session).is_ok());
+    }
+
+    #[test]
+    fn user_quotas_should_be_shared_by_all_clients_of_the_same_user() {
+        let quota_manager = QuotaManager::new(&create_config(true));
+        assert!(quota_manager
+            .check_produce(&create_session(1, 1), 1000)
+            .is_ok());
+        let result = quota_manager.check_produce(&create_session(1, 2), 1);
+        assert!(matches!(result, Err(IggyError::QuotaExceeded(_))));
+    }
+
+    #[test]
+    fn consume_should_be_throttled_when_client_quota_is_exceeded() {
+        let quota_manager = QuotaManager::new(&create_config(true));
+        let session = create_session(1, 1);
+        assert!(quota_manager.check_consume(&session).is_ok());
+        quota_manager.record_consume(&session, 500);
+        let result = quota_manager.check_consume(&session);
+        assert!(matches!(result, Err(IggyError::QuotaExceeded(_))));
+        assert!(quota_manager.check_consume(&create_session(1, 2)).is_ok());
+    }
+
+    #[test]
+    fn user_quotas_should_be_overridden() {
+        let quota_manager = QuotaManager::new(&create_config(true));
+        let session = create_session(1, 1);
+        quota_manager.set_user_quotas(
+            1,
+            Some(UserQuotas {
+                produce_bytes_per_second: 0,
+                consume_bytes_per_second: 0,
+                requests_per_second: 0,
+            }),
+        );
+        for _ in 0..10 {
+            assert!(quota_manager.check_request(&session).is_ok());
+        }
+
+        quota_manager.set_user_quotas(1, None);
+        for _ in 0..3 {
+            assert!(quota_manager.check_request(&session).is_ok());
+        }
+        assert!(quota_manager.check_request(&session).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::Instant;
+
+/// The token bucket refilled continuously with the given rate per second, holding at most one second worth of tokens.
+/// The tokens can be consumed beyond the available amount (going into the debt),
+/// so that a single large request is never rejected forever, but the subsequent ones are delayed accordingly.
+#[derive(Debug)]
+pub struct TokenBucket {
+    rate: u64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a new, full token bucket with the given rate per second.
+    pub fn new(rate: u64) -> Self {
+        Self {
+            rate,
+            tokens: rate as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Returns the number of milliseconds to wait until at least one token is available, or `None` if it's available now.
+    pub fn get_retry_after(&mut self) -> Option<u64> {
+        self.refill();
+        if self.tokens >= 1.0 {
+            return None;
+        }
+
+        let missing_tokens = 1.0 - self.tokens;
+        let retry_after = (missing_tokens * 1000.0 / self.rate as f64).ceil() as u64;
+        Some(retry_after.max(1))
+    }
+
+    /// Consumes the given amount of tokens, possibly going into the debt.
+    pub fn consume(&mut self, amount: u64) {
+        self.refill();
+        self.tokens -= amount as f64;
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.last_refill = now;
+        self.tokens = (self.tokens + elapsed * self.rate as f64).min(self.rate as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    fn token_bucket_should_allow_consuming_tokens_until_empty() {
+        let rate = 10;
+        let mut bucket = TokenBucket::new(rate);
+        for _ in 0..rate {
+            assert!(bucket.get_retry_after().is_none());
+            bucket.consume(1);
+        }
+
+        let retry_after = bucket.get_retry_after();
+        assert!(retry_after.is_some());
+        assert!(retry_after.unwrap() <= 100);
+    }
+
+    #[test]
+    fn token_bucket_should_be_refilled_over_time() {
+        let mut bucket = TokenBucket::new(100);
+        bucket.consume(100);
+        assert!(bucket.get_retry_after().is_some());
+        sleep(Duration::from_millis(50));
+        assert!(bucket.get_retry_after().is_none());
+    }
+
+    #[test]
+    fn token_bucket_should_delay_next_request_after_going_into_debt() {
+        let mut bucket = TokenBucket::new(1000);
+        bucket.consume(3000);
+        let retry_after = bucket.get_retry_after().unwrap();
+        assert!(retry_after > 1000);
+        assert!(retry_after <= 2001);
+    }
+}
@@ -0,0 +1,32 @@
+use iggy::models::permissions::Permissions;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// The named set of permissions, which can be assigned to the users.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Role {
+    pub id: u32,
+    pub name: String,
+    pub created_at: u64,
+    pub permissions: Permissions,
+}
+
+impl Role {
+    pub fn empty(id: u32) -> Self {
+        Self {
+            id,
+            name: String::new(),
+            created_at: 0,
+            permissions: Permissions::default(),
+        }
+    }
+
+    pub fn new(id: u32, name: &str, permissions: Permissions) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            created_at: IggyTimestamp::now().to_micros(),
+            permissions,
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::streaming::roles::role::Role;
+use crate::streaming::storage::{RoleStorage, Storage};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use sled::Db;
+use std::sync::Arc;
+use tracing::info;
+
+const KEY_PREFIX: &str = "roles";
+
+#[derive(Debug)]
+pub struct FileRoleStorage {
+    db: Arc<Db>,
+}
+
+impl FileRoleStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FileRoleStorage {}
+unsafe impl Sync for FileRoleStorage {}
+
+#[async_trait]
+impl RoleStorage for FileRoleStorage {
+    async fn load_by_id(&self, id: u32) -> Result<Role, IggyError> {
+        let mut role = Role::empty(id);
+        self.load(&mut role).await?;
+        Ok(role)
+    }
+
+    async fn load_by_name(&self, name: &str) -> Result<Role, IggyError> {
+        let role_id_key = get_id_key(name);
+        let role_id = self.db.get(&role_id_key).with_context(|| {
+            format!(
+                "Failed to load role with key: {}, name: {}",
+                role_id_key, name
+            )
+        });
+        match role_id {
+            Ok(Some(role_id)) => {
+                let role_id = u32::from_le_bytes(role_id.as_ref().try_into()?);
+                self.load_by_id(role_id).await
+            }
+            Ok(None) => Err(IggyError::ResourceNotFound(role_id_key)),
+            Err(err) => Err(IggyError::CannotLoadResource(err)),
+        }
+    }
+
+    async fn load_all(&self) -> Result<Vec<Role>, IggyError> {
+        let mut roles = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+            let role = match data.with_context(|| {
+                format!(
+                    "Failed to load role, when searching for key: {}",
+                    KEY_PREFIX
+                )
+            }) {
+                Ok((_, value)) => match rmp_serde::from_slice::<Role>(&value).with_context(|| {
+                    format!(
+                        "Failed to deserialize role, when searching for key: {}",
+                        KEY_PREFIX
+                    )
+                }) {
+                    Ok(role) => role,
+                    Err(err) => {
+                        return Err(IggyError::CannotDeserializeResource(err));
+                    }
+                },
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            };
+            roles.push(role);
+        }
+
+        Ok(roles)
+    }
+
+    async fn delete_name(&self, name: &str) -> Result<(), IggyError> {
+        let id_key = get_id_key(name);
+        if let Err(err) = self
+            .db
+            .remove(&id_key)
+            .with_context(|| format!("Failed to delete role name key: {}", id_key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage<Role> for FileRoleStorage {
+    async fn load(&self, role: &mut Role) -> Result<(), IggyError> {
+        let key = get_key(role.id);
+        let role_data = match self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load role with key: {}", key))
+        {
+            Ok(Some(role_data)) => role_data,
+            Ok(None) => {
+                return Err(IggyError::ResourceNotFound(key));
+            }
+            Err(err) => {
+                return Err(IggyError::CannotLoadResource(err));
+            }
+        };
+
+        match rmp_serde::from_slice::<Role>(&role_data)
+            .with_context(|| format!("Failed to deserialize role with key: {}", key))
+        {
+            Ok(role_data) => {
+                role.name = role_data.name;
+                role.created_at = role_data.created_at;
+                role.permissions = role_data.permissions;
+                Ok(())
+            }
+            Err(err) => Err(IggyError::CannotDeserializeResource(err)),
+        }
+    }
+
+    async fn save(&self, role: &Role) -> Result<(), IggyError> {
+        let key = get_key(role.id);
+        let data = match rmp_serde::to_vec(&role)
+            .with_context(|| format!("Failed to serialize role with key: {}", key))
+        {
+            Ok(data) => data,
+            Err(err) => {
+                return Err(IggyError::CannotSerializeResource(err));
+            }
+        };
+
+        if let Err(err) = self
+            .db
+            .insert(&key, data)
+            .with_context(|| format!("Failed to insert role with key: {}", key))
+        {
+            return Err(IggyError::CannotSaveResource(err));
+        }
+
+        let id_key = get_id_key(&role.name);
+        if let Err(err) = self
+            .db
+            .insert(&id_key, &role.id.to_le_bytes())
+            .with_context(|| format!("Failed to insert role with ID: {} key: {}", role.id, id_key))
+        {
+            return Err(IggyError::CannotSaveResource(err));
+        }
+
+        info!("Saved role with ID: {}.", role.id);
+        Ok(())
+    }
+
+    async fn delete(&self, role: &Role) -> Result<(), IggyError> {
+        info!("Deleting role with ID: {}...", role.id);
+        let key = get_key(role.id);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete role with ID: {}, key: {}", role.id, key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        let id_key = get_id_key(&role.name);
+        if let Err(err) = self.db.remove(&id_key).with_context(|| {
+            format!(
+                "Failed to delete role with ID: {}, key: {}",
+                role.id, id_key
+            )
+        }) {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        info!("Deleted role with ID: {}.", role.id);
+        Ok(())
+    }
+}
+
+fn get_key(role_id: u32) -> String {
+    format!("{}:{}", KEY_PREFIX, role_id)
+}
+
+fn get_id_key(name: &str) -> String {
+    format!("{}_id:{}", KEY_PREFIX, name)
+}
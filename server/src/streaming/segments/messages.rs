@@ -3,6 +3,8 @@ use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::time_index::TimeIndex;
 use iggy::error::IggyError;
 use iggy::models::messages::Message;
+use iggy::utils::checksum;
+use iggy::utils::crypto::KeyRingEncryptor;
 use std::sync::Arc;
 use tracing::trace;
 
@@ -297,4 +299,68 @@ impl Segment {
 
         Ok(())
     }
+
+    pub async fn sync_messages(&mut self) -> Result<(), IggyError> {
+        self.persist_messages().await?;
+        self.storage.segment.sync_messages(self).await
+    }
+
+    /// Re-encrypts the messages of the closed segment, which were encrypted with other than the active key,
+    /// and overwrites the segment with its indexes. Returns the number of re-encrypted messages.
+    pub async fn re_encrypt_messages(
+        &mut self,
+        encryptor: &KeyRingEncryptor,
+    ) -> Result<u64, IggyError> {
+        if !self.is_closed {
+            return Ok(0);
+        }
+
+        let storage = self.storage.segment.clone();
+        let mut messages = storage
+            .load_messages(self, &IndexRange::max_range())
+            .await?;
+        let mut re_encrypted_messages = 0;
+        for message in messages.iter_mut() {
+            // The control records, e.g. transaction markers, are never encrypted.
+            if message.state.is_control() {
+                continue;
+            }
+
+            let payload = encryptor.re_encrypt(&message.payload)?;
+            if payload.is_none() {
+                continue;
+            }
+
+            let payload = payload.unwrap();
+            let checksum = checksum::calculate(&payload);
+            *message = Arc::new(Message::create(
+                message.offset,
+                message.state,
+                message.timestamp,
+                message.id,
+                payload.into(),
+                checksum,
+                message.headers.clone(),
+            ));
+            re_encrypted_messages += 1;
+        }
+
+        if re_encrypted_messages == 0 {
+            return Ok(0);
+        }
+
+        self.current_size_bytes = storage.overwrite_messages(self, &messages).await?;
+        storage.sync_messages(self).await?;
+        if self.indexes.is_some() {
+            self.indexes = Some(storage.load_all_indexes(self).await?);
+        }
+
+        trace!(
+            "Re-encrypted {} messages in segment with start offset: {} for partition with ID: {}.",
+            re_encrypted_messages,
+            self.start_offset,
+            self.partition_id
+        );
+        Ok(re_encrypted_messages)
+    }
 }
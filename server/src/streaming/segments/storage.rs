@@ -15,7 +15,8 @@ use std::collections::HashMap;
 use std::io::SeekFrom;
 use std::path::Path;
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncSeekExt, BufReader};
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader};
 use tracing::log::{trace, warn};
 use tracing::{error, info};
 
@@ -23,6 +24,7 @@ const EMPTY_INDEXES: Vec<Index> = vec![];
 const EMPTY_TIME_INDEXES: Vec<TimeIndex> = vec![];
 const INDEX_SIZE: u32 = 4;
 const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
+const TEMP_EXTENSION: &str = "tmp";
 
 #[derive(Debug)]
 pub struct FileSegmentStorage {
@@ -232,10 +234,98 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(messages_size)
     }
 
-    async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, IggyError> {
+    async fn overwrite_messages(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<u32, IggyError> {
+        let messages_size = messages
+            .iter()
+            .map(|message| message.get_size_bytes())
+            .sum::<u32>();
+
+        let mut bytes = Vec::with_capacity(messages_size as usize);
+        let mut index_bytes = Vec::with_capacity(messages.len() * INDEX_SIZE as usize);
+        for message in messages {
+            index_bytes.put_u32_le(bytes.len() as u32);
+            message.extend(&mut bytes);
+        }
+
+        // The files are written and synced aside, then renamed over the original ones,
+        // so that a crash in the middle of the rewrite can't corrupt the segment.
+        let log_temp_path = format!("{}.{}", segment.log_path, TEMP_EXTENSION);
+        let index_temp_path = format!("{}.{}", segment.index_path, TEMP_EXTENSION);
+        if let Err(err) = write_synced_file(&log_temp_path, &bytes)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to overwrite messages in segment: {}",
+                    segment.log_path
+                )
+            })
+        {
+            return Err(IggyError::CannotSaveMessagesToSegment(err));
+        }
+
+        if let Err(err) = write_synced_file(&index_temp_path, &index_bytes)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to overwrite index in segment: {}",
+                    segment.index_path
+                )
+            })
+        {
+            let _ = fs::remove_file(&log_temp_path).await;
+            return Err(IggyError::CannotSaveIndexToSegment(err));
+        }
+
+        if let Err(err) = replace_files(&[
+            (log_temp_path.as_str(), segment.log_path.as_str()),
+            (index_temp_path.as_str(), segment.index_path.as_str()),
+        ])
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to replace the files of segment: {}",
+                segment.log_path
+            )
+        }) {
+            return Err(IggyError::CannotSaveMessagesToSegment(err));
+        }
+
+        Ok(messages_size)
+    }
+
+    async fn sync_messages(&self, segment: &Segment) -> Result<(), IggyError> {
+        for path in [
+            &segment.log_path,
+            &segment.index_path,
+            &segment.time_index_path,
+        ] {
+            if let Err(err) = self
+                .persister
+                .sync(path)
+                .await
+                .with_context(|| format!("Failed to sync segment file: {}", path))
+            {
+                return Err(IggyError::CannotSaveMessagesToSegment(err));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn load_message_ids(
+        &self,
+        segment: &Segment,
+        min_timestamp: u64,
+    ) -> Result<Vec<u128>, IggyError> {
         let mut message_ids = Vec::new();
         load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
-            message_ids.push(message.id);
+            if message.timestamp >= min_timestamp {
+                message_ids.push(message.id);
+            }
             Ok(())
         })
         .await?;
@@ -717,3 +807,22 @@ async fn load_messages_by_size(
 
     Ok(())
 }
+
+async fn write_synced_file(path: &str, bytes: &[u8]) -> Result<(), std::io::Error> {
+    let mut file = file::overwrite(path).await?;
+    file.write_all(bytes).await?;
+    file.sync_all().await
+}
+
+/// Renames the files over the original ones and syncs their directory, so that the renames are durable.
+async fn replace_files(files: &[(&str, &str)]) -> Result<(), std::io::Error> {
+    for (temp_path, path) in files {
+        fs::rename(temp_path, path).await?;
+    }
+
+    let directory = files.first().and_then(|(_, path)| Path::new(path).parent());
+    if let Some(directory) = directory {
+        fs::File::open(directory).await?.sync_all().await?;
+    }
+    Ok(())
+}
@@ -1,12 +1,15 @@
 use iggy::models::user_info::{AtomicUserId, UserId};
+use iggy::tcp::pipelining::{ERROR_PAYLOAD_PROTOCOL_VERSION, LEGACY_PROTOCOL_VERSION};
 use std::fmt::Display;
 use std::net::SocketAddr;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 // This might be extended with more fields in the future e.g. custom name, permissions etc.
 #[derive(Debug)]
 pub struct Session {
     user_id: AtomicUserId,
+    scope_id: AtomicU32,
+    protocol_version: AtomicU32,
     pub client_id: u32,
     pub ip_address: SocketAddr,
 }
@@ -16,6 +19,8 @@ impl Session {
         Self {
             client_id,
             user_id: AtomicUserId::new(user_id),
+            scope_id: AtomicU32::new(0),
+            protocol_version: AtomicU32::new(LEGACY_PROTOCOL_VERSION),
             ip_address,
         }
     }
@@ -33,13 +38,52 @@ impl Session {
     }
 
     pub fn set_user_id(&self, user_id: UserId) {
-        self.user_id.store(user_id, Ordering::Release)
+        self.user_id.store(user_id, Ordering::Release);
+        self.set_scope_id(0)
     }
 
     pub fn clear_user_id(&self) {
         self.set_user_id(0)
     }
 
+    /// Returns the ID of the personal access token scope the session is restricted to, or 0 if there is none.
+    pub fn get_scope_id(&self) -> u32 {
+        self.scope_id.load(Ordering::Acquire)
+    }
+
+    pub fn set_scope_id(&self, scope_id: u32) {
+        self.scope_id.store(scope_id, Ordering::Release)
+    }
+
+    pub fn is_scoped(&self) -> bool {
+        self.get_scope_id() > 0
+    }
+
+    /// Returns the ID which should be used to check the permissions, which is the scope ID for the sessions
+    /// authenticated with a scoped personal access token, and the user ID otherwise.
+    pub fn get_principal_id(&self) -> u32 {
+        let scope_id = self.get_scope_id();
+        if scope_id > 0 {
+            return scope_id;
+        }
+
+        self.get_user_id()
+    }
+
+    /// Returns the version of the binary protocol used by the client, as agreed when the client has sent its version.
+    pub fn get_protocol_version(&self) -> u32 {
+        self.protocol_version.load(Ordering::Acquire)
+    }
+
+    pub fn set_protocol_version(&self, protocol_version: u32) {
+        self.protocol_version
+            .store(protocol_version, Ordering::Release)
+    }
+
+    pub fn supports_error_payload(&self) -> bool {
+        self.get_protocol_version() >= ERROR_PAYLOAD_PROTOCOL_VERSION
+    }
+
     pub fn is_authenticated(&self) -> bool {
         self.get_user_id() > 0
     }
@@ -0,0 +1,127 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::{watch, Notify};
+
+/// Coordinates the graceful shutdown of the server: notifies the listeners and connections
+/// to stop accepting the new requests and tracks the in-flight ones, so that they can complete
+/// before the buffered data is flushed to disk.
+#[derive(Debug, Clone)]
+pub struct ShutdownSignal {
+    sender: Arc<watch::Sender<bool>>,
+    in_flight_requests: Arc<AtomicUsize>,
+    requests_completed: Arc<Notify>,
+}
+
+/// Marks the request as completed once dropped.
+#[derive(Debug)]
+pub struct InFlightRequest {
+    in_flight_requests: Arc<AtomicUsize>,
+    requests_completed: Arc<Notify>,
+}
+
+impl Default for ShutdownSignal {
+    fn default() -> Self {
+        let (sender, _) = watch::channel(false);
+        Self {
+            sender: Arc::new(sender),
+            in_flight_requests: Arc::new(AtomicUsize::new(0)),
+            requests_completed: Arc::new(Notify::new()),
+        }
+    }
+}
+
+impl ShutdownSignal {
+    /// Notifies all the subscribers that the shutdown has begun.
+    pub fn trigger(&self) {
+        self.sender.send_replace(true);
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<bool> {
+        self.sender.subscribe()
+    }
+
+    /// Starts tracking the request, the returned guard should be kept until the response is sent.
+    /// Returns `None` once the shutdown has been triggered, as the new requests must be rejected then.
+    pub fn start_request(&self) -> Option<InFlightRequest> {
+        self.in_flight_requests.fetch_add(1, Ordering::SeqCst);
+        let request = InFlightRequest {
+            in_flight_requests: self.in_flight_requests.clone(),
+            requests_completed: self.requests_completed.clone(),
+        };
+        // The request is counted before checking the signal, so that it's either rejected or awaited by the shutdown.
+        if self.is_triggered() {
+            return None;
+        }
+
+        Some(request)
+    }
+
+    pub fn get_in_flight_requests(&self) -> usize {
+        self.in_flight_requests.load(Ordering::SeqCst)
+    }
+
+    /// Waits until all the in-flight requests are completed.
+    pub async fn wait_for_in_flight_requests(&self) {
+        loop {
+            let completed = self.requests_completed.notified();
+            if self.get_in_flight_requests() == 0 {
+                return;
+            }
+            completed.await;
+        }
+    }
+}
+
+impl Drop for InFlightRequest {
+    fn drop(&mut self) {
+        if self.in_flight_requests.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.requests_completed.notify_waiters();
+        }
+    }
+}
+
+/// Completes once the shutdown has been triggered, or the signal has been dropped.
+pub async fn wait_for_shutdown(receiver: &mut watch::Receiver<bool>) {
+    let _ = receiver.wait_for(|triggered| *triggered).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn waiting_for_in_flight_requests_should_complete_once_all_requests_are_dropped() {
+        let signal = ShutdownSignal::default();
+        let mut receiver = signal.subscribe();
+        let first_request = signal.start_request();
+        let second_request = signal.start_request();
+        assert_eq!(signal.get_in_flight_requests(), 2);
+
+        signal.trigger();
+        wait_for_shutdown(&mut receiver).await;
+        assert!(signal.is_triggered());
+        assert!(signal.start_request().is_none());
+
+        drop(first_request);
+        let waiting = tokio::time::timeout(
+            Duration::from_millis(50),
+            signal.wait_for_in_flight_requests(),
+        )
+        .await;
+        assert!(waiting.is_err());
+
+        drop(second_request);
+        let waiting = tokio::time::timeout(
+            Duration::from_millis(50),
+            signal.wait_for_in_flight_requests(),
+        )
+        .await;
+        assert!(waiting.is_ok());
+        assert_eq!(signal.get_in_flight_requests(), 0);
+    }
+}
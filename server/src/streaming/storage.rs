@@ -1,8 +1,12 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{
+    ConsumerOffset, Partition, PartitionTransaction, ProducerState,
+};
 use crate::streaming::partitions::storage::FilePartitionStorage;
 use crate::streaming::persistence::persister::Persister;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::personal_access_tokens::storage::FilePersonalAccessTokenStorage;
+use crate::streaming::roles::role::Role;
+use crate::streaming::roles::storage::FileRoleStorage;
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::storage::FileSegmentStorage;
@@ -33,7 +37,9 @@ pub trait Storage<T>: Sync + Send {
 }
 
 #[async_trait]
-pub trait SystemInfoStorage: Storage<SystemInfo> {}
+pub trait SystemInfoStorage: Storage<SystemInfo> {
+    async fn generate_producer_id(&self) -> Result<u64, IggyError>;
+}
 
 #[async_trait]
 pub trait UserStorage: Storage<User> {
@@ -42,6 +48,14 @@ pub trait UserStorage: Storage<User> {
     async fn load_all(&self) -> Result<Vec<User>, IggyError>;
 }
 
+#[async_trait]
+pub trait RoleStorage: Storage<Role> {
+    async fn load_by_id(&self, id: u32) -> Result<Role, IggyError>;
+    async fn load_by_name(&self, name: &str) -> Result<Role, IggyError>;
+    async fn load_all(&self) -> Result<Vec<Role>, IggyError>;
+    async fn delete_name(&self, name: &str) -> Result<(), IggyError>;
+}
+
 #[async_trait]
 pub trait PersonalAccessTokenStorage: Storage<PersonalAccessToken> {
     async fn load_all(&self) -> Result<Vec<PersonalAccessToken>, IggyError>;
@@ -56,7 +70,9 @@ pub trait PersonalAccessTokenStorage: Storage<PersonalAccessToken> {
 }
 
 #[async_trait]
-pub trait StreamStorage: Storage<Stream> {}
+pub trait StreamStorage: Storage<Stream> {
+    async fn delete_encryption_key(&self, stream: &Stream) -> Result<(), IggyError>;
+}
 
 #[async_trait]
 pub trait TopicStorage: Storage<Topic> {
@@ -90,6 +106,37 @@ pub trait PartitionStorage: Storage<Partition> {
         topic_id: u32,
         partition_id: u32,
     ) -> Result<(), IggyError>;
+    async fn save_producer_state(&self, state: &ProducerState) -> Result<(), IggyError>;
+    async fn load_producer_states(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<ProducerState>, IggyError>;
+    async fn delete_producer_states(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), IggyError>;
+    async fn save_transactions(
+        &self,
+        transactions: &[PartitionTransaction],
+    ) -> Result<(), IggyError>;
+    async fn load_transactions(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<PartitionTransaction>, IggyError>;
+    async fn delete_transaction(&self, transaction: &PartitionTransaction)
+        -> Result<(), IggyError>;
+    async fn delete_transactions(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), IggyError>;
 }
 
 #[async_trait]
@@ -109,7 +156,17 @@ pub trait SegmentStorage: Storage<Segment> {
         segment: &Segment,
         messages: &[Arc<Message>],
     ) -> Result<u32, IggyError>;
-    async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, IggyError>;
+    async fn overwrite_messages(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<u32, IggyError>;
+    async fn sync_messages(&self, segment: &Segment) -> Result<(), IggyError>;
+    async fn load_message_ids(
+        &self,
+        segment: &Segment,
+        min_timestamp: u64,
+    ) -> Result<Vec<u128>, IggyError>;
     async fn load_checksums(&self, segment: &Segment) -> Result<(), IggyError>;
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, IggyError>;
     async fn load_index_range(
@@ -139,6 +196,7 @@ pub trait SegmentStorage: Storage<Segment> {
 pub struct SystemStorage {
     pub info: Arc<dyn SystemInfoStorage>,
     pub user: Arc<dyn UserStorage>,
+    pub role: Arc<dyn RoleStorage>,
     pub personal_access_token: Arc<dyn PersonalAccessTokenStorage>,
     pub stream: Arc<dyn StreamStorage>,
     pub topic: Arc<dyn TopicStorage>,
@@ -151,6 +209,7 @@ impl SystemStorage {
         Self {
             info: Arc::new(FileSystemInfoStorage::new(db.clone())),
             user: Arc::new(FileUserStorage::new(db.clone())),
+            role: Arc::new(FileRoleStorage::new(db.clone())),
             personal_access_token: Arc::new(FilePersonalAccessTokenStorage::new(db.clone())),
             stream: Arc::new(FileStreamStorage::new(db.clone())),
             topic: Arc::new(FileTopicStorage::new(db.clone())),
@@ -172,6 +231,12 @@ impl Debug for dyn UserStorage {
     }
 }
 
+impl Debug for dyn RoleStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RoleStorage")
+    }
+}
+
 impl Debug for dyn PersonalAccessTokenStorage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "PersonalAccessTokenStorage")
@@ -217,6 +282,7 @@ pub(crate) mod tests {
 
     struct TestSystemInfoStorage {}
     struct TestUserStorage {}
+    struct TestRoleStorage {}
     struct TestPersonalAccessTokenStorage {}
     struct TestStreamStorage {}
     struct TestTopicStorage {}
@@ -239,7 +305,11 @@ pub(crate) mod tests {
     }
 
     #[async_trait]
-    impl SystemInfoStorage for TestSystemInfoStorage {}
+    impl SystemInfoStorage for TestSystemInfoStorage {
+        async fn generate_producer_id(&self) -> Result<u64, IggyError> {
+            Ok(1)
+        }
+    }
 
     #[async_trait]
     impl Storage<User> for TestUserStorage {
@@ -295,6 +365,40 @@ pub(crate) mod tests {
         }
     }
 
+    #[async_trait]
+    impl Storage<Role> for TestRoleStorage {
+        async fn load(&self, _role: &mut Role) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn save(&self, _role: &Role) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn delete(&self, _role: &Role) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl RoleStorage for TestRoleStorage {
+        async fn load_by_id(&self, id: u32) -> Result<Role, IggyError> {
+            Ok(Role::empty(id))
+        }
+
+        async fn load_by_name(&self, _name: &str) -> Result<Role, IggyError> {
+            Ok(Role::empty(1))
+        }
+
+        async fn load_all(&self) -> Result<Vec<Role>, IggyError> {
+            Ok(vec![])
+        }
+
+        async fn delete_name(&self, _name: &str) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
     #[async_trait]
     impl PersonalAccessTokenStorage for TestPersonalAccessTokenStorage {
         async fn load_all(&self) -> Result<Vec<PersonalAccessToken>, IggyError> {
@@ -340,7 +444,12 @@ pub(crate) mod tests {
         }
     }
 
-    impl StreamStorage for TestStreamStorage {}
+    #[async_trait]
+    impl StreamStorage for TestStreamStorage {
+        async fn delete_encryption_key(&self, _stream: &Stream) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
 
     #[async_trait]
     impl Storage<Topic> for TestTopicStorage {
@@ -423,6 +532,60 @@ pub(crate) mod tests {
         ) -> Result<(), IggyError> {
             Ok(())
         }
+
+        async fn save_producer_state(&self, _state: &ProducerState) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn load_producer_states(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<Vec<ProducerState>, IggyError> {
+            Ok(vec![])
+        }
+
+        async fn delete_producer_states(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn save_transactions(
+            &self,
+            _transactions: &[PartitionTransaction],
+        ) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn load_transactions(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<Vec<PartitionTransaction>, IggyError> {
+            Ok(vec![])
+        }
+
+        async fn delete_transaction(
+            &self,
+            _transaction: &PartitionTransaction,
+        ) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn delete_transactions(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<(), IggyError> {
+            Ok(())
+        }
     }
 
     #[async_trait]
@@ -466,7 +629,23 @@ pub(crate) mod tests {
             Ok(0)
         }
 
-        async fn load_message_ids(&self, _segment: &Segment) -> Result<Vec<u128>, IggyError> {
+        async fn overwrite_messages(
+            &self,
+            _segment: &Segment,
+            _messages: &[Arc<Message>],
+        ) -> Result<u32, IggyError> {
+            Ok(0)
+        }
+
+        async fn sync_messages(&self, _segment: &Segment) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn load_message_ids(
+            &self,
+            _segment: &Segment,
+            _min_timestamp: u64,
+        ) -> Result<Vec<u128>, IggyError> {
             Ok(vec![])
         }
 
@@ -524,6 +703,7 @@ pub(crate) mod tests {
         SystemStorage {
             info: Arc::new(TestSystemInfoStorage {}),
             user: Arc::new(TestUserStorage {}),
+            role: Arc::new(TestRoleStorage {}),
             personal_access_token: Arc::new(TestPersonalAccessTokenStorage {}),
             stream: Arc::new(TestStreamStorage {}),
             topic: Arc::new(TestTopicStorage {}),
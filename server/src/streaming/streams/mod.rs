@@ -1,6 +1,7 @@
 pub mod messages;
 pub mod partitions;
 pub mod persistence;
+pub mod quotas;
 pub mod segments;
 pub mod storage;
 pub mod stream;
@@ -11,7 +11,15 @@ impl Stream {
         self.storage.stream.save(self).await
     }
 
-    pub async fn delete(&self) -> Result<(), IggyError> {
+    pub async fn delete(&mut self) -> Result<(), IggyError> {
+        // Deleting the stream's data key first makes its messages unrecoverable (crypto-shredding),
+        // even if removing the files below fails or gets interrupted.
+        if self.encryption_key.is_some() {
+            self.storage.stream.delete_encryption_key(self).await?;
+            self.encryption_key = None;
+            self.encryptor = None;
+        }
+
         for topic in self.get_topics() {
             topic.delete().await?;
         }
@@ -27,6 +35,20 @@ impl Stream {
         Ok(())
     }
 
+    /// Syncs the messages of all the topics, even if any of them fails, and returns the first error.
+    pub async fn sync_messages(&self) -> Result<(), IggyError> {
+        let mut result = Ok(());
+        for topic in self.get_topics() {
+            if let Err(error) = topic.sync_all_messages().await {
+                if result.is_ok() {
+                    result = Err(error);
+                }
+            }
+        }
+
+        result
+    }
+
     pub async fn purge(&self) -> Result<(), IggyError> {
         for topic in self.get_topics() {
             topic.purge().await?;
@@ -0,0 +1,150 @@
+use crate::streaming::streams::stream::Stream;
+use iggy::error::IggyError;
+
+impl Stream {
+    pub fn get_consumer_groups_count(&self) -> u32 {
+        let mut consumer_groups_count = 0;
+        for topic in self.topics.values() {
+            consumer_groups_count += topic.get_consumer_groups().len() as u32;
+        }
+
+        consumer_groups_count
+    }
+
+    pub fn ensure_topic_can_be_created(&self, partitions_count: u32) -> Result<(), IggyError> {
+        if let Some(max_topics) = self.quotas.and_then(|quotas| quotas.max_topics) {
+            if self.get_topics_count() >= max_topics {
+                return Err(IggyError::StreamTopicsLimitExceeded(
+                    self.stream_id,
+                    max_topics,
+                ));
+            }
+        }
+
+        self.ensure_partitions_can_be_created(partitions_count)
+    }
+
+    pub fn ensure_partitions_can_be_created(&self, partitions_count: u32) -> Result<(), IggyError> {
+        if let Some(max_partitions) = self.quotas.and_then(|quotas| quotas.max_partitions) {
+            if self.get_partitions_count() + partitions_count > max_partitions {
+                return Err(IggyError::StreamPartitionsLimitExceeded(
+                    self.stream_id,
+                    max_partitions,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_consumer_group_can_be_created(&self) -> Result<(), IggyError> {
+        if let Some(max_consumer_groups) = self.quotas.and_then(|quotas| quotas.max_consumer_groups)
+        {
+            if self.get_consumer_groups_count() >= max_consumer_groups {
+                return Err(IggyError::StreamConsumerGroupsLimitExceeded(
+                    self.stream_id,
+                    max_consumer_groups,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_messages_can_be_appended(&self, size_bytes: u64) -> Result<(), IggyError> {
+        if let Some(max_size) = self.quotas.and_then(|quotas| quotas.max_size) {
+            if self.get_tracked_size_bytes() + size_bytes > max_size.as_bytes_u64() {
+                return Err(IggyError::StreamSizeLimitExceeded(self.stream_id, max_size));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use iggy::models::stream_quotas::StreamQuotas;
+    use iggy::utils::byte_size::IggyByteSize;
+    use std::sync::Arc;
+
+    fn create_stream(quotas: Option<StreamQuotas>) -> Stream {
+        let storage = Arc::new(get_test_system_storage());
+        let config = Arc::new(SystemConfig::default());
+        let mut stream = Stream::create(1, "test", config, storage);
+        stream.quotas = quotas;
+        stream
+    }
+
+    #[test]
+    fn unlimited_stream_should_allow_creating_any_resources() {
+        let stream = create_stream(None);
+        assert!(stream.ensure_topic_can_be_created(1000).is_ok());
+        assert!(stream.ensure_partitions_can_be_created(1000).is_ok());
+        assert!(stream.ensure_consumer_group_can_be_created().is_ok());
+    }
+
+    #[test]
+    fn topic_should_not_be_created_when_exceeding_partitions_limit() {
+        let stream = create_stream(Some(StreamQuotas {
+            max_topics: Some(1),
+            max_partitions: Some(10),
+            ..Default::default()
+        }));
+        assert!(stream.ensure_topic_can_be_created(10).is_ok());
+        assert!(matches!(
+            stream.ensure_topic_can_be_created(11),
+            Err(IggyError::StreamPartitionsLimitExceeded(1, 10))
+        ));
+    }
+
+    #[tokio::test]
+    async fn topic_should_not_be_created_when_exceeding_topics_limit() {
+        let mut stream = create_stream(Some(StreamQuotas {
+            max_topics: Some(1),
+            ..Default::default()
+        }));
+        stream
+            .create_topic(Some(1), "topic", 1, None, None, 1)
+            .await
+            .unwrap();
+        assert!(matches!(
+            stream.ensure_topic_can_be_created(1),
+            Err(IggyError::StreamTopicsLimitExceeded(1, 1))
+        ));
+    }
+
+    #[test]
+    fn messages_should_not_be_appended_when_exceeding_size_limit() {
+        let stream = create_stream(Some(StreamQuotas {
+            max_size: Some(IggyByteSize::from(1000)),
+            ..Default::default()
+        }));
+        assert!(stream.ensure_messages_can_be_appended(1000).is_ok());
+        assert!(matches!(
+            stream.ensure_messages_can_be_appended(1001),
+            Err(IggyError::StreamSizeLimitExceeded(1, _))
+        ));
+    }
+
+    #[tokio::test]
+    async fn messages_should_not_be_appended_when_tracked_size_reaches_limit() {
+        let stream = create_stream(Some(StreamQuotas {
+            max_size: Some(IggyByteSize::from(1000)),
+            ..Default::default()
+        }));
+        stream.increase_size_bytes(600);
+        assert!(stream.ensure_messages_can_be_appended(400).is_ok());
+        assert!(matches!(
+            stream.ensure_messages_can_be_appended(401),
+            Err(IggyError::StreamSizeLimitExceeded(1, _))
+        ));
+
+        stream.refresh_size_bytes().await;
+        assert_eq!(stream.get_tracked_size_bytes(), 0);
+        assert!(stream.ensure_messages_can_be_appended(1000).is_ok());
+    }
+}
@@ -5,6 +5,7 @@ use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::join_all;
 use iggy::error::IggyError;
+use iggy::models::stream_quotas::StreamQuotas;
 use serde::{Deserialize, Serialize};
 use sled::Db;
 use std::path::Path;
@@ -28,12 +29,63 @@ impl FileStreamStorage {
 unsafe impl Send for FileStreamStorage {}
 unsafe impl Sync for FileStreamStorage {}
 
-impl StreamStorage for FileStreamStorage {}
+#[async_trait]
+impl StreamStorage for FileStreamStorage {
+    async fn delete_encryption_key(&self, stream: &Stream) -> Result<(), IggyError> {
+        info!(
+            "Deleting encryption key for stream with ID: {}...",
+            stream.stream_id
+        );
+        let key = get_key(stream.stream_id);
+        match rmp_serde::to_vec(&StreamData {
+            name: stream.name.clone(),
+            created_at: stream.created_at,
+            quotas: stream.quotas,
+            encryption_key: None,
+        })
+        .with_context(|| format!("Failed to serialize stream with key: {}", key))
+        {
+            Ok(data) => {
+                if let Err(err) = self
+                    .db
+                    .insert(&key, data)
+                    .with_context(|| format!("Failed to insert stream with key: {}", key))
+                {
+                    return Err(IggyError::CannotSaveResource(err));
+                }
+            }
+            Err(err) => {
+                return Err(IggyError::CannotSerializeResource(err));
+            }
+        }
+
+        // The data key must be durably removed before any file is deleted,
+        // otherwise the messages could still be recovered after the crash.
+        if let Err(err) = self
+            .db
+            .flush_async()
+            .await
+            .with_context(|| format!("Failed to flush stream with key: {}", key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        info!(
+            "Deleted encryption key for stream with ID: {}.",
+            stream.stream_id
+        );
+        Ok(())
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 struct StreamData {
     name: String,
     created_at: u64,
+    #[serde(default)]
+    quotas: Option<StreamQuotas>,
+    #[serde(default)]
+    encryption_key: Option<Vec<u8>>,
 }
 
 #[async_trait]
@@ -77,6 +129,8 @@ impl Storage<Stream> for FileStreamStorage {
 
         stream.name = stream_data.name;
         stream.created_at = stream_data.created_at;
+        stream.quotas = stream_data.quotas;
+        stream.encryption_key = stream_data.encryption_key;
         let mut unloaded_topics = Vec::new();
         let dir_entries = fs::read_dir(&stream.topics_path).await;
         if dir_entries.is_err() {
@@ -169,6 +223,8 @@ impl Storage<Stream> for FileStreamStorage {
         match rmp_serde::to_vec(&StreamData {
             name: stream.name.clone(),
             created_at: stream.created_at,
+            quotas: stream.quotas,
+            encryption_key: stream.encryption_key.clone(),
         })
         .with_context(|| format!("Failed to serialize stream with key: {}", key))
         {
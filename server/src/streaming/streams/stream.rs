@@ -1,10 +1,12 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::topic::Topic;
+use iggy::models::stream_quotas::StreamQuotas;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::crypto::Aes256GcmEncryptor;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
-use std::sync::atomic::AtomicU32;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -15,6 +17,13 @@ pub struct Stream {
     pub topics_path: String,
     pub created_at: u64,
     pub current_topic_id: AtomicU32,
+    pub quotas: Option<StreamQuotas>,
+    /// The stream's own data key, wrapped (encrypted) by the master key, if per-stream keys are enabled.
+    pub(crate) encryption_key: Option<Vec<u8>>,
+    /// The unwrapped data key, available only in memory.
+    pub(crate) encryptor: Option<Aes256GcmEncryptor>,
+    /// The size of the stream's messages, updated on append and recalculated when they are deleted.
+    pub(crate) size_bytes: AtomicU64,
     pub(crate) topics: HashMap<u32, Topic>,
     pub(crate) topics_ids: HashMap<String, u32>,
     pub(crate) config: Arc<SystemConfig>,
@@ -42,6 +51,10 @@ impl Stream {
             topics_path,
             config,
             current_topic_id: AtomicU32::new(1),
+            quotas: None,
+            encryption_key: None,
+            encryptor: None,
+            size_bytes: AtomicU64::new(0),
             topics: HashMap::new(),
             topics_ids: HashMap::new(),
             storage,
@@ -56,6 +69,19 @@ impl Stream {
         }
         IggyByteSize::from(size_bytes)
     }
+
+    pub fn get_tracked_size_bytes(&self) -> u64 {
+        self.size_bytes.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn increase_size_bytes(&self, size_bytes: u64) {
+        self.size_bytes.fetch_add(size_bytes, Ordering::SeqCst);
+    }
+
+    pub(crate) async fn refresh_size_bytes(&self) {
+        let size_bytes = self.get_size().await.as_bytes_u64();
+        self.size_bytes.store(size_bytes, Ordering::SeqCst);
+    }
 }
 
 #[cfg(test)]
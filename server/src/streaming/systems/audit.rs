@@ -0,0 +1,94 @@
+use crate::streaming::audit::audit_action::AuditAction;
+use crate::streaming::audit::audit_log::AuditEvent;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::models::audit_log_entry::AuditLogEntry;
+use tracing::error;
+
+const HTTP_TRANSPORT: &str = "HTTP";
+const UNKNOWN_TRANSPORT: &str = "Unknown";
+
+impl System {
+    pub async fn get_audit_log(
+        &self,
+        session: &Session,
+        offset: u64,
+        count: u32,
+    ) -> Result<Vec<AuditLogEntry>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_audit_log(session.get_principal_id())?;
+        self.audit_log.read(offset, count).await
+    }
+
+    /// Records the successful action in the audit log. The client manager must not be locked by the caller.
+    pub(crate) async fn audit(&self, session: &Session, action: AuditAction, resource: String) {
+        self.record_audit_event(session, action, resource, true)
+            .await;
+    }
+
+    /// Records the failed action in the audit log. The client manager must not be locked by the caller.
+    pub(crate) async fn audit_failure(
+        &self,
+        session: &Session,
+        action: AuditAction,
+        resource: String,
+    ) {
+        self.record_audit_event(session, action, resource, false)
+            .await;
+    }
+
+    /// Records the failed login attempt in the audit log, if the session is available.
+    pub(crate) async fn audit_login_failure(
+        &self,
+        session: Option<&Session>,
+        action: AuditAction,
+        resource: String,
+    ) {
+        if let Some(session) = session {
+            self.audit_failure(session, action, resource).await;
+        }
+    }
+
+    async fn record_audit_event(
+        &self,
+        session: &Session,
+        action: AuditAction,
+        resource: String,
+        succeeded: bool,
+    ) {
+        if !self.audit_log.is_enabled() {
+            return;
+        }
+
+        let event = AuditEvent {
+            user_id: session.get_user_id(),
+            client_id: session.client_id,
+            address: session.ip_address.to_string(),
+            transport: self.get_transport(session).await,
+            action,
+            resource,
+            succeeded,
+        };
+        if let Err(error) = self.audit_log.append(event).await {
+            error!("Failed to record the audit event: {action} for session: {session}. {error}");
+        }
+    }
+
+    async fn get_transport(&self, session: &Session) -> String {
+        if session.client_id == 0 {
+            return HTTP_TRANSPORT.to_string();
+        }
+
+        let client = self
+            .client_manager
+            .read()
+            .await
+            .get_client_by_id(session.client_id);
+        match client {
+            Ok(client) => client.read().await.transport.to_string(),
+            Err(_) => UNKNOWN_TRANSPORT.to_string(),
+        }
+    }
+}
@@ -56,6 +56,9 @@ impl System {
             }
         }
 
+        self.abort_client_transaction(client_id).await;
+        self.quota_manager.delete_client(client_id);
+
         {
             let mut client_manager = self.client_manager.write().await;
             let client = client_manager.delete_client(address);
@@ -80,7 +83,7 @@ impl System {
         client_id: u32,
     ) -> Result<Arc<RwLock<Client>>, IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.get_client(session.get_user_id())?;
+        self.permissioner.get_client(session.get_principal_id())?;
         let client_manager = self.client_manager.read().await;
         client_manager.get_client_by_id(client_id)
     }
@@ -90,7 +93,7 @@ impl System {
         session: &Session,
     ) -> Result<Vec<Arc<RwLock<Client>>>, IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.get_clients(session.get_user_id())?;
+        self.permissioner.get_clients(session.get_principal_id())?;
         let client_manager = self.client_manager.read().await;
         Ok(client_manager.get_clients())
     }
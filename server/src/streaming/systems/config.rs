@@ -0,0 +1,44 @@
+use crate::configs::reloader::ConfigReloader;
+use crate::configs::server::ServerConfig;
+use crate::streaming::audit::audit_action::AuditAction;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::models::config_reload::ConfigReload;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+impl System {
+    pub fn set_config_reloader(&mut self, config_reloader: Arc<ConfigReloader>) {
+        self.config_reloader = Some(config_reloader);
+    }
+
+    /// Returns the receiver of the reloaded configuration, if the reload is available.
+    pub fn subscribe_config_updates(&self) -> Option<watch::Receiver<Arc<ServerConfig>>> {
+        self.config_reloader
+            .as_ref()
+            .map(|config_reloader| config_reloader.subscribe())
+    }
+
+    pub async fn reload_config(&self, session: &Session) -> Result<ConfigReload, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .reload_config(session.get_principal_id())?;
+        if self.config_reloader.is_none() {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let config_reload = self.config_reloader.as_ref().unwrap().reload().await?;
+        self.audit(
+            session,
+            AuditAction::ReloadConfig,
+            format!(
+                "applied changes: [{}], restart required changes: [{}]",
+                config_reload.applied_changes.join(", "),
+                config_reload.restart_required_changes.join(", ")
+            ),
+        )
+        .await;
+        Ok(config_reload)
+    }
+}
@@ -17,7 +17,7 @@ impl System {
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner.get_consumer_group(
-            session.get_user_id(),
+            session.get_principal_id(),
             stream.stream_id,
             topic.topic_id,
         )?;
@@ -35,7 +35,7 @@ impl System {
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner.get_consumer_groups(
-            session.get_user_id(),
+            session.get_principal_id(),
             stream.stream_id,
             topic.topic_id,
         )?;
@@ -56,10 +56,11 @@ impl System {
             let stream = self.get_stream(stream_id)?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.create_consumer_group(
-                session.get_user_id(),
+                session.get_principal_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
+            stream.ensure_consumer_group_can_be_created()?;
         }
 
         let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
@@ -81,7 +82,7 @@ impl System {
             let stream = self.get_stream(stream_id)?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.delete_consumer_group(
-                session.get_user_id(),
+                session.get_principal_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
@@ -127,7 +128,7 @@ impl System {
             let stream = self.get_stream(stream_id)?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.join_consumer_group(
-                session.get_user_id(),
+                session.get_principal_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
@@ -170,7 +171,7 @@ impl System {
             let stream = self.get_stream(stream_id)?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.leave_consumer_group(
-                session.get_user_id(),
+                session.get_principal_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
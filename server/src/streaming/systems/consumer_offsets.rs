@@ -18,11 +18,20 @@ impl System {
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner.store_consumer_offset(
-            session.get_user_id(),
+            session.get_principal_id(),
             stream.stream_id,
             topic.topic_id,
         )?;
 
+        if let Some(transaction_id) = self.get_transaction_id(session).await? {
+            let partition_id = topic
+                .store_transaction_consumer_offset(transaction_id, consumer, offset)
+                .await?;
+            return self
+                .add_transaction_partition(session, stream.stream_id, topic.topic_id, partition_id)
+                .await;
+        }
+
         topic.store_consumer_offset(consumer, offset).await
     }
 
@@ -37,7 +46,7 @@ impl System {
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner.get_consumer_offset(
-            session.get_user_id(),
+            session.get_principal_id(),
             stream.stream_id,
             topic.topic_id,
         )?;
@@ -0,0 +1,94 @@
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use tracing::{error, info};
+
+impl System {
+    /// Generates the new data key for the stream and wraps it with the master key, if the per-stream keys are enabled.
+    pub(crate) fn create_stream_encryption_key(
+        &self,
+        stream: &mut Stream,
+    ) -> Result<(), IggyError> {
+        if self.encryptor.is_none() || !self.config.encryption.per_stream_keys {
+            return Ok(());
+        }
+
+        let master = self.encryptor.as_ref().unwrap();
+        let key = Aes256GcmEncryptor::generate_key();
+        stream.encryptor = Some(Aes256GcmEncryptor::new(&key)?);
+        stream.encryption_key = Some(master.encrypt(&key)?);
+        Ok(())
+    }
+
+    /// Unwraps the stored data key of the stream with the master key, so that its messages can be encrypted and decrypted.
+    pub(crate) fn unwrap_stream_encryption_key(
+        &self,
+        stream: &mut Stream,
+    ) -> Result<(), IggyError> {
+        if self.encryptor.is_none() || stream.encryption_key.is_none() {
+            return Ok(());
+        }
+
+        let master = self.encryptor.as_ref().unwrap();
+        let key = master.decrypt(stream.encryption_key.as_ref().unwrap())?;
+        stream.encryptor = Some(Aes256GcmEncryptor::new(&key)?);
+        Ok(())
+    }
+
+    /// Returns the encryptor for the messages of the given stream, which is either its own data key or the master key.
+    pub(crate) fn get_stream_encryptor<'a>(
+        &'a self,
+        stream: &'a Stream,
+    ) -> Result<Option<&'a dyn Encryptor>, IggyError> {
+        if self.encryptor.is_none() {
+            return Ok(None);
+        }
+
+        if stream.encryption_key.is_none() {
+            return Ok(Some(&**self.encryptor.as_ref().unwrap()));
+        }
+
+        match stream.encryptor {
+            Some(ref encryptor) => Ok(Some(encryptor)),
+            None => {
+                error!(
+                    "Encryption key for stream with ID: {} is unavailable.",
+                    stream.stream_id
+                );
+                Err(IggyError::CannotDecryptData)
+            }
+        }
+    }
+
+    /// Wraps the data keys of the streams with the active master key, if they were wrapped with any other key.
+    /// The messages themselves remain encrypted with the same data keys.
+    pub async fn re_wrap_stream_encryption_keys(&mut self) -> Result<u32, IggyError> {
+        if self.encryptor.is_none() {
+            return Ok(0);
+        }
+
+        let master = self.encryptor.clone().unwrap();
+        let mut re_wrapped_keys = 0;
+        for stream in self.streams.values_mut() {
+            if stream.encryption_key.is_none() {
+                continue;
+            }
+
+            let key = master.re_encrypt(stream.encryption_key.as_ref().unwrap())?;
+            if key.is_none() {
+                continue;
+            }
+
+            stream.encryption_key = key;
+            stream.persist().await?;
+            re_wrapped_keys += 1;
+            info!(
+                "Re-wrapped encryption key for stream with ID: {} with the active key.",
+                stream.stream_id
+            );
+        }
+
+        Ok(re_wrapped_keys)
+    }
+}
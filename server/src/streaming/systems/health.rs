@@ -0,0 +1,149 @@
+use crate::streaming::systems::system::System;
+use crate::streaming::utils::random_id;
+use iggy::models::health::{Health, HealthCheck};
+use iggy::utils::byte_size::IggyByteSize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use sysinfo::Disks;
+use tokio::fs;
+use tokio::time::timeout;
+
+const HEALTH_CHECK_FILE_PREFIX: &str = ".health_check";
+const HEALTH_CHECK_KEY: &str = "health_check";
+const DATABASE_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl System {
+    /// Returns the health used for the liveness probe, which fails only if any background task has stopped,
+    /// as the server is unable to recover from it without the restart.
+    pub fn get_liveness(&self) -> Health {
+        Health {
+            checks: vec![self.check_background_tasks()],
+        }
+    }
+
+    /// Returns the health used for the readiness probe, including the storage, database, background tasks and free disk space checks.
+    pub async fn get_health(&self) -> Health {
+        Health {
+            checks: vec![
+                self.check_storage().await,
+                self.check_database().await,
+                self.check_background_tasks(),
+                self.check_disk_space(),
+            ],
+        }
+    }
+
+    async fn check_storage(&self) -> HealthCheck {
+        // Every check uses its own file, so that the concurrent probes don't remove each other's file.
+        let file_name = format!("{HEALTH_CHECK_FILE_PREFIX}_{}", random_id::get_ulid());
+        let path = Path::new(&self.config.get_system_path()).join(file_name);
+        let result = match fs::write(&path, b"ok").await {
+            Ok(_) => fs::remove_file(&path).await,
+            Err(error) => Err(error),
+        };
+        match result {
+            Ok(_) => create_check("storage", true, "storage is writable".to_string()),
+            Err(error) => create_check(
+                "storage",
+                false,
+                format!("storage is not writable: {error}"),
+            ),
+        }
+    }
+
+    async fn check_database(&self) -> HealthCheck {
+        if self.db.is_none() {
+            return create_check("database", false, "database is not available".to_string());
+        }
+
+        let db = self.db.clone().unwrap();
+        let result = timeout(
+            DATABASE_TIMEOUT,
+            tokio::task::spawn_blocking(move || db.get(HEALTH_CHECK_KEY)),
+        )
+        .await;
+        match result {
+            Ok(Ok(Ok(_))) => create_check("database", true, "database is responsive".to_string()),
+            Ok(Ok(Err(error))) => {
+                create_check("database", false, format!("database error: {error}"))
+            }
+            Ok(Err(error)) => {
+                create_check("database", false, format!("database check failed: {error}"))
+            }
+            Err(_) => create_check(
+                "database",
+                false,
+                format!(
+                    "database did not respond within: {} s",
+                    DATABASE_TIMEOUT.as_secs()
+                ),
+            ),
+        }
+    }
+
+    fn check_background_tasks(&self) -> HealthCheck {
+        let states = self.background_tasks.get_states();
+        let stopped_tasks = states
+            .iter()
+            .filter(|(_, running)| !running)
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        if !stopped_tasks.is_empty() {
+            return create_check(
+                "background_tasks",
+                false,
+                format!("stopped tasks: {}", stopped_tasks.join(", ")),
+            );
+        }
+
+        let running_tasks = states.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        match running_tasks.is_empty() {
+            true => create_check("background_tasks", true, "no tasks enabled".to_string()),
+            false => create_check(
+                "background_tasks",
+                true,
+                format!("running tasks: {}", running_tasks.join(", ")),
+            ),
+        }
+    }
+
+    fn check_disk_space(&self) -> HealthCheck {
+        let min_free_disk_space = &self.config.health.min_free_disk_space;
+        if min_free_disk_space.as_bytes_u64() == 0 {
+            return create_check("disk_space", true, "check is disabled".to_string());
+        }
+
+        let system_path = std::fs::canonicalize(self.config.get_system_path())
+            .unwrap_or_else(|_| PathBuf::from(self.config.get_system_path()));
+        let disks = Disks::new_with_refreshed_list();
+        // The disk storing the system data is the one with the longest mount point being the prefix of its path.
+        let disk = disks
+            .list()
+            .iter()
+            .filter(|disk| system_path.starts_with(disk.mount_point()))
+            .max_by_key(|disk| disk.mount_point().as_os_str().len());
+        if disk.is_none() {
+            return create_check(
+                "disk_space",
+                false,
+                format!("disk not found for path: {}", system_path.display()),
+            );
+        }
+
+        let free_disk_space = IggyByteSize::from(disk.unwrap().available_space());
+        let details = format!("free space: {free_disk_space}, minimum: {min_free_disk_space}");
+        create_check(
+            "disk_space",
+            free_disk_space.as_bytes_u64() >= min_free_disk_space.as_bytes_u64(),
+            details,
+        )
+    }
+}
+
+fn create_check(name: &str, healthy: bool, details: String) -> HealthCheck {
+    HealthCheck {
+        name: name.to_string(),
+        healthy,
+        details,
+    }
+}
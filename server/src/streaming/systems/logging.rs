@@ -0,0 +1,55 @@
+use crate::logging::LogLevelUpdater;
+use crate::streaming::audit::audit_action::AuditAction;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use std::time::Duration;
+use tokio::time;
+use tracing::error;
+
+impl System {
+    pub fn set_log_level_updater(&mut self, log_level_updater: LogLevelUpdater) {
+        self.log_level_updater = Some(log_level_updater);
+    }
+
+    /// Overrides the log level with the given filter directives, and reverts it to the configured log level after the given time (in seconds), if provided.
+    pub async fn set_log_filter(
+        &self,
+        session: &Session,
+        filter: &str,
+        revert_after: Option<u32>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .set_log_filter(session.get_principal_id())?;
+        if self.log_level_updater.is_none() {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let log_level_updater = self.log_level_updater.clone().unwrap();
+        let version = log_level_updater.set_filter(filter).map_err(|error| {
+            error!("Failed to set log filter: {filter}. Error: {error}");
+            IggyError::InvalidLogFilter(filter.to_string())
+        })?;
+
+        if let Some(revert_after) = revert_after {
+            tokio::spawn(async move {
+                time::sleep(Duration::from_secs(u64::from(revert_after))).await;
+                if let Err(error) = log_level_updater.reset_filter(Some(version)) {
+                    error!("Failed to reset log filter. Error: {error}");
+                }
+            });
+        }
+
+        self.audit(
+            session,
+            AuditAction::SetLogFilter,
+            format!(
+                "filter: {filter}, revert after: {} s",
+                revert_after.unwrap_or(0)
+            ),
+        )
+        .await;
+        Ok(())
+    }
+}
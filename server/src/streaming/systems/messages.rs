@@ -1,4 +1,5 @@
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::diagnostics::metrics::PartitionLabels;
 use crate::streaming::models::messages::PolledMessages;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
@@ -8,7 +9,7 @@ use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{AckLevel, Partitioning, ProducerBatch};
 use iggy::models::messages::Message;
 use std::sync::Arc;
 use tracing::{error, trace};
@@ -29,8 +30,12 @@ impl System {
 
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
-        self.permissioner
-            .poll_messages(session.get_user_id(), stream.stream_id, topic.topic_id)?;
+        self.permissioner.poll_messages(
+            session.get_principal_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+        self.quota_manager.check_consume(session)?;
 
         if !topic.has_partitions() {
             return Err(IggyError::NoPartitions(topic.topic_id, topic.stream_id));
@@ -48,26 +53,56 @@ impl System {
         };
 
         let mut polled_messages = topic
-            .get_messages(consumer, partition_id, args.strategy, args.count)
+            .get_messages(
+                consumer,
+                partition_id,
+                args.strategy,
+                args.count,
+                args.read_committed,
+            )
             .await?;
 
         if polled_messages.messages.is_empty() {
             return Ok(polled_messages);
         }
 
+        let polled_size_bytes = polled_messages
+            .messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum();
+        self.quota_manager
+            .record_consume(session, polled_size_bytes);
+        self.metrics.record_polled_messages(
+            &PartitionLabels {
+                stream_id: stream.stream_id,
+                topic_id: topic.topic_id,
+                partition_id,
+            },
+            polled_messages.messages.len() as u64,
+            polled_size_bytes,
+        );
+
         let offset = polled_messages.messages.last().unwrap().offset;
         if args.auto_commit {
             trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
             topic.store_consumer_offset(consumer, offset).await?;
         }
 
-        if self.encryptor.is_none() {
+        let encryptor = self.get_stream_encryptor(stream)?;
+        if encryptor.is_none() {
             return Ok(polled_messages);
         }
 
-        let encryptor = self.encryptor.as_ref().unwrap();
+        let encryptor = encryptor.unwrap();
         let mut decrypted_messages = Vec::with_capacity(polled_messages.messages.len());
         for message in polled_messages.messages.iter() {
+            // The control records, e.g. transaction markers, are appended by the server and never encrypted.
+            if message.state.is_control() {
+                decrypted_messages.push(message.clone());
+                continue;
+            }
+
             let payload = encryptor.decrypt(&message.payload);
             match payload {
                 Ok(payload) => {
@@ -100,25 +135,35 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitioning: &Partitioning,
+        producer: Option<&ProducerBatch>,
+        ack: AckLevel,
         messages: &Vec<send_messages::Message>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner.append_messages(
-            session.get_user_id(),
+            session.get_principal_id(),
             stream.stream_id,
             topic.topic_id,
         )?;
+        let produced_size_bytes = messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum();
+        self.quota_manager
+            .check_produce(session, produced_size_bytes)?;
+        stream.ensure_messages_can_be_appended(produced_size_bytes)?;
 
+        let encryptor = self.get_stream_encryptor(stream)?;
         let mut received_messages = Vec::with_capacity(messages.len());
         let mut batch_size_bytes = 0u64;
 
         // For large batches it would be better to use par_iter() from rayon.
         for message in messages {
             let encrypted_message;
-            let message = match self.encryptor {
-                Some(ref encryptor) => {
+            let message = match encryptor {
+                Some(encryptor) => {
                     let payload = encryptor.encrypt(message.payload.as_ref())?;
                     encrypted_message = send_messages::Message {
                         id: message.id,
@@ -141,12 +186,50 @@ impl System {
                 self.clean_cache(batch_size_bytes).await;
             }
         }
-        topic
-            .append_messages(partitioning, received_messages)
+        let stored_size_bytes = received_messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum();
+        let transaction_id = self.get_transaction_id(session).await?;
+        let partition_id = topic
+            .append_messages(partitioning, producer, transaction_id, received_messages)
             .await?;
+        if partition_id.is_none() {
+            return Ok(());
+        }
+
+        let partition_id = partition_id.unwrap();
+        stream.increase_size_bytes(stored_size_bytes);
+        if transaction_id.is_some() {
+            self.add_transaction_partition(session, stream.stream_id, topic.topic_id, partition_id)
+                .await?;
+        }
+        if ack == AckLevel::Fsync {
+            topic.sync_messages(partition_id).await?;
+        }
         self.metrics.increment_messages(messages.len() as u64);
+        self.metrics.record_appended_messages(
+            &PartitionLabels {
+                stream_id: stream.stream_id,
+                topic_id: topic.topic_id,
+                partition_id,
+            },
+            messages.len() as u64,
+            produced_size_bytes,
+        );
         Ok(())
     }
+
+    pub async fn init_producer(&self, session: &Session) -> Result<u64, IggyError> {
+        self.ensure_authenticated(session)?;
+        let producer_id = self.storage.info.generate_producer_id().await?;
+        trace!(
+            "Initialized producer with ID: {} for session: {}",
+            producer_id,
+            session
+        );
+        Ok(producer_id)
+    }
 }
 
 #[derive(Debug)]
@@ -154,14 +237,21 @@ pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
     pub auto_commit: bool,
+    pub read_committed: bool,
 }
 
 impl PollingArgs {
-    pub fn new(strategy: PollingStrategy, count: u32, auto_commit: bool) -> Self {
+    pub fn new(
+        strategy: PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        read_committed: bool,
+    ) -> Self {
         Self {
             strategy,
             count,
             auto_commit,
+            read_committed,
         }
     }
 }
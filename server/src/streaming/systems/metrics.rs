@@ -0,0 +1,53 @@
+use crate::streaming::diagnostics::metrics::{ConsumerLabels, PartitionLabels};
+use crate::streaming::systems::system::System;
+
+impl System {
+    /// Updates the metrics derived from the current state of the partitions
+    /// (segments, cache hit ratio and consumer lag) and returns all the metrics in the Prometheus text format.
+    pub async fn get_formatted_metrics(&self) -> String {
+        self.metrics.clear_partition_state();
+        let mut segments_count = 0;
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.get_partitions() {
+                    let partition = partition.read().await;
+                    let labels = PartitionLabels {
+                        stream_id: partition.stream_id,
+                        topic_id: partition.topic_id,
+                        partition_id: partition.partition_id,
+                    };
+                    let partition_segments_count = partition.get_segments_count();
+                    segments_count += partition_segments_count;
+                    self.metrics
+                        .set_partition_segments(&labels, partition_segments_count);
+                    if let Some(ratio) =
+                        partition.cache.as_ref().and_then(|cache| cache.hit_ratio())
+                    {
+                        self.metrics.set_cache_hit_ratio(&labels, ratio);
+                    }
+
+                    for consumer_offset in partition
+                        .consumer_offsets
+                        .iter()
+                        .chain(partition.consumer_group_offsets.iter())
+                    {
+                        let consumer_labels = ConsumerLabels {
+                            stream_id: partition.stream_id,
+                            topic_id: partition.topic_id,
+                            partition_id: partition.partition_id,
+                            kind: consumer_offset.kind.to_string(),
+                            consumer_id: consumer_offset.consumer_id,
+                        };
+                        let lag = partition
+                            .current_offset
+                            .saturating_sub(consumer_offset.offset);
+                        self.metrics.set_consumer_lag(&consumer_labels, lag);
+                    }
+                }
+            }
+        }
+
+        self.metrics.set_segments(segments_count);
+        self.metrics.get_formatted_output()
+    }
+}
@@ -1,13 +1,22 @@
+pub mod audit;
 pub mod clients;
+pub mod config;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod encryption;
+pub mod health;
 pub mod info;
+pub mod logging;
 pub mod messages;
+pub mod metrics;
+pub mod oidc;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod roles;
 pub mod stats;
 pub mod storage;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
@@ -0,0 +1,134 @@
+use crate::streaming::audit::audit_action::AuditAction;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::oidc::{OidcIdentity, OidcValidator};
+use crate::streaming::users::user::User;
+use iggy::error::IggyError;
+use iggy::utils::text;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+impl System {
+    pub(crate) async fn load_oidc_keys(&self) {
+        if self.oidc.is_none() {
+            return;
+        }
+
+        // The identity provider might be temporarily unavailable, thus the keys will be loaded again on the next login.
+        if let Err(error) = self.oidc.as_ref().unwrap().load_keys().await {
+            error!("Cannot load the OIDC keys, error: {error}");
+        }
+    }
+
+    pub fn get_oidc_validator(&self) -> Result<Arc<OidcValidator>, IggyError> {
+        match &self.oidc {
+            Some(validator) => Ok(validator.clone()),
+            None => {
+                error!("OIDC is disabled.");
+                Err(IggyError::FeatureUnavailable)
+            }
+        }
+    }
+
+    /// Maps the identity from the externally issued JWT to the existing and active user.
+    /// When the roles claim is configured, also returns the IDs of the roles from the claim,
+    /// but only if they differ from the currently assigned ones.
+    pub async fn authenticate_with_oidc_identity(
+        &self,
+        identity: &OidcIdentity,
+    ) -> Result<(User, Option<Vec<u32>>), IggyError> {
+        let username = &identity.username;
+        let user = self.storage.user.load_by_username(username).await;
+        if user.is_err() {
+            warn!("Cannot authenticate OIDC user: {username} (not found).");
+            return Err(IggyError::InvalidCredentials);
+        }
+
+        let user = user.unwrap();
+        if !user.is_active() {
+            warn!("OIDC user: {username} with ID: {} is inactive.", user.id);
+            return Err(IggyError::UserInactive);
+        }
+
+        if identity.roles.is_none() {
+            return Ok((user, None));
+        }
+
+        let mut role_ids = Vec::new();
+        for role in identity.roles.as_ref().unwrap() {
+            let role = text::to_lowercase_non_whitespace(role);
+            match self.storage.role.load_by_name(&role).await {
+                Ok(role) => {
+                    if !role_ids.contains(&role.id) {
+                        role_ids.push(role.id);
+                    }
+                }
+                Err(_) => warn!("OIDC role: {role} of user: {username} was not found."),
+            }
+        }
+
+        let mut current_role_ids = user.roles.clone();
+        current_role_ids.sort_unstable();
+        role_ids.sort_unstable();
+        if current_role_ids == role_ids {
+            return Ok((user, None));
+        }
+
+        Ok((user, Some(role_ids)))
+    }
+
+    pub async fn sync_user_roles_with_oidc_identity(
+        &mut self,
+        session: &Session,
+        mut user: User,
+        role_ids: Vec<u32>,
+    ) -> Result<(), IggyError> {
+        let resource = format!("user ID: {}, username: {}", user.id, user.username);
+        let username = user.username.clone();
+        info!(
+            "Synchronizing roles for OIDC user: {username} with ID: {}...",
+            user.id
+        );
+        user.roles = role_ids;
+        self.storage.user.save(&user).await?;
+        self.permissioner.update_permissions_for_user(user);
+        info!("Synchronized roles for OIDC user: {username}.");
+        self.audit(session, AuditAction::UpdateUserRoles, resource)
+            .await;
+        Ok(())
+    }
+
+    pub async fn login_with_oidc_identity(
+        &mut self,
+        identity: &OidcIdentity,
+        session: &Session,
+    ) -> Result<User, IggyError> {
+        let result = self.authenticate_with_oidc_identity(identity).await;
+        if let Err(error) = result {
+            self.audit_login_failure(
+                Some(session),
+                AuditAction::LoginWithToken,
+                format!("username: {}", identity.username),
+            )
+            .await;
+            return Err(error);
+        }
+
+        let (authenticated_user, role_ids) = result.unwrap();
+        let mut user = self
+            .login_user_with_action(
+                &identity.username,
+                None,
+                AuditAction::LoginWithToken,
+                Some(session),
+            )
+            .await?;
+        if let Some(role_ids) = role_ids {
+            self.sync_user_roles_with_oidc_identity(session, authenticated_user, role_ids.clone())
+                .await?;
+            user.roles = role_ids;
+        }
+
+        Ok(user)
+    }
+}
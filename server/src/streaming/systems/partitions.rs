@@ -1,3 +1,4 @@
+use crate::streaming::audit::audit_action::AuditAction;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use iggy::error::IggyError;
@@ -12,14 +13,19 @@ impl System {
         partitions_count: u32,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let stream_id_value;
+        let topic_id_value;
         {
             let stream = self.get_stream(stream_id)?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.create_partitons(
-                session.get_user_id(),
+                session.get_principal_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
+            stream.ensure_partitions_can_be_created(partitions_count)?;
+            stream_id_value = stream.stream_id;
+            topic_id_value = topic.topic_id;
         }
 
         let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
@@ -27,6 +33,15 @@ impl System {
         topic.reassign_consumer_groups().await;
         self.metrics.increment_partitions(partitions_count);
         self.metrics.increment_segments(partitions_count);
+        self.audit(
+            session,
+            AuditAction::CreatePartitions,
+            format!(
+                "stream ID: {}, topic ID: {}, partitions: {}",
+                stream_id_value, topic_id_value, partitions_count
+            ),
+        )
+        .await;
         Ok(())
     }
 
@@ -38,24 +53,39 @@ impl System {
         partitions_count: u32,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let stream_id_value;
+        let topic_id_value;
         {
             let stream = self.get_stream(stream_id)?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.delete_partitions(
-                session.get_user_id(),
+                session.get_principal_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
+            stream_id_value = stream.stream_id;
+            topic_id_value = topic.topic_id;
         }
 
-        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        let stream = self.get_stream_mut(stream_id)?;
+        let topic = stream.get_topic_mut(topic_id)?;
         let partitions = topic.delete_persisted_partitions(partitions_count).await?;
         topic.reassign_consumer_groups().await;
+        stream.refresh_size_bytes().await;
         if let Some(partitions) = partitions {
             self.metrics.decrement_partitions(partitions_count);
             self.metrics.decrement_segments(partitions.segments_count);
             self.metrics.decrement_messages(partitions.messages_count);
         }
+        self.audit(
+            session,
+            AuditAction::DeletePartitions,
+            format!(
+                "stream ID: {}, topic ID: {}, partitions: {}",
+                stream_id_value, topic_id_value, partitions_count
+            ),
+        )
+        .await;
         Ok(())
     }
 }
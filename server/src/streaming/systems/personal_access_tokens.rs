@@ -1,13 +1,33 @@
+use crate::streaming::audit::audit_action::AuditAction;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::users::user::User;
 use iggy::error::IggyError;
+use iggy::models::permissions::Permissions;
 use iggy::utils::text;
 use iggy::utils::timestamp::IggyTimestamp;
 use tracing::{error, info};
 
 impl System {
+    pub(crate) async fn load_personal_access_tokens(&mut self) -> Result<(), IggyError> {
+        info!("Loading personal access tokens scopes...");
+        let personal_access_tokens = self.storage.personal_access_token.load_all().await?;
+        let mut scopes_count = 0;
+        for personal_access_token in personal_access_tokens {
+            if let Some(scope) = personal_access_token.scope {
+                self.permissioner.init_personal_access_token_scope(
+                    personal_access_token.user_id,
+                    &personal_access_token.name,
+                    scope,
+                );
+                scopes_count += 1;
+            }
+        }
+        info!("Initialized {scopes_count} personal access token scope(s).");
+        Ok(())
+    }
+
     pub async fn get_personal_access_tokens(
         &self,
         session: &Session,
@@ -28,12 +48,14 @@ impl System {
     }
 
     pub async fn create_personal_access_token(
-        &self,
+        &mut self,
         session: &Session,
         name: &str,
         expiry: Option<u32>,
+        scope: Option<Permissions>,
     ) -> Result<String, IggyError> {
         self.ensure_authenticated(session)?;
+        self.ensure_not_scoped(session)?;
         let user_id = session.get_user_id();
         let max_token_per_user = self.personal_access_token.max_tokens_per_user;
         let name = text::to_lowercase_non_whitespace(name);
@@ -62,22 +84,38 @@ impl System {
         }
 
         info!("Creating personal access token: {name} for user with ID: {user_id}...");
-        let (personal_access_token, token) =
-            PersonalAccessToken::new(user_id, &name, IggyTimestamp::now().to_micros(), expiry);
+        let (personal_access_token, token) = PersonalAccessToken::new(
+            user_id,
+            &name,
+            IggyTimestamp::now().to_micros(),
+            expiry,
+            scope,
+        );
         self.storage
             .personal_access_token
             .save(&personal_access_token)
             .await?;
+        if let Some(scope) = personal_access_token.scope {
+            self.permissioner
+                .init_personal_access_token_scope(user_id, &name, scope);
+        }
         info!("Created personal access token: {name} for user with ID: {user_id}.");
+        self.audit(
+            session,
+            AuditAction::CreatePersonalAccessToken,
+            format!("user ID: {user_id}, token name: {name}"),
+        )
+        .await;
         Ok(token)
     }
 
     pub async fn delete_personal_access_token(
-        &self,
+        &mut self,
         session: &Session,
         name: &str,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        self.ensure_not_scoped(session)?;
         let user_id = session.get_user_id();
         let name = text::to_lowercase_non_whitespace(name);
         info!("Deleting personal access token: {name} for user with ID: {user_id}...");
@@ -85,7 +123,15 @@ impl System {
             .personal_access_token
             .delete_for_user(user_id, &name)
             .await?;
+        self.permissioner
+            .delete_personal_access_token_scope(user_id, &name);
         info!("Deleted personal access token: {name} for user with ID: {user_id}.");
+        self.audit(
+            session,
+            AuditAction::DeletePersonalAccessToken,
+            format!("user ID: {user_id}, token name: {name}"),
+        )
+        .await;
         Ok(())
     }
 
@@ -93,18 +139,39 @@ impl System {
         &self,
         token: &str,
         session: Option<&Session>,
-    ) -> Result<User, IggyError> {
+    ) -> Result<(User, PersonalAccessToken), IggyError> {
         let token_hash = PersonalAccessToken::hash_token(token);
-        let personal_access_token = self
+        let personal_access_token = match self
             .storage
             .personal_access_token
             .load_by_token(&token_hash)
-            .await?;
+            .await
+        {
+            Ok(personal_access_token) => personal_access_token,
+            Err(error) => {
+                self.audit_login_failure(
+                    session,
+                    AuditAction::LoginWithPersonalAccessToken,
+                    "unknown personal access token".to_string(),
+                )
+                .await;
+                return Err(error);
+            }
+        };
         if personal_access_token.is_expired(IggyTimestamp::now().to_micros()) {
             error!(
                 "Personal access token: {} for user with ID: {} has expired.",
                 personal_access_token.name, personal_access_token.user_id
             );
+            self.audit_login_failure(
+                session,
+                AuditAction::LoginWithPersonalAccessToken,
+                format!(
+                    "user ID: {}, expired token name: {}",
+                    personal_access_token.user_id, personal_access_token.name
+                ),
+            )
+            .await;
             return Err(IggyError::PersonalAccessTokenExpired(
                 personal_access_token.name,
                 personal_access_token.user_id,
@@ -116,7 +183,27 @@ impl System {
             .user
             .load_by_id(personal_access_token.user_id)
             .await?;
-        self.login_user_with_credentials(&user.username, None, session)
-            .await
+        let user = self
+            .login_user_with_credentials(&user.username, None, session)
+            .await?;
+        if let Some(session) = session {
+            if let Some(scope_id) = self.permissioner.get_personal_access_token_scope(
+                personal_access_token.user_id,
+                &personal_access_token.name,
+            ) {
+                session.set_scope_id(scope_id);
+            }
+        }
+        Ok((user, personal_access_token))
+    }
+
+    /// Personal access tokens cannot be managed with the scoped sessions, as otherwise the scope could be escaped.
+    fn ensure_not_scoped(&self, session: &Session) -> Result<(), IggyError> {
+        if session.is_scoped() {
+            error!("{session} is restricted by the personal access token scope.");
+            return Err(IggyError::Unauthorized);
+        }
+
+        Ok(())
     }
 }
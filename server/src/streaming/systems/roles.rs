@@ -0,0 +1,181 @@
+use crate::streaming::audit::audit_action::AuditAction;
+use crate::streaming::roles::role::Role;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::models::permissions::Permissions;
+use iggy::utils::text;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tracing::{error, info};
+
+static ROLE_ID: AtomicU32 = AtomicU32::new(1);
+
+impl System {
+    pub(crate) async fn load_roles(&mut self) -> Result<(), IggyError> {
+        info!("Loading roles...");
+        let roles = self.storage.role.load_all().await?;
+        let roles_count = roles.len();
+        let current_role_id = roles.iter().map(|role| role.id).max().unwrap_or(0);
+        ROLE_ID.store(current_role_id + 1, Ordering::SeqCst);
+        self.permissioner.init_roles(roles);
+        info!("Initialized {} role(s).", roles_count);
+        Ok(())
+    }
+
+    pub async fn find_role(
+        &self,
+        session: &Session,
+        role_id: &Identifier,
+    ) -> Result<Role, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_role(session.get_principal_id())?;
+        self.get_role(role_id).await
+    }
+
+    pub async fn get_role(&self, role_id: &Identifier) -> Result<Role, IggyError> {
+        Ok(match role_id.kind {
+            IdKind::Numeric => {
+                self.storage
+                    .role
+                    .load_by_id(role_id.get_u32_value()?)
+                    .await?
+            }
+            IdKind::String => {
+                self.storage
+                    .role
+                    .load_by_name(&role_id.get_string_value()?)
+                    .await?
+            }
+        })
+    }
+
+    pub async fn get_roles(&self, session: &Session) -> Result<Vec<Role>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_roles(session.get_principal_id())?;
+        self.storage.role.load_all().await
+    }
+
+    pub async fn create_role(
+        &mut self,
+        session: &Session,
+        name: &str,
+        permissions: Permissions,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.create_role(session.get_principal_id())?;
+        let name = text::to_lowercase_non_whitespace(name);
+        if self.storage.role.load_by_name(&name).await.is_ok() {
+            error!("Role: {name} already exists.");
+            return Err(IggyError::RoleAlreadyExists(name));
+        }
+
+        let role_id = ROLE_ID.fetch_add(1, Ordering::SeqCst);
+        info!("Creating role: {name} with ID: {role_id}...");
+        let role = Role::new(role_id, &name, permissions);
+        self.storage.role.save(&role).await?;
+        self.permissioner.update_permissions_for_role(&role);
+        info!("Created role: {name} with ID: {role_id}.");
+        self.audit(
+            session,
+            AuditAction::CreateRole,
+            format!("role ID: {role_id}, name: {name}"),
+        )
+        .await;
+        Ok(())
+    }
+
+    pub async fn update_role(
+        &mut self,
+        session: &Session,
+        role_id: &Identifier,
+        name: Option<String>,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.update_role(session.get_principal_id())?;
+        let mut role = self.get_role(role_id).await?;
+        let previous_name = role.name.clone();
+        if let Some(name) = name {
+            let name = text::to_lowercase_non_whitespace(&name);
+            let existing_role = self.storage.role.load_by_name(&name).await;
+            if existing_role.is_ok() && existing_role.unwrap().id != role.id {
+                error!("Role: {name} already exists.");
+                return Err(IggyError::RoleAlreadyExists(name));
+            }
+            role.name = name;
+        }
+
+        let permissions_changed = permissions.is_some();
+        if let Some(permissions) = permissions {
+            role.permissions = permissions;
+        }
+
+        info!("Updating role: {} with ID: {}...", role.name, role.id);
+        self.storage.role.save(&role).await?;
+        // The previous name is removed only once the updated role has been saved, so that it's never lost.
+        if role.name != previous_name {
+            self.storage.role.delete_name(&previous_name).await?;
+        }
+        if permissions_changed {
+            self.permissioner.update_permissions_for_role(&role);
+            self.update_permissions_for_users_with_role(role.id).await?;
+        }
+        info!("Updated role: {} with ID: {}.", role.name, role.id);
+        self.audit(
+            session,
+            AuditAction::UpdateRole,
+            format!("role ID: {}, name: {}", role.id, role.name),
+        )
+        .await;
+        Ok(())
+    }
+
+    pub async fn delete_role(
+        &mut self,
+        session: &Session,
+        role_id: &Identifier,
+    ) -> Result<Role, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.delete_role(session.get_principal_id())?;
+        let role = self.get_role(role_id).await?;
+        info!("Deleting role: {} with ID: {}...", role.name, role.id);
+        self.storage.role.delete(&role).await?;
+        self.permissioner.delete_permissions_for_role(role.id);
+        for mut user in self.storage.user.load_all().await? {
+            if !user.roles.contains(&role.id) {
+                continue;
+            }
+
+            user.roles.retain(|id| *id != role.id);
+            self.storage.user.save(&user).await?;
+            info!(
+                "Unassigned role: {} from user: {} with ID: {}.",
+                role.name, user.username, user.id
+            );
+            self.permissioner.update_permissions_for_user(user);
+        }
+        info!("Deleted role: {} with ID: {}.", role.name, role.id);
+        self.audit(
+            session,
+            AuditAction::DeleteRole,
+            format!("role ID: {}, name: {}", role.id, role.name),
+        )
+        .await;
+        Ok(role)
+    }
+
+    /// Recalculates the effective permissions of the users having the role.
+    async fn update_permissions_for_users_with_role(
+        &mut self,
+        role_id: u32,
+    ) -> Result<(), IggyError> {
+        for user in self.storage.user.load_all().await? {
+            if user.roles.contains(&role_id) {
+                self.permissioner.update_permissions_for_user(user);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -23,7 +23,21 @@ impl FileSystemInfoStorage {
 unsafe impl Send for FileSystemInfoStorage {}
 unsafe impl Sync for FileSystemInfoStorage {}
 
-impl SystemInfoStorage for FileSystemInfoStorage {}
+#[async_trait]
+impl SystemInfoStorage for FileSystemInfoStorage {
+    async fn generate_producer_id(&self) -> Result<u64, IggyError> {
+        // The generated IDs are monotonic and remain unique after the restart (also the unclean one),
+        // while the producer ID equal to 0 is reserved for the non-idempotent producers.
+        match self
+            .db
+            .generate_id()
+            .with_context(|| "Failed to generate producer ID")
+        {
+            Ok(id) => Ok(id + 1),
+            Err(err) => Err(IggyError::CannotSaveResource(err)),
+        }
+    }
+}
 
 #[async_trait]
 impl Storage<SystemInfo> for FileSystemInfoStorage {
@@ -1,9 +1,11 @@
+use crate::streaming::audit::audit_action::AuditAction;
 use crate::streaming::session::Session;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::system::System;
 use futures::future::try_join_all;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
+use iggy::models::stream_quotas::StreamQuotas;
 use iggy::utils::text;
 use std::cell::RefCell;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -47,7 +49,14 @@ impl System {
         });
         try_join_all(load_stream_tasks).await?;
 
-        for stream in loaded_streams.take() {
+        for mut stream in loaded_streams.take() {
+            if let Err(error) = self.unwrap_stream_encryption_key(&mut stream) {
+                error!(
+                    "Cannot unwrap encryption key for stream with ID: {}. Error: {}",
+                    stream.stream_id, error
+                );
+            }
+
             if self.streams.contains_key(&stream.stream_id) {
                 error!("Stream with ID: '{}' already exists.", &stream.stream_id);
                 continue;
@@ -66,7 +75,14 @@ impl System {
                 .increment_segments(stream.get_segments_count().await);
             self.metrics
                 .increment_messages(stream.get_messages_count().await);
+            stream.refresh_size_bytes().await;
 
+            self.permissioner
+                .register_stream(stream.stream_id, &stream.name);
+            for topic in stream.get_topics() {
+                self.permissioner
+                    .register_topic(stream.stream_id, topic.topic_id, &topic.name);
+            }
             self.streams_ids
                 .insert(stream.name.clone(), stream.stream_id);
             self.streams.insert(stream.stream_id, stream);
@@ -82,7 +98,7 @@ impl System {
 
     pub fn find_streams(&self, session: &Session) -> Result<Vec<&Stream>, IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.get_streams(session.get_user_id())?;
+        self.permissioner.get_streams(session.get_principal_id())?;
         Ok(self.get_streams())
     }
 
@@ -94,7 +110,7 @@ impl System {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(identifier)?;
         self.permissioner
-            .get_stream(session.get_user_id(), stream.stream_id)?;
+            .get_stream(session.get_principal_id(), stream.stream_id)?;
         Ok(stream)
     }
 
@@ -158,9 +174,11 @@ impl System {
         session: &Session,
         stream_id: Option<u32>,
         name: &str,
+        quotas: Option<StreamQuotas>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.create_stream(session.get_user_id())?;
+        self.permissioner
+            .create_stream(session.get_principal_id())?;
         let name = text::to_lowercase_non_whitespace(name);
         if self.streams_ids.contains_key(&name) {
             return Err(IggyError::StreamNameAlreadyExists(name.to_string()));
@@ -187,12 +205,18 @@ impl System {
             return Err(IggyError::StreamIdAlreadyExists(id));
         }
 
-        let stream = Stream::create(id, &name, self.config.clone(), self.storage.clone());
+        let mut stream = Stream::create(id, &name, self.config.clone(), self.storage.clone());
+        stream.quotas = quotas.filter(|quotas| !quotas.is_unlimited());
+        self.create_stream_encryption_key(&mut stream)?;
         stream.persist().await?;
         info!("Created stream with ID: {id}, name: '{name}'.");
+        let resource = format!("stream ID: {id}, name: '{name}'");
+        self.permissioner.register_stream(stream.stream_id, &name);
         self.streams_ids.insert(name, stream.stream_id);
         self.streams.insert(stream.stream_id, stream);
         self.metrics.increment_streams(1);
+        self.audit(session, AuditAction::CreateStream, resource)
+            .await;
         Ok(())
     }
 
@@ -201,6 +225,7 @@ impl System {
         session: &Session,
         id: &Identifier,
         name: &str,
+        quotas: Option<StreamQuotas>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let stream_id;
@@ -210,7 +235,7 @@ impl System {
         }
 
         self.permissioner
-            .update_stream(session.get_user_id(), stream_id)?;
+            .update_stream(session.get_principal_id(), stream_id)?;
         let updated_name = text::to_lowercase_non_whitespace(name);
 
         {
@@ -226,12 +251,16 @@ impl System {
             let stream = self.get_stream_mut(id)?;
             old_name = stream.name.clone();
             stream.name = updated_name.clone();
+            if let Some(quotas) = quotas {
+                stream.quotas = Some(quotas).filter(|quotas| !quotas.is_unlimited());
+            }
             stream.persist().await?;
         }
 
         {
             self.streams_ids.remove(&old_name);
             self.streams_ids.insert(updated_name.clone(), stream_id);
+            self.permissioner.register_stream(stream_id, &updated_name);
         }
 
         info!(
@@ -250,23 +279,32 @@ impl System {
         let stream = self.get_stream(id)?;
         let stream_id = stream.stream_id;
         self.permissioner
-            .delete_stream(session.get_user_id(), stream_id)?;
+            .delete_stream(session.get_principal_id(), stream_id)?;
         let stream_name = stream.name.clone();
+        let topics_count = stream.get_topics_count();
+        let partitions_count = stream.get_partitions_count();
+        let messages_count = stream.get_messages_count().await;
+        let segments_count = stream.get_segments_count().await;
+        let stream = self.get_stream_mut(id)?;
         if stream.delete().await.is_err() {
             return Err(IggyError::CannotDeleteStream(stream_id));
         }
 
         self.metrics.decrement_streams(1);
-        self.metrics.decrement_topics(stream.get_topics_count());
-        self.metrics
-            .decrement_partitions(stream.get_partitions_count());
-        self.metrics
-            .decrement_messages(stream.get_messages_count().await);
-        self.metrics
-            .decrement_segments(stream.get_segments_count().await);
+        self.metrics.decrement_topics(topics_count);
+        self.metrics.decrement_partitions(partitions_count);
+        self.metrics.decrement_messages(messages_count);
+        self.metrics.decrement_segments(segments_count);
 
         self.streams.remove(&stream_id);
         self.streams_ids.remove(&stream_name);
+        self.permissioner.unregister_stream(stream_id);
+        self.audit(
+            session,
+            AuditAction::DeleteStream,
+            format!("stream ID: {stream_id}, name: '{stream_name}'"),
+        )
+        .await;
         let client_manager = self.client_manager.read().await;
         client_manager
             .delete_consumer_groups_for_stream(stream_id)
@@ -281,8 +319,10 @@ impl System {
     ) -> Result<(), IggyError> {
         let stream = self.get_stream(stream_id)?;
         self.permissioner
-            .purge_stream(session.get_user_id(), stream.stream_id)?;
-        stream.purge().await
+            .purge_stream(session.get_principal_id(), stream.stream_id)?;
+        let result = stream.purge().await;
+        stream.refresh_size_bytes().await;
+        result
     }
 }
 
@@ -314,7 +354,7 @@ mod tests {
         );
         system.permissioner.init_permissions_for_user(root);
         system
-            .create_stream(&session, Some(stream_id), stream_name)
+            .create_stream(&session, Some(stream_id), stream_name, None)
             .await
             .unwrap();
 
@@ -1,23 +1,33 @@
+use crate::configs::reloader::ConfigReloader;
 use crate::configs::server::PersonalAccessTokenConfig;
 use crate::configs::system::SystemConfig;
+use crate::logging::LogLevelUpdater;
+use crate::streaming::audit::audit_log::AuditLog;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::clients::client_manager::ClientManager;
+use crate::streaming::diagnostics::background_tasks::BackgroundTasks;
 use crate::streaming::diagnostics::metrics::Metrics;
 use crate::streaming::persistence::persister::*;
+use crate::streaming::quotas::quota_manager::QuotaManager;
 use crate::streaming::session::Session;
+use crate::streaming::shutdown::ShutdownSignal;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::users::login_backoff::LoginBackoff;
+use crate::streaming::users::oidc::OidcValidator;
 use crate::streaming::users::permissioner::Permissioner;
+use dashmap::DashMap;
 use iggy::error::IggyError;
-use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use iggy::models::user_info::UserId;
+use iggy::utils::crypto::KeyRingEncryptor;
 use sled::Db;
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs::{create_dir, remove_dir_all};
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use tokio::time::Instant;
-use tracing::{info, trace};
+use tracing::{error, info, trace};
 
 use keepcalm::{SharedMut, SharedReadLock, SharedWriteLock};
 
@@ -58,9 +68,19 @@ pub struct System {
     pub(crate) streams_ids: HashMap<String, u32>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) client_manager: Arc<RwLock<ClientManager>>,
-    pub(crate) encryptor: Option<Box<dyn Encryptor>>,
+    pub(crate) encryptor: Option<Arc<KeyRingEncryptor>>,
     pub(crate) metrics: Metrics,
+    pub(crate) background_tasks: BackgroundTasks,
+    pub(crate) shutdown_signal: ShutdownSignal,
+    pub(crate) quota_manager: QuotaManager,
+    pub(crate) audit_log: AuditLog,
     pub(crate) db: Option<Arc<Db>>,
+    pub(crate) oidc: Option<Arc<OidcValidator>>,
+    pub(crate) login_backoff: LoginBackoff,
+    /// Serializes the updates of the user's failed login attempts, which are loaded, changed and saved again.
+    pub(crate) user_login_locks: DashMap<UserId, Arc<Mutex<()>>>,
+    pub(crate) config_reloader: Option<Arc<ConfigReloader>>,
+    pub(crate) log_level_updater: Option<LogLevelUpdater>,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
@@ -84,15 +104,18 @@ impl System {
                 Arc::new(db.unwrap())
             }
         };
+        let metrics = Metrics::init();
+        let fsync_duration = metrics.get_fsync_duration();
         let persister: Arc<dyn Persister> = match config.partition.enforce_fsync {
-            true => Arc::new(FileWithSyncPersister {}),
-            false => Arc::new(FilePersister {}),
+            true => Arc::new(FileWithSyncPersister::new(fsync_duration)),
+            false => Arc::new(FilePersister::new(fsync_duration)),
         };
-        Self::create(
+        Self::create_with_metrics(
             config,
             SystemStorage::new(db.clone(), persister),
             Some(db),
             pat_config,
+            metrics,
         )
     }
 
@@ -101,6 +124,16 @@ impl System {
         storage: SystemStorage,
         db: Option<Arc<Db>>,
         pat_config: PersonalAccessTokenConfig,
+    ) -> System {
+        Self::create_with_metrics(config, storage, db, pat_config, Metrics::init())
+    }
+
+    fn create_with_metrics(
+        config: Arc<SystemConfig>,
+        storage: SystemStorage,
+        db: Option<Arc<Db>>,
+        pat_config: PersonalAccessTokenConfig,
+        metrics: Metrics,
     ) -> System {
         info!(
             "Server-side encryption is {}.",
@@ -108,18 +141,28 @@ impl System {
         );
         System {
             encryptor: match config.encryption.enabled {
-                true => Some(Box::new(
-                    Aes256GcmEncryptor::from_base64_key(&config.encryption.key).unwrap(),
-                )),
+                true => Some(Arc::new(config.encryption.create_encryptor().unwrap())),
+                false => None,
+            },
+            quota_manager: QuotaManager::new(&config.quotas),
+            audit_log: AuditLog::new(&config),
+            oidc: match config.oidc.enabled {
+                true => Some(Arc::new(OidcValidator::new(&config.oidc))),
                 false => None,
             },
+            login_backoff: LoginBackoff::new(&config.login_lockout),
+            user_login_locks: DashMap::new(),
+            config_reloader: None,
+            log_level_updater: None,
             config,
             streams: HashMap::new(),
             streams_ids: HashMap::new(),
             storage: Arc::new(storage),
             client_manager: Arc::new(RwLock::new(ClientManager::default())),
             permissioner: Permissioner::default(),
-            metrics: Metrics::init(),
+            metrics,
+            background_tasks: BackgroundTasks::default(),
+            shutdown_signal: ShutdownSignal::default(),
             db,
             personal_access_token: pat_config,
         }
@@ -151,16 +194,51 @@ impl System {
             self.config.get_system_path()
         );
         let now = Instant::now();
+        self.audit_log.init().await?;
         self.load_version().await?;
+        self.load_roles().await?;
         self.load_users().await?;
+        self.load_personal_access_tokens().await?;
+        self.load_oidc_keys().await;
         self.load_streams().await?;
         info!("Initialized system in {} ms.", now.elapsed().as_millis());
         Ok(())
     }
 
-    pub async fn shutdown(&mut self) -> Result<(), IggyError> {
-        self.persist_messages().await?;
-        Ok(())
+    pub fn get_shutdown_signal(&self) -> ShutdownSignal {
+        self.shutdown_signal.clone()
+    }
+
+    /// Persists and synchronizes the buffered messages of all the partitions, and flushes the database,
+    /// so that the consumer offsets and the other metadata are durably stored.
+    /// Every step is attempted regardless of the failures of the previous ones, which are reported at the end.
+    pub async fn shutdown(&self) -> Result<(), IggyError> {
+        info!("Saving and synchronizing the buffered messages on disk...");
+        let mut errors = Vec::new();
+        for stream in self.streams.values() {
+            if let Err(error) = stream.sync_messages().await {
+                error!(
+                    "Failed to synchronize the messages of stream with ID: {}. Error: {error}",
+                    stream.stream_id
+                );
+                errors.push(error);
+            }
+        }
+
+        if let Some(db) = &self.db {
+            info!("Flushing the database...");
+            if let Err(error) = db.flush_async().await {
+                error!("Failed to flush the database. Error: {error}");
+                errors.push(IggyError::CannotSaveResource(error.into()));
+            }
+        }
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        error!("Shutdown has completed with {} error(s).", errors.len());
+        Err(errors.remove(0))
     }
 
     pub async fn persist_messages(&self) -> Result<(), IggyError> {
@@ -1,9 +1,11 @@
+use crate::streaming::audit::audit_action::AuditAction;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::text;
 
 impl System {
     pub fn find_topic(
@@ -15,8 +17,11 @@ impl System {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
-        self.permissioner
-            .get_topic(session.get_user_id(), stream.stream_id, topic.topic_id)?;
+        self.permissioner.get_topic(
+            session.get_principal_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
         Ok(topic)
     }
 
@@ -28,7 +33,7 @@ impl System {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(stream_id)?;
         self.permissioner
-            .get_topics(session.get_user_id(), stream.stream_id)?;
+            .get_topics(session.get_principal_id(), stream.stream_id)?;
         Ok(stream.get_topics())
     }
 
@@ -45,13 +50,17 @@ impl System {
         replication_factor: u8,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let stream_id_value;
         {
             let stream = self.get_stream(stream_id)?;
             self.permissioner
-                .create_topic(session.get_user_id(), stream.stream_id)?;
+                .create_topic(session.get_principal_id(), stream.stream_id)?;
+            stream.ensure_topic_can_be_created(partitions_count)?;
+            stream_id_value = stream.stream_id;
         }
 
-        self.get_stream_mut(stream_id)?
+        let stream = self.get_stream_mut(stream_id)?;
+        stream
             .create_topic(
                 topic_id,
                 name,
@@ -61,9 +70,22 @@ impl System {
                 replication_factor,
             )
             .await?;
+        let topic_name = text::to_lowercase_non_whitespace(name);
+        let topic_id_value = stream.get_topic(&Identifier::named(&topic_name)?)?.topic_id;
+        self.permissioner
+            .register_topic(stream_id_value, topic_id_value, &topic_name);
         self.metrics.increment_topics(1);
         self.metrics.increment_partitions(partitions_count);
         self.metrics.increment_segments(partitions_count);
+        self.audit(
+            session,
+            AuditAction::CreateTopic,
+            format!(
+                "stream ID: {}, topic name: '{}', partitions: {}",
+                stream_id_value, name, partitions_count
+            ),
+        )
+        .await;
         Ok(())
     }
 
@@ -79,17 +101,22 @@ impl System {
         replication_factor: u8,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let stream_id_value;
+        let topic_id_value;
         {
             let stream = self.get_stream(stream_id)?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.update_topic(
-                session.get_user_id(),
+                session.get_principal_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
+            stream_id_value = stream.stream_id;
+            topic_id_value = topic.topic_id;
         }
 
-        self.get_stream_mut(stream_id)?
+        let stream = self.get_stream_mut(stream_id)?;
+        stream
             .update_topic(
                 topic_id,
                 name,
@@ -98,6 +125,12 @@ impl System {
                 replication_factor,
             )
             .await?;
+        let topic_name = stream
+            .get_topic(&Identifier::numeric(topic_id_value)?)?
+            .name
+            .clone();
+        self.permissioner
+            .register_topic(stream_id_value, topic_id_value, &topic_name);
 
         // TODO: if message_expiry is changed, we need to check if we need to purge messages based on the new expiry
         // TODO: if max_size_bytes is changed, we need to check if we need to purge messages based on the new size
@@ -117,17 +150,18 @@ impl System {
             let stream = self.get_stream(stream_id)?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.delete_topic(
-                session.get_user_id(),
+                session.get_principal_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
             stream_id_value = stream.stream_id;
         }
 
-        let topic = self
-            .get_stream_mut(stream_id)?
-            .delete_topic(topic_id)
-            .await?;
+        let stream = self.get_stream_mut(stream_id)?;
+        let topic = stream.delete_topic(topic_id).await?;
+        stream.refresh_size_bytes().await;
+        self.permissioner
+            .unregister_topic(stream_id_value, topic.topic_id);
         self.metrics.decrement_topics(1);
         self.metrics
             .decrement_partitions(topic.get_partitions_count());
@@ -135,6 +169,15 @@ impl System {
             .decrement_messages(topic.get_messages_count().await);
         self.metrics
             .decrement_segments(topic.get_segments_count().await);
+        self.audit(
+            session,
+            AuditAction::DeleteTopic,
+            format!(
+                "stream ID: {stream_id_value}, topic ID: {}, name: '{}'",
+                topic.topic_id, topic.name
+            ),
+        )
+        .await;
         let client_manager = self.client_manager.read().await;
         client_manager
             .delete_consumer_groups_for_topic(stream_id_value, topic.topic_id)
@@ -150,8 +193,13 @@ impl System {
     ) -> Result<(), IggyError> {
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
-        self.permissioner
-            .purge_topic(session.get_user_id(), stream.stream_id, topic.topic_id)?;
-        topic.purge().await
+        self.permissioner.purge_topic(
+            session.get_principal_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+        let result = topic.purge().await;
+        stream.refresh_size_bytes().await;
+        result
     }
 }
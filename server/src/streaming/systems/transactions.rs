@@ -0,0 +1,237 @@
+use crate::streaming::clients::client_manager::{Transaction, TransactionPartition};
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::utils::random_id;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+
+impl System {
+    pub async fn begin_transaction(&self, session: &Session) -> Result<u64, IggyError> {
+        self.ensure_authenticated(session)?;
+        // The transaction ID equal to 0 is reserved, the same as for the producer ID.
+        let mut transaction_id = 0;
+        while transaction_id == 0 {
+            transaction_id = random_id::get_uuid() as u64;
+        }
+
+        let client_manager = self.client_manager.read().await;
+        client_manager
+            .begin_transaction(session.client_id, transaction_id)
+            .await?;
+        info!(
+            "Started transaction with ID: {} for client with ID: {}.",
+            transaction_id, session.client_id
+        );
+        Ok(transaction_id)
+    }
+
+    pub async fn commit_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let transaction = self.take_transaction(session, transaction_id).await?;
+        let partitions = self.get_partitions(transaction.transaction_id, &transaction.partitions);
+        if transaction.is_expired(
+            IggyTimestamp::now().to_micros(),
+            self.config.transaction.timeout,
+        ) {
+            Self::complete_transaction(&partitions, transaction_id, false).await?;
+            return Err(IggyError::TransactionExpired(transaction_id));
+        }
+
+        let mut prepared_transactions = Vec::with_capacity(partitions.len());
+        for partition in &partitions {
+            let mut partition = partition.write().await;
+            if let Some(transaction) = partition.prepare_transaction_commit(transaction_id) {
+                prepared_transactions.push(transaction);
+            }
+        }
+
+        // Once the commit decision is stored, the transaction will be committed even if the server stops in the meantime.
+        if let Err(error) = self
+            .storage
+            .partition
+            .save_transactions(&prepared_transactions)
+            .await
+        {
+            error!(
+                "Failed to save the commit decision for transaction with ID: {}, it will be aborted. Error: {}",
+                transaction_id, error
+            );
+            Self::complete_transaction(&partitions, transaction_id, false).await?;
+            return Err(error);
+        }
+
+        Self::complete_transaction(&partitions, transaction_id, true).await?;
+        info!(
+            "Committed transaction with ID: {} for client with ID: {}.",
+            transaction_id, session.client_id
+        );
+        Ok(())
+    }
+
+    pub async fn abort_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let transaction = self.take_transaction(session, transaction_id).await?;
+        let partitions = self.get_partitions(transaction.transaction_id, &transaction.partitions);
+        Self::complete_transaction(&partitions, transaction_id, false).await?;
+        info!(
+            "Aborted transaction with ID: {} for client with ID: {}.",
+            transaction_id, session.client_id
+        );
+        Ok(())
+    }
+
+    pub(crate) async fn abort_client_transaction(&self, client_id: u32) {
+        let transaction;
+        {
+            let client_manager = self.client_manager.read().await;
+            let client = client_manager.get_client_by_id(client_id);
+            if client.is_err() {
+                return;
+            }
+
+            let client = client.unwrap();
+            let mut client = client.write().await;
+            transaction = client.transaction.take();
+        }
+
+        if let Some(transaction) = transaction {
+            let partitions =
+                self.get_partitions(transaction.transaction_id, &transaction.partitions);
+            if let Err(error) =
+                Self::complete_transaction(&partitions, transaction.transaction_id, false).await
+            {
+                error!(
+                    "Failed to abort transaction with ID: {} for client with ID: {}. Error: {}",
+                    transaction.transaction_id, client_id, error
+                );
+            }
+        }
+    }
+
+    pub async fn abort_expired_transactions(&self) -> usize {
+        let expired_transactions = {
+            let client_manager = self.client_manager.read().await;
+            client_manager
+                .expire_transactions(self.config.transaction.timeout)
+                .await
+        };
+
+        for (client_id, transaction) in &expired_transactions {
+            let partitions =
+                self.get_partitions(transaction.transaction_id, &transaction.partitions);
+            match Self::complete_transaction(&partitions, transaction.transaction_id, false).await {
+                Ok(()) => info!(
+                    "Aborted expired transaction with ID: {} for client with ID: {}.",
+                    transaction.transaction_id, client_id
+                ),
+                Err(error) => error!(
+                    "Failed to abort expired transaction with ID: {} for client with ID: {}. Error: {}",
+                    transaction.transaction_id, client_id, error
+                ),
+            }
+        }
+        expired_transactions.len()
+    }
+
+    pub(crate) async fn get_transaction_id(
+        &self,
+        session: &Session,
+    ) -> Result<Option<u64>, IggyError> {
+        // The stateless clients (HTTP) can't take part in the transactions.
+        if session.client_id == 0 {
+            return Ok(None);
+        }
+
+        let client_manager = self.client_manager.read().await;
+        client_manager.get_transaction_id(session.client_id).await
+    }
+
+    pub(crate) async fn add_transaction_partition(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), IggyError> {
+        let transaction_partition = TransactionPartition {
+            stream_id,
+            topic_id,
+            partition_id,
+        };
+        let result = {
+            let client_manager = self.client_manager.read().await;
+            client_manager
+                .add_transaction_partition(session.client_id, transaction_partition)
+                .await
+        };
+
+        // The transaction has expired after the data was added to the partition, so it must be aborted there as well.
+        if let Err(IggyError::TransactionExpired(transaction_id)) = result {
+            let partitions = self.get_partitions(transaction_id, &[transaction_partition]);
+            Self::complete_transaction(&partitions, transaction_id, false).await?;
+        }
+        result
+    }
+
+    async fn take_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<Transaction, IggyError> {
+        let client_manager = self.client_manager.read().await;
+        client_manager
+            .take_transaction(session.client_id, transaction_id)
+            .await
+    }
+
+    fn get_partitions(
+        &self,
+        transaction_id: u64,
+        transaction_partitions: &[TransactionPartition],
+    ) -> Vec<Arc<RwLock<Partition>>> {
+        let mut partitions = Vec::with_capacity(transaction_partitions.len());
+        for transaction_partition in transaction_partitions {
+            let partition = self
+                .get_stream(&Identifier::numeric(transaction_partition.stream_id).unwrap())
+                .and_then(|stream| {
+                    stream.get_topic(&Identifier::numeric(transaction_partition.topic_id).unwrap())
+                })
+                .and_then(|topic| topic.get_partition(transaction_partition.partition_id));
+            match partition {
+                Ok(partition) => partitions.push(partition),
+                Err(error) => warn!(
+                    "Partition: {:?} of transaction with ID: {} is no longer available. Error: {}",
+                    transaction_partition, transaction_id, error
+                ),
+            }
+        }
+        partitions
+    }
+
+    async fn complete_transaction(
+        partitions: &[Arc<RwLock<Partition>>],
+        transaction_id: u64,
+        committed: bool,
+    ) -> Result<(), IggyError> {
+        for partition in partitions {
+            let mut partition = partition.write().await;
+            partition
+                .complete_transaction(transaction_id, committed)
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -1,13 +1,20 @@
+use crate::streaming::audit::audit_action::AuditAction;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use crate::streaming::users::password_policy;
 use crate::streaming::users::user::User;
 use crate::streaming::utils::crypto;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::models::permissions::Permissions;
+use iggy::models::user_info::UserId;
+use iggy::models::user_quotas::UserQuotas;
 use iggy::models::user_status::UserStatus;
 use iggy::utils::text;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use tokio::sync::Mutex;
 use tracing::log::error;
 use tracing::{info, warn};
 
@@ -28,6 +35,9 @@ impl System {
         let users_count = users.len();
         let current_user_id = users.iter().map(|user| user.id).max().unwrap_or(1);
         USER_ID.store(current_user_id + 1, Ordering::SeqCst);
+        for user in users.iter().filter(|user| user.quotas.is_some()) {
+            self.quota_manager.set_user_quotas(user.id, user.quotas);
+        }
         self.permissioner.init(users);
         info!("Initialized {} user(s).", users_count);
         Ok(())
@@ -42,7 +52,7 @@ impl System {
         let user = self.get_user(user_id).await?;
         let session_user_id = session.get_user_id();
         if user.id != session_user_id {
-            self.permissioner.get_user(session_user_id)?;
+            self.permissioner.get_user(session.get_principal_id())?;
         }
 
         Ok(user)
@@ -67,7 +77,7 @@ impl System {
 
     pub async fn get_users(&self, session: &Session) -> Result<Vec<User>, IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.get_users(session.get_user_id())?;
+        self.permissioner.get_users(session.get_principal_id())?;
         self.storage.user.load_all().await
     }
 
@@ -80,12 +90,13 @@ impl System {
         permissions: Option<Permissions>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.create_user(session.get_user_id())?;
+        self.permissioner.create_user(session.get_principal_id())?;
         let username = text::to_lowercase_non_whitespace(username);
         if self.storage.user.load_by_username(&username).await.is_ok() {
             error!("User: {username} already exists.");
             return Err(IggyError::UserAlreadyExists);
         }
+        password_policy::validate_password(&self.config.password_policy, password)?;
         let user_id = USER_ID.fetch_add(1, Ordering::SeqCst);
         info!("Creating user: {username} with ID: {user_id}...");
         let user = User::new(user_id, &username, password, status, permissions);
@@ -93,6 +104,12 @@ impl System {
         self.permissioner.init_permissions_for_user(user);
         info!("Created user: {username} with ID: {user_id}.");
         self.metrics.increment_users(1);
+        self.audit(
+            session,
+            AuditAction::CreateUser,
+            format!("user ID: {user_id}, username: {username}"),
+        )
+        .await;
         Ok(())
     }
 
@@ -102,7 +119,7 @@ impl System {
         user_id: &Identifier,
     ) -> Result<User, IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.delete_user(session.get_user_id())?;
+        self.permissioner.delete_user(session.get_principal_id())?;
         let user = self.get_user(user_id).await?;
         if user.is_root() {
             error!("Cannot delete the root user.");
@@ -112,6 +129,16 @@ impl System {
         info!("Deleting user: {} with ID: {user_id}...", user.username);
         self.storage.user.delete(&user).await?;
         self.permissioner.delete_permissions_for_user(user.id);
+        self.permissioner
+            .delete_personal_access_tokens_scopes_for_user(user.id);
+        self.quota_manager.delete_user(user.id);
+        self.user_login_locks.remove(&user.id);
+        self.audit(
+            session,
+            AuditAction::DeleteUser,
+            format!("user ID: {}, username: {}", user.id, user.username),
+        )
+        .await;
         let mut client_manager = self.client_manager.write().await;
         client_manager.delete_clients_for_user(user.id).await?;
         info!("Deleted user: {} with ID: {user_id}.", user.username);
@@ -127,7 +154,7 @@ impl System {
         status: Option<UserStatus>,
     ) -> Result<User, IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.update_user(session.get_user_id())?;
+        self.permissioner.update_user(session.get_principal_id())?;
         let mut user = self.get_user(user_id).await?;
         if let Some(username) = username {
             let username = text::to_lowercase_non_whitespace(&username);
@@ -158,7 +185,7 @@ impl System {
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .update_permissions(session.get_user_id())?;
+            .update_permissions(session.get_principal_id())?;
         let mut user = self.get_user(user_id).await?;
         if user.is_root() {
             error!("Cannot change the root user permissions.");
@@ -166,6 +193,7 @@ impl System {
         }
 
         user.permissions = permissions;
+        let resource = format!("user ID: {}, username: {}", user.id, user.username);
         let username = user.username.clone();
         info!(
             "Updating permissions for user: {} with ID: {user_id}...",
@@ -177,6 +205,67 @@ impl System {
             "Updated permissions for user: {} with ID: {user_id}.",
             username
         );
+        self.audit(session, AuditAction::UpdatePermissions, resource)
+            .await;
+        Ok(())
+    }
+
+    pub async fn update_quotas(
+        &self,
+        session: &Session,
+        user_id: &Identifier,
+        quotas: Option<UserQuotas>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_quotas(session.get_principal_id())?;
+        let mut user = self.get_user(user_id).await?;
+        user.quotas = quotas;
+        info!(
+            "Updating quotas for user: {} with ID: {user_id}...",
+            user.username
+        );
+        self.storage.user.save(&user).await?;
+        self.quota_manager.set_user_quotas(user.id, quotas);
+        info!(
+            "Updated quotas for user: {} with ID: {user_id}.",
+            user.username
+        );
+        Ok(())
+    }
+
+    pub async fn update_user_roles(
+        &mut self,
+        session: &Session,
+        user_id: &Identifier,
+        role_ids: Vec<u32>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_user_roles(session.get_principal_id())?;
+        let mut user = self.get_user(user_id).await?;
+        let mut roles = Vec::with_capacity(role_ids.len());
+        for role_id in role_ids {
+            if roles.contains(&role_id) {
+                continue;
+            }
+
+            if self.storage.role.load_by_id(role_id).await.is_err() {
+                error!("Role with ID: {role_id} was not found.");
+                return Err(IggyError::RoleNotFound(role_id));
+            }
+            roles.push(role_id);
+        }
+
+        user.roles = roles;
+        let resource = format!("user ID: {}, username: {}", user.id, user.username);
+        let username = user.username.clone();
+        info!("Updating roles for user: {username} with ID: {user_id}...");
+        self.storage.user.save(&user).await?;
+        self.permissioner.update_permissions_for_user(user);
+        info!("Updated roles for user: {username} with ID: {user_id}.");
+        self.audit(session, AuditAction::UpdateUserRoles, resource)
+            .await;
         Ok(())
     }
 
@@ -190,8 +279,9 @@ impl System {
         self.ensure_authenticated(session)?;
         let mut user = self.get_user(user_id).await?;
         let session_user_id = session.get_user_id();
-        if user.id != session_user_id {
-            self.permissioner.change_password(session_user_id)?;
+        if user.id != session_user_id || session.is_scoped() {
+            self.permissioner
+                .change_password(session.get_principal_id())?;
         }
 
         if !crypto::verify_password(current_password, &user.password) {
@@ -202,6 +292,7 @@ impl System {
             return Err(IggyError::InvalidCredentials);
         }
 
+        password_policy::validate_password(&self.config.password_policy, new_password)?;
         info!(
             "Changing password for user: {} with ID: {user_id}...",
             user.username
@@ -231,30 +322,114 @@ impl System {
         password: Option<&str>,
         session: Option<&Session>,
     ) -> Result<User, IggyError> {
-        let user = match self.storage.user.load_by_username(username).await {
+        // The credentials are not provided only when logging in with the personal access token.
+        let action = match password {
+            Some(_) => AuditAction::LoginUser,
+            None => AuditAction::LoginWithPersonalAccessToken,
+        };
+        self.login_user_with_action(username, password, action, session)
+            .await
+    }
+
+    /// Logs in the user with the username mapped from the client certificate, which has already been verified
+    /// during the TLS handshake. The root user can be logged in this way only when it's explicitly allowed.
+    pub async fn login_with_client_certificate(
+        &self,
+        username: &str,
+        allow_root: bool,
+        session: &Session,
+    ) -> Result<User, IggyError> {
+        let user = self.storage.user.load_by_username(username).await;
+        if user.is_err() || (!allow_root && user.unwrap().is_root()) {
+            error!("Cannot login with the client certificate as user: {username}.");
+            self.audit_login_failure(
+                Some(session),
+                AuditAction::LoginWithClientCertificate,
+                format!("username: {username}"),
+            )
+            .await;
+            return Err(IggyError::InvalidCredentials);
+        }
+
+        self.login_user_with_action(
+            username,
+            None,
+            AuditAction::LoginWithClientCertificate,
+            Some(session),
+        )
+        .await
+    }
+
+    pub(crate) async fn login_user_with_action(
+        &self,
+        username: &str,
+        password: Option<&str>,
+        action: AuditAction,
+        session: Option<&Session>,
+    ) -> Result<User, IggyError> {
+        let now = IggyTimestamp::now().to_micros();
+        if let Some(session) = session {
+            if let Err(error) = self.login_backoff.check(&session.ip_address.ip(), now) {
+                warn!(
+                    "Too many failed login attempts from address: {}.",
+                    session.ip_address
+                );
+                self.audit_login_failure(Some(session), action, format!("username: {username}"))
+                    .await;
+                return Err(error);
+            }
+        }
+
+        let mut user = match self.storage.user.load_by_username(username).await {
             Ok(user) => user,
             Err(_) => {
                 error!("Cannot login user: {username} (not found).");
+                self.record_failed_login_address(session, now);
+                self.audit_login_failure(session, action, format!("username: {username}"))
+                    .await;
                 return Err(IggyError::InvalidCredentials);
             }
         };
 
         info!("Logging in user: {username} with ID: {}...", user.id);
+        let resource = format!("user ID: {}, username: {username}", user.id);
         if !user.is_active() {
             warn!("User: {username} with ID: {} is inactive.", user.id);
+            self.audit_login_failure(session, action, resource).await;
             return Err(IggyError::UserInactive);
         }
 
+        let lockout_enabled = self.config.login_lockout.enabled;
+        if lockout_enabled && user.is_locked_out(now) {
+            warn!("User: {username} with ID: {} is locked out.", user.id);
+            self.audit_login_failure(session, action, resource).await;
+            return Err(IggyError::UserLockedOut(user.locked_until));
+        }
+
         if let Some(password) = password {
             if !crypto::verify_password(password, &user.password) {
                 warn!(
                     "Invalid password for user: {username} with ID: {}.",
                     user.id
                 );
+                self.record_failed_login_address(session, now);
+                if lockout_enabled {
+                    self.record_failed_login_attempt(user.id, now).await?;
+                }
+                self.audit_login_failure(session, action, resource).await;
                 return Err(IggyError::InvalidCredentials);
             }
         }
 
+        if lockout_enabled && (user.failed_login_attempts > 0 || user.locked_until > 0) {
+            self.reset_failed_login_attempts(user.id).await?;
+            user.failed_login_attempts = 0;
+            user.locked_until = 0;
+        }
+        if let Some(session) = session {
+            self.login_backoff.record_success(&session.ip_address.ip());
+        }
+
         info!("Logged in user: {username} with ID: {}.", user.id);
         if session.is_none() {
             return Ok(user);
@@ -271,13 +446,87 @@ impl System {
         }
 
         session.set_user_id(user.id);
-        let mut client_manager = self.client_manager.write().await;
-        client_manager
-            .set_user_id(session.client_id, user.id)
-            .await?;
+        if session.client_id > 0 {
+            let mut client_manager = self.client_manager.write().await;
+            client_manager
+                .set_user_id(session.client_id, user.id)
+                .await?;
+        }
+        self.audit(session, action, resource).await;
         Ok(user)
     }
 
+    fn record_failed_login_address(&self, session: Option<&Session>, now: u64) {
+        if let Some(session) = session {
+            self.login_backoff
+                .record_failure(&session.ip_address.ip(), now);
+        }
+    }
+
+    async fn record_failed_login_attempt(
+        &self,
+        user_id: UserId,
+        now: u64,
+    ) -> Result<(), IggyError> {
+        let config = &self.config.login_lockout;
+        let lock = self.get_user_login_lock(user_id);
+        let _guard = lock.lock().await;
+        // The user is loaded again while holding its lock, so that none of the concurrent failed attempts is lost.
+        let mut user = self.storage.user.load_by_id(user_id).await?;
+        if user.is_locked_out(now) {
+            return Ok(());
+        }
+
+        // The counter starts over once the previous lockout has expired.
+        if user.locked_until > 0 {
+            user.locked_until = 0;
+            user.failed_login_attempts = 0;
+        }
+
+        user.failed_login_attempts += 1;
+        if user.failed_login_attempts >= config.max_failed_attempts {
+            user.locked_until = now + config.lockout_duration.as_micros();
+            warn!(
+                "User: {} with ID: {} has been locked out after {} failed login attempts.",
+                user.username, user.id, user.failed_login_attempts
+            );
+        }
+        self.storage.user.save(&user).await
+    }
+
+    async fn reset_failed_login_attempts(&self, user_id: UserId) -> Result<(), IggyError> {
+        let lock = self.get_user_login_lock(user_id);
+        let _guard = lock.lock().await;
+        let mut user = self.storage.user.load_by_id(user_id).await?;
+        user.failed_login_attempts = 0;
+        user.locked_until = 0;
+        self.storage.user.save(&user).await
+    }
+
+    fn get_user_login_lock(&self, user_id: UserId) -> Arc<Mutex<()>> {
+        self.user_login_locks.entry(user_id).or_default().clone()
+    }
+
+    pub async fn unlock_user(
+        &self,
+        session: &Session,
+        user_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.unlock_user(session.get_principal_id())?;
+        let user = self.get_user(user_id).await?;
+        info!("Unlocking user: {} with ID: {user_id}...", user.username);
+        self.reset_failed_login_attempts(user.id).await?;
+        info!("Unlocked user: {} with ID: {user_id}.", user.username);
+        self.audit(
+            session,
+            AuditAction::UnlockUser,
+            format!("user ID: {}, username: {}", user.id, user.username),
+        )
+        .await;
+        Ok(())
+    }
+
     pub async fn logout_user(&self, session: &Session) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let user = self
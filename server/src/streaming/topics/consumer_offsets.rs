@@ -16,6 +16,20 @@ impl Topic {
         partition.store_consumer_offset(consumer, offset).await
     }
 
+    pub async fn store_transaction_consumer_offset(
+        &self,
+        transaction_id: u64,
+        consumer: PollingConsumer,
+        offset: u64,
+    ) -> Result<u32, IggyError> {
+        let partition = self.resolve_partition(consumer).await?;
+        let mut partition = partition.write().await;
+        partition
+            .add_transaction_consumer_offset(transaction_id, consumer, offset)
+            .await?;
+        Ok(partition.partition_id)
+    }
+
     pub async fn get_consumer_offset(
         &self,
         consumer: PollingConsumer,
@@ -5,7 +5,7 @@ use crate::streaming::utils::file::folder_size;
 use crate::streaming::utils::hash;
 use iggy::error::IggyError;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
-use iggy::messages::send_messages::{Partitioning, PartitioningKind};
+use iggy::messages::send_messages::{Partitioning, PartitioningKind, ProducerBatch};
 use iggy::models::messages::Message;
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
@@ -28,6 +28,7 @@ impl Topic {
         partition_id: u32,
         strategy: PollingStrategy,
         count: u32,
+        read_committed: bool,
     ) -> Result<PolledMessages, IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
@@ -52,6 +53,9 @@ impl Topic {
             PollingKind::Last => partition.get_last_messages(count).await,
             PollingKind::Next => partition.get_next_messages(consumer, count).await,
         }?;
+        let messages = partition
+            .get_visible_messages(messages, count, read_committed)
+            .await?;
 
         Ok(PolledMessages {
             messages,
@@ -63,14 +67,16 @@ impl Topic {
     pub async fn append_messages(
         &self,
         partitioning: &Partitioning,
+        producer: Option<&ProducerBatch>,
+        transaction_id: Option<u64>,
         messages: Vec<Message>,
-    ) -> Result<(), IggyError> {
+    ) -> Result<Option<u32>, IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
         }
 
         if messages.is_empty() {
-            return Ok(());
+            return Ok(None);
         }
 
         let partition_id = match partitioning.kind {
@@ -83,13 +89,16 @@ impl Topic {
             }
         };
 
-        self.append_messages_to_partition(partition_id, messages)
-            .await
+        self.append_messages_to_partition(partition_id, producer, transaction_id, messages)
+            .await?;
+        Ok(Some(partition_id))
     }
 
     async fn append_messages_to_partition(
         &self,
         partition_id: u32,
+        producer: Option<&ProducerBatch>,
+        transaction_id: Option<u64>,
         messages: Vec<Message>,
     ) -> Result<(), IggyError> {
         let partition = self.partitions.get(&partition_id);
@@ -103,7 +112,20 @@ impl Topic {
 
         let partition = partition.unwrap();
         let mut partition = partition.write().await;
-        partition.append_messages(messages).await?;
+        let start_offset = partition.get_next_message_offset();
+        match producer {
+            Some(producer) => {
+                partition
+                    .append_producer_messages(producer, messages)
+                    .await?
+            }
+            None => partition.append_messages(messages).await?,
+        }
+        if let Some(transaction_id) = transaction_id {
+            partition
+                .add_transaction_messages(transaction_id, start_offset)
+                .await?;
+        }
         Ok(())
     }
 
@@ -283,7 +305,7 @@ mod tests {
                 None,
             )];
             topic
-                .append_messages(&partitioning, messages)
+                .append_messages(&partitioning, None, None, messages)
                 .await
                 .unwrap();
         }
@@ -319,7 +341,7 @@ mod tests {
                 None,
             )];
             topic
-                .append_messages(&partitioning, messages)
+                .append_messages(&partitioning, None, None, messages)
                 .await
                 .unwrap();
         }
@@ -337,6 +359,19 @@ mod tests {
         assert_eq!(read_messages_count, messages_count as usize);
     }
 
+    #[tokio::test]
+    async fn given_empty_batch_no_partition_should_be_chosen() {
+        let topic = init_topic(3);
+        let partitioning = Partitioning::partition_id(1);
+
+        let partition_id = topic
+            .append_messages(&partitioning, None, None, Vec::new())
+            .await
+            .unwrap();
+
+        assert!(partition_id.is_none());
+    }
+
     #[test]
     fn given_multiple_partitions_calculate_next_partition_id_should_return_next_partition_id_using_round_robin(
     ) {
@@ -1,7 +1,9 @@
+use crate::streaming::partitions::partition::Partition;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
 use tokio::sync::RwLock;
+use tracing::error;
 
 impl Topic {
     pub async fn load(&mut self) -> Result<(), IggyError> {
@@ -50,6 +52,32 @@ impl Topic {
         Ok(())
     }
 
+    pub async fn sync_messages(&self, partition_id: u32) -> Result<(), IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let mut partition = partition.write().await;
+        partition.sync_messages().await
+    }
+
+    /// Persists the buffered messages of all the partitions and syncs their segment files on disk.
+    /// The failure of any partition doesn't stop the others from being synced, and the first error is returned.
+    pub async fn sync_all_messages(&self) -> Result<(), IggyError> {
+        let mut result = Ok(());
+        for partition in self.get_partitions() {
+            let mut partition = partition.write().await;
+            if let Err(error) = sync_partition_messages(&mut partition).await {
+                error!(
+                    "Failed to synchronize the messages of partition with ID: {} for topic with ID: {} and stream with ID: {}. Error: {error}",
+                    partition.partition_id, self.topic_id, self.stream_id
+                );
+                if result.is_ok() {
+                    result = Err(error);
+                }
+            }
+        }
+
+        result
+    }
+
     pub async fn purge(&self) -> Result<(), IggyError> {
         for partition in self.get_partitions() {
             let mut partition = partition.write().await;
@@ -58,3 +86,10 @@ impl Topic {
         Ok(())
     }
 }
+
+async fn sync_partition_messages(partition: &mut Partition) -> Result<(), IggyError> {
+    for segment in partition.get_segments_mut() {
+        segment.persist_messages().await?;
+    }
+    partition.sync_messages().await
+}
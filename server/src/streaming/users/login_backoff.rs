@@ -0,0 +1,159 @@
+use crate::configs::system::LoginLockoutConfig;
+use dashmap::DashMap;
+use iggy::error::IggyError;
+use std::net::IpAddr;
+
+/// The login backoff tracks the consecutive failed login attempts from the same IP address,
+/// and once exceeding the threshold, delays the next attempt exponentially up to the max backoff.
+/// The failures are forgotten after the successful login, or when no attempt was made for the max backoff.
+#[derive(Debug)]
+pub struct LoginBackoff {
+    enabled: bool,
+    threshold: u32,
+    backoff: u64,
+    max_backoff: u64,
+    addresses: DashMap<IpAddr, LoginFailures>,
+}
+
+#[derive(Debug)]
+struct LoginFailures {
+    count: u32,
+    last_failure_at: u64,
+}
+
+impl LoginBackoff {
+    /// Creates a new login backoff based on the provided configuration.
+    pub fn new(config: &LoginLockoutConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            threshold: config.ip_failed_attempts_threshold,
+            backoff: config.ip_backoff.as_micros(),
+            max_backoff: config.ip_max_backoff.as_micros(),
+            addresses: DashMap::new(),
+        }
+    }
+
+    /// Returns an error with the remaining backoff (in milliseconds) if the next attempt from the given IP address is not allowed yet.
+    pub fn check(&self, address: &IpAddr, now: u64) -> Result<(), IggyError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let failures = self.addresses.get(address);
+        if failures.is_none() {
+            return Ok(());
+        }
+
+        let failures = failures.unwrap();
+        if failures.count < self.threshold {
+            return Ok(());
+        }
+
+        let retry_at = failures.last_failure_at + self.get_backoff(failures.count);
+        if now >= retry_at {
+            return Ok(());
+        }
+
+        Err(IggyError::TooManyLoginAttempts((retry_at - now) / 1000))
+    }
+
+    pub fn record_failure(&self, address: &IpAddr, now: u64) {
+        if !self.enabled {
+            return;
+        }
+
+        let mut failures = self.addresses.entry(*address).or_insert(LoginFailures {
+            count: 0,
+            last_failure_at: now,
+        });
+        if now.saturating_sub(failures.last_failure_at) > self.max_backoff {
+            failures.count = 0;
+        }
+        failures.count += 1;
+        failures.last_failure_at = now;
+    }
+
+    pub fn record_success(&self, address: &IpAddr) {
+        if !self.enabled {
+            return;
+        }
+
+        self.addresses.remove(address);
+    }
+
+    fn get_backoff(&self, count: u32) -> u64 {
+        let exponent = (count - self.threshold).min(32);
+        self.backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    const SECOND: u64 = 1_000_000;
+
+    fn create_backoff() -> LoginBackoff {
+        LoginBackoff::new(&LoginLockoutConfig {
+            enabled: true,
+            max_failed_attempts: 5,
+            lockout_duration: "15m".parse().unwrap(),
+            ip_failed_attempts_threshold: 2,
+            ip_backoff: "1s".parse().unwrap(),
+            ip_max_backoff: "4s".parse().unwrap(),
+        })
+    }
+
+    #[test]
+    fn attempts_should_be_allowed_until_exceeding_the_threshold() {
+        let backoff = create_backoff();
+        let address = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        backoff.record_failure(&address, SECOND);
+        assert!(backoff.check(&address, SECOND).is_ok());
+
+        backoff.record_failure(&address, SECOND);
+        let result = backoff.check(&address, SECOND);
+        assert!(matches!(result, Err(IggyError::TooManyLoginAttempts(1000))));
+        assert!(backoff.check(&address, 2 * SECOND).is_ok());
+    }
+
+    #[test]
+    fn backoff_should_grow_exponentially_up_to_the_max_backoff() {
+        let backoff = create_backoff();
+        let address = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        for _ in 0..3 {
+            backoff.record_failure(&address, SECOND);
+        }
+        let result = backoff.check(&address, SECOND);
+        assert!(matches!(result, Err(IggyError::TooManyLoginAttempts(2000))));
+
+        for _ in 0..5 {
+            backoff.record_failure(&address, SECOND);
+        }
+        let result = backoff.check(&address, SECOND);
+        assert!(matches!(result, Err(IggyError::TooManyLoginAttempts(4000))));
+    }
+
+    #[test]
+    fn failures_should_be_cleared_after_successful_login() {
+        let backoff = create_backoff();
+        let address = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        backoff.record_failure(&address, SECOND);
+        backoff.record_failure(&address, SECOND);
+        backoff.record_success(&address);
+        assert!(backoff.check(&address, SECOND).is_ok());
+    }
+
+    #[test]
+    fn attempts_should_not_be_limited_given_disabled_lockout() {
+        let backoff = LoginBackoff::new(&LoginLockoutConfig::default());
+        let address = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        for _ in 0..100 {
+            backoff.record_failure(&address, SECOND);
+        }
+        assert!(backoff.check(&address, SECOND).is_ok());
+    }
+}
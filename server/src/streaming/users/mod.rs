@@ -1,3 +1,6 @@
+pub mod login_backoff;
+pub mod oidc;
+pub mod password_policy;
 pub mod permissioner;
 pub mod permissioner_rules;
 pub mod storage;
@@ -0,0 +1,467 @@
+use crate::configs::system::OidcConfig;
+use iggy::error::IggyError;
+use iggy::utils::duration::IggyDuration;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm, PublicKeyUse,
+};
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use tokio::sync::RwLock;
+use tokio::time::Instant;
+use tracing::{debug, error, info, warn};
+
+/// The identity of the user authenticated with the JWT issued by the external identity provider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OidcIdentity {
+    pub username: String,
+    /// The names of the roles, available only when the roles claim is configured and present in the token,
+    /// otherwise the roles currently assigned to the user are kept.
+    pub roles: Option<Vec<String>>,
+    /// The token ID (`jti` claim), which might be missing in the externally issued tokens.
+    pub token_id: String,
+    pub expiry: u64,
+}
+
+/// The public key of the identity provider along with the algorithms, which the tokens signed with it can use.
+#[derive(Clone)]
+struct OidcKey {
+    key: DecodingKey,
+    algorithms: Vec<Algorithm>,
+}
+
+/// Validates the JWTs issued by the external identity provider, using the public keys
+/// from its JSON Web Key Set, which is loaded from the local file or the URL.
+pub struct OidcValidator {
+    jwks: String,
+    jwks_refresh_interval: IggyDuration,
+    valid_issuers: Vec<String>,
+    valid_audiences: Vec<String>,
+    clock_skew: IggyDuration,
+    username_claim: String,
+    roles_claim: String,
+    keys: RwLock<HashMap<String, OidcKey>>,
+    keys_loaded_at: RwLock<Option<Instant>>,
+}
+
+impl Debug for OidcValidator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OidcValidator {{ jwks: {} }}", self.jwks)
+    }
+}
+
+impl OidcValidator {
+    pub fn new(config: &OidcConfig) -> Self {
+        Self {
+            jwks: config.jwks.clone(),
+            jwks_refresh_interval: config.jwks_refresh_interval,
+            valid_issuers: config.valid_issuers.clone(),
+            valid_audiences: config.valid_audiences.clone(),
+            clock_skew: config.clock_skew,
+            username_claim: config.username_claim.clone(),
+            roles_claim: config.roles_claim.clone(),
+            keys: RwLock::new(HashMap::new()),
+            keys_loaded_at: RwLock::new(None),
+        }
+    }
+
+    pub async fn load_keys(&self) -> Result<(), IggyError> {
+        *self.keys_loaded_at.write().await = Some(Instant::now());
+        self.refresh_keys().await
+    }
+
+    async fn refresh_keys(&self) -> Result<(), IggyError> {
+        info!("Loading JWKS from: {}...", self.jwks);
+        let jwks = self.load_jwks().await?;
+        let mut keys = HashMap::new();
+        for jwk in jwks.keys {
+            if let Some(PublicKeyUse::Encryption) = jwk.common.public_key_use {
+                continue;
+            }
+
+            let key_id = jwk.common.key_id.clone().unwrap_or_default();
+            let algorithms = get_algorithms(&jwk);
+            if algorithms.is_empty() {
+                warn!("Skipping JWK with ID: {key_id}, its algorithm is not supported.");
+                continue;
+            }
+
+            match DecodingKey::from_jwk(&jwk) {
+                Ok(key) => {
+                    keys.insert(key_id, OidcKey { key, algorithms });
+                }
+                Err(error) => warn!("Skipping invalid JWK with ID: {key_id}, error: {error}"),
+            }
+        }
+
+        info!("Loaded {} key(s) from JWKS: {}.", keys.len(), self.jwks);
+        *self.keys.write().await = keys;
+        Ok(())
+    }
+
+    async fn load_jwks(&self) -> Result<JwkSet, IggyError> {
+        let jwks = if self.jwks.starts_with("http://") || self.jwks.starts_with("https://") {
+            let response = reqwest::get(&self.jwks)
+                .await
+                .and_then(|response| response.error_for_status());
+            if let Err(error) = response {
+                error!("Cannot fetch JWKS from: {}, error: {error}", self.jwks);
+                return Err(IggyError::CannotLoadJwks);
+            }
+
+            response.unwrap().text().await.map_err(|error| {
+                error!("Cannot read JWKS from: {}, error: {error}", self.jwks);
+                IggyError::CannotLoadJwks
+            })?
+        } else {
+            tokio::fs::read_to_string(&self.jwks)
+                .await
+                .map_err(|error| {
+                    error!("Cannot read JWKS file: {}, error: {error}", self.jwks);
+                    IggyError::CannotLoadJwks
+                })?
+        };
+
+        serde_json::from_str::<JwkSet>(&jwks).map_err(|error| {
+            error!("Cannot parse JWKS from: {}, error: {error}", self.jwks);
+            IggyError::CannotLoadJwks
+        })
+    }
+
+    /// Returns the key for the provided key ID. When the key is unknown (e.g. due to the rotation
+    /// of the keys by the identity provider), the key set is reloaded, but not more often than the refresh interval.
+    async fn get_key(&self, key_id: &str) -> Option<OidcKey> {
+        if let Some(key) = self.find_key(key_id).await {
+            return Some(key);
+        }
+
+        let mut keys_loaded_at = self.keys_loaded_at.write().await;
+        if let Some(loaded_at) = *keys_loaded_at {
+            if loaded_at.elapsed() < self.jwks_refresh_interval.get_duration() {
+                debug!("JWK with ID: {key_id} was not found, JWKS was refreshed recently.");
+                return None;
+            }
+        }
+
+        *keys_loaded_at = Some(Instant::now());
+        drop(keys_loaded_at);
+        if let Err(error) = self.refresh_keys().await {
+            error!("Cannot reload JWKS, error: {error}");
+            return None;
+        }
+
+        self.find_key(key_id).await
+    }
+
+    async fn find_key(&self, key_id: &str) -> Option<OidcKey> {
+        let keys = self.keys.read().await;
+        if let Some(key) = keys.get(key_id) {
+            return Some(key.clone());
+        }
+
+        // The token without the key ID can be validated only with the single available key.
+        if key_id.is_empty() && keys.len() == 1 {
+            return keys.values().next().cloned();
+        }
+
+        None
+    }
+
+    pub async fn validate(&self, token: &str) -> Result<OidcIdentity, IggyError> {
+        let header = decode_header(token).map_err(|error| {
+            debug!("Cannot decode the header of JWT, error: {error}");
+            IggyError::Unauthenticated
+        })?;
+
+        let key_id = header.kid.unwrap_or_default();
+        let key = self.get_key(&key_id).await;
+        if key.is_none() {
+            debug!("JWK with ID: {key_id} was not found.");
+            return Err(IggyError::Unauthenticated);
+        }
+
+        // The algorithm is taken from the key rather than from the header controlled by the token issuer.
+        let key = key.unwrap();
+        let mut validation = Validation::new(key.algorithms[0]);
+        validation.algorithms = key.algorithms;
+        validation.set_issuer(&self.valid_issuers);
+        validation.set_audience(&self.valid_audiences);
+        validation.leeway = self.clock_skew.as_secs() as u64;
+
+        let claims = decode::<Value>(token, &key.key, &validation)
+            .map_err(|error| {
+                debug!("Invalid external JWT, error: {error}");
+                IggyError::Unauthenticated
+            })?
+            .claims;
+        self.map_identity(&claims)
+    }
+
+    fn map_identity(&self, claims: &Value) -> Result<OidcIdentity, IggyError> {
+        let username = get_claim(claims, &self.username_claim).and_then(Value::as_str);
+        if username.is_none() || username.unwrap().is_empty() {
+            debug!("JWT is missing the username claim: {}", self.username_claim);
+            return Err(IggyError::Unauthenticated);
+        }
+
+        let roles = match self.roles_claim.is_empty() {
+            true => None,
+            false => match get_claim(claims, &self.roles_claim) {
+                Some(Value::Array(roles)) => Some(
+                    roles
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(|role| role.to_string())
+                        .collect(),
+                ),
+                Some(Value::String(roles)) => Some(
+                    roles
+                        .split_whitespace()
+                        .map(|role| role.to_string())
+                        .collect(),
+                ),
+                // The missing claim must not strip the user of all the roles.
+                _ => {
+                    debug!(
+                        "JWT is missing the roles claim: {}, the current roles will be kept.",
+                        self.roles_claim
+                    );
+                    None
+                }
+            },
+        };
+
+        Ok(OidcIdentity {
+            username: username.unwrap().to_string(),
+            roles,
+            token_id: claims
+                .get("jti")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            expiry: claims.get("exp").and_then(Value::as_u64).unwrap_or(0),
+        })
+    }
+}
+
+/// Returns the algorithms allowed for the key, either the one specified by the `alg` parameter,
+/// or the ones matching its type. The symmetric algorithms are never allowed, as they would allow
+/// anyone knowing the shared secret to issue the tokens.
+fn get_algorithms(jwk: &Jwk) -> Vec<Algorithm> {
+    if let Some(algorithm) = &jwk.common.key_algorithm {
+        return match algorithm {
+            KeyAlgorithm::ES256 => vec![Algorithm::ES256],
+            KeyAlgorithm::ES384 => vec![Algorithm::ES384],
+            KeyAlgorithm::RS256 => vec![Algorithm::RS256],
+            KeyAlgorithm::RS384 => vec![Algorithm::RS384],
+            KeyAlgorithm::RS512 => vec![Algorithm::RS512],
+            KeyAlgorithm::PS256 => vec![Algorithm::PS256],
+            KeyAlgorithm::PS384 => vec![Algorithm::PS384],
+            KeyAlgorithm::PS512 => vec![Algorithm::PS512],
+            KeyAlgorithm::EdDSA => vec![Algorithm::EdDSA],
+            _ => Vec::new(),
+        };
+    }
+
+    match &jwk.algorithm {
+        AlgorithmParameters::EllipticCurve(parameters) => match parameters.curve {
+            EllipticCurve::P256 => vec![Algorithm::ES256],
+            EllipticCurve::P384 => vec![Algorithm::ES384],
+            _ => Vec::new(),
+        },
+        AlgorithmParameters::RSA(_) => vec![
+            Algorithm::RS256,
+            Algorithm::RS384,
+            Algorithm::RS512,
+            Algorithm::PS256,
+            Algorithm::PS384,
+            Algorithm::PS512,
+        ],
+        AlgorithmParameters::OctetKeyPair(_) => vec![Algorithm::EdDSA],
+        AlgorithmParameters::OctetKey(_) => Vec::new(),
+    }
+}
+
+/// Returns the claim by its name, where the nested claims are separated with the dots, e.g. `realm_access.roles`.
+fn get_claim<'a>(claims: &'a Value, name: &str) -> Option<&'a Value> {
+    if let Some(claim) = claims.get(name) {
+        return Some(claim);
+    }
+
+    let mut claim = claims;
+    for part in name.split('.') {
+        claim = claim.get(part)?;
+    }
+    Some(claim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::utils::test_key::TestKey;
+    use iggy::utils::timestamp::IggyTimestamp;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+
+    const ISSUER: &str = "https://idp.iggy.rs";
+    const AUDIENCE: &str = "iggy";
+
+    fn create_claims(issuer: &str, expiry: u64) -> Value {
+        json!({
+            "jti": "8d4e1f2a",
+            "sub": "6f1c2b3a",
+            "preferred_username": "user1",
+            "iss": issuer,
+            "aud": AUDIENCE,
+            "exp": expiry,
+            "realm_access": { "roles": ["producers", "consumers"] },
+        })
+    }
+
+    fn get_expiry() -> u64 {
+        IggyTimestamp::now().to_secs() + 3600
+    }
+
+    fn write_jwks(path: &str, keys: &[Value]) {
+        let jwks = json!({ "keys": keys });
+        std::fs::write(path, jwks.to_string()).unwrap();
+    }
+
+    async fn create_validator(path: &str, jwks_refresh_interval: &str) -> OidcValidator {
+        let config = OidcConfig {
+            enabled: true,
+            jwks: path.to_string(),
+            jwks_refresh_interval: jwks_refresh_interval.parse().unwrap(),
+            valid_issuers: vec![ISSUER.to_string()],
+            valid_audiences: vec![AUDIENCE.to_string()],
+            roles_claim: "realm_access.roles".to_string(),
+            ..Default::default()
+        };
+        let validator = OidcValidator::new(&config);
+        validator.load_keys().await.unwrap();
+        validator
+    }
+
+    fn get_jwks_path() -> String {
+        std::env::temp_dir()
+            .join(format!("iggy-jwks-{}.json", uuid::Uuid::new_v4()))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn token_signed_with_key_from_jwks_should_be_mapped_to_identity() {
+        let path = get_jwks_path();
+        let key = TestKey::generate("key-1");
+        write_jwks(&path, &[key.jwk()]);
+        let validator = create_validator(&path, "5m").await;
+        let expiry = get_expiry();
+
+        let identity = validator
+            .validate(&key.sign(&create_claims(ISSUER, expiry)))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            identity,
+            OidcIdentity {
+                username: "user1".to_string(),
+                roles: Some(vec!["producers".to_string(), "consumers".to_string()]),
+                token_id: "8d4e1f2a".to_string(),
+                expiry,
+            }
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn token_without_roles_claim_should_be_mapped_to_identity_without_roles() {
+        let path = get_jwks_path();
+        let key = TestKey::generate("key-1");
+        write_jwks(&path, &[key.jwk()]);
+        let validator = create_validator(&path, "5m").await;
+        let mut claims = create_claims(ISSUER, get_expiry());
+        claims.as_object_mut().unwrap().remove("realm_access");
+
+        let identity = validator.validate(&key.sign(&claims)).await.unwrap();
+
+        assert_eq!(identity.username, "user1");
+        assert!(identity.roles.is_none());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn token_with_invalid_issuer_or_expiry_should_be_rejected() {
+        let path = get_jwks_path();
+        let key = TestKey::generate("key-1");
+        write_jwks(&path, &[key.jwk()]);
+        let validator = create_validator(&path, "5m").await;
+
+        let invalid_issuer = validator
+            .validate(&key.sign(&create_claims("https://other.idp", get_expiry())))
+            .await;
+        let expired = validator
+            .validate(&key.sign(&create_claims(ISSUER, 1000)))
+            .await;
+
+        assert!(matches!(invalid_issuer, Err(IggyError::Unauthenticated)));
+        assert!(matches!(expired, Err(IggyError::Unauthenticated)));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn token_signed_with_unknown_key_or_shared_secret_should_be_rejected() {
+        let path = get_jwks_path();
+        let key = TestKey::generate("key-1");
+        let unknown_key = TestKey::generate("key-1");
+        write_jwks(&path, &[key.jwk()]);
+        let validator = create_validator(&path, "5m").await;
+        let claims = create_claims(ISSUER, get_expiry());
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some(key.id.clone());
+        let symmetric_token =
+            encode(&header, &claims, &EncodingKey::from_secret(b"secret")).unwrap();
+
+        assert!(validator
+            .validate(&unknown_key.sign(&claims))
+            .await
+            .is_err());
+        assert!(validator.validate(&symmetric_token).await.is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn token_with_algorithm_other_than_key_algorithm_should_be_rejected() {
+        let path = get_jwks_path();
+        let key = TestKey::generate("key-1");
+        let mut jwk = key.jwk();
+        jwk["alg"] = json!("ES384");
+        write_jwks(&path, &[jwk]);
+        let validator = create_validator(&path, "5m").await;
+
+        let identity = validator
+            .validate(&key.sign(&create_claims(ISSUER, get_expiry())))
+            .await;
+
+        assert!(matches!(identity, Err(IggyError::Unauthenticated)));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn jwks_should_be_reloaded_given_token_signed_with_rotated_key() {
+        let path = get_jwks_path();
+        let key = TestKey::generate("key-1");
+        let rotated_key = TestKey::generate("key-2");
+        write_jwks(&path, &[key.jwk()]);
+        let validator = create_validator(&path, "0s").await;
+        write_jwks(&path, &[key.jwk(), rotated_key.jwk()]);
+
+        let identity = validator
+            .validate(&rotated_key.sign(&create_claims(ISSUER, get_expiry())))
+            .await;
+
+        assert!(identity.is_ok());
+        std::fs::remove_file(path).unwrap();
+    }
+}
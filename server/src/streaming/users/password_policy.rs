@@ -0,0 +1,78 @@
+use crate::configs::system::PasswordPolicyConfig;
+use iggy::error::IggyError;
+
+pub fn validate_password(config: &PasswordPolicyConfig, password: &str) -> Result<(), IggyError> {
+    if (password.chars().count() as u32) < config.min_length {
+        return Err(IggyError::PasswordPolicyViolation(format!(
+            "password must be at least {} characters long",
+            config.min_length
+        )));
+    }
+
+    if config.require_uppercase && !password.chars().any(|c| c.is_uppercase()) {
+        return Err(IggyError::PasswordPolicyViolation(
+            "password must contain an uppercase letter".to_string(),
+        ));
+    }
+
+    if config.require_lowercase && !password.chars().any(|c| c.is_lowercase()) {
+        return Err(IggyError::PasswordPolicyViolation(
+            "password must contain a lowercase letter".to_string(),
+        ));
+    }
+
+    if config.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+        return Err(IggyError::PasswordPolicyViolation(
+            "password must contain a digit".to_string(),
+        ));
+    }
+
+    if config.require_special_character && password.chars().all(|c| c.is_alphanumeric()) {
+        return Err(IggyError::PasswordPolicyViolation(
+            "password must contain a special character".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strict_policy() -> PasswordPolicyConfig {
+        PasswordPolicyConfig {
+            min_length: 8,
+            require_uppercase: true,
+            require_lowercase: true,
+            require_digit: true,
+            require_special_character: true,
+        }
+    }
+
+    #[test]
+    fn any_password_should_be_valid_given_default_policy() {
+        let config = PasswordPolicyConfig::default();
+        assert!(validate_password(&config, "abc").is_ok());
+    }
+
+    #[test]
+    fn password_should_be_valid_given_all_rules_are_met() {
+        assert!(validate_password(&strict_policy(), "Secret#123").is_ok());
+    }
+
+    #[test]
+    fn password_should_be_invalid_given_any_rule_is_not_met() {
+        let config = strict_policy();
+        for password in [
+            "Sec#1",
+            "secret#123",
+            "SECRET#123",
+            "Secret#abc",
+            "Secret1234",
+        ] {
+            let result = validate_password(&config, password);
+            assert!(matches!(result, Err(IggyError::PasswordPolicyViolation(_))));
+        }
+    }
+}
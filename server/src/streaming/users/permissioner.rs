@@ -1,7 +1,12 @@
+use crate::streaming::roles::role::Role;
 use crate::streaming::users::user::User;
-use iggy::models::permissions::{GlobalPermissions, StreamPermissions};
+use iggy::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
 use iggy::models::user_info::UserId;
+use iggy::utils::text;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -12,6 +17,14 @@ pub struct Permissioner {
     pub(super) users_that_can_send_messages_to_all_streams: HashSet<UserId>,
     pub(super) users_that_can_poll_messages_from_specific_streams: HashSet<(UserId, u32)>,
     pub(super) users_that_can_send_messages_to_specific_streams: HashSet<(UserId, u32)>,
+    pub(super) users_streams_patterns_permissions:
+        HashMap<UserId, HashMap<String, StreamPermissions>>,
+    pub(super) roles_permissions: HashMap<u32, Permissions>,
+    pub(super) streams_names: HashMap<u32, String>,
+    pub(super) topics_names: HashMap<(u32, u32), String>,
+    pub(super) scopes_owners: HashMap<u32, UserId>,
+    pub(super) personal_access_tokens_scopes: HashMap<(UserId, String), u32>,
+    pub(super) scopes_sequence: u32,
 }
 
 impl Permissioner {
@@ -21,23 +34,58 @@ impl Permissioner {
         }
     }
 
+    pub fn init_roles(&mut self, roles: Vec<Role>) {
+        for role in roles {
+            self.roles_permissions.insert(role.id, role.permissions);
+        }
+    }
+
+    /// Updates the permissions of the role, the permissions of the users having it must be updated separately.
+    pub fn update_permissions_for_role(&mut self, role: &Role) {
+        self.roles_permissions
+            .insert(role.id, role.permissions.clone());
+    }
+
+    /// Deletes the permissions of the role, the permissions of the users having it must be updated separately.
+    pub fn delete_permissions_for_role(&mut self, role_id: u32) {
+        self.roles_permissions.remove(&role_id);
+    }
+
+    /// Initializes the effective permissions of the user, which are the union of its own permissions and the permissions of its roles.
     pub fn init_permissions_for_user(&mut self, user: User) {
-        if user.permissions.is_none() {
+        let mut permissions = user.permissions;
+        for role_id in &user.roles {
+            if let Some(role_permissions) = self.roles_permissions.get(role_id) {
+                permissions = Some(match permissions {
+                    Some(permissions) => permissions.union(role_permissions),
+                    None => role_permissions.clone(),
+                });
+            }
+        }
+
+        if permissions.is_none() {
             return;
         }
 
-        let permissions = user.permissions.unwrap();
+        self.init_permissions(user.id, permissions.unwrap());
+    }
+
+    fn init_permissions(&mut self, id: u32, permissions: Permissions) {
         if permissions.global.poll_messages {
             self.users_that_can_poll_messages_from_all_streams
-                .insert(user.id);
+                .insert(id);
         }
 
         if permissions.global.send_messages {
-            self.users_that_can_send_messages_to_all_streams
-                .insert(user.id);
+            self.users_that_can_send_messages_to_all_streams.insert(id);
+        }
+
+        self.users_permissions.insert(id, permissions.global);
+        if let Some(stream_patterns) = permissions.stream_patterns {
+            self.users_streams_patterns_permissions
+                .insert(id, stream_patterns);
         }
 
-        self.users_permissions.insert(user.id, permissions.global);
         if permissions.streams.is_none() {
             return;
         }
@@ -46,16 +94,16 @@ impl Permissioner {
         for (stream_id, stream) in streams {
             if stream.poll_messages {
                 self.users_that_can_poll_messages_from_specific_streams
-                    .insert((user.id, stream_id));
+                    .insert((id, stream_id));
             }
 
             if stream.send_messages {
                 self.users_that_can_send_messages_to_specific_streams
-                    .insert((user.id, stream_id));
+                    .insert((id, stream_id));
             }
 
             self.users_streams_permissions
-                .insert((user.id, stream_id), stream);
+                .insert((id, stream_id), stream);
         }
     }
 
@@ -72,9 +120,350 @@ impl Permissioner {
             .remove(&user_id);
         self.users_streams_permissions
             .retain(|(id, _), _| *id != user_id);
+        self.users_streams_patterns_permissions.remove(&user_id);
         self.users_that_can_poll_messages_from_specific_streams
             .retain(|(id, _)| *id != user_id);
         self.users_that_can_send_messages_to_specific_streams
             .retain(|(id, _)| *id != user_id);
     }
+
+    /// Initializes the scope of the personal access token, which gets its own ID allocated downwards from `u32::MAX`,
+    /// so that it never clashes with the user IDs. The sessions authenticated with the token are checked against
+    /// the permissions of the scope and the permissions of its owner, thus getting their intersection.
+    pub fn init_personal_access_token_scope(
+        &mut self,
+        user_id: UserId,
+        name: &str,
+        permissions: Permissions,
+    ) -> u32 {
+        self.delete_personal_access_token_scope(user_id, name);
+        self.scopes_sequence += 1;
+        let scope_id = u32::MAX - self.scopes_sequence;
+        self.scopes_owners.insert(scope_id, user_id);
+        self.personal_access_tokens_scopes
+            .insert((user_id, name.to_string()), scope_id);
+        self.init_permissions(scope_id, permissions);
+        scope_id
+    }
+
+    pub fn get_personal_access_token_scope(&self, user_id: UserId, name: &str) -> Option<u32> {
+        self.personal_access_tokens_scopes
+            .get(&(user_id, name.to_string()))
+            .copied()
+    }
+
+    pub fn delete_personal_access_token_scope(&mut self, user_id: UserId, name: &str) {
+        if let Some(scope_id) = self
+            .personal_access_tokens_scopes
+            .remove(&(user_id, name.to_string()))
+        {
+            self.scopes_owners.remove(&scope_id);
+            self.delete_permissions_for_user(scope_id);
+        }
+    }
+
+    pub fn delete_personal_access_tokens_scopes_for_user(&mut self, user_id: UserId) {
+        let names = self
+            .personal_access_tokens_scopes
+            .keys()
+            .filter(|(id, _)| *id == user_id)
+            .map(|(_, name)| name.clone())
+            .collect::<Vec<_>>();
+        for name in names {
+            self.delete_personal_access_token_scope(user_id, &name);
+        }
+    }
+
+    /// Returns the owner of the scope if the given ID belongs to the personal access token scope.
+    pub(super) fn get_scope_owner(&self, id: u32) -> Option<UserId> {
+        self.scopes_owners.get(&id).copied()
+    }
+
+    /// Registers the name of the stream, so that the stream pattern permissions can be resolved for it.
+    pub fn register_stream(&mut self, stream_id: u32, name: &str) {
+        self.streams_names.insert(stream_id, name.to_string());
+    }
+
+    pub fn unregister_stream(&mut self, stream_id: u32) {
+        self.streams_names.remove(&stream_id);
+        self.topics_names.retain(|(id, _), _| *id != stream_id);
+    }
+
+    /// Registers the name of the topic, so that the topic pattern permissions can be resolved for it.
+    pub fn register_topic(&mut self, stream_id: u32, topic_id: u32, name: &str) {
+        self.topics_names
+            .insert((stream_id, topic_id), name.to_string());
+    }
+
+    pub fn unregister_topic(&mut self, stream_id: u32, topic_id: u32) {
+        self.topics_names.remove(&(stream_id, topic_id));
+    }
+
+    /// Returns the permissions of the user for the stream, which are the union of the permissions granted by its ID
+    /// and the permissions granted by all the stream patterns matching its current name.
+    pub(super) fn get_stream_permissions(
+        &self,
+        user_id: UserId,
+        stream_id: u32,
+    ) -> Option<Cow<StreamPermissions>> {
+        let stream_permissions = self
+            .users_streams_permissions
+            .get(&(user_id, stream_id))
+            .map(Cow::Borrowed);
+        let stream_patterns = self.users_streams_patterns_permissions.get(&user_id);
+        let stream_name = self.streams_names.get(&stream_id);
+        if stream_patterns.is_none() || stream_name.is_none() {
+            return stream_permissions;
+        }
+
+        let stream_name = stream_name.unwrap();
+        let mut result = stream_permissions;
+        for (pattern, permissions) in stream_patterns.unwrap() {
+            if !text::matches_pattern(pattern, stream_name) {
+                continue;
+            }
+
+            result = Some(Cow::Owned(match result {
+                Some(result) => result.union(permissions),
+                None => permissions.clone(),
+            }));
+        }
+        result
+    }
+
+    /// Returns the permissions for the topic defined in the stream permissions, which are the union of the permissions
+    /// granted by its ID and the permissions granted by all the topic patterns matching its current name.
+    pub(super) fn get_topic_permissions<'a>(
+        &self,
+        stream_permissions: &'a StreamPermissions,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Option<Cow<'a, TopicPermissions>> {
+        let topic_permissions = stream_permissions
+            .topics
+            .as_ref()
+            .and_then(|topics| topics.get(&topic_id))
+            .map(Cow::Borrowed);
+        let topic_name = self.topics_names.get(&(stream_id, topic_id));
+        if stream_permissions.topic_patterns.is_none() || topic_name.is_none() {
+            return topic_permissions;
+        }
+
+        let topic_name = topic_name.unwrap();
+        let mut result = topic_permissions;
+        for (pattern, permissions) in stream_permissions.topic_patterns.as_ref().unwrap() {
+            if !text::matches_pattern(pattern, topic_name) {
+                continue;
+            }
+
+            result = Some(Cow::Owned(match result {
+                Some(result) => result.union(permissions),
+                None => permissions.clone(),
+            }));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::user_status::UserStatus;
+
+    #[test]
+    fn user_permissions_should_be_extended_by_the_permissions_of_its_roles() {
+        let mut permissioner = Permissioner::default();
+        let readers = Role::new(
+            1,
+            "readers",
+            Permissions {
+                global: GlobalPermissions {
+                    read_users: true,
+                    ..Default::default()
+                },
+                streams: Some(HashMap::from([(
+                    1,
+                    StreamPermissions {
+                        poll_messages: true,
+                        ..Default::default()
+                    },
+                )])),
+                stream_patterns: None,
+            },
+        );
+        let senders = Role::new(
+            2,
+            "senders",
+            Permissions {
+                global: GlobalPermissions {
+                    send_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+                stream_patterns: None,
+            },
+        );
+        permissioner.init_roles(vec![readers, senders]);
+        let mut user = User::new(
+            2,
+            "user",
+            "secret",
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_servers: true,
+                    ..Default::default()
+                },
+                streams: None,
+                stream_patterns: None,
+            }),
+        );
+        user.roles = vec![1, 2, 3];
+
+        permissioner.init_permissions_for_user(user);
+
+        let global_permissions = permissioner.users_permissions.get(&2).unwrap();
+        assert!(global_permissions.read_servers);
+        assert!(global_permissions.read_users);
+        assert!(global_permissions.send_messages);
+        assert!(!global_permissions.manage_users);
+        assert!(permissioner
+            .users_that_can_send_messages_to_all_streams
+            .contains(&2));
+        assert!(permissioner
+            .users_that_can_poll_messages_from_specific_streams
+            .contains(&(2, 1)));
+        assert!(permissioner.users_streams_permissions.contains_key(&(2, 1)));
+    }
+
+    #[test]
+    fn user_without_permissions_should_get_the_permissions_of_its_roles() {
+        let mut permissioner = Permissioner::default();
+        permissioner.init_roles(vec![Role::new(
+            1,
+            "readers",
+            Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    ..Default::default()
+                },
+                streams: None,
+                stream_patterns: None,
+            },
+        )]);
+        let mut user = User::new(2, "user", "secret", UserStatus::Active, None);
+        user.roles = vec![1];
+
+        permissioner.init_permissions_for_user(user);
+
+        assert!(permissioner.users_permissions.get(&2).unwrap().read_streams);
+    }
+
+    #[test]
+    fn stream_and_topic_patterns_should_be_resolved_by_current_names() {
+        let mut permissioner = Permissioner::default();
+        permissioner.register_stream(1, "orders-eu");
+        permissioner.register_topic(1, 1, "payments");
+        permissioner.register_topic(1, 2, "refunds");
+        permissioner.register_stream(2, "invoices");
+        let user = User::new(
+            2,
+            "user",
+            "secret",
+            UserStatus::Active,
+            Some(Permissions {
+                stream_patterns: Some(HashMap::from([(
+                    "orders-*".to_string(),
+                    StreamPermissions {
+                        read_stream: true,
+                        topic_patterns: Some(HashMap::from([(
+                            "pay*".to_string(),
+                            TopicPermissions {
+                                poll_messages: true,
+                                ..Default::default()
+                            },
+                        )])),
+                        ..Default::default()
+                    },
+                )])),
+                ..Default::default()
+            }),
+        );
+
+        permissioner.init_permissions_for_user(user);
+
+        assert!(permissioner.get_stream(2, 1).is_ok());
+        assert!(permissioner.get_stream(2, 2).is_err());
+        assert!(permissioner.poll_messages(2, 1, 1).is_ok());
+        assert!(permissioner.poll_messages(2, 1, 2).is_err());
+        assert!(permissioner.append_messages(2, 1, 1).is_err());
+
+        permissioner.register_stream(2, "orders-us");
+        permissioner.register_topic(2, 1, "payments");
+        assert!(permissioner.get_stream(2, 2).is_ok());
+        assert!(permissioner.poll_messages(2, 2, 1).is_ok());
+
+        permissioner.unregister_stream(1);
+        assert!(permissioner.get_stream(2, 1).is_err());
+        assert!(permissioner.poll_messages(2, 1, 1).is_err());
+    }
+
+    #[test]
+    fn personal_access_token_scope_should_be_intersected_with_the_permissions_of_its_owner() {
+        let mut permissioner = Permissioner::default();
+        let user = User::new(
+            2,
+            "user",
+            "secret",
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    poll_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+                stream_patterns: None,
+            }),
+        );
+        permissioner.init_permissions_for_user(user);
+
+        let scope_id = permissioner.init_personal_access_token_scope(
+            2,
+            "reader",
+            Permissions {
+                global: GlobalPermissions {
+                    manage_streams: true,
+                    send_messages: true,
+                    ..Default::default()
+                },
+                streams: Some(HashMap::from([(
+                    1,
+                    StreamPermissions {
+                        poll_messages: true,
+                        ..Default::default()
+                    },
+                )])),
+                stream_patterns: None,
+            },
+        );
+
+        assert_ne!(scope_id, 2);
+        assert_eq!(
+            permissioner.get_personal_access_token_scope(2, "reader"),
+            Some(scope_id)
+        );
+        assert!(permissioner.get_stream(scope_id, 1).is_ok());
+        assert!(permissioner.create_stream(scope_id).is_err());
+        assert!(permissioner.append_messages(scope_id, 1, 1).is_err());
+        assert!(permissioner.poll_messages(scope_id, 1, 1).is_ok());
+        assert!(permissioner.poll_messages(scope_id, 2, 1).is_err());
+        assert!(permissioner.poll_messages(2, 2, 1).is_ok());
+
+        permissioner.delete_personal_access_token_scope(2, "reader");
+        assert!(permissioner
+            .get_personal_access_token_scope(2, "reader")
+            .is_none());
+        assert!(permissioner.get_stream(scope_id, 1).is_err());
+    }
 }
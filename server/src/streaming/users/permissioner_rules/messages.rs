@@ -8,6 +8,10 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.poll_messages(owner_id, stream_id, topic_id)?;
+        }
+
         if self
             .users_that_can_poll_messages_from_all_streams
             .contains(&user_id)
@@ -22,7 +26,7 @@ impl Permissioner {
             return Ok(());
         }
 
-        let stream_permissions = self.users_streams_permissions.get(&(user_id, stream_id));
+        let stream_permissions = self.get_stream_permissions(user_id, stream_id);
         if stream_permissions.is_none() {
             return Err(IggyError::Unauthorized);
         }
@@ -32,12 +36,9 @@ impl Permissioner {
             return Ok(());
         }
 
-        if stream_permissions.topics.is_none() {
-            return Err(IggyError::Unauthorized);
-        }
-
-        let topic_permissions = stream_permissions.topics.as_ref().unwrap();
-        if let Some(topic_permissions) = topic_permissions.get(&topic_id) {
+        if let Some(topic_permissions) =
+            self.get_topic_permissions(&stream_permissions, stream_id, topic_id)
+        {
             return match topic_permissions.poll_messages {
                 true => Ok(()),
                 false => Err(IggyError::Unauthorized),
@@ -53,6 +54,10 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.append_messages(owner_id, stream_id, topic_id)?;
+        }
+
         if self
             .users_that_can_send_messages_to_all_streams
             .contains(&user_id)
@@ -67,7 +72,7 @@ impl Permissioner {
             return Ok(());
         }
 
-        let stream_permissions = self.users_streams_permissions.get(&(user_id, stream_id));
+        let stream_permissions = self.get_stream_permissions(user_id, stream_id);
         if stream_permissions.is_none() {
             return Err(IggyError::Unauthorized);
         }
@@ -77,12 +82,9 @@ impl Permissioner {
             return Ok(());
         }
 
-        if stream_permissions.topics.is_none() {
-            return Err(IggyError::Unauthorized);
-        }
-
-        let topic_permissions = stream_permissions.topics.as_ref().unwrap();
-        if let Some(topic_permissions) = topic_permissions.get(&topic_id) {
+        if let Some(topic_permissions) =
+            self.get_topic_permissions(&stream_permissions, stream_id, topic_id)
+        {
             return match topic_permissions.send_messages {
                 true => Ok(()),
                 false => Err(IggyError::Unauthorized),
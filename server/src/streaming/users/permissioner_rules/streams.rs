@@ -3,14 +3,17 @@ use iggy::error::IggyError;
 
 impl Permissioner {
     pub fn get_stream(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.get_stream(owner_id, stream_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.read_streams {
                 return Ok(());
             }
         }
 
-        if let Some(stream_permissions) = self.users_streams_permissions.get(&(user_id, stream_id))
-        {
+        if let Some(stream_permissions) = self.get_stream_permissions(user_id, stream_id) {
             if stream_permissions.manage_stream || stream_permissions.read_stream {
                 return Ok(());
             }
@@ -20,6 +23,10 @@ impl Permissioner {
     }
 
     pub fn get_streams(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.get_streams(owner_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.read_streams {
                 return Ok(());
@@ -30,6 +37,10 @@ impl Permissioner {
     }
 
     pub fn create_stream(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.create_stream(owner_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams {
                 return Ok(());
@@ -52,13 +63,17 @@ impl Permissioner {
     }
 
     fn manage_stream(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.manage_stream(owner_id, stream_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams {
                 return Ok(());
             }
         }
 
-        let stream_permissions = self.users_streams_permissions.get(&(user_id, stream_id));
+        let stream_permissions = self.get_stream_permissions(user_id, stream_id);
         if let Some(stream_permissions) = stream_permissions {
             if stream_permissions.manage_stream {
                 return Ok(());
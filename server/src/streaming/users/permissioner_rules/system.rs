@@ -14,7 +14,37 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
+    pub fn get_audit_log(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn reload_config(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_servers(user_id)
+    }
+
+    pub fn set_log_filter(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_servers(user_id)
+    }
+
+    fn manage_servers(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.manage_servers(owner_id)?;
+        }
+
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
     fn get_server_info(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.get_server_info(owner_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_servers || global_permissions.read_servers {
                 return Ok(());
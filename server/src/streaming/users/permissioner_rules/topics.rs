@@ -3,6 +3,10 @@ use iggy::error::IggyError;
 
 impl Permissioner {
     pub fn get_topic(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.get_topic(owner_id, stream_id, topic_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.read_streams
                 || global_permissions.manage_streams
@@ -13,14 +17,13 @@ impl Permissioner {
             }
         }
 
-        if let Some(stream_permissions) = self.users_streams_permissions.get(&(user_id, stream_id))
-        {
+        if let Some(stream_permissions) = self.get_stream_permissions(user_id, stream_id) {
             if stream_permissions.manage_topics || stream_permissions.read_topics {
                 return Ok(());
             }
 
             if let Some(topic_permissions) =
-                stream_permissions.topics.as_ref().unwrap().get(&topic_id)
+                self.get_topic_permissions(&stream_permissions, stream_id, topic_id)
             {
                 if topic_permissions.manage_topic || topic_permissions.read_topic {
                     return Ok(());
@@ -32,6 +35,10 @@ impl Permissioner {
     }
 
     pub fn get_topics(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.get_topics(owner_id, stream_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.read_streams
                 || global_permissions.manage_streams
@@ -42,14 +49,15 @@ impl Permissioner {
             }
         }
 
-        if let Some(stream_permissions) = self.users_streams_permissions.get(&(user_id, stream_id))
-        {
+        if let Some(stream_permissions) = self.get_stream_permissions(user_id, stream_id) {
             if stream_permissions.manage_topics || stream_permissions.read_topics {
                 return Ok(());
             }
 
-            if let Some(topic_permissions) =
-                stream_permissions.topics.as_ref().unwrap().get(&stream_id)
+            if let Some(topic_permissions) = stream_permissions
+                .topics
+                .as_ref()
+                .and_then(|topics| topics.get(&stream_id))
             {
                 if topic_permissions.manage_topic || topic_permissions.read_topic {
                     return Ok(());
@@ -61,14 +69,17 @@ impl Permissioner {
     }
 
     pub fn create_topic(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.create_topic(owner_id, stream_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.manage_topics {
                 return Ok(());
             }
         }
 
-        if let Some(stream_permissions) = self.users_streams_permissions.get(&(user_id, stream_id))
-        {
+        if let Some(stream_permissions) = self.get_stream_permissions(user_id, stream_id) {
             if stream_permissions.manage_topics {
                 return Ok(());
             }
@@ -105,20 +116,23 @@ impl Permissioner {
     }
 
     fn manage_topic(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.manage_topic(owner_id, stream_id, topic_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.manage_topics {
                 return Ok(());
             }
         }
 
-        if let Some(stream_permissions) = self.users_streams_permissions.get(&(user_id, stream_id))
-        {
+        if let Some(stream_permissions) = self.get_stream_permissions(user_id, stream_id) {
             if stream_permissions.manage_topics {
                 return Ok(());
             }
 
             if let Some(topic_permissions) =
-                stream_permissions.topics.as_ref().unwrap().get(&topic_id)
+                self.get_topic_permissions(&stream_permissions, stream_id, topic_id)
             {
                 if topic_permissions.manage_topic {
                     return Ok(());
@@ -26,11 +26,47 @@ impl Permissioner {
         self.manager_users(user_id)
     }
 
+    pub fn update_quotas(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manager_users(user_id)
+    }
+
+    pub fn update_user_roles(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manager_users(user_id)
+    }
+
+    pub fn unlock_user(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manager_users(user_id)
+    }
+
     pub fn change_password(&self, user_id: u32) -> Result<(), IggyError> {
         self.manager_users(user_id)
     }
 
+    pub fn get_role(&self, user_id: u32) -> Result<(), IggyError> {
+        self.read_users(user_id)
+    }
+
+    pub fn get_roles(&self, user_id: u32) -> Result<(), IggyError> {
+        self.read_users(user_id)
+    }
+
+    pub fn create_role(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manager_users(user_id)
+    }
+
+    pub fn delete_role(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manager_users(user_id)
+    }
+
+    pub fn update_role(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manager_users(user_id)
+    }
+
     fn manager_users(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.manager_users(owner_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_users {
                 return Ok(());
@@ -41,6 +77,10 @@ impl Permissioner {
     }
 
     fn read_users(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.get_scope_owner(user_id) {
+            self.read_users(owner_id)?;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_users || global_permissions.read_users {
                 return Ok(());
@@ -117,6 +117,10 @@ impl Storage<User> for FileUserStorage {
                 user.password = user_data.password;
                 user.created_at = user_data.created_at;
                 user.permissions = user_data.permissions;
+                user.quotas = user_data.quotas;
+                user.roles = user_data.roles;
+                user.failed_login_attempts = user_data.failed_login_attempts;
+                user.locked_until = user_data.locked_until;
                 Ok(())
             }
             Err(err) => {
@@ -1,4 +1,5 @@
 use crate::streaming::utils::crypto;
+use iggy::models::user_quotas::UserQuotas;
 use iggy::models::user_status::UserStatus;
 use iggy::models::{permissions::Permissions, user_info::UserId};
 use iggy::users::defaults::*;
@@ -13,6 +14,14 @@ pub struct User {
     pub password: String,
     pub created_at: u64,
     pub permissions: Option<Permissions>,
+    #[serde(default)]
+    pub quotas: Option<UserQuotas>,
+    #[serde(default)]
+    pub roles: Vec<u32>,
+    #[serde(default)]
+    pub failed_login_attempts: u32,
+    #[serde(default)]
+    pub locked_until: u64,
 }
 
 impl Default for User {
@@ -24,6 +33,10 @@ impl Default for User {
             password: "secret".to_string(),
             created_at: IggyTimestamp::now().to_micros(),
             permissions: None,
+            quotas: None,
+            roles: Vec::new(),
+            failed_login_attempts: 0,
+            locked_until: 0,
         }
     }
 }
@@ -50,6 +63,10 @@ impl User {
             created_at: IggyTimestamp::now().to_micros(),
             status,
             permissions,
+            quotas: None,
+            roles: Vec::new(),
+            failed_login_attempts: 0,
+            locked_until: 0,
         }
     }
 
@@ -70,6 +87,18 @@ impl User {
     pub fn is_active(&self) -> bool {
         self.status == UserStatus::Active
     }
+
+    pub fn is_locked_out(&self, now: u64) -> bool {
+        self.locked_until > now
+    }
+
+    pub fn get_locked_until(&self) -> Option<u64> {
+        if self.locked_until > 0 {
+            Some(self.locked_until)
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -96,4 +125,16 @@ mod tests {
         let user = User::new(1, "test", "test", status, None);
         assert_eq!(user.status, status);
     }
+
+    #[test]
+    fn should_be_locked_out_only_until_the_lockout_expires() {
+        let mut user = User::new(1, "test", "test", UserStatus::Active, None);
+        assert!(!user.is_locked_out(100));
+        assert!(user.get_locked_until().is_none());
+
+        user.locked_until = 200;
+        assert!(user.is_locked_out(100));
+        assert!(!user.is_locked_out(200));
+        assert_eq!(user.get_locked_until(), Some(200));
+    }
 }
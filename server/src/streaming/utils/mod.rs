@@ -2,3 +2,5 @@ pub mod crypto;
 pub mod file;
 pub mod hash;
 pub mod random_id;
+#[cfg(test)]
+pub(crate) mod test_key;
@@ -0,0 +1,49 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::{encode, Algorithm, DecodingKey, EncodingKey, Header};
+use rcgen::{KeyPair, PKCS_ECDSA_P256_SHA256};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// The ES256 key pair with the key ID, used to sign and verify the tokens in the tests.
+pub(crate) struct TestKey {
+    pub id: String,
+    key_pair: KeyPair,
+}
+
+impl TestKey {
+    pub fn generate(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            key_pair: KeyPair::generate(&PKCS_ECDSA_P256_SHA256).unwrap(),
+        }
+    }
+
+    pub fn encoding_key(&self) -> EncodingKey {
+        EncodingKey::from_ec_pem(self.key_pair.serialize_pem().as_bytes()).unwrap()
+    }
+
+    pub fn decoding_key(&self) -> DecodingKey {
+        DecodingKey::from_ec_pem(self.key_pair.public_key_pem().as_bytes()).unwrap()
+    }
+
+    /// Returns the public key in the JWK format, as published in the JWKS by the identity provider.
+    pub fn jwk(&self) -> Value {
+        let public_key = self.key_pair.public_key_raw();
+        json!({
+            "kty": "EC",
+            "crv": "P-256",
+            "use": "sig",
+            "alg": "ES256",
+            "kid": self.id,
+            "x": URL_SAFE_NO_PAD.encode(&public_key[1..33]),
+            "y": URL_SAFE_NO_PAD.encode(&public_key[33..65]),
+        })
+    }
+
+    pub fn sign(&self, claims: &impl Serialize) -> String {
+        let mut header = Header::new(Algorithm::ES256);
+        header.kid = Some(self.id.clone());
+        encode(&header, claims, &self.encoding_key()).unwrap()
+    }
+}
@@ -1,14 +1,23 @@
+use crate::binary::client_certificate::ClientCertificateIdentity;
 use crate::binary::command;
+use crate::binary::connection_limiter::{
+    with_timeout, ConnectionLimiter, ConnectionPermit, ConnectionViolation,
+};
 use crate::binary::sender::Sender;
 use crate::server_error::ServerError;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
+use crate::streaming::shutdown::wait_for_shutdown;
 use crate::streaming::systems::system::SharedSystem;
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::Command;
+use iggy::error::IggyError;
+use iggy::tcp::pipelining::{REQUEST_ID_FLAG, REQUEST_ID_LENGTH};
 use std::io::ErrorKind;
 use std::net::SocketAddr;
-use tracing::{debug, error, info};
+use std::sync::Arc;
+use tokio::sync::{RwLock, Semaphore};
+use tracing::{debug, error, info, warn};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
 
@@ -16,35 +25,254 @@ pub(crate) async fn handle_connection(
     address: SocketAddr,
     sender: &mut dyn Sender,
     system: SharedSystem,
+    client_certificate_identity: Option<ClientCertificateIdentity>,
+    limiter: ConnectionLimiter,
+    _permit: ConnectionPermit,
 ) -> Result<(), ServerError> {
     let client_id = system.read().add_client(&address, Transport::Tcp).await;
 
-    let session = Session::from_client_id(client_id, address);
+    let session = Arc::new(Session::from_client_id(client_id, address));
+    if let Some(identity) = client_certificate_identity {
+        if let Err(error) = system
+            .read()
+            .login_with_client_certificate(&identity.username, identity.allow_root, &session)
+            .await
+        {
+            warn!("Client: {address} was not authenticated with its certificate: {error}");
+        }
+    }
+
+    let pipelined_requests = Arc::new(Semaphore::new(limiter.max_concurrent_requests()));
+    let requests_order = Arc::new(RwLock::new(()));
+    let mut shutdown = system.read().shutdown_signal.subscribe();
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
     loop {
-        let read_length = sender.read(&mut initial_buffer).await?;
+        let read_length = tokio::select! {
+            read_length = with_timeout(
+                limiter.idle_timeout(),
+                sender.read(&mut initial_buffer),
+            ) => read_length,
+            _ = wait_for_shutdown(&mut shutdown) => {
+                info!("Closing the TCP connection: {address} as the server is shutting down.");
+                return Ok(());
+            }
+        };
+        if read_length.is_none() {
+            let error = IggyError::IdleTimeout(limiter.idle_timeout().to_string());
+            return close_connection(
+                address,
+                sender,
+                &system,
+                ConnectionViolation::IdleTimeout,
+                error,
+            )
+            .await;
+        }
+
+        let read_length = read_length.unwrap()?;
         if read_length != INITIAL_BYTES_LENGTH {
-            error!(
-                "Unable to read the TCP request length, expected: {INITIAL_BYTES_LENGTH} bytes, received: {read_length} bytes.",
-            );
-            continue;
+            let error = IggyError::MalformedFrame(format!(
+                "expected: {INITIAL_BYTES_LENGTH} bytes of the request length, received: {read_length} bytes"
+            ));
+            return close_connection(
+                address,
+                sender,
+                &system,
+                ConnectionViolation::MalformedFrame,
+                error,
+            )
+            .await;
         }
 
+        // The most significant bit of the length marks the pipelined request, followed by its ID.
         let length = u32::from_le_bytes(initial_buffer);
-        debug!("Received a TCP request, length: {length}");
+        let pipelined = length & REQUEST_ID_FLAG != 0;
+        let length = length & !REQUEST_ID_FLAG;
+        debug!("Received a TCP request, length: {length}, pipelined: {pipelined}");
+        // The frame size is validated before allocating the buffer, so that the client cannot exhaust the memory.
+        if let Err(error) = limiter.validate_frame_size(length as u64) {
+            return close_connection(
+                address,
+                sender,
+                &system,
+                ConnectionViolation::FrameTooLarge,
+                error,
+            )
+            .await;
+        }
+
+        let mut request_id_buffer = [0u8; REQUEST_ID_LENGTH];
         let mut command_buffer = vec![0u8; length as usize];
-        sender.read(&mut command_buffer).await?;
-        let command = Command::from_bytes(&command_buffer)?;
+        let read_result = with_timeout(limiter.read_timeout(), async {
+            if pipelined {
+                sender.read(&mut request_id_buffer).await?;
+            }
+            sender.read(&mut command_buffer).await
+        })
+        .await;
+        if read_result.is_none() {
+            let error = IggyError::ReadTimeout(limiter.read_timeout().to_string());
+            return close_connection(
+                address,
+                sender,
+                &system,
+                ConnectionViolation::ReadTimeout,
+                error,
+            )
+            .await;
+        }
+
+        read_result.unwrap()?;
+        // Once the request ID is known, the errors are sent as the pipelined responses, so that the client can match them.
+        let request_id = u64::from_le_bytes(request_id_buffer);
+        let mut pipelined_sender = None;
+        if pipelined {
+            pipelined_sender = sender.create_pipelined_sender(request_id);
+            if pipelined_sender.is_none() {
+                let error =
+                    IggyError::MalformedFrame("pipelined requests are not supported".to_string());
+                return close_connection(
+                    address,
+                    sender,
+                    &system,
+                    ConnectionViolation::MalformedFrame,
+                    error,
+                )
+                .await;
+            }
+        }
+
+        let command = match Command::from_bytes(&command_buffer) {
+            Ok(command) => command,
+            Err(error) => {
+                let error = IggyError::MalformedFrame(error.to_string());
+                let sender: &mut dyn Sender = match pipelined_sender.as_mut() {
+                    Some(pipelined_sender) => pipelined_sender.as_mut(),
+                    None => sender,
+                };
+                return close_connection(
+                    address,
+                    sender,
+                    &system,
+                    ConnectionViolation::MalformedFrame,
+                    error,
+                )
+                .await;
+            }
+        };
         debug!("Received a TCP command: {command}, payload size: {length}");
-        let result = command::handle(&command, sender, &session, system.clone()).await;
-        if result.is_err() {
-            error!("Error when handling the TCP request: {:?}", result.err());
+        if pipelined_sender.is_none() {
+            // The request is handled once all the pipelined ones received before it have completed.
+            let _order = requests_order.write().await;
+            let result =
+                command::handle(&command, sender, &session, system.clone(), Transport::Tcp).await;
+            match result {
+                Ok(_) => debug!("Sent a TCP response."),
+                Err(error) => error!("Error when handling the TCP request: {:?}", error),
+            }
             continue;
         }
-        debug!("Sent a TCP response.");
+
+        // Waiting for the permit stops reading the next requests, once the limit of the concurrent ones is reached.
+        let permit = pipelined_requests.clone().acquire_owned().await.unwrap();
+        // The requests which may change the state (e.g. send messages or log in) are handled in their arrival order.
+        // The read-only ones run concurrently, but only after all the preceding requests changing the state have completed.
+        let (shared_order, exclusive_order) = match is_read_only(&command) {
+            true => (Some(requests_order.clone().read_owned().await), None),
+            false => (None, Some(requests_order.clone().write_owned().await)),
+        };
+        let mut pipelined_sender = pipelined_sender.unwrap();
+        let session = session.clone();
+        let system = system.clone();
+        tokio::spawn(async move {
+            let _permit = permit;
+            let _order = (shared_order, exclusive_order);
+            let result = command::handle(
+                &command,
+                pipelined_sender.as_mut(),
+                &session,
+                system,
+                Transport::Tcp,
+            )
+            .await;
+            if let Err(error) = result {
+                error!("Error when handling the pipelined TCP request with ID: {request_id}, error: {error}");
+                return;
+            }
+            debug!("Sent a TCP response to the pipelined request with ID: {request_id}.");
+        });
     }
 }
 
+fn is_read_only(command: &Command) -> bool {
+    matches!(
+        command,
+        Command::Ping(_)
+            | Command::GetHealth(_)
+            | Command::GetProtocolVersion(_)
+            | Command::GetStats(_)
+            | Command::GetMe(_)
+            | Command::GetClient(_)
+            | Command::GetClients(_)
+            | Command::GetAuditLog(_)
+            | Command::GetUser(_)
+            | Command::GetUsers(_)
+            | Command::GetPersonalAccessTokens(_)
+            | Command::GetRole(_)
+            | Command::GetRoles(_)
+            | Command::GetConsumerOffset(_)
+            | Command::GetStream(_)
+            | Command::GetStreams(_)
+            | Command::GetTopic(_)
+            | Command::GetTopics(_)
+            | Command::GetConsumerGroup(_)
+            | Command::GetConsumerGroups(_)
+    )
+}
+
+/// Sends the error describing the violation to the client and records it, the connection is closed afterwards.
+/// Closes the connection exceeding the limit of the connections per IP address, before handling any command.
+pub(crate) async fn reject_connection(
+    address: SocketAddr,
+    sender: &mut dyn Sender,
+    system: &SharedSystem,
+    error: IggyError,
+) -> Result<(), ServerError> {
+    close_connection(
+        address,
+        sender,
+        system,
+        ConnectionViolation::TooManyConnections,
+        error,
+    )
+    .await
+}
+
+async fn close_connection(
+    address: SocketAddr,
+    sender: &mut dyn Sender,
+    system: &SharedSystem,
+    violation: ConnectionViolation,
+    error: IggyError,
+) -> Result<(), ServerError> {
+    record_violation(address, system, violation, &error);
+    sender.send_error_response(error, &[]).await?;
+    Ok(())
+}
+
+pub(crate) fn record_violation(
+    address: SocketAddr,
+    system: &SharedSystem,
+    violation: ConnectionViolation,
+    error: &IggyError,
+) {
+    warn!("Closing the TCP connection: {address} due to {violation}, error: {error}");
+    system
+        .read()
+        .metrics
+        .record_connection_violation(&Transport::Tcp.to_string(), violation.as_str());
+}
+
 pub(crate) fn handle_error(error: ServerError) {
     match error {
         ServerError::IoError(error) => match error.kind() {
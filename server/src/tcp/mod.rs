@@ -1,7 +1,7 @@
 pub mod connection_handler;
 pub mod sender;
 pub mod tcp_listener;
-mod tcp_sender;
+pub mod tcp_sender;
 pub mod tcp_server;
 pub mod tcp_tls_listener;
 pub mod tcp_tls_sender;
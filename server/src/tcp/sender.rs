@@ -6,7 +6,7 @@ const STATUS_OK: &[u8] = &[0; 4];
 
 pub(crate) async fn read<T>(stream: &mut T, buffer: &mut [u8]) -> Result<usize, IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncRead + Unpin,
 {
     let read_bytes = stream.read_exact(buffer).await;
     if let Err(error) = read_bytes {
@@ -18,26 +18,50 @@ where
 
 pub(crate) async fn send_empty_ok_response<T>(stream: &mut T) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
     send_ok_response(stream, &[]).await
 }
 
 pub(crate) async fn send_ok_response<T>(stream: &mut T, payload: &[u8]) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
     send_response(stream, STATUS_OK, payload).await
 }
 
+pub(crate) async fn send_pipelined_ok_response<T>(
+    stream: &mut T,
+    request_id: u64,
+    payload: &[u8],
+) -> Result<(), IggyError>
+where
+    T: AsyncWrite + Unpin,
+{
+    send_pipelined_response(stream, request_id, STATUS_OK, payload).await
+}
+
+pub(crate) async fn send_pipelined_error_response<T>(
+    stream: &mut T,
+    request_id: u64,
+    error: IggyError,
+    payload: &[u8],
+) -> Result<(), IggyError>
+where
+    T: AsyncWrite + Unpin,
+{
+    send_pipelined_response(stream, request_id, &error.as_code().to_le_bytes(), payload).await
+}
+
 pub(crate) async fn send_error_response<T>(
     stream: &mut T,
     error: IggyError,
+    payload: &[u8],
 ) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
-    send_response(stream, &error.as_code().to_le_bytes(), &[]).await
+    send_response(stream, &error.as_code().to_le_bytes(), payload).await
 }
 
 pub(crate) async fn send_response<T>(
@@ -46,7 +70,7 @@ pub(crate) async fn send_response<T>(
     payload: &[u8],
 ) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
     debug!("Sending response with status: {:?}...", status);
     let length = (payload.len() as u32).to_le_bytes();
@@ -56,3 +80,27 @@ where
     debug!("Sent response with status: {:?}", status);
     Ok(())
 }
+
+/// Sends the response to the pipelined request, the request ID follows the status and length,
+/// so that the client can match the responses received out of order.
+pub(crate) async fn send_pipelined_response<T>(
+    stream: &mut T,
+    request_id: u64,
+    status: &[u8],
+    payload: &[u8],
+) -> Result<(), IggyError>
+where
+    T: AsyncWrite + Unpin,
+{
+    debug!("Sending response to the request with ID: {request_id}, status: {status:?}...");
+    let length = (payload.len() as u32).to_le_bytes();
+    stream
+        .write_all(
+            &[status, &length, &request_id.to_le_bytes(), payload]
+                .as_slice()
+                .concat(),
+        )
+        .await?;
+    debug!("Sent response to the request with ID: {request_id}, status: {status:?}");
+    Ok(())
+}
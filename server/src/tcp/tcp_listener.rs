@@ -1,12 +1,14 @@
+use crate::binary::connection_limiter::ConnectionLimiter;
+use crate::streaming::shutdown::wait_for_shutdown;
 use crate::streaming::systems::system::SharedSystem;
-use crate::tcp::connection_handler::{handle_connection, handle_error};
+use crate::tcp::connection_handler::{handle_connection, handle_error, reject_connection};
 use crate::tcp::tcp_sender::TcpSender;
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
 use tokio::sync::oneshot;
 use tracing::{error, info};
 
-pub async fn start(address: &str, system: SharedSystem) -> SocketAddr {
+pub async fn start(address: &str, limiter: ConnectionLimiter, system: SharedSystem) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
@@ -25,19 +27,43 @@ pub async fn start(address: &str, system: SharedSystem) -> SocketAddr {
             )
         });
 
+        let mut shutdown = system.read().shutdown_signal.subscribe();
         loop {
-            match listener.accept().await {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = wait_for_shutdown(&mut shutdown) => {
+                    info!("TCP listener has stopped accepting new connections.");
+                    break;
+                }
+            };
+
+            match accepted {
                 Ok((stream, address)) => {
                     info!("Accepted new TCP connection: {}", address);
                     let system = system.clone();
-                    let mut sender = TcpSender { stream };
+                    let limiter = limiter.clone();
+                    let mut sender = TcpSender::new(stream);
                     tokio::spawn(async move {
-                        if let Err(error) =
-                            handle_connection(address, &mut sender, system.clone()).await
-                        {
+                        let result = match limiter.try_acquire(address.ip()) {
+                            Ok(permit) => {
+                                handle_connection(
+                                    address,
+                                    &mut sender,
+                                    system.clone(),
+                                    None,
+                                    limiter,
+                                    permit,
+                                )
+                                .await
+                            }
+                            Err(error) => {
+                                reject_connection(address, &mut sender, &system, error).await
+                            }
+                        };
+                        if let Err(error) = result {
                             handle_error(error);
-                            system.read().delete_client(&address).await;
                         }
+                        system.read().delete_client(&address).await;
                     });
                 }
                 Err(error) => error!("Unable to accept TCP socket, error: {}", error),
@@ -2,31 +2,102 @@ use crate::binary::sender::Sender;
 use crate::tcp::sender;
 use async_trait::async_trait;
 use iggy::error::IggyError;
-use tokio::net::TcpStream;
+use std::fmt::Debug;
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncWrite, ReadHalf, WriteHalf};
+use tokio::sync::Mutex;
 
+/// Sender of the TCP connection (plain or TLS), the write half is shared with the senders
+/// of the pipelined requests, which are handled concurrently.
 #[derive(Debug)]
-pub struct TcpSender {
-    pub(crate) stream: TcpStream,
+pub struct TcpSender<S> {
+    reader: ReadHalf<S>,
+    writer: Arc<Mutex<WriteHalf<S>>>,
 }
 
-unsafe impl Send for TcpSender {}
-unsafe impl Sync for TcpSender {}
+/// Sender of the response to the pipelined request, prefixed with the request ID.
+#[derive(Debug)]
+pub struct PipelinedTcpSender<S> {
+    request_id: u64,
+    writer: Arc<Mutex<WriteHalf<S>>>,
+}
+
+impl<S> TcpSender<S>
+where
+    S: AsyncRead + AsyncWrite,
+{
+    pub fn new(stream: S) -> Self {
+        let (reader, writer) = tokio::io::split(stream);
+        Self {
+            reader,
+            writer: Arc::new(Mutex::new(writer)),
+        }
+    }
+}
 
 #[async_trait]
-impl Sender for TcpSender {
+impl<S> Sender for TcpSender<S>
+where
+    S: AsyncRead + AsyncWrite + Debug + Send + 'static,
+{
     async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
-        sender::read(&mut self.stream, buffer).await
+        sender::read(&mut self.reader, buffer).await
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        sender::send_empty_ok_response(&mut *self.writer.lock().await).await
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        sender::send_ok_response(&mut *self.writer.lock().await, payload).await
+    }
+
+    async fn send_error_response(
+        &mut self,
+        error: IggyError,
+        payload: &[u8],
+    ) -> Result<(), IggyError> {
+        sender::send_error_response(&mut *self.writer.lock().await, error, payload).await
+    }
+
+    fn create_pipelined_sender(&self, request_id: u64) -> Option<Box<dyn Sender>> {
+        Some(Box::new(PipelinedTcpSender {
+            request_id,
+            writer: self.writer.clone(),
+        }))
+    }
+}
+
+#[async_trait]
+impl<S> Sender for PipelinedTcpSender<S>
+where
+    S: AsyncRead + AsyncWrite + Debug + Send + 'static,
+{
+    async fn read(&mut self, _buffer: &mut [u8]) -> Result<usize, IggyError> {
+        // The requests are read only by the connection handler.
+        Err(IggyError::FeatureUnavailable)
     }
 
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
-        sender::send_empty_ok_response(&mut self.stream).await
+        self.send_ok_response(&[]).await
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
-        sender::send_ok_response(&mut self.stream, payload).await
+        sender::send_pipelined_ok_response(&mut *self.writer.lock().await, self.request_id, payload)
+            .await
     }
 
-    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        sender::send_error_response(&mut self.stream, error).await
+    async fn send_error_response(
+        &mut self,
+        error: IggyError,
+        payload: &[u8],
+    ) -> Result<(), IggyError> {
+        sender::send_pipelined_error_response(
+            &mut *self.writer.lock().await,
+            self.request_id,
+            error,
+            payload,
+        )
+        .await
     }
 }
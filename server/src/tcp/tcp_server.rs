@@ -1,3 +1,4 @@
+use crate::binary::connection_limiter::ConnectionLimiter;
 use crate::configs::tcp::TcpConfig;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::{tcp_listener, tcp_tls_listener};
@@ -13,9 +14,10 @@ pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
         "Iggy TCP"
     };
     info!("Initializing {server_name} server...");
+    let limiter = ConnectionLimiter::new(config.limits);
     let addr = match config.tls.enabled {
-        true => tcp_tls_listener::start(&config.address, config.tls, system).await,
-        false => tcp_listener::start(&config.address, system).await,
+        true => tcp_tls_listener::start(&config.address, config.tls, limiter, system).await,
+        false => tcp_listener::start(&config.address, limiter, system).await,
     };
     info!("{server_name} server has started on: {:?}", addr);
     addr
@@ -1,35 +1,40 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::future::Future;
 use std::net::SocketAddr;
+use std::sync::Arc;
 
+use crate::binary::client_certificate;
+use crate::binary::client_certificate::ClientCertificateMapper;
+use crate::binary::connection_limiter::{
+    with_timeout, ConnectionLimiter, ConnectionPermit, ConnectionViolation,
+};
+use crate::configs::reloader::wait_for_config_update;
 use crate::configs::tcp::TcpTlsConfig;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::shutdown::wait_for_shutdown;
 use crate::streaming::systems::system::SharedSystem;
-use crate::tcp::connection_handler::{handle_connection, handle_error};
-use crate::tcp::tcp_tls_sender::TcpTlsSender;
-use tokio::net::TcpListener;
+use crate::tcp::connection_handler::{handle_connection, handle_error, record_violation};
+use crate::tcp::tcp_tls_sender::{TcpMutualTlsSender, TcpTlsSender};
+use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::oneshot;
 use tokio_native_tls::native_tls;
 use tokio_native_tls::native_tls::Identity;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-pub(crate) async fn start(address: &str, config: TcpTlsConfig, system: SharedSystem) -> SocketAddr {
+pub(crate) async fn start(
+    address: &str,
+    config: TcpTlsConfig,
+    limiter: ConnectionLimiter,
+    system: SharedSystem,
+) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
-        let certificate = std::fs::read(config.certificate.clone());
-        if certificate.is_err() {
-            panic!("Unable to read certificate file.");
-        }
-
-        let identity = Identity::from_pkcs12(&certificate.unwrap(), &config.password);
-        if identity.is_err() {
-            panic!("Unable to create identity from certificate.");
-        }
-
-        let acceptor = tokio_native_tls::TlsAcceptor::from(
-            native_tls::TlsAcceptor::builder(identity.unwrap())
-                .build()
-                .unwrap(),
-        );
-
+        let mut acceptor = TlsAcceptor::create(&config)
+            .unwrap_or_else(|error| panic!("Unable to create TLS acceptor: {error}"));
+        let mut config_updates = system.read().subscribe_config_updates();
+        let mut shutdown = system.read().shutdown_signal.subscribe();
         let listener = TcpListener::bind(&address)
             .await
             .expect("Unable to start TCP TLS server.");
@@ -46,20 +51,49 @@ pub(crate) async fn start(address: &str, config: TcpTlsConfig, system: SharedSys
         });
 
         loop {
-            match listener.accept().await {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = wait_for_shutdown(&mut shutdown) => {
+                    info!("TCP TLS listener has stopped accepting new connections.");
+                    break;
+                }
+                config = wait_for_config_update(&mut config_updates) => {
+                    match TlsAcceptor::create(&config.tcp.tls) {
+                        Ok(reloaded_acceptor) => {
+                            acceptor = reloaded_acceptor;
+                            info!("TCP TLS certificate has been reloaded.");
+                        }
+                        Err(error) => error!("Unable to reload TCP TLS certificate, error: {error}"),
+                    }
+                    continue;
+                }
+            };
+
+            match accepted {
                 Ok((stream, address)) => {
                     info!("Accepted new TCP TLS connection: {}", address);
+                    // The permit is acquired before the handshake, so that the connections exceeding the limit
+                    // are closed without spending the resources on it.
+                    let permit = match limiter.try_acquire(address.ip()) {
+                        Ok(permit) => permit,
+                        Err(error) => {
+                            record_violation(
+                                address,
+                                &system,
+                                ConnectionViolation::TooManyConnections,
+                                &error,
+                            );
+                            continue;
+                        }
+                    };
+
                     let acceptor = acceptor.clone();
-                    let stream = acceptor.accept(stream).await.unwrap();
                     let system = system.clone();
-                    let mut sender = TcpTlsSender { stream };
+                    let limiter = limiter.clone();
                     tokio::spawn(async move {
-                        if let Err(error) =
-                            handle_connection(address, &mut sender, system.clone()).await
-                        {
-                            handle_error(error);
-                            system.read().delete_client(&address).await;
-                        }
+                        acceptor
+                            .handle_connection(stream, address, system, limiter, permit)
+                            .await;
                     });
                 }
                 Err(error) => error!("Unable to accept TCP TLS socket, error: {}", error),
@@ -71,3 +105,124 @@ pub(crate) async fn start(address: &str, config: TcpTlsConfig, system: SharedSys
         Err(_) => panic!("Failed to get the local address for TCP TLS listener"),
     }
 }
+
+// The native TLS acceptor cannot request the client certificates, thus the mutual TLS is handled by rustls,
+// which uses the PEM certificate and key instead of the PKCS #12 one.
+#[derive(Clone)]
+enum TlsAcceptor {
+    Native(tokio_native_tls::TlsAcceptor),
+    Mutual(tokio_rustls::TlsAcceptor, ClientCertificateMapper),
+}
+
+impl TlsAcceptor {
+    fn create(config: &TcpTlsConfig) -> Result<Self, Box<dyn Error>> {
+        if config.client_certificate.enabled {
+            return Ok(Self::Mutual(
+                create_mutual_tls_acceptor(config)?,
+                ClientCertificateMapper::new(&config.client_certificate),
+            ));
+        }
+
+        Ok(Self::Native(create_tls_acceptor(config)?))
+    }
+
+    async fn handle_connection(
+        &self,
+        stream: TcpStream,
+        address: SocketAddr,
+        system: SharedSystem,
+        limiter: ConnectionLimiter,
+        permit: ConnectionPermit,
+    ) {
+        let result = match self {
+            Self::Native(acceptor) => {
+                let stream =
+                    complete_handshake(address, &system, &limiter, acceptor.accept(stream)).await;
+                if stream.is_none() {
+                    return;
+                }
+
+                let mut sender = TcpTlsSender::new(stream.unwrap());
+                handle_connection(address, &mut sender, system.clone(), None, limiter, permit).await
+            }
+            Self::Mutual(acceptor, mapper) => {
+                let stream =
+                    complete_handshake(address, &system, &limiter, acceptor.accept(stream)).await;
+                if stream.is_none() {
+                    return;
+                }
+
+                let stream = stream.unwrap();
+                let identity = stream
+                    .get_ref()
+                    .1
+                    .peer_certificates()
+                    .and_then(|certificates| mapper.map(certificates));
+                let mut sender = TcpMutualTlsSender::new(stream);
+                handle_connection(
+                    address,
+                    &mut sender,
+                    system.clone(),
+                    identity,
+                    limiter,
+                    permit,
+                )
+                .await
+            }
+        };
+        if let Err(error) = result {
+            handle_error(error);
+        }
+        system.read().delete_client(&address).await;
+    }
+}
+
+async fn complete_handshake<S, E: Display>(
+    address: SocketAddr,
+    system: &SharedSystem,
+    limiter: &ConnectionLimiter,
+    handshake: impl Future<Output = Result<S, E>>,
+) -> Option<S> {
+    match with_timeout(limiter.read_timeout(), handshake).await {
+        Some(Ok(stream)) => Some(stream),
+        Some(Err(error)) => {
+            handle_handshake_failure(address, system, &error.to_string());
+            None
+        }
+        None => {
+            handle_handshake_failure(address, system, "timed out");
+            None
+        }
+    }
+}
+
+fn handle_handshake_failure(address: SocketAddr, system: &SharedSystem, error: &str) {
+    let violation = ConnectionViolation::TlsHandshakeFailed;
+    warn!("TLS handshake with: {address} has failed due to {violation}, error: {error}");
+    system
+        .read()
+        .metrics
+        .record_connection_violation(&Transport::Tcp.to_string(), violation.as_str());
+}
+
+fn create_tls_acceptor(
+    config: &TcpTlsConfig,
+) -> Result<tokio_native_tls::TlsAcceptor, Box<dyn Error>> {
+    let certificate = std::fs::read(&config.certificate)?;
+    let identity = Identity::from_pkcs12(&certificate, &config.password)?;
+    let acceptor = native_tls::TlsAcceptor::builder(identity).build()?;
+    Ok(tokio_native_tls::TlsAcceptor::from(acceptor))
+}
+
+fn create_mutual_tls_acceptor(
+    config: &TcpTlsConfig,
+) -> Result<tokio_rustls::TlsAcceptor, Box<dyn Error>> {
+    let (certificates, key) =
+        client_certificate::load_certificates(&config.cert_file, &config.key_file)?;
+    let verifier = client_certificate::create_verifier(&config.client_certificate)?;
+    let tls_config = rustls::ServerConfig::builder()
+        .with_safe_defaults()
+        .with_client_cert_verifier(verifier)
+        .with_single_cert(certificates, key)?;
+    Ok(tokio_rustls::TlsAcceptor::from(Arc::new(tls_config)))
+}
@@ -2,7 +2,7 @@ use iggy::client::{MessageClient, StreamClient, TopicClient};
 use iggy::clients::client::IggyClient;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
 use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -27,18 +27,21 @@ async fn create_streams(client: &IggyClient) -> Result<(), IggyError> {
         .create_stream(&CreateStream {
             stream_id: Some(PROD_STREAM_ID),
             name: "prod".to_string(),
+            quotas: None,
         })
         .await?;
     client
         .create_stream(&CreateStream {
             stream_id: Some(TEST_STREAM_ID),
             name: "test".to_string(),
+            quotas: None,
         })
         .await?;
     client
         .create_stream(&CreateStream {
             stream_id: Some(DEV_STREAM_ID),
             name: "dev".to_string(),
+            quotas: None,
         })
         .await?;
     Ok(())
@@ -152,6 +155,8 @@ async fn send_messages(client: &IggyClient) -> Result<(), IggyError> {
                         stream_id: Identifier::numeric(stream_id)?,
                         topic_id: Identifier::numeric(topic.id)?,
                         partitioning: Partitioning::balanced(),
+                        producer: None,
+                        ack: AckLevel::Memory,
                         messages,
                     })
                     .await?;